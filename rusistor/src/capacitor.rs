@@ -0,0 +1,226 @@
+use crate::{BandRole, Color, ColorCoded};
+
+/// A capacitor marked with colored bands or dots, read from the first digit on.
+/// The value is coded in pF like the resistance of a resistor in Ω.
+#[derive(PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Capacitor {
+    FourBand {
+        band1: Color,
+        band2: Color,
+        band3: Color,
+        band4: Color,
+    },
+    /// The fifth band codes the rated voltage.
+    FiveBand {
+        band1: Color,
+        band2: Color,
+        band3: Color,
+        band4: Color,
+        band5: Color,
+    },
+}
+
+#[derive(PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CapacitorSpecs {
+    pub picofarad: f64,
+    pub tolerance: f64,
+    pub voltage: Option<u32>,
+}
+
+impl Default for Capacitor {
+    fn default() -> Self {
+        // 10nF ±10%
+        Capacitor::FourBand {
+            band1: Color::Brown,
+            band2: Color::Black,
+            band3: Color::Orange,
+            band4: Color::White,
+        }
+    }
+}
+
+impl Capacitor {
+    /// The significant digit of a color, only black to white are digits.
+    pub fn digit(color: &Color) -> Option<u32> {
+        let idx = *color as u32;
+        (idx <= 9).then_some(idx)
+    }
+
+    pub fn multiplier(color: &Color) -> Option<f64> {
+        match color {
+            Color::Black => Some(1.0),
+            Color::Brown => Some(10.0),
+            Color::Red => Some(100.0),
+            Color::Orange => Some(1_000.0),
+            Color::Yellow => Some(10_000.0),
+            Color::Green => Some(100_000.0),
+            Color::Grey => Some(0.01),
+            Color::White => Some(0.1),
+            _ => None,
+        }
+    }
+
+    /// The tolerance as a fraction, e.g. 0.05 for 5%.
+    pub fn tolerance(color: &Color) -> Option<f64> {
+        match color {
+            Color::Black => Some(0.2),
+            Color::Brown => Some(0.01),
+            Color::Red => Some(0.02),
+            Color::Green => Some(0.05),
+            Color::White => Some(0.1),
+            _ => None,
+        }
+    }
+
+    /// The rated voltage in V.
+    pub fn voltage(color: &Color) -> Option<u32> {
+        match color {
+            Color::Brown => Some(100),
+            Color::Red => Some(250),
+            Color::Yellow => Some(400),
+            Color::Blue => Some(630),
+            _ => None,
+        }
+    }
+
+    pub fn band_role(band_idx: usize) -> Option<BandRole> {
+        match band_idx {
+            0 | 1 => Some(BandRole::Digit(band_idx)),
+            2 => Some(BandRole::Multiplier),
+            3 => Some(BandRole::Tolerance),
+            4 => Some(BandRole::Voltage),
+            _ => None,
+        }
+    }
+
+    fn is_valid_color_in_band(color: &Color, band_idx: usize) -> bool {
+        match band_idx {
+            0 => Capacitor::digit(color).is_some_and(|d| d > 0),
+            1 => Capacitor::digit(color).is_some(),
+            2 => Capacitor::multiplier(color).is_some(),
+            3 => Capacitor::tolerance(color).is_some(),
+            4 => Capacitor::voltage(color).is_some(),
+            _ => false,
+        }
+    }
+
+    pub fn try_create(bands: Vec<Color>) -> Result<Capacitor, String> {
+        if !bands
+            .iter()
+            .enumerate()
+            .all(|(idx, c)| Capacitor::is_valid_color_in_band(c, idx))
+        {
+            return Err(format!("no valid {}-band capacitor", bands.len()));
+        }
+        match bands[..] {
+            [band1, band2, band3, band4] => Ok(Capacitor::FourBand {
+                band1,
+                band2,
+                band3,
+                band4,
+            }),
+            [band1, band2, band3, band4, band5] => Ok(Capacitor::FiveBand {
+                band1,
+                band2,
+                band3,
+                band4,
+                band5,
+            }),
+            _ => Err(String::from("This is not a valid capacitor configuration")),
+        }
+    }
+
+    pub fn specs(&self) -> CapacitorSpecs {
+        let bands = self.bands();
+        let digits = Capacitor::digit(bands[0]).unwrap_or_default() * 10
+            + Capacitor::digit(bands[1]).unwrap_or_default();
+        let multiplier = Capacitor::multiplier(bands[2]).unwrap_or(1.0);
+        // dividing avoids float artifacts like 0.30000000000000004
+        let picofarad = if multiplier < 1.0 {
+            digits as f64 / (1.0 / multiplier).round()
+        } else {
+            digits as f64 * multiplier
+        };
+        CapacitorSpecs {
+            picofarad,
+            tolerance: Capacitor::tolerance(bands[3]).unwrap_or_default(),
+            voltage: bands.get(4).and_then(|c| Capacitor::voltage(c)),
+        }
+    }
+}
+
+impl ColorCoded for Capacitor {
+    fn bands(&self) -> Vec<&Color> {
+        match self {
+            Capacitor::FourBand {
+                band1,
+                band2,
+                band3,
+                band4,
+            } => vec![band1, band2, band3, band4],
+            Capacitor::FiveBand {
+                band1,
+                band2,
+                band3,
+                band4,
+                band5,
+            } => vec![band1, band2, band3, band4, band5],
+        }
+    }
+
+    fn with_color(&self, color: Color, band_idx: usize) -> Result<Capacitor, String> {
+        let mut current = self.bands();
+        if band_idx < current.len() {
+            current[band_idx] = &color;
+            Capacitor::try_create(current.into_iter().cloned().collect())
+        } else {
+            Err("given band_idx out of bounds".to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn calc_capacitor_specs() {
+        let capacitor = Capacitor::default();
+        assert_eq!(
+            capacitor.specs(),
+            CapacitorSpecs {
+                picofarad: 10_000.0,
+                tolerance: 0.1,
+                voltage: None
+            }
+        );
+        let capacitor = Capacitor::try_create(vec![
+            Color::Yellow,
+            Color::Violet,
+            Color::Grey,
+            Color::Green,
+            Color::Red,
+        ])
+        .unwrap();
+        assert_eq!(
+            capacitor.specs(),
+            CapacitorSpecs {
+                picofarad: 0.47,
+                tolerance: 0.05,
+                voltage: Some(250)
+            }
+        );
+    }
+
+    #[test]
+    pub fn invalid_capacitors() {
+        assert!(
+            Capacitor::try_create(vec![Color::Black, Color::Black, Color::Black, Color::Black])
+                .is_err()
+        );
+        assert!(Capacitor::default().with_color(Color::Gold, 2).is_err());
+        assert!(Capacitor::try_create(vec![Color::Brown, Color::Black, Color::Black]).is_err());
+    }
+}
@@ -0,0 +1,74 @@
+use crate::eseries::ESeries;
+
+/// A voltage divider, the output is taken across `r2`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Divider {
+    pub r1: f64,
+    pub r2: f64,
+}
+
+impl Divider {
+    pub fn vout(&self, vin: f64) -> f64 {
+        vin * self.r2 / (self.r1 + self.r2)
+    }
+
+    /// The current through the unloaded divider in A.
+    pub fn current(&self, vin: f64) -> f64 {
+        vin / (self.r1 + self.r2)
+    }
+
+    /// The relative error of the output voltage compared to the target.
+    pub fn error(&self, vin: f64, vout: f64) -> f64 {
+        (self.vout(vin) - vout) / vout
+    }
+
+    /// Pairs of values of the given series for the target output voltage, best first.
+    /// R2 is taken from the 1kΩ decade, so the currents stay in the mA range.
+    pub fn suggest(vin: f64, vout: f64, series: ESeries) -> Result<Vec<Divider>, String> {
+        if !(vin > 0.0 && vin.is_finite()) {
+            return Err(String::from("input voltage must be positive"));
+        }
+        if !(vout > 0.0 && vout < vin) {
+            return Err(String::from(
+                "output voltage must be between 0 and the input voltage",
+            ));
+        }
+        let mut dividers = series
+            .values_in_decade(3)
+            .into_iter()
+            .map(|r2| {
+                Ok(Divider {
+                    r1: series.nearest(r2 * (vin / vout - 1.0))?,
+                    r2,
+                })
+            })
+            .collect::<Result<Vec<Divider>, String>>()?;
+        dividers.sort_by(|a, b| {
+            let ea = a.error(vin, vout).abs();
+            let eb = b.error(vin, vout).abs();
+            ea.total_cmp(&eb)
+        });
+        Ok(dividers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn suggest() {
+        let dividers = Divider::suggest(5.0, 2.5, ESeries::E12).unwrap();
+        assert_eq!(dividers.len(), 12);
+        assert_eq!(dividers[0].r1, dividers[0].r2);
+        assert_eq!(dividers[0].vout(5.0), 2.5);
+
+        let dividers = Divider::suggest(12.0, 3.3, ESeries::E24).unwrap();
+        let best = dividers[0];
+        assert!(best.error(12.0, 3.3).abs() < 0.01);
+        assert!(dividers[1].error(12.0, 3.3).abs() >= best.error(12.0, 3.3).abs());
+
+        assert!(Divider::suggest(5.0, 6.0, ESeries::E12).is_err());
+        assert!(Divider::suggest(0.0, 1.0, ESeries::E12).is_err());
+    }
+}
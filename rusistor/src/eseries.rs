@@ -0,0 +1,189 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::RangeInclusive,
+};
+
+use crate::Resistor;
+
+/// The IEC 60063 series of preferred resistance values.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum ESeries {
+    E6,
+    #[default]
+    E12,
+    E24,
+    E48,
+    E96,
+}
+
+const E6: [u32; 6] = [10, 15, 22, 33, 47, 68];
+
+const E12: [u32; 12] = [10, 12, 15, 18, 22, 27, 33, 39, 47, 56, 68, 82];
+
+const E24: [u32; 24] = [
+    10, 11, 12, 13, 15, 16, 18, 20, 22, 24, 27, 30, 33, 36, 39, 43, 47, 51, 56, 62, 68, 75, 82, 91,
+];
+
+const E48: [u32; 48] = [
+    100, 105, 110, 115, 121, 127, 133, 140, 147, 154, 162, 169, 178, 187, 196, 205, 215, 226, 237,
+    249, 261, 274, 287, 301, 316, 332, 348, 365, 383, 402, 422, 442, 464, 487, 511, 536, 562, 590,
+    619, 649, 681, 715, 750, 787, 825, 866, 909, 953,
+];
+
+const E96: [u32; 96] = [
+    100, 102, 105, 107, 110, 113, 115, 118, 121, 124, 127, 130, 133, 137, 140, 143, 147, 150, 154,
+    158, 162, 165, 169, 174, 178, 182, 187, 191, 196, 200, 205, 210, 215, 221, 226, 232, 237, 243,
+    249, 255, 261, 267, 274, 280, 287, 294, 301, 309, 316, 324, 332, 340, 348, 357, 365, 374, 383,
+    392, 402, 412, 422, 432, 442, 453, 464, 475, 487, 499, 511, 523, 536, 549, 562, 576, 590, 604,
+    619, 634, 649, 665, 681, 698, 715, 732, 750, 768, 787, 806, 825, 845, 866, 887, 909, 931, 953,
+    976,
+];
+
+impl ESeries {
+    pub const ALL: [ESeries; 5] = [
+        ESeries::E6,
+        ESeries::E12,
+        ESeries::E24,
+        ESeries::E48,
+        ESeries::E96,
+    ];
+
+    /// The significant digits of the values, e.g. 47 for 4.7, 47 and 470.
+    pub fn significands(&self) -> &'static [u32] {
+        match self {
+            ESeries::E6 => &E6,
+            ESeries::E12 => &E12,
+            ESeries::E24 => &E24,
+            ESeries::E48 => &E48,
+            ESeries::E96 => &E96,
+        }
+    }
+
+    /// The tolerance in %, as expected by `Resistor::determine`.
+    pub fn tolerance(&self) -> f64 {
+        match self {
+            ESeries::E6 => 20.0,
+            ESeries::E12 => 10.0,
+            ESeries::E24 => 5.0,
+            ESeries::E48 => 2.0,
+            ESeries::E96 => 1.0,
+        }
+    }
+
+    pub fn next(&self) -> ESeries {
+        let idx = ESeries::ALL.iter().position(|s| s == self).unwrap_or(0);
+        ESeries::ALL[(idx + 1) % ESeries::ALL.len()]
+    }
+
+    pub fn prev(&self) -> ESeries {
+        let idx = ESeries::ALL.iter().position(|s| s == self).unwrap_or(0);
+        ESeries::ALL[(idx + ESeries::ALL.len() - 1) % ESeries::ALL.len()]
+    }
+
+    /// The values in ohm of the decade starting at 10^decade, e.g. 1000 to 8200 for E12 and decade 3.
+    pub fn values_in_decade(&self, decade: i32) -> Vec<f64> {
+        let digits = match self {
+            ESeries::E6 | ESeries::E12 | ESeries::E24 => 2,
+            ESeries::E48 | ESeries::E96 => 3,
+        };
+        let exponent = decade - digits + 1;
+        self.significands()
+            .iter()
+            .map(|s| {
+                if exponent >= 0 {
+                    (*s as u64 * 10u64.pow(exponent as u32)) as f64
+                } else {
+                    *s as f64 / 10f64.powi(-exponent)
+                }
+            })
+            .collect()
+    }
+
+    /// The values in ohm of the decades, ascending, e.g. 0.1 to 6.8G for E6 and -1..=9.
+    pub fn values(&self, decades: RangeInclusive<i32>) -> Vec<f64> {
+        decades.flat_map(|d| self.values_in_decade(d)).collect()
+    }
+}
+
+impl ESeries {
+    /// The value of the series closest to the given value in ohm, which must be positive.
+    pub fn nearest(&self, value: f64) -> Result<f64, String> {
+        if !(value > 0.0 && value.is_finite()) {
+            return Err(format!("no nearest standard value for {}", value));
+        }
+        let decade = value.log10().floor() as i32;
+        (decade - 1..=decade + 1)
+            .flat_map(|d| self.values_in_decade(d))
+            .min_by(|a, b| {
+                let da = (a - value).abs() / a;
+                let db = (b - value).abs() / b;
+                da.total_cmp(&db)
+            })
+            .ok_or_else(|| format!("no nearest standard value for {}", value))
+    }
+
+    /// The resistor for a value of this series, E6 values use the implicit 20% of 3 bands.
+    pub fn resistor(&self, value: f64) -> Result<Resistor, String> {
+        let tolerance = match self {
+            ESeries::E6 => None,
+            series => Some(series.tolerance()),
+        };
+        Resistor::determine(value, tolerance, None)
+    }
+}
+
+impl Display for ESeries {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let s = match self {
+            ESeries::E6 => "E6",
+            ESeries::E12 => "E12",
+            ESeries::E24 => "E24",
+            ESeries::E48 => "E48",
+            ESeries::E96 => "E96",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn values_in_decade() {
+        assert_eq!(
+            ESeries::E6.values_in_decade(3),
+            vec![1000.0, 1500.0, 2200.0, 3300.0, 4700.0, 6800.0]
+        );
+        assert_eq!(
+            ESeries::E6.values_in_decade(-1),
+            vec![0.1, 0.15, 0.22, 0.33, 0.47, 0.68]
+        );
+        let e96 = ESeries::E96.values_in_decade(0);
+        assert_eq!(e96.len(), 96);
+        assert_eq!(e96[1], 1.02);
+        assert_eq!(e96[95], 9.76);
+    }
+
+    #[test]
+    pub fn values() {
+        let values = ESeries::E12.values(0..=2);
+        assert_eq!(values.len(), 36);
+        assert_eq!(values[0], 1.0);
+        assert_eq!(values[12], 10.0);
+        assert_eq!(values[35], 820.0);
+    }
+
+    #[test]
+    pub fn nearest() {
+        assert_eq!(ESeries::E12.nearest(4600.0), Ok(4700.0));
+        assert_eq!(ESeries::E12.nearest(9000.0), Ok(8200.0));
+        assert_eq!(ESeries::E12.nearest(9500.0), Ok(10000.0));
+        assert_eq!(ESeries::E24.nearest(1000.0), Ok(1000.0));
+        assert_eq!(ESeries::E96.nearest(0.98), Ok(0.976));
+        assert!(ESeries::E12.nearest(0.0).is_err());
+        assert!(ESeries::E12.nearest(-4700.0).is_err());
+        assert!(ESeries::E12.nearest(f64::INFINITY).is_err());
+        assert!(ESeries::E12.nearest(f64::NAN).is_err());
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{BandRole, Color, ColorCoded};
+
+/// A 4-band inductor, the value is coded in µH.
+#[derive(PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Inductor {
+    pub band1: Color,
+    pub band2: Color,
+    pub band3: Color,
+    pub band4: Color,
+}
+
+#[derive(PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InductorSpecs {
+    pub microhenry: f64,
+    pub tolerance: f64,
+}
+
+impl Default for Inductor {
+    fn default() -> Self {
+        // 10µH ±10%
+        Inductor {
+            band1: Color::Brown,
+            band2: Color::Black,
+            band3: Color::Black,
+            band4: Color::Silver,
+        }
+    }
+}
+
+impl Inductor {
+    pub fn digit(color: &Color) -> Option<u32> {
+        let idx = *color as u32;
+        (idx <= 9).then_some(idx)
+    }
+
+    pub fn multiplier(color: &Color) -> Option<f64> {
+        match color {
+            Color::Black => Some(1.0),
+            Color::Brown => Some(10.0),
+            Color::Red => Some(100.0),
+            Color::Orange => Some(1_000.0),
+            Color::Gold => Some(0.1),
+            Color::Silver => Some(0.01),
+            _ => None,
+        }
+    }
+
+    /// The tolerance as a fraction, e.g. 0.05 for 5%.
+    pub fn tolerance(color: &Color) -> Option<f64> {
+        match color {
+            Color::Black => Some(0.2),
+            Color::Brown => Some(0.01),
+            Color::Red => Some(0.02),
+            Color::Orange => Some(0.03),
+            Color::Yellow => Some(0.04),
+            Color::Gold => Some(0.05),
+            Color::Silver => Some(0.1),
+            _ => None,
+        }
+    }
+
+    pub fn band_role(band_idx: usize) -> Option<BandRole> {
+        match band_idx {
+            0 | 1 => Some(BandRole::Digit(band_idx)),
+            2 => Some(BandRole::Multiplier),
+            3 => Some(BandRole::Tolerance),
+            _ => None,
+        }
+    }
+
+    fn is_valid_color_in_band(color: &Color, band_idx: usize) -> bool {
+        match band_idx {
+            0 => Inductor::digit(color).is_some_and(|d| d > 0),
+            1 => Inductor::digit(color).is_some(),
+            2 => Inductor::multiplier(color).is_some(),
+            3 => Inductor::tolerance(color).is_some(),
+            _ => false,
+        }
+    }
+
+    pub fn try_create(bands: Vec<Color>) -> Result<Inductor, String> {
+        match bands[..] {
+            [band1, band2, band3, band4]
+                if bands
+                    .iter()
+                    .enumerate()
+                    .all(|(idx, c)| Inductor::is_valid_color_in_band(c, idx)) =>
+            {
+                Ok(Inductor {
+                    band1,
+                    band2,
+                    band3,
+                    band4,
+                })
+            }
+            [_, _, _, _] => Err(String::from("no valid 4-band inductor")),
+            _ => Err(String::from("This is not a valid inductor configuration")),
+        }
+    }
+
+    pub fn specs(&self) -> InductorSpecs {
+        let digits = Inductor::digit(&self.band1).unwrap_or_default() * 10
+            + Inductor::digit(&self.band2).unwrap_or_default();
+        let multiplier = Inductor::multiplier(&self.band3).unwrap_or(1.0);
+        // dividing avoids float artifacts like 0.30000000000000004
+        let microhenry = if multiplier < 1.0 {
+            digits as f64 / (1.0 / multiplier).round()
+        } else {
+            digits as f64 * multiplier
+        };
+        InductorSpecs {
+            microhenry,
+            tolerance: Inductor::tolerance(&self.band4).unwrap_or_default(),
+        }
+    }
+}
+
+impl ColorCoded for Inductor {
+    fn bands(&self) -> Vec<&Color> {
+        vec![&self.band1, &self.band2, &self.band3, &self.band4]
+    }
+
+    fn with_color(&self, color: Color, band_idx: usize) -> Result<Inductor, String> {
+        let mut current = self.bands();
+        if band_idx < current.len() {
+            current[band_idx] = &color;
+            Inductor::try_create(current.into_iter().cloned().collect())
+        } else {
+            Err("given band_idx out of bounds".to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn calc_inductor_specs() {
+        assert_eq!(
+            Inductor::default().specs(),
+            InductorSpecs {
+                microhenry: 10.0,
+                tolerance: 0.1
+            }
+        );
+        let inductor =
+            Inductor::try_create(vec![Color::Yellow, Color::Violet, Color::Gold, Color::Gold])
+                .unwrap();
+        assert_eq!(
+            inductor.specs(),
+            InductorSpecs {
+                microhenry: 4.7,
+                tolerance: 0.05
+            }
+        );
+        assert!(Inductor::default().with_color(Color::Pink, 3).is_err());
+    }
+}
@@ -0,0 +1,94 @@
+/// A stock of resistors with the same resistance and tolerance.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Part {
+    pub ohm: f64,
+    /// The tolerance as a fraction, e.g. 0.05 for 5%.
+    pub tolerance: f64,
+    pub quantity: u32,
+}
+
+/// The resistors at hand, ordered by resistance.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Inventory {
+    pub parts: Vec<Part>,
+}
+
+impl Inventory {
+    /// Adds to the stock of an existing part or inserts a new one.
+    pub fn add(&mut self, ohm: f64, tolerance: f64, quantity: u32) {
+        match self
+            .parts
+            .iter_mut()
+            .find(|p| p.ohm == ohm && p.tolerance == tolerance)
+        {
+            Some(part) => part.quantity += quantity,
+            None => {
+                let idx = self
+                    .parts
+                    .partition_point(|p| (p.ohm, p.tolerance) < (ohm, tolerance));
+                self.parts.insert(
+                    idx,
+                    Part {
+                        ohm,
+                        tolerance,
+                        quantity,
+                    },
+                );
+            }
+        }
+    }
+
+    /// Takes from the stock of the part at the given index, the part is removed when it runs out.
+    pub fn take(&mut self, idx: usize, quantity: u32) {
+        if let Some(part) = self.parts.get_mut(idx) {
+            part.quantity = part.quantity.saturating_sub(quantity);
+            if part.quantity == 0 {
+                self.parts.remove(idx);
+            }
+        }
+    }
+
+    /// The indices of the parts between `min_ohm` and `max_ohm`, the closest to `ohm` first.
+    pub fn search(&self, ohm: f64, min_ohm: f64, max_ohm: f64) -> Vec<usize> {
+        let mut matches = (0..self.parts.len())
+            .filter(|&i| (min_ohm..=max_ohm).contains(&self.parts[i].ohm))
+            .collect::<Vec<usize>>();
+        matches.sort_by(|&a, &b| {
+            let diff = |i: usize| (self.parts[i].ohm - ohm).abs();
+            diff(a).total_cmp(&diff(b))
+        });
+        matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Inventory;
+
+    #[test]
+    fn test_add_and_take() {
+        let mut inventory = Inventory::default();
+        inventory.add(4700.0, 0.05, 10);
+        inventory.add(1000.0, 0.01, 5);
+        inventory.add(4700.0, 0.05, 2);
+        assert_eq!(inventory.parts.len(), 2);
+        assert_eq!(inventory.parts[0].ohm, 1000.0);
+        assert_eq!(inventory.parts[1].quantity, 12);
+        inventory.take(0, 5);
+        assert_eq!(inventory.parts.len(), 1);
+        assert_eq!(inventory.parts[0].ohm, 4700.0);
+    }
+
+    #[test]
+    fn test_search() {
+        let mut inventory = Inventory::default();
+        inventory.add(3000.0, 0.05, 1);
+        inventory.add(3300.0, 0.05, 1);
+        inventory.add(3400.0, 0.01, 1);
+        inventory.add(3900.0, 0.05, 1);
+        assert_eq!(inventory.search(3300.0, 3135.0, 3465.0), vec![1, 2]);
+        assert!(inventory.search(10.0, 9.0, 11.0).is_empty());
+    }
+}
@@ -0,0 +1,75 @@
+use crate::eseries::ESeries;
+pub use crate::power::POWER_RATINGS;
+
+/// The series resistor of a LED.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct LedResistor {
+    /// The exact resistance for the desired current in Ω.
+    pub resistance: f64,
+    /// The nearest value of the chosen E-series in Ω.
+    pub standard: f64,
+    /// The current through the standard resistor in A.
+    pub current: f64,
+    /// The power dissipated by the standard resistor in W.
+    pub power: f64,
+}
+
+impl LedResistor {
+    /// The current is expected in A.
+    pub fn calculate(
+        supply: f64,
+        forward_voltage: f64,
+        current: f64,
+        series: ESeries,
+    ) -> Result<LedResistor, String> {
+        if !(current > 0.0 && current.is_finite()) {
+            return Err(String::from("current must be positive"));
+        }
+        if !(forward_voltage >= 0.0 && forward_voltage < supply) {
+            return Err(String::from(
+                "forward voltage must be between 0 and the supply voltage",
+            ));
+        }
+        let drop = supply - forward_voltage;
+        let resistance = drop / current;
+        let standard = series.nearest(resistance)?;
+        let current = drop / standard;
+        Ok(LedResistor {
+            resistance,
+            standard,
+            current,
+            power: current * current * standard,
+        })
+    }
+
+    /// The smallest common rating with at least twice the dissipated power.
+    pub fn recommended_rating(&self) -> Option<f64> {
+        POWER_RATINGS
+            .iter()
+            .find(|rating| **rating >= 2.0 * self.power)
+            .copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn calculate() {
+        let led = LedResistor::calculate(5.0, 2.0, 0.02, ESeries::E12).unwrap();
+        assert_eq!(led.resistance, 150.0);
+        assert_eq!(led.standard, 150.0);
+        assert_eq!(led.current, 0.02);
+        assert!((led.power - 0.06).abs() < 1e-12);
+        assert_eq!(led.recommended_rating(), Some(0.125));
+
+        let led = LedResistor::calculate(12.0, 2.0, 0.02, ESeries::E12).unwrap();
+        assert_eq!(led.resistance, 500.0);
+        assert_eq!(led.standard, 470.0);
+        assert_eq!(led.recommended_rating(), Some(0.5));
+
+        assert!(LedResistor::calculate(2.0, 3.0, 0.02, ESeries::E12).is_err());
+        assert!(LedResistor::calculate(5.0, 2.0, 0.0, ESeries::E12).is_err());
+    }
+}
@@ -1,10 +1,27 @@
+pub mod capacitor;
+pub mod divider;
+pub mod eseries;
+pub mod inductor;
+pub mod inventory;
+pub mod led;
+pub mod network;
+pub mod ohms_law;
+pub mod power;
+pub mod random;
+
 use core::panic;
 use std::{
     collections::HashSet,
     fmt::{self, Display, Formatter},
+    str::FromStr,
 };
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
 pub enum Color {
     Black = 0,
     Brown = 1,
@@ -22,6 +39,22 @@ pub enum Color {
 }
 
 impl Color {
+    pub const ALL: [Color; 13] = [
+        Color::Black,
+        Color::Brown,
+        Color::Red,
+        Color::Orange,
+        Color::Yellow,
+        Color::Green,
+        Color::Blue,
+        Color::Violet,
+        Color::Grey,
+        Color::White,
+        Color::Gold,
+        Color::Silver,
+        Color::Pink,
+    ];
+
     pub fn as_digit(&self) -> Option<usize> {
         match self {
             Color::Black => Some(0),
@@ -94,6 +127,35 @@ impl Color {
         }
     }
 
+    /// The sRGB value of the color, e.g. to match a faded band with.
+    pub fn as_rgb(&self) -> (u8, u8, u8) {
+        match self {
+            Color::Black => (0, 0, 0),
+            Color::Brown => (165, 42, 42),
+            Color::Red => (255, 0, 0),
+            Color::Orange => (255, 165, 0),
+            Color::Yellow => (255, 255, 0),
+            Color::Green => (0, 128, 0),
+            Color::Blue => (0, 0, 255),
+            Color::Violet => (148, 0, 211),
+            Color::Grey => (128, 128, 128),
+            Color::White => (255, 255, 255),
+            Color::Gold => (255, 215, 0),
+            Color::Silver => (192, 192, 192),
+            Color::Pink => (255, 105, 180),
+        }
+    }
+
+    /// Other names of the color in datasheets and charts, `from_str` takes them, too.
+    pub fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            Color::Grey => &["gray", "slate"],
+            Color::Violet => &["purple"],
+            Color::Pink => &["rose"],
+            _ => &[],
+        }
+    }
+
     fn from_tolerance(tolerance: f64) -> Color {
         match tolerance {
             1.0 => Color::Brown,
@@ -189,7 +251,35 @@ impl Display for Color {
     }
 }
 
+impl FromStr for Color {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().to_lowercase();
+        match name.as_str() {
+            "black" => Ok(Color::Black),
+            "brown" => Ok(Color::Brown),
+            "red" => Ok(Color::Red),
+            "orange" => Ok(Color::Orange),
+            "yellow" => Ok(Color::Yellow),
+            "green" => Ok(Color::Green),
+            "blue" => Ok(Color::Blue),
+            "violet" => Ok(Color::Violet),
+            "grey" => Ok(Color::Grey),
+            "white" => Ok(Color::White),
+            "gold" => Ok(Color::Gold),
+            "silver" => Ok(Color::Silver),
+            "pink" => Ok(Color::Pink),
+            _ => Color::ALL
+                .into_iter()
+                .find(|c| c.aliases().contains(&name.as_str()))
+                .ok_or_else(|| format!("invalid color name {}", s)),
+        }
+    }
+}
+
 #[derive(PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ResistorSpecs {
     pub ohm: f64,
     pub tolerance: f64,
@@ -198,7 +288,58 @@ pub struct ResistorSpecs {
     pub tcr: Option<u32>,
 }
 
-#[derive(PartialEq, Debug)]
+impl ResistorSpecs {
+    /// Whether the tolerance ranges share a value, e.g. of a part and its substitute.
+    pub fn overlaps(&self, other: &ResistorSpecs) -> bool {
+        self.min_ohm <= other.max_ohm && other.min_ohm <= self.max_ohm
+    }
+
+    /// Whether the resistance, e.g. a measured one, is within the tolerance range.
+    pub fn contains(&self, ohm: f64) -> bool {
+        self.min_ohm <= ohm && ohm <= self.max_ohm
+    }
+
+    /// The temperature in °C the resistance and the tolerance are specified at.
+    pub const REFERENCE_CELSIUS: i32 = 25;
+
+    /// The resistance change at the temperature in °C, the TCR allows it in both directions.
+    pub fn drift_ohm(&self, celsius: i32) -> f64 {
+        self.ohm * self.drift_ratio(celsius)
+    }
+
+    /// The specs at the temperature in °C, the range widened by the TCR drift.
+    pub fn at_temperature(&self, celsius: i32) -> ResistorSpecs {
+        let ratio = self.drift_ratio(celsius);
+        ResistorSpecs {
+            min_ohm: round_significant(self.min_ohm * (1.0 - ratio)),
+            max_ohm: round_significant(self.max_ohm * (1.0 + ratio)),
+            ..*self
+        }
+    }
+
+    fn drift_ratio(&self, celsius: i32) -> f64 {
+        let ppm = self.tcr.unwrap_or_default() as u64
+            * celsius.abs_diff(ResistorSpecs::REFERENCE_CELSIUS) as u64;
+        ppm as f64 / 1_000_000.0
+    }
+}
+
+/// Drops the float noise of the drift, e.g. 98.40599999999999 becomes 98.406.
+fn round_significant(value: f64) -> f64 {
+    if value == 0.0 {
+        return value;
+    }
+    let scale = 10f64.powi(11 - value.abs().log10().floor() as i32);
+    (value * scale).round() / scale
+}
+
+/// Serialized as its bands, deserializing goes through `Resistor::try_create`.
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "Vec<Color>", into = "Vec<Color>")
+)]
 pub enum Resistor {
     ZeroOhm,
     ThreeBand {
@@ -229,7 +370,74 @@ pub enum Resistor {
     },
 }
 
+impl TryFrom<Vec<Color>> for Resistor {
+    type Error = String;
+
+    fn try_from(bands: Vec<Color>) -> Result<Self, Self::Error> {
+        Resistor::try_create(bands)
+    }
+}
+
+impl From<Resistor> for Vec<Color> {
+    fn from(resistor: Resistor) -> Self {
+        resistor.bands().into_iter().cloned().collect()
+    }
+}
+
+/// What a band of a color-coded component stands for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BandRole {
+    /// A significant digit, the first one is 0.
+    Digit(usize),
+    Multiplier,
+    Tolerance,
+    Tcr,
+    /// The rated voltage of a capacitor.
+    Voltage,
+}
+
+/// A component marked with a sequence of colored bands.
+pub trait ColorCoded: Sized {
+    fn bands(&self) -> Vec<&Color>;
+
+    /// The component with the given color in the given band, if that is a valid marking.
+    fn with_color(&self, color: Color, band_idx: usize) -> Result<Self, String>;
+}
+
+impl ColorCoded for Resistor {
+    fn bands(&self) -> Vec<&Color> {
+        Resistor::bands(self)
+    }
+
+    fn with_color(&self, color: Color, band_idx: usize) -> Result<Resistor, String> {
+        Resistor::with_color(self, color, band_idx)
+    }
+}
+
 impl Resistor {
+    /// The tolerances in % a tolerance band can encode, the widest first.
+    pub const TOLERANCES: [f64; 10] = [10.0, 5.0, 2.0, 1.0, 0.5, 0.25, 0.1, 0.05, 0.02, 0.01];
+
+    /// The temperature coefficients in ppm/K a TCR band can encode, the highest first.
+    pub const TCRS: [u32; 9] = [250, 100, 50, 25, 20, 15, 10, 5, 1];
+
+    /// The role of a band of a resistor with the given count of bands, `None` past the last one.
+    pub fn band_role(band_count: usize, band_idx: usize) -> Option<BandRole> {
+        let digits = match band_count {
+            1 => return (band_idx == 0).then_some(BandRole::Digit(0)),
+            3 | 4 => 2,
+            5 | 6 => 3,
+            _ => return None,
+        };
+        match band_idx {
+            i if i < digits => Some(BandRole::Digit(i)),
+            i if i == digits => Some(BandRole::Multiplier),
+            i if i == digits + 1 && band_count > 3 => Some(BandRole::Tolerance),
+            5 if band_count == 6 => Some(BandRole::Tcr),
+            _ => None,
+        }
+    }
+
     fn is_valid_color_in_band(color: &Color, band_position: usize, band_count: usize) -> bool {
         let mut valid_configs = HashSet::new();
         // zero-ohm resistor
@@ -516,10 +724,9 @@ impl Resistor {
     }
 
     fn validate_tolerance(tolerance: &Option<f64>) -> Result<Option<f64>, String> {
-        let valid_values = [1.0, 2.0, 0.05, 0.02, 0.5, 0.25, 0.1, 0.01, 5.0, 10.0];
         match tolerance {
             Some(tolerance) => {
-                if valid_values.contains(tolerance) {
+                if Resistor::TOLERANCES.contains(tolerance) {
                     Result::Ok(Some(*tolerance))
                 } else {
                     Result::Err(String::from("not a valid tolerance value"))
@@ -530,10 +737,9 @@ impl Resistor {
     }
 
     fn validate_tcr(tcr: &Option<u32>) -> Result<Option<u32>, String> {
-        let valid_values = [250, 100, 50, 15, 25, 20, 10, 5, 1];
         match tcr {
             Some(tcr) => {
-                if valid_values.contains(tcr) {
+                if Resistor::TCRS.contains(tcr) {
                     Result::Ok(Some(*tcr))
                 } else {
                     Result::Err(String::from("not a valid tcr value"))
@@ -670,23 +876,9 @@ impl Resistor {
                 band2,
                 band3,
             } => {
-                let digit1 = band1.as_digit_or_exponent();
-                let digit2 = band2.as_digit_or_exponent();
-                let exponent = band3.as_digit_or_exponent();
-                let tolerance = 0.2;
-                let multiplier = 10.0f64.powf(exponent);
-                let ohm = (digit1 * 10.0 + digit2) * multiplier;
-                let tolerance_ohm = ohm * tolerance;
-                let min_ohm = ohm - tolerance_ohm;
-                let max_ohm = ohm + tolerance_ohm;
-                let tcr = None;
-                ResistorSpecs {
-                    ohm,
-                    tolerance,
-                    min_ohm,
-                    max_ohm,
-                    tcr,
-                }
+                let significand = Resistor::significand(&[band1, band2]);
+                let exponent = band3.as_digit_or_exponent() as i32;
+                Resistor::exact_specs(significand, exponent, 0.2, None)
             }
             Resistor::FourBand {
                 band1,
@@ -694,25 +886,12 @@ impl Resistor {
                 band3,
                 band4,
             } => {
-                let digit1 = band1.as_digit_or_exponent();
-                let digit2 = band2.as_digit_or_exponent();
-                let exponent = band3.as_digit_or_exponent();
+                let significand = Resistor::significand(&[band1, band2]);
+                let exponent = band3.as_digit_or_exponent() as i32;
                 let tolerance = band4
                     .as_tolerance()
                     .expect("valid tolerance color expected");
-                let multiplier = 10.0f64.powf(exponent);
-                let ohm = (digit1 * 10.0 + digit2) * multiplier;
-                let tolerance_ohm = ohm * tolerance;
-                let min_ohm = ohm - tolerance_ohm;
-                let max_ohm = ohm + tolerance_ohm;
-                let tcr = None;
-                ResistorSpecs {
-                    ohm,
-                    tolerance,
-                    min_ohm,
-                    max_ohm,
-                    tcr,
-                }
+                Resistor::exact_specs(significand, exponent, tolerance, None)
             }
             Resistor::FiveBand {
                 band1,
@@ -721,26 +900,12 @@ impl Resistor {
                 band4,
                 band5,
             } => {
-                let digit1 = band1.as_digit_or_exponent();
-                let digit2 = band2.as_digit_or_exponent();
-                let digit3 = band3.as_digit_or_exponent();
-                let exponent = band4.as_digit_or_exponent();
+                let significand = Resistor::significand(&[band1, band2, band3]);
+                let exponent = band4.as_digit_or_exponent() as i32;
                 let tolerance = band5
                     .as_tolerance()
                     .expect("valid tolerance color expected");
-                let multiplier = 10.0f64.powf(exponent);
-                let ohm = (digit1 * 100.0 + digit2 * 10.0 + digit3) * multiplier;
-                let tolerance_ohm = ohm * tolerance;
-                let min_ohm = ohm - tolerance_ohm;
-                let max_ohm = ohm + tolerance_ohm;
-                let tcr = None;
-                ResistorSpecs {
-                    ohm,
-                    tolerance,
-                    min_ohm,
-                    max_ohm,
-                    tcr,
-                }
+                Resistor::exact_specs(significand, exponent, tolerance, None)
             }
             Resistor::SixBand {
                 band1,
@@ -750,30 +915,63 @@ impl Resistor {
                 band5,
                 band6,
             } => {
-                let digit1 = band1.as_digit_or_exponent();
-                let digit2 = band2.as_digit_or_exponent();
-                let digit3 = band3.as_digit_or_exponent();
-                let exponent = band4.as_digit_or_exponent();
+                let significand = Resistor::significand(&[band1, band2, band3]);
+                let exponent = band4.as_digit_or_exponent() as i32;
                 let tolerance = band5
                     .as_tolerance()
                     .expect("valid tolerance color expected");
-                let tcr = band6.as_tcr();
-                let multiplier = 10.0f64.powf(exponent);
-                let ohm = (digit1 * 100.0 + digit2 * 10.0 + digit3) * multiplier;
-                let tolerance_ohm = ohm * tolerance;
-                let min_ohm = ohm - tolerance_ohm;
-                let max_ohm = ohm + tolerance_ohm;
-                ResistorSpecs {
-                    ohm,
-                    tolerance,
-                    min_ohm,
-                    max_ohm,
-                    tcr,
-                }
+                Resistor::exact_specs(significand, exponent, tolerance, band6.as_tcr())
             }
         }
     }
 
+    fn significand(digit_bands: &[&Color]) -> u128 {
+        digit_bands
+            .iter()
+            .map(|c| c.as_digit().expect("valid digit color expected") as u128)
+            .fold(0, |acc, d| acc * 10 + d)
+    }
+
+    // All values are calculated as integers scaled by a power of ten and only converted
+    // to f64 at the very end, so results like 67.966 don't show up as 67.96600000000001.
+    fn exact_specs(
+        significand: u128,
+        exponent: i32,
+        tolerance: f64,
+        tcr: Option<u32>,
+    ) -> ResistorSpecs {
+        // the smallest multiplier is 10^-3 and the finest tolerance is 0.01%
+        const OHM_SCALE: u32 = 3;
+        const TOLERANCE_SCALE: u32 = 4;
+
+        let ohm_scaled = significand * 10u128.pow((exponent + OHM_SCALE as i32) as u32);
+        let tolerance_scaled = (tolerance * 10f64.powi(TOLERANCE_SCALE as i32)).round() as u128;
+        let nominal_scaled = ohm_scaled * 10u128.pow(TOLERANCE_SCALE);
+        let tolerance_ohm_scaled = ohm_scaled * tolerance_scaled;
+
+        ResistorSpecs {
+            ohm: Resistor::unscale(ohm_scaled, OHM_SCALE),
+            tolerance,
+            min_ohm: Resistor::unscale(
+                nominal_scaled - tolerance_ohm_scaled,
+                OHM_SCALE + TOLERANCE_SCALE,
+            ),
+            max_ohm: Resistor::unscale(
+                nominal_scaled + tolerance_ohm_scaled,
+                OHM_SCALE + TOLERANCE_SCALE,
+            ),
+            tcr,
+        }
+    }
+
+    fn unscale(mut value: u128, mut scale: u32) -> f64 {
+        while scale > 0 && value.is_multiple_of(10) {
+            value /= 10;
+            scale -= 1;
+        }
+        value as f64 / 10f64.powi(scale as i32)
+    }
+
     pub fn with_color(&self, color: Color, band_idx: usize) -> Result<Resistor, String> {
         let mut current = self.bands();
         if band_idx < current.len() {
@@ -897,6 +1095,67 @@ impl Resistor {
         }
     }
 
+    /// Like `determine`, but with the given count of bands instead of the fewest possible.
+    pub fn determine_with_bands(
+        resistance: f64,
+        tolerance: Option<f64>,
+        tcr: Option<u32>,
+        bands: usize,
+    ) -> Result<Resistor, String> {
+        let (mut digits, mut exponent) = Resistor::determine_digits_and_exponent(resistance)?;
+        let tolerance = Resistor::validate_tolerance(&tolerance)?;
+        let tcr = Resistor::validate_tcr(&tcr)?;
+        if resistance == 0.0 {
+            return Err(String::from("A zero ohm resistor has a single band."));
+        }
+        match (bands, tolerance, tcr) {
+            (4..=6, None, _) => {
+                return Err(format!("A {}-band resistor needs a tolerance.", bands));
+            }
+            (6, _, None) => return Err(String::from("A 6-band resistor needs a TCR.")),
+            (4 | 5, _, Some(_)) => return Err(String::from("Only 6-band resistors have a TCR.")),
+            (4..=6, _, _) => (),
+            _ => return Err(format!("{} is not a valid bands count here.", bands)),
+        }
+        let digits_len = if bands == 4 { 2 } else { 3 };
+        while digits.len() < digits_len {
+            digits.push(0);
+            exponent -= 1;
+        }
+        if digits.len() > digits_len {
+            return Err(format!(
+                "{} has too many digits for {} bands.",
+                resistance, bands
+            ));
+        }
+        if !(-3..=9).contains(&exponent) {
+            return Err(String::from("Not a representable resistance value."));
+        }
+        let mut colors = digits
+            .iter()
+            .map(|d| Color::from(*d as i32))
+            .collect::<Vec<Color>>();
+        colors.push(Color::from(exponent));
+        colors.extend(tolerance.map(Color::from_tolerance));
+        colors.extend(tcr.map(Color::from_tcr));
+        Resistor::try_create(colors)
+    }
+
+    /// The 4, 5 and 6 band resistors of the resistance and tolerance that exist,
+    /// only 6 bands encode the TCR and need one.
+    pub fn determine_all(
+        resistance: f64,
+        tolerance: Option<f64>,
+        tcr: Option<u32>,
+    ) -> Vec<Resistor> {
+        (4..=6)
+            .filter_map(|bands| {
+                let tcr = if bands == 6 { tcr } else { None };
+                Resistor::determine_with_bands(resistance, tolerance, tcr, bands).ok()
+            })
+            .collect()
+    }
+
     pub fn bands(&self) -> Vec<&Color> {
         match self {
             Resistor::ZeroOhm => vec![&Color::Black],
@@ -934,6 +1193,52 @@ impl Resistor {
 mod tests {
     use super::*;
 
+    #[test]
+    pub fn parse_color_names() {
+        assert_eq!("red".parse::<Color>(), Ok(Color::Red));
+        assert_eq!("Violet".parse::<Color>(), Ok(Color::Violet));
+        assert_eq!("gray".parse::<Color>(), Ok(Color::Grey));
+        assert_eq!("Purple".parse::<Color>(), Ok(Color::Violet));
+        assert_eq!("slate".parse::<Color>(), Ok(Color::Grey));
+        assert!("mauve".parse::<Color>().is_err());
+    }
+
+    #[test]
+    pub fn color_rgb_and_aliases() {
+        assert_eq!(Color::Brown.as_rgb(), (165, 42, 42));
+        assert_eq!(Color::Violet.aliases(), ["purple"]);
+        assert!(Color::Red.aliases().is_empty());
+    }
+
+    #[test]
+    pub fn bands_conversion() {
+        let bands = vec![Color::Brown, Color::Black, Color::Red, Color::Gold];
+        let r = Resistor::try_from(bands.clone()).unwrap();
+        assert_eq!(Vec::from(r), bands);
+        assert!(
+            Resistor::try_from(vec![Color::Black, Color::Black, Color::Red, Color::Gold]).is_err()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    pub fn deserialize_validates_bands() {
+        use serde::{Deserialize, de::IntoDeserializer, de::value::Error};
+        let deserialize = |bands: Vec<&str>| {
+            Resistor::deserialize(IntoDeserializer::<Error>::into_deserializer(bands))
+        };
+        assert_eq!(
+            deserialize(vec!["yellow", "violet", "red", "gold"]),
+            Ok(Resistor::FourBand {
+                band1: Color::Yellow,
+                band2: Color::Violet,
+                band3: Color::Red,
+                band4: Color::Gold,
+            })
+        );
+        assert!(deserialize(vec!["black", "violet", "red", "gold"]).is_err());
+    }
+
     #[test]
     pub fn try_create_valid_zeroohm_resistor() {
         let bands = vec![Color::Black];
@@ -1168,6 +1473,61 @@ mod tests {
         )
     }
 
+    #[test]
+    pub fn calc_specs_without_float_artifacts() {
+        let r =
+            Resistor::try_create_4_band(Color::Yellow, Color::Violet, Color::Silver, Color::Gold)
+                .unwrap();
+        let o = r.specs();
+        assert_eq!(
+            o,
+            ResistorSpecs {
+                ohm: 0.47,
+                tolerance: 0.05,
+                min_ohm: 0.4465,
+                max_ohm: 0.4935,
+                tcr: None
+            }
+        );
+
+        let r = Resistor::try_create_5_band(
+            Color::Yellow,
+            Color::Violet,
+            Color::Black,
+            Color::Pink,
+            Color::Violet,
+        )
+        .unwrap();
+        let o = r.specs();
+        assert_eq!(o.ohm, 0.47);
+        assert_eq!(o.min_ohm, 0.46953);
+        assert_eq!(o.max_ohm, 0.47047);
+
+        let r =
+            Resistor::try_create_4_band(Color::Orange, Color::Orange, Color::Gold, Color::Brown)
+                .unwrap();
+        let o = r.specs();
+        assert_eq!(o.ohm, 3.3);
+        assert_eq!(o.min_ohm, 3.267);
+        assert_eq!(o.max_ohm, 3.333);
+    }
+
+    #[test]
+    pub fn calc_largest_resistor() {
+        let r = Resistor::try_create_5_band(
+            Color::White,
+            Color::White,
+            Color::White,
+            Color::White,
+            Color::Silver,
+        )
+        .unwrap();
+        let o = r.specs();
+        assert_eq!(o.ohm, 999000000000.0);
+        assert_eq!(o.min_ohm, 899100000000.0);
+        assert_eq!(o.max_ohm, 1098900000000.0);
+    }
+
     #[test]
     pub fn determine_resistor() {
         let r = Resistor::determine(200.0, None, None).unwrap();
@@ -1278,4 +1638,90 @@ mod tests {
         let digs = Resistor::determine_digits_and_exponent(0.01003);
         assert!(digs.is_err());
     }
+
+    #[test]
+    pub fn test_determine_with_bands() {
+        let resistor = Resistor::determine_with_bands(4700.0, Some(1.0), None, 5).unwrap();
+        assert_eq!(
+            resistor.bands(),
+            vec![
+                &Color::Yellow,
+                &Color::Violet,
+                &Color::Black,
+                &Color::Brown,
+                &Color::Brown
+            ]
+        );
+        let resistor = Resistor::determine_with_bands(4700.0, Some(5.0), Some(50), 6).unwrap();
+        assert_eq!(resistor.bands().len(), 6);
+        assert!(Resistor::determine_with_bands(4710.0, Some(1.0), None, 4).is_err());
+        assert!(Resistor::determine_with_bands(4700.0, None, None, 5).is_err());
+        assert!(Resistor::determine_with_bands(4700.0, Some(1.0), Some(50), 5).is_err());
+    }
+
+    #[test]
+    pub fn test_determine_all() {
+        let counts = |resistors: Vec<Resistor>| {
+            resistors
+                .iter()
+                .map(|r| r.bands().len())
+                .collect::<Vec<usize>>()
+        };
+        assert_eq!(
+            counts(Resistor::determine_all(4700.0, Some(5.0), Some(50))),
+            vec![4, 5, 6]
+        );
+        assert_eq!(
+            counts(Resistor::determine_all(4710.0, Some(1.0), None)),
+            vec![5]
+        );
+        assert!(Resistor::determine_all(4700.0, None, None).is_empty());
+    }
+
+    #[test]
+    pub fn test_band_role() {
+        assert_eq!(Resistor::band_role(1, 0), Some(BandRole::Digit(0)));
+        assert_eq!(Resistor::band_role(3, 2), Some(BandRole::Multiplier));
+        assert_eq!(Resistor::band_role(3, 3), None);
+        assert_eq!(Resistor::band_role(4, 3), Some(BandRole::Tolerance));
+        assert_eq!(Resistor::band_role(5, 2), Some(BandRole::Digit(2)));
+        assert_eq!(Resistor::band_role(6, 5), Some(BandRole::Tcr));
+        assert_eq!(Resistor::band_role(2, 0), None);
+    }
+
+    #[test]
+    pub fn test_overlaps() {
+        let specs = |ohm: f64, tolerance: f64| Resistor::determine(ohm, Some(tolerance), None);
+        let a = specs(4700.0, 5.0).unwrap().specs();
+        assert!(a.overlaps(&specs(5100.0, 5.0).unwrap().specs()));
+        assert!(!a.overlaps(&specs(5100.0, 1.0).unwrap().specs()));
+        assert!(a.overlaps(&a));
+    }
+
+    #[test]
+    pub fn test_contains() {
+        let specs = Resistor::determine(4700.0, Some(5.0), None)
+            .unwrap()
+            .specs();
+        assert!(specs.contains(4465.0));
+        assert!(specs.contains(4800.0));
+        assert!(!specs.contains(4936.0));
+    }
+
+    #[test]
+    pub fn test_at_temperature() {
+        let specs = Resistor::determine(100.0, Some(1.0), Some(100))
+            .unwrap()
+            .specs();
+        assert_eq!(specs.at_temperature(25), specs);
+        let hot = specs.at_temperature(85);
+        assert_eq!(hot.ohm, 100.0);
+        assert_eq!(hot.min_ohm, 98.406);
+        assert_eq!(hot.max_ohm, 101.606);
+        assert_eq!(specs.at_temperature(-35), hot);
+        assert_eq!(specs.drift_ohm(85), 0.6);
+
+        let specs = Resistor::determine(100.0, Some(1.0), None).unwrap().specs();
+        assert_eq!(specs.at_temperature(155), specs);
+    }
 }
@@ -0,0 +1,115 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::ResistorSpecs;
+
+/// How the resistors of a group are connected.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum Connection {
+    #[default]
+    Series,
+    Parallel,
+}
+
+impl Connection {
+    pub fn toggle(&self) -> Connection {
+        match self {
+            Connection::Series => Connection::Parallel,
+            Connection::Parallel => Connection::Series,
+        }
+    }
+}
+
+impl Display for Connection {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let s = match self {
+            Connection::Series => "series",
+            Connection::Parallel => "parallel",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// A nominal resistance with its worst-case bounds.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Combined {
+    pub ohm: f64,
+    pub min_ohm: f64,
+    pub max_ohm: f64,
+}
+
+impl Combined {
+    /// The tolerance is expected as a fraction, e.g. 0.05 for 5%.
+    pub fn new(ohm: f64, tolerance: f64) -> Combined {
+        Combined {
+            ohm,
+            min_ohm: ohm * (1.0 - tolerance),
+            max_ohm: ohm * (1.0 + tolerance),
+        }
+    }
+
+    /// The worst-case deviation from the nominal resistance as a fraction.
+    pub fn tolerance(&self) -> f64 {
+        if self.ohm == 0.0 {
+            0.0
+        } else {
+            ((self.max_ohm - self.ohm).max(self.ohm - self.min_ohm)) / self.ohm
+        }
+    }
+
+    /// Combines the given parts, `None` if there are no parts.
+    pub fn combine(connection: Connection, parts: &[Combined]) -> Option<Combined> {
+        if parts.is_empty() {
+            return None;
+        }
+        let combine = |f: fn(&Combined) -> f64| match connection {
+            Connection::Series => parts.iter().map(f).sum(),
+            // a zero ohm part shorts the group: 1 / (1 / 0) == 0
+            Connection::Parallel => 1.0 / parts.iter().map(|p| 1.0 / f(p)).sum::<f64>(),
+        };
+        Some(Combined {
+            ohm: combine(|p| p.ohm),
+            min_ohm: combine(|p| p.min_ohm),
+            max_ohm: combine(|p| p.max_ohm),
+        })
+    }
+}
+
+impl From<&ResistorSpecs> for Combined {
+    fn from(specs: &ResistorSpecs) -> Self {
+        Combined {
+            ohm: specs.ohm,
+            min_ohm: specs.min_ohm,
+            max_ohm: specs.max_ohm,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn combine_series() {
+        let parts = [Combined::new(100.0, 0.05), Combined::new(200.0, 0.01)];
+        let combined = Combined::combine(Connection::Series, &parts).unwrap();
+        assert_eq!(combined.ohm, 300.0);
+        assert_eq!(combined.min_ohm, 95.0 + 198.0);
+        assert_eq!(combined.max_ohm, 105.0 + 202.0);
+        assert_eq!(Combined::combine(Connection::Series, &[]), None);
+    }
+
+    #[test]
+    pub fn combine_parallel() {
+        let parts = [Combined::new(100.0, 0.1), Combined::new(100.0, 0.1)];
+        let combined = Combined::combine(Connection::Parallel, &parts).unwrap();
+        assert_eq!(combined.ohm, 50.0);
+        assert_eq!(combined.min_ohm, 45.0);
+        assert_eq!(combined.max_ohm, 55.0);
+        assert!((combined.tolerance() - 0.1).abs() < 1e-12);
+
+        let shorted = [Combined::new(0.0, 0.0), Combined::new(100.0, 0.1)];
+        let combined = Combined::combine(Connection::Parallel, &shorted).unwrap();
+        assert_eq!(combined.ohm, 0.0);
+        assert_eq!(combined.tolerance(), 0.0);
+    }
+}
@@ -0,0 +1,87 @@
+/// Voltage (V), current (A), resistance (Ω) and power (W) of a resistor.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct OhmsLaw {
+    pub voltage: f64,
+    pub current: f64,
+    pub resistance: f64,
+    pub power: f64,
+}
+
+impl OhmsLaw {
+    /// Computes the two missing quantities, exactly two positive quantities have to be given.
+    pub fn solve(
+        voltage: Option<f64>,
+        current: Option<f64>,
+        resistance: Option<f64>,
+        power: Option<f64>,
+    ) -> Result<OhmsLaw, String> {
+        let given = [voltage, current, resistance, power];
+        if given.iter().flatten().count() != 2 {
+            return Err(String::from("exactly two values are needed"));
+        }
+        if given.iter().flatten().any(|v| *v <= 0.0 || !v.is_finite()) {
+            return Err(String::from("values must be positive"));
+        }
+        let (voltage, current) = match (voltage, current, resistance, power) {
+            (Some(v), Some(i), _, _) => (v, i),
+            (Some(v), _, Some(r), _) => (v, v / r),
+            (Some(v), _, _, Some(p)) => (v, p / v),
+            (_, Some(i), Some(r), _) => (i * r, i),
+            (_, Some(i), _, Some(p)) => (p / i, i),
+            (_, _, Some(r), Some(p)) => ((p * r).sqrt(), (p / r).sqrt()),
+            _ => unreachable!("two values are given"),
+        };
+        Ok(OhmsLaw {
+            voltage,
+            current,
+            resistance: resistance.unwrap_or(voltage / current),
+            power: power.unwrap_or(voltage * current),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn solve() {
+        let expected = OhmsLaw {
+            voltage: 12.0,
+            current: 2.0,
+            resistance: 6.0,
+            power: 24.0,
+        };
+        assert_eq!(
+            OhmsLaw::solve(Some(12.0), Some(2.0), None, None),
+            Ok(expected)
+        );
+        assert_eq!(
+            OhmsLaw::solve(Some(12.0), None, Some(6.0), None),
+            Ok(expected)
+        );
+        assert_eq!(
+            OhmsLaw::solve(Some(12.0), None, None, Some(24.0)),
+            Ok(expected)
+        );
+        assert_eq!(
+            OhmsLaw::solve(None, Some(2.0), Some(6.0), None),
+            Ok(expected)
+        );
+        assert_eq!(
+            OhmsLaw::solve(None, Some(2.0), None, Some(24.0)),
+            Ok(expected)
+        );
+        assert_eq!(
+            OhmsLaw::solve(None, None, Some(6.0), Some(24.0)),
+            Ok(expected)
+        );
+    }
+
+    #[test]
+    pub fn solve_invalid() {
+        assert!(OhmsLaw::solve(Some(12.0), None, None, None).is_err());
+        assert!(OhmsLaw::solve(Some(12.0), Some(2.0), Some(6.0), None).is_err());
+        assert!(OhmsLaw::solve(Some(12.0), None, Some(0.0), None).is_err());
+    }
+}
@@ -0,0 +1,66 @@
+/// Common power ratings of through-hole resistors in W.
+pub const POWER_RATINGS: [f64; 7] = [0.125, 0.25, 0.5, 1.0, 2.0, 3.0, 5.0];
+
+/// The ambient temperature in °C up to which a resistor can dissipate its rated power.
+pub const DERATING_START_CELSIUS: f64 = 70.0;
+
+/// The ambient temperature in °C at which the derated power reaches zero.
+pub const MAX_CELSIUS: f64 = 155.0;
+
+/// The maximum continuous load of a resistor at a power rating.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct PowerLimits {
+    /// The derated power in W.
+    pub power: f64,
+    /// The maximum voltage across the resistor in V.
+    pub voltage: f64,
+    /// The maximum current through the resistor in A.
+    pub current: f64,
+}
+
+impl PowerLimits {
+    /// The limits of the resistance in Ω with the rating in W at the ambient temperature in °C,
+    /// the power is derated linearly above `DERATING_START_CELSIUS`.
+    pub fn calculate(ohm: f64, rating: f64, ambient_celsius: f64) -> Result<PowerLimits, String> {
+        if !(ohm > 0.0 && ohm.is_finite()) {
+            return Err(String::from("resistance must be positive"));
+        }
+        if !(rating > 0.0 && rating.is_finite()) {
+            return Err(String::from("power rating must be positive"));
+        }
+        let power = rating * derating_factor(ambient_celsius);
+        Ok(PowerLimits {
+            power,
+            voltage: (power * ohm).sqrt(),
+            current: (power / ohm).sqrt(),
+        })
+    }
+}
+
+/// The fraction of the rated power a resistor can dissipate at the ambient temperature in °C.
+pub fn derating_factor(ambient_celsius: f64) -> f64 {
+    ((MAX_CELSIUS - ambient_celsius) / (MAX_CELSIUS - DERATING_START_CELSIUS)).clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn calculate() {
+        let limits = PowerLimits::calculate(100.0, 0.25, 25.0).unwrap();
+        assert_eq!(limits.power, 0.25);
+        assert_eq!(limits.voltage, 5.0);
+        assert_eq!(limits.current, 0.05);
+
+        let limits = PowerLimits::calculate(100.0, 1.0, 112.5).unwrap();
+        assert_eq!(limits.power, 0.5);
+        assert_eq!(
+            PowerLimits::calculate(100.0, 1.0, 155.0).unwrap().voltage,
+            0.0
+        );
+
+        assert!(PowerLimits::calculate(0.0, 0.25, 25.0).is_err());
+        assert!(PowerLimits::calculate(100.0, 0.0, 25.0).is_err());
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{Color, Resistor};
+
+/// A small xorshift generator, good enough to pick practice resistors.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        // xorshift gets stuck on a zero state
+        Rng { state: seed.max(1) }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+
+    /// A random number in `0..n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+}
+
+const MULTIPLIERS: [Color; 9] = [
+    Color::Silver,
+    Color::Gold,
+    Color::Black,
+    Color::Brown,
+    Color::Red,
+    Color::Orange,
+    Color::Yellow,
+    Color::Green,
+    Color::Blue,
+];
+
+impl Resistor {
+    /// A random resistor with 3 to 6 bands and a multiplier between 10^-2 and 10^6.
+    pub fn random(rng: &mut Rng) -> Resistor {
+        // 4-band resistors are the most common ones
+        let band_count = [3, 4, 4, 5, 6][rng.below(5)];
+        let multiplier_idx = if band_count < 5 { 2 } else { 3 };
+        loop {
+            let bands = (0..band_count)
+                .map(|idx| {
+                    if idx == multiplier_idx {
+                        MULTIPLIERS[rng.below(MULTIPLIERS.len())]
+                    } else {
+                        Color::ALL[rng.below(Color::ALL.len())]
+                    }
+                })
+                .collect();
+            if let Ok(resistor) = Resistor::try_create(bands) {
+                return resistor;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn random_resistors() {
+        let mut rng = Rng::new(42);
+        for _ in 0..100 {
+            let resistor = Resistor::random(&mut rng);
+            assert!(resistor.specs().ohm <= 999_000_000.0);
+            let bands = resistor
+                .bands()
+                .into_iter()
+                .copied()
+                .collect::<Vec<Color>>();
+            assert_eq!(Resistor::try_create(bands), Ok(resistor));
+        }
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        assert_eq!(Resistor::random(&mut a), Resistor::random(&mut b));
+    }
+}
@@ -0,0 +1,271 @@
+use rusistor::{Color, Resistor};
+
+use crate::{
+    model::SelectedTab,
+    update::{ColorCodesMsg, Msg, SpecsMsg},
+};
+
+/// A command of the command language, e.g. `bands 5`.
+#[derive(Debug, PartialEq)]
+pub struct Command {
+    pub name: &'static str,
+    /// The arguments it takes, empty for none.
+    pub args: &'static str,
+}
+
+/// The commands both frontends share, a frontend adds its own ones.
+pub const COMMANDS: [Command; 5] = [
+    Command {
+        name: "decode",
+        args: "<colors>",
+    },
+    Command {
+        name: "bands",
+        args: "3|4|5|6",
+    },
+    Command {
+        name: "set",
+        args: "<band> <color>",
+    },
+    Command {
+        name: "determine",
+        args: "<resistance> [tolerance] [tcr]",
+    },
+    Command {
+        name: "tab",
+        args: "<name>",
+    },
+];
+
+/// The command the input starts with, the longest name wins.
+pub fn find<'c>(
+    commands: impl IntoIterator<Item = &'c Command>,
+    input: &str,
+) -> Option<&'c Command> {
+    let input = input.trim_start().to_lowercase();
+    commands
+        .into_iter()
+        .filter(|c| input.trim_end() == c.name || input.starts_with(&format!("{} ", c.name)))
+        .max_by_key(|c| c.name.len())
+}
+
+/// The commands whose names contain the letters of the input in order, best matches first.
+pub fn matches<'c>(
+    commands: impl IntoIterator<Item = &'c Command> + Clone,
+    input: &str,
+) -> Vec<&'c Command> {
+    // the arguments of a complete command don't narrow the matches
+    if let Some(command) = find(commands.clone(), input) {
+        return vec![command];
+    }
+    let input = input.to_lowercase();
+    let mut scored = commands
+        .into_iter()
+        .filter_map(|c| fuzzy_score(c.name, &input).map(|score| (score, c)))
+        .collect::<Vec<_>>();
+    scored.sort_by_key(|(score, _)| *score);
+    scored.into_iter().map(|(_, c)| c).collect()
+}
+
+/// The count of the skipped letters of the name, `None` if the input isn't in it.
+fn fuzzy_score(name: &str, input: &str) -> Option<usize> {
+    let mut letters = name.chars().enumerate();
+    let mut next_idx = 0;
+    let mut score = 0;
+    for c in input.chars().filter(|c| !c.is_whitespace()) {
+        let (idx, _) = letters.find(|(_, letter)| *letter == c)?;
+        score += idx - next_idx;
+        next_idx = idx + 1;
+    }
+    Some(score)
+}
+
+/// The messages of a command line of `COMMANDS`, e.g. `set 3 red` or `determine 4k7 5`.
+/// `tab` only selects one of `tabs`, the tabs of the frontend.
+pub fn parse(input: &str, tabs: &[SelectedTab]) -> Result<Vec<Msg>, String> {
+    let command =
+        find(&COMMANDS, input).ok_or_else(|| format!("unknown command: {}", input.trim()))?;
+    // the inputs keep their case, e.g. the M of 4M7
+    let args = input.trim()[command.name.len()..]
+        .split_whitespace()
+        .collect::<Vec<_>>();
+    let color_codes_tab = Msg::SelectTab {
+        tab: SelectedTab::ColorCodesToSpecs,
+    };
+    let msgs = match (command.name, args.as_slice()) {
+        ("decode", colors) if !colors.is_empty() => {
+            let bands = colors
+                .iter()
+                .map(|c| c.to_lowercase().parse::<Color>())
+                .collect::<Result<Vec<_>, _>>()?;
+            vec![
+                Msg::ColorCodesMsg {
+                    msg: ColorCodesMsg::SetResistor {
+                        resistor: Resistor::try_create(bands)?,
+                    },
+                },
+                color_codes_tab,
+            ]
+        }
+        ("bands", [count]) => {
+            let msg = match *count {
+                "3" => ColorCodesMsg::ThreeBands,
+                "4" => ColorCodesMsg::FourBands,
+                "5" => ColorCodesMsg::FiveBands,
+                "6" => ColorCodesMsg::SixBands,
+                _ => return Err(format!("invalid bands count: {}", count)),
+            };
+            vec![Msg::ColorCodesMsg { msg }, color_codes_tab]
+        }
+        ("set", [band, color]) => {
+            let band_idx = band
+                .parse::<usize>()
+                .ok()
+                .and_then(|band| band.checked_sub(1))
+                .ok_or_else(|| format!("invalid band: {}", band))?;
+            let color = color.to_lowercase().parse::<Color>()?;
+            vec![
+                Msg::ColorCodesMsg {
+                    msg: ColorCodesMsg::SelectColor { band_idx, color },
+                },
+                color_codes_tab,
+            ]
+        }
+        ("determine", [resistance, rest @ ..]) if rest.len() <= 2 => vec![
+            Msg::SelectTab {
+                tab: SelectedTab::SpecsToColorCodes,
+            },
+            Msg::SpecsMsg {
+                msg: SpecsMsg::SetSpecs {
+                    resistance: resistance.to_string(),
+                    tolerance: rest.first().copied().unwrap_or_default().to_string(),
+                    tcr: rest.get(1).copied().unwrap_or_default().to_string(),
+                },
+            },
+            Msg::SpecsMsg {
+                msg: SpecsMsg::Determine,
+            },
+        ],
+        ("tab", [name]) => vec![Msg::SelectTab {
+            tab: SelectedTab::from_name(name)
+                .filter(|tab| tabs.contains(tab))
+                .ok_or_else(|| format!("invalid tab: {}", name))?,
+        }],
+        (name, _) => {
+            return Err(format!("usage: {} {}", name, command.args)
+                .trim()
+                .to_string());
+        }
+    };
+    Ok(msgs)
+}
+
+/// The messages of a script with a command per line, empty lines and the ones starting
+/// with `#` are skipped. The errors name the line, e.g. "line 2: invalid tab: x".
+pub fn parse_script<M>(
+    script: &str,
+    parse: impl Fn(&str) -> Result<Vec<M>, String>,
+) -> Result<Vec<M>, String> {
+    let mut msgs = Vec::new();
+    for (idx, line) in script.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        msgs.extend(parse(line).map_err(|e| format!("line {}: {}", idx + 1, e))?);
+    }
+    Ok(msgs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{COMMANDS, matches, parse, parse_script};
+    use crate::{
+        model::SelectedTab,
+        update::{ColorCodesMsg, Msg, SpecsMsg},
+    };
+    use rusistor::Color;
+
+    #[test]
+    fn test_matches() {
+        assert_eq!(matches(&COMMANDS, "").len(), COMMANDS.len());
+        let names = |input| {
+            matches(&COMMANDS, input)
+                .iter()
+                .map(|c| c.name)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names("dtm"), vec!["determine"]);
+        assert_eq!(names("bands 5"), vec!["bands"]);
+        assert!(names("xyz").is_empty());
+    }
+
+    #[test]
+    fn test_parse() {
+        assert!(matches!(
+            parse("set 3 Red", &SelectedTab::ALL).as_deref(),
+            Ok([
+                Msg::ColorCodesMsg {
+                    msg: ColorCodesMsg::SelectColor {
+                        band_idx: 2,
+                        color: Color::Red
+                    }
+                },
+                Msg::SelectTab {
+                    tab: SelectedTab::ColorCodesToSpecs
+                }
+            ])
+        ));
+        assert!(matches!(
+            parse("determine 4M7 5", &SelectedTab::ALL).as_deref(),
+            Ok([
+                _,
+                Msg::SpecsMsg {
+                    msg: SpecsMsg::SetSpecs { resistance, tolerance, tcr }
+                },
+                Msg::SpecsMsg {
+                    msg: SpecsMsg::Determine
+                }
+            ]) if resistance == "4M7" && tolerance == "5" && tcr.is_empty()
+        ));
+        assert!(matches!(
+            parse(" Tab ohms-law", &SelectedTab::ALL).as_deref(),
+            Ok([Msg::SelectTab {
+                tab: SelectedTab::OhmsLaw
+            }])
+        ));
+        assert!(matches!(
+            parse("decode brown black red gold", &SelectedTab::ALL).as_deref(),
+            Ok([_, _])
+        ));
+        assert_eq!(
+            parse("bands 7", &SelectedTab::ALL).err(),
+            Some("invalid bands count: 7".to_string())
+        );
+        assert_eq!(
+            parse("set 0 red", &SelectedTab::ALL).err(),
+            Some("invalid band: 0".to_string())
+        );
+        assert_eq!(
+            parse("determine", &SelectedTab::ALL).err(),
+            Some("usage: determine <resistance> [tolerance] [tcr]".to_string())
+        );
+        assert!(parse("decode brown blak red", &SelectedTab::ALL).is_err());
+        assert!(parse("frobnicate", &SelectedTab::ALL).is_err());
+        assert_eq!(
+            parse("tab ohmslaw", &[SelectedTab::SpecsToColorCodes]).err(),
+            Some("invalid tab: ohmslaw".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_script() {
+        let parse = |line: &str| parse(line, &SelectedTab::ALL);
+        let msgs = parse_script("# a 4k7 resistor\nbands 4\n\ndetermine 4k7 5\n", parse).unwrap();
+        assert_eq!(msgs.len(), 5);
+        assert_eq!(
+            parse_script("bands 4\ntab x", parse).err(),
+            Some("line 2: invalid tab: x".to_string())
+        );
+    }
+}
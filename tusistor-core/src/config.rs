@@ -0,0 +1,161 @@
+use std::{collections::BTreeMap, path::PathBuf, str::FromStr};
+
+use serde::Deserialize;
+
+use crate::{
+    i18n::Language,
+    model::SelectedTab,
+    theme::{PaletteOverrides, Theme},
+    update::ColorCodesMsg,
+};
+
+/// The actions whose keys can be remapped in the config file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    Exit,
+    NextTab,
+    PrevTab,
+    NextBand,
+    PrevBand,
+    NextColor,
+    PrevColor,
+    Determine,
+    Reset,
+}
+
+/// One or more keys of an action, e.g. "ctrl+n" or ["ctrl+p", "f1"].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum Keys {
+    One(String),
+    Many(Vec<String>),
+}
+
+/// The settings of `config.toml`, the web version ignores the ones it has no use for.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub tab: Option<SelectedTab>,
+    pub bands: Option<u8>,
+    pub theme: Option<Theme>,
+    pub no_color: Option<bool>,
+    pub accessible: Option<bool>,
+    /// Shows the resistances with SI prefixes, the default.
+    pub si_units: Option<bool>,
+    /// Shows the hex values and the other names of the colors in the band lists.
+    pub color_details: Option<bool>,
+    /// Describes every change in plain text on the last row, implies `no_color`.
+    pub screen_reader: Option<bool>,
+    /// The language of the UI texts, the locale's one by default.
+    pub language: Option<Language>,
+    /// Adds h/j/k/l and gt/gT to the keys of the tabs without inputs.
+    pub vim_keys: Option<bool>,
+    pub inventory: Option<PathBuf>,
+    /// The count of specs inputs to keep in the history.
+    pub history_len: Option<usize>,
+    /// Restores the tab, the color codes and the specs inputs of the last run.
+    pub restore_session: Option<bool>,
+    /// Starts the color codes tab with the resistor of the last run, `restore_session` includes it.
+    pub restore_resistor: Option<bool>,
+    /// Colors replacing the ones of the theme, the keys are `accent`, `highlight`
+    /// or a band color, e.g. `yellow = "#d4b000"`.
+    pub colors: BTreeMap<String, String>,
+    /// Keys replacing the default ones of an action, e.g. `next_tab = "ctrl+n"`.
+    pub keys: BTreeMap<Action, Keys>,
+}
+
+impl Config {
+    pub fn from_toml(content: &str) -> Result<Config, String> {
+        let config: Config = toml::from_str(content).map_err(|e| e.to_string())?;
+        if let Some(bands) = config.bands
+            && !(3..=6).contains(&bands)
+        {
+            return Err(format!("invalid bands count {}", bands));
+        }
+        config.palette_overrides()?;
+        Ok(config)
+    }
+
+    /// The message that selects the configured bands count of the color codes.
+    pub fn bands_msg(&self) -> Option<ColorCodesMsg> {
+        match self.bands {
+            Some(3) => Some(ColorCodesMsg::ThreeBands),
+            Some(4) => Some(ColorCodesMsg::FourBands),
+            Some(5) => Some(ColorCodesMsg::FiveBands),
+            Some(6) => Some(ColorCodesMsg::SixBands),
+            _ => None,
+        }
+    }
+
+    pub fn palette_overrides(&self) -> Result<PaletteOverrides, String> {
+        let mut overrides = PaletteOverrides::default();
+        for (key, value) in &self.colors {
+            let color = ratatui_core::style::Color::from_str(value)
+                .map_err(|_| format!("invalid color {} for {}", value, key))?;
+            match key.as_str() {
+                "accent" => overrides.accent = Some(color),
+                "highlight" => overrides.highlight = Some(color),
+                band => {
+                    let band = rusistor::Color::from_str(band)?;
+                    overrides.band_colors.insert(band, color);
+                }
+            }
+        }
+        Ok(overrides)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Action, Config, Keys};
+    use crate::i18n::Language;
+    use crate::{model::SelectedTab, theme::Theme};
+    use ratatui_core::style::Color;
+
+    #[test]
+    fn test_from_toml() {
+        let config = Config::from_toml(
+            r#"
+            tab = "ohmslaw"
+            bands = 5
+            theme = "light"
+            language = "de"
+            keys = { prev_tab = ["ctrl+p", "f1"] }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.tab, Some(SelectedTab::OhmsLaw));
+        assert_eq!(config.bands, Some(5));
+        assert_eq!(config.theme, Some(Theme::Light));
+        assert_eq!(config.language, Some(Language::De));
+        assert_eq!(
+            config.keys.get(&Action::PrevTab),
+            Some(&Keys::Many(vec!["ctrl+p".to_string(), "f1".to_string()]))
+        );
+
+        assert!(Config::from_toml("bands = 7").is_err());
+        assert!(Config::from_toml("tabs = \"specs\"").is_err());
+        assert!(Config::from_toml("keys = { jump = \"j\" }").is_err());
+    }
+
+    #[test]
+    fn test_palette_overrides() {
+        let config = Config::from_toml(
+            r##"
+            [colors]
+            accent = "cyan"
+            yellow = "#d4b000"
+            "##,
+        )
+        .unwrap();
+        let overrides = config.palette_overrides().unwrap();
+        assert_eq!(overrides.accent, Some(Color::Cyan));
+        assert_eq!(
+            overrides.band_colors.get(&rusistor::Color::Yellow),
+            Some(&Color::Rgb(0xd4, 0xb0, 0x00))
+        );
+
+        assert!(Config::from_toml("colors = { mauve = \"red\" }").is_err());
+    }
+}
@@ -0,0 +1,201 @@
+/// The thousands separator of SI, a thin space.
+const THIN_SPACE: char = '\u{2009}';
+
+/// The languages of the locales that write a decimal comma, e.g. "4,7".
+const DECIMAL_COMMA_LANGUAGES: [&str; 24] = [
+    "bg", "cs", "da", "de", "el", "es", "et", "fi", "fr", "hr", "hu", "id", "it", "lt", "lv", "nb",
+    "nl", "pl", "pt", "ro", "ru", "sk", "sv", "uk",
+];
+
+/// The SI prefixes from giga to pico with their factors, the largest first.
+const SI_PREFIXES: [(f64, &str); 8] = [
+    (1e9, "G"),
+    (1e6, "M"),
+    (1e3, "k"),
+    (1.0, ""),
+    (1e-3, "m"),
+    (1e-6, "µ"),
+    (1e-9, "n"),
+    (1e-12, "p"),
+];
+
+/// How the numbers of a locale are written, plain like `to_string()` by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberFormat {
+    pub decimal_separator: char,
+    /// Separates the groups of three digits of integer parts with more than four digits.
+    pub thousands_separator: Option<char>,
+    /// The significant digits to round to, `None` only hides floating point noise.
+    pub precision: Option<u32>,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        NumberFormat {
+            decimal_separator: '.',
+            thousands_separator: None,
+            precision: None,
+        }
+    }
+}
+
+/// Rounds to the count of significant digits, e.g. 8.7447 to 8.74 with 3 digits.
+pub fn round_significant(value: f64, digits: i32) -> f64 {
+    if value == 0.0 || !value.is_finite() {
+        return value;
+    }
+    let magnitude = value.abs().log10().floor() as i32;
+    let factor = 10f64.powi(digits - 1 - magnitude);
+    (value * factor).round() / factor
+}
+
+impl NumberFormat {
+    /// The format of a locale like `de-DE` or `en_US.UTF-8`, with thin spaces for thousands.
+    pub fn from_locale(locale: &str) -> NumberFormat {
+        let language = locale.split(['_', '-', '.']).next().unwrap_or_default();
+        let decimal_separator =
+            if DECIMAL_COMMA_LANGUAGES.contains(&language.to_lowercase().as_str()) {
+                ','
+            } else {
+                '.'
+            };
+        NumberFormat {
+            decimal_separator,
+            thousands_separator: Some(THIN_SPACE),
+            precision: None,
+        }
+    }
+
+    /// The format rounding to `digits` significant digits.
+    pub fn with_precision(self, digits: u32) -> NumberFormat {
+        NumberFormat {
+            precision: Some(digits),
+            ..self
+        }
+    }
+
+    /// The number in this format, e.g. "68 000 000 000" or "0,05".
+    pub fn format(self, value: f64) -> String {
+        let value = match self.precision {
+            Some(digits) => round_significant(value, digits as i32),
+            None => value,
+        };
+        let text = value.to_string();
+        let (sign, text) = match text.strip_prefix('-') {
+            Some(text) => ("-", text),
+            None => ("", text.as_str()),
+        };
+        let (integer, fraction) = text.split_once('.').unwrap_or((text, ""));
+        let mut formatted = sign.to_string();
+        match self.thousands_separator {
+            Some(separator) if integer.len() > 4 => {
+                for (idx, digit) in integer.chars().enumerate() {
+                    if idx > 0 && (integer.len() - idx) % 3 == 0 {
+                        formatted.push(separator);
+                    }
+                    formatted.push(digit);
+                }
+            }
+            _ => formatted.push_str(integer),
+        }
+        if !fraction.is_empty() {
+            formatted.push(self.decimal_separator);
+            formatted.push_str(fraction);
+        }
+        formatted
+    }
+
+    /// The value with the SI prefix that leaves one to three integer digits and the unit,
+    /// e.g. "4.7 kΩ" or "100 nF". Floating point noise like 4.7000000000000002 is hidden.
+    pub fn si(self, value: f64, unit: &str) -> String {
+        let (factor, prefix) = SI_PREFIXES
+            .iter()
+            .find(|(factor, _)| value.abs() >= *factor)
+            .filter(|_| value != 0.0)
+            .copied()
+            .unwrap_or((1.0, ""));
+        let scaled = ((value / factor) * 1e6).round() / 1e6;
+        format!("{} {}{}", self.format(scaled), prefix, unit)
+    }
+
+    /// A resistance with an SI prefix, e.g. "68 GΩ" instead of "68000000000". Resistances
+    /// below 1 Ω are written like the parts are labeled, e.g. "0.47 Ω".
+    pub fn ohm(self, ohm: f64) -> String {
+        if ohm.abs() < 1.0 {
+            format!("{} Ω", self.format((ohm * 1e6).round() / 1e6))
+        } else {
+            self.si(ohm, "Ω")
+        }
+    }
+
+    /// A fraction as a percentage, e.g. "0.05%" of 0.0005.
+    pub fn percent(self, fraction: f64) -> String {
+        format!("{}%", self.format((fraction * 100.0 * 1e6).round() / 1e6))
+    }
+
+    /// A temperature coefficient, e.g. "50 ppm/K".
+    pub fn ppm(self, ppm: u32) -> String {
+        format!("{} ppm/K", self.format(ppm as f64))
+    }
+
+    /// The input with the separators of this format replaced by the ones the parsers take,
+    /// e.g. "4.7k" of "4,7k". A point stays a decimal one in all formats.
+    pub fn normalize(self, input: &str) -> String {
+        input
+            .chars()
+            .filter(|c| Some(*c) != self.thousands_separator)
+            .map(|c| if c == self.decimal_separator { '.' } else { c })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NumberFormat, round_significant};
+
+    #[test]
+    fn test_format() {
+        let plain = NumberFormat::default();
+        assert_eq!(plain.format(68_000_000_000.0), "68000000000");
+        assert_eq!(plain.format(0.05), "0.05");
+
+        let de = NumberFormat::from_locale("de-DE");
+        assert_eq!(
+            de.format(68_000_000_000.0),
+            "68\u{2009}000\u{2009}000\u{2009}000"
+        );
+        assert_eq!(de.format(4700.0), "4700");
+        assert_eq!(de.format(-12345.5), "-12\u{2009}345,5");
+        assert_eq!(de.format(0.05), "0,05");
+
+        let en = NumberFormat::from_locale("en_US.UTF-8");
+        assert_eq!(en.format(47_000.25), "47\u{2009}000.25");
+    }
+
+    #[test]
+    fn test_units() {
+        let plain = NumberFormat::default();
+        assert_eq!(plain.ohm(68_000_000_000.0), "68 GΩ");
+        assert_eq!(plain.ohm(4700.0), "4.7 kΩ");
+        assert_eq!(plain.ohm(0.0), "0 Ω");
+        assert_eq!(plain.ohm(0.22), "0.22 Ω");
+        assert_eq!(plain.si(1e-7, "F"), "100 nF");
+        assert_eq!(plain.percent(0.0005), "0.05%");
+        assert_eq!(plain.ppm(250), "250 ppm/K");
+
+        let de = NumberFormat::from_locale("de").with_precision(3);
+        assert_eq!(de.ohm(8744.7), "8,74 kΩ");
+        assert_eq!(de.format(12_345.0), "12\u{2009}300");
+        assert_eq!(round_significant(8.7447, 3), 8.74);
+    }
+
+    #[test]
+    fn test_normalize() {
+        let de = NumberFormat::from_locale("de");
+        assert_eq!(de.normalize("4,7k"), "4.7k");
+        assert_eq!(de.normalize("4.7k"), "4.7k");
+        assert_eq!(de.normalize("47\u{2009}000"), "47000");
+        // a comma is no decimal separator in English
+        assert_eq!(NumberFormat::from_locale("en").normalize("4,7k"), "4,7k");
+    }
+}
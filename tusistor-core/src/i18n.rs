@@ -0,0 +1,623 @@
+use std::fmt::Display;
+
+/// The languages of the UI texts.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum Language {
+    #[default]
+    En,
+    De,
+}
+
+impl Language {
+    /// The language of a locale like `de_DE.UTF-8`, `None` for an unsupported one.
+    pub fn from_locale(locale: &str) -> Option<Language> {
+        let code = locale.split(['_', '-', '.']).next()?;
+        match code.to_lowercase().as_str() {
+            "en" => Some(Language::En),
+            "de" => Some(Language::De),
+            _ => None,
+        }
+    }
+
+    pub fn text(self, text: Text) -> &'static str {
+        text.translations()[self as usize]
+    }
+
+    /// The text with its `{}` replaced by the arguments in order.
+    pub fn fill(self, text: Text, args: &[&dyn Display]) -> String {
+        let mut parts = self.text(text).split("{}");
+        let mut filled = parts.next().unwrap_or_default().to_string();
+        for (part, arg) in parts.zip(args) {
+            filled.push_str(&arg.to_string());
+            filled.push_str(part);
+        }
+        filled
+    }
+
+    /// The name of a band color.
+    pub fn color(self, color: &rusistor::Color) -> &'static str {
+        let names = match color {
+            rusistor::Color::Black => ["black", "schwarz"],
+            rusistor::Color::Brown => ["brown", "braun"],
+            rusistor::Color::Red => ["red", "rot"],
+            rusistor::Color::Orange => ["orange", "orange"],
+            rusistor::Color::Yellow => ["yellow", "gelb"],
+            rusistor::Color::Green => ["green", "grün"],
+            rusistor::Color::Blue => ["blue", "blau"],
+            rusistor::Color::Violet => ["violet", "violett"],
+            rusistor::Color::Grey => ["grey", "grau"],
+            rusistor::Color::White => ["white", "weiß"],
+            rusistor::Color::Gold => ["gold", "gold"],
+            rusistor::Color::Silver => ["silver", "silber"],
+            rusistor::Color::Pink => ["pink", "rosa"],
+        };
+        names[self as usize]
+    }
+}
+
+/// The texts of the UI, named after their English text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Text {
+    TabColorCodes,
+    TabSpecs,
+    TabESeries,
+    TabNetwork,
+    TabOhmsLaw,
+    TabDivider,
+    TabLed,
+    TabQuiz,
+    TabCapacitor,
+    TabInductor,
+    TabCompare,
+    TabInventory,
+    Accept,
+    Add,
+    AllCounts,
+    BackToQuiz,
+    Band,
+    BandsCount,
+    Calculate,
+    CalculateColorCodes,
+    Cancel,
+    Check,
+    Clear,
+    ClickOrTap,
+    Close,
+    Color,
+    Complete,
+    Connection,
+    Dismiss,
+    Exit,
+    Flip,
+    Focus,
+    Group,
+    Help,
+    Hide,
+    JumpToColor,
+    LinkResistor,
+    Load,
+    LoadIntoSpecs,
+    Mode,
+    NewGroup,
+    NextBand,
+    NextInput,
+    NextSeries,
+    Older,
+    Or,
+    OneCount,
+    PowerRatingKey,
+    PrevNextBand,
+    PrevNextColor,
+    PrevNextDecade,
+    PrevNextHistory,
+    PrevNextInput,
+    PrevNextPair,
+    PrevNextTab,
+    PrevNextValue,
+    PrevNextWord,
+    Print,
+    Quantity,
+    Recall,
+    Remove,
+    Reset,
+    ResistorKey,
+    Run,
+    Save,
+    Search,
+    Select,
+    SelectAll,
+    SeriesKey,
+    Share,
+    ShowAll,
+    ShowColorCodes,
+    Skip,
+    Stats,
+    Suggest,
+    Tab,
+    TemperatureKey,
+    ThemeKey,
+    UndoRedo,
+    AllRuns,
+    Answers,
+    At,
+    AutoBands,
+    BandTitle,
+    Bands,
+    BandsOf,
+    ByBandsCount,
+    ByColor,
+    Capacitance,
+    ColorTitle,
+    Command,
+    CorrectTitle,
+    CorrectAnswer,
+    Current,
+    CurrentLabel,
+    Debug,
+    Decade,
+    Difference,
+    Digit,
+    Eg,
+    Error,
+    ErrorLabel,
+    ExactResistance,
+    Favorites,
+    FavoriteName,
+    BookmarkHint,
+    Filter,
+    Groups,
+    InTolerance,
+    Inductance,
+    Inventory,
+    Keys,
+    LastAnswer,
+    LedVoltage,
+    Line,
+    Linked,
+    MaxCurrent,
+    MaxVoltage,
+    MoreThan,
+    Multiplier,
+    NearestStandardValue,
+    NearestStandardValues,
+    None,
+    OutOfTolerance,
+    Power,
+    PowerDissipation,
+    PowerRating,
+    Qty,
+    Question,
+    Range,
+    RangesOverlap,
+    RangesDontOverlap,
+    ReadLeftToRight,
+    RecommendedRating,
+    References,
+    Resistance,
+    ResistanceOfBands,
+    Score,
+    Series,
+    StandardValues,
+    Stock,
+    Suggestions,
+    Supply,
+    TargetVout,
+    Tcr,
+    Temperature,
+    Time,
+    Tolerance,
+    Total,
+    Value,
+    ValueAndTolerance,
+    Values,
+    Voltage,
+    Within,
+    WrongAnswer,
+    AnnounceBand,
+    AnnounceBandSelected,
+    AnnounceResistor,
+    AnnounceTab,
+    QuizScore,
+    QuizStats,
+    StockSummary,
+    WhichBands,
+}
+
+impl Text {
+    pub const ALL: [Text; 163] = [
+        Text::TabColorCodes,
+        Text::TabSpecs,
+        Text::TabESeries,
+        Text::TabNetwork,
+        Text::TabOhmsLaw,
+        Text::TabDivider,
+        Text::TabLed,
+        Text::TabQuiz,
+        Text::TabCapacitor,
+        Text::TabInductor,
+        Text::TabCompare,
+        Text::TabInventory,
+        Text::Accept,
+        Text::Add,
+        Text::AllCounts,
+        Text::BackToQuiz,
+        Text::Band,
+        Text::BandsCount,
+        Text::Calculate,
+        Text::CalculateColorCodes,
+        Text::Cancel,
+        Text::Check,
+        Text::Clear,
+        Text::ClickOrTap,
+        Text::Close,
+        Text::Color,
+        Text::Complete,
+        Text::Connection,
+        Text::Dismiss,
+        Text::Exit,
+        Text::Flip,
+        Text::Focus,
+        Text::Group,
+        Text::Help,
+        Text::Hide,
+        Text::JumpToColor,
+        Text::LinkResistor,
+        Text::Load,
+        Text::LoadIntoSpecs,
+        Text::Mode,
+        Text::NewGroup,
+        Text::NextBand,
+        Text::NextInput,
+        Text::NextSeries,
+        Text::Older,
+        Text::Or,
+        Text::OneCount,
+        Text::PowerRatingKey,
+        Text::PrevNextBand,
+        Text::PrevNextColor,
+        Text::PrevNextDecade,
+        Text::PrevNextHistory,
+        Text::PrevNextInput,
+        Text::PrevNextPair,
+        Text::PrevNextTab,
+        Text::PrevNextValue,
+        Text::PrevNextWord,
+        Text::Print,
+        Text::Quantity,
+        Text::Recall,
+        Text::Remove,
+        Text::Reset,
+        Text::ResistorKey,
+        Text::Run,
+        Text::Save,
+        Text::Search,
+        Text::Select,
+        Text::SelectAll,
+        Text::SeriesKey,
+        Text::Share,
+        Text::ShowAll,
+        Text::ShowColorCodes,
+        Text::Skip,
+        Text::Stats,
+        Text::Suggest,
+        Text::Tab,
+        Text::TemperatureKey,
+        Text::ThemeKey,
+        Text::UndoRedo,
+        Text::AllRuns,
+        Text::Answers,
+        Text::At,
+        Text::AutoBands,
+        Text::BandTitle,
+        Text::Bands,
+        Text::BandsOf,
+        Text::ByBandsCount,
+        Text::ByColor,
+        Text::Capacitance,
+        Text::ColorTitle,
+        Text::Command,
+        Text::CorrectTitle,
+        Text::CorrectAnswer,
+        Text::Current,
+        Text::CurrentLabel,
+        Text::Debug,
+        Text::Decade,
+        Text::Difference,
+        Text::Digit,
+        Text::Eg,
+        Text::Error,
+        Text::ErrorLabel,
+        Text::ExactResistance,
+        Text::Favorites,
+        Text::FavoriteName,
+        Text::BookmarkHint,
+        Text::Filter,
+        Text::Groups,
+        Text::InTolerance,
+        Text::Inductance,
+        Text::Inventory,
+        Text::Keys,
+        Text::LastAnswer,
+        Text::LedVoltage,
+        Text::Line,
+        Text::Linked,
+        Text::MaxCurrent,
+        Text::MaxVoltage,
+        Text::MoreThan,
+        Text::Multiplier,
+        Text::NearestStandardValue,
+        Text::NearestStandardValues,
+        Text::None,
+        Text::OutOfTolerance,
+        Text::Power,
+        Text::PowerDissipation,
+        Text::PowerRating,
+        Text::Qty,
+        Text::Question,
+        Text::Range,
+        Text::RangesOverlap,
+        Text::RangesDontOverlap,
+        Text::ReadLeftToRight,
+        Text::RecommendedRating,
+        Text::References,
+        Text::Resistance,
+        Text::ResistanceOfBands,
+        Text::Score,
+        Text::Series,
+        Text::StandardValues,
+        Text::Stock,
+        Text::Suggestions,
+        Text::Supply,
+        Text::TargetVout,
+        Text::Tcr,
+        Text::Temperature,
+        Text::Time,
+        Text::Tolerance,
+        Text::Total,
+        Text::Value,
+        Text::ValueAndTolerance,
+        Text::Values,
+        Text::Voltage,
+        Text::Within,
+        Text::WrongAnswer,
+        Text::AnnounceBand,
+        Text::AnnounceBandSelected,
+        Text::AnnounceResistor,
+        Text::AnnounceTab,
+        Text::QuizScore,
+        Text::QuizStats,
+        Text::StockSummary,
+        Text::WhichBands,
+    ];
+
+    /// The English and the German text.
+    fn translations(self) -> [&'static str; 2] {
+        match self {
+            Text::TabColorCodes => ["color codes to specs", "Farbcode zu Werten"],
+            Text::TabSpecs => ["specs to color codes", "Werte zu Farbcode"],
+            Text::TabESeries => ["E-series", "E-Reihen"],
+            Text::TabNetwork => ["series/parallel", "Reihe/parallel"],
+            Text::TabOhmsLaw => ["Ohm's law", "Ohmsches Gesetz"],
+            Text::TabDivider => ["voltage divider", "Spannungsteiler"],
+            Text::TabLed => ["LED resistor", "LED-Vorwiderstand"],
+            Text::TabQuiz => ["quiz", "Quiz"],
+            Text::TabCapacitor => ["capacitor", "Kondensator"],
+            Text::TabInductor => ["inductor", "Spule"],
+            Text::TabCompare => ["compare", "Vergleich"],
+            Text::TabInventory => ["inventory", "Inventar"],
+            Text::Accept => ["accept", "übernehmen"],
+            Text::Add => ["add", "hinzufügen"],
+            Text::AllCounts => ["all counts", "alle Anzahlen"],
+            Text::BackToQuiz => ["back to the quiz", "zurück zum Quiz"],
+            Text::Band => ["band", "Ring"],
+            Text::BandsCount => ["bands count", "Anzahl der Ringe"],
+            Text::Calculate => ["calculate", "berechnen"],
+            Text::CalculateColorCodes => ["calculate color codes", "Farbcode berechnen"],
+            Text::Cancel => ["cancel", "abbrechen"],
+            Text::Check => ["check", "prüfen"],
+            Text::Clear => ["clear", "leeren"],
+            Text::ClickOrTap => ["click/tap", "Klicken/Tippen"],
+            Text::Close => ["close", "schließen"],
+            Text::Color => ["color", "Farbe"],
+            Text::Complete => ["complete", "vervollständigen"],
+            Text::Connection => ["connection", "Schaltung"],
+            Text::Dismiss => ["dismiss", "schließen"],
+            Text::Exit => ["exit", "beenden"],
+            Text::Flip => ["flip", "umdrehen"],
+            Text::Focus => ["focus", "Fokus"],
+            Text::Group => ["group", "Gruppe"],
+            Text::Help => ["help", "Hilfe"],
+            Text::Hide => ["hide", "ausblenden"],
+            Text::JumpToColor => ["jump to color", "zu Farbe springen"],
+            Text::LinkResistor => ["link resistor", "Widerstand verknüpfen"],
+            Text::Load => ["load", "laden"],
+            Text::LoadIntoSpecs => ["load into the specs tab", "in den Werte-Tab laden"],
+            Text::Mode => ["mode", "Modus"],
+            Text::NewGroup => ["new group", "neue Gruppe"],
+            Text::NextBand => ["next band", "nächster Ring"],
+            Text::NextInput => ["next input", "nächste Eingabe"],
+            Text::NextSeries => ["next series", "nächste Reihe"],
+            Text::Older => ["older", "älter"],
+            Text::Or => ["or", "oder"],
+            Text::OneCount => ["one count", "eine Anzahl"],
+            Text::PowerRatingKey => ["power rating", "Nennleistung"],
+            Text::PrevNextBand => ["prev/next band", "vorheriger/nächster Ring"],
+            Text::PrevNextColor => ["prev/next color", "vorherige/nächste Farbe"],
+            Text::PrevNextDecade => ["prev/next decade", "vorherige/nächste Dekade"],
+            Text::PrevNextHistory => ["prev/next history", "Verlauf zurück/vor"],
+            Text::PrevNextInput => ["prev/next input", "vorherige/nächste Eingabe"],
+            Text::PrevNextPair => ["prev/next pair", "vorheriges/nächstes Paar"],
+            Text::PrevNextTab => ["prev/next tab", "vorheriger/nächster Tab"],
+            Text::PrevNextValue => ["prev/next value", "vorheriger/nächster Wert"],
+            Text::PrevNextWord => ["prev/next word", "vorheriges/nächstes Wort"],
+            Text::Print => ["print", "drucken"],
+            Text::Quantity => ["quantity", "Menge"],
+            Text::Recall => ["recall", "abrufen"],
+            Text::Remove => ["remove", "entfernen"],
+            Text::Reset => ["reset", "zurücksetzen"],
+            Text::ResistorKey => ["resistor", "Widerstand"],
+            Text::Run => ["run", "ausführen"],
+            Text::Save => ["save", "speichern"],
+            Text::Search => ["search", "suchen"],
+            Text::Select => ["select", "auswählen"],
+            Text::SelectAll => ["select all", "alles auswählen"],
+            Text::SeriesKey => ["series", "Reihe"],
+            Text::Share => ["share", "teilen"],
+            Text::ShowAll => ["show all", "alle zeigen"],
+            Text::ShowColorCodes => ["show color codes", "Farbcode zeigen"],
+            Text::Skip => ["skip", "überspringen"],
+            Text::Stats => ["stats", "Statistik"],
+            Text::Suggest => ["suggest", "vorschlagen"],
+            Text::Tab => ["tab", "Tab"],
+            Text::TemperatureKey => ["temperature", "Temperatur"],
+            Text::ThemeKey => ["theme", "Farbschema"],
+            Text::UndoRedo => ["undo/redo", "rückgängig/wiederholen"],
+            Text::AllRuns => ["All runs", "Alle Durchläufe"],
+            Text::Answers => ["Answers", "Antworten"],
+            Text::At => ["at", "bei"],
+            Text::AutoBands => ["auto bands", "Ringe automatisch"],
+            Text::BandTitle => ["Band", "Ring"],
+            Text::Bands => ["Bands", "Ringe"],
+            Text::BandsOf => ["{} bands", "{} Ringe"],
+            Text::ByBandsCount => ["By bands count", "Nach Anzahl der Ringe"],
+            Text::ByColor => ["By color", "Nach Farbe"],
+            Text::Capacitance => ["Capacitance", "Kapazität"],
+            Text::ColorTitle => ["Color", "Farbe"],
+            Text::Command => ["Command", "Befehl"],
+            Text::CorrectTitle => ["Correct", "Richtig"],
+            Text::CorrectAnswer => ["correct", "richtig"],
+            Text::Current => ["Current", "Strom"],
+            Text::CurrentLabel => ["current", "Strom"],
+            Text::Debug => ["Debug", "Debug"],
+            Text::Decade => ["Decade", "Dekade"],
+            Text::Difference => ["Difference", "Differenz"],
+            Text::Digit => ["Digit {}", "Ziffer {}"],
+            Text::Eg => ["e.g.", "z. B."],
+            Text::Error => ["Error", "Fehler"],
+            Text::ErrorLabel => ["error", "Fehler"],
+            Text::ExactResistance => ["Exact resistance", "Exakter Widerstand"],
+            Text::Favorites => ["Favorites", "Favoriten"],
+            Text::FavoriteName => ["Name of the favorite", "Name des Favoriten"],
+            Text::BookmarkHint => [
+                "Ctrl+D bookmarks the current resistor",
+                "Ctrl+D merkt den aktuellen Widerstand vor",
+            ],
+            Text::Filter => ["Filter", "Filter"],
+            Text::Groups => [
+                "Groups (connected in series)",
+                "Gruppen (in Reihe geschaltet)",
+            ],
+            Text::InTolerance => ["in tolerance", "in der Toleranz"],
+            Text::Inductance => ["Inductance", "Induktivität"],
+            Text::Inventory => ["Inventory", "Inventar"],
+            Text::Keys => ["Keys", "Tasten"],
+            Text::LastAnswer => ["Last answer", "Letzte Antwort"],
+            Text::LedVoltage => ["LED forward voltage", "LED-Flussspannung"],
+            Text::Line => ["Line", "Zeile"],
+            Text::Linked => ["linked", "verknüpft"],
+            Text::MaxCurrent => ["Max current", "Max. Strom"],
+            Text::MaxVoltage => ["Max voltage", "Max. Spannung"],
+            Text::MoreThan => ["more than {}", "mehr als {}"],
+            Text::Multiplier => ["Multiplier", "Multiplikator"],
+            Text::NearestStandardValue => ["Nearest standard value", "Nächster Normwert"],
+            Text::NearestStandardValues => ["Nearest standard values", "Nächste Normwerte"],
+            Text::None => ["none", "keine"],
+            Text::OutOfTolerance => ["out of tolerance", "außerhalb der Toleranz"],
+            Text::Power => ["Power", "Leistung"],
+            Text::PowerDissipation => ["Power dissipation", "Verlustleistung"],
+            Text::PowerRating => ["Power rating", "Nennleistung"],
+            Text::Qty => ["Qty", "Anz."],
+            Text::Question => ["Question", "Frage"],
+            Text::Range => ["Range", "Bereich"],
+            Text::RangesOverlap => ["the ranges overlap", "die Bereiche überlappen"],
+            Text::RangesDontOverlap => {
+                ["the ranges don't overlap", "die Bereiche überlappen nicht"]
+            }
+            Text::ReadLeftToRight => ["read from left to right →", "von links nach rechts lesen →"],
+            Text::RecommendedRating => ["Recommended rating", "Empfohlene Belastbarkeit"],
+            Text::References => ["References", "Referenzen"],
+            Text::Resistance => ["Resistance", "Widerstand"],
+            Text::ResistanceOfBands => [
+                "Resistance (Ω) of these bands?",
+                "Widerstand (Ω) dieser Ringe?",
+            ],
+            Text::Score => ["Score", "Punkte"],
+            Text::Series => ["Series", "Reihe"],
+            Text::StandardValues => ["Standard values", "Normwerte"],
+            Text::Stock => ["Stock", "Bestand"],
+            Text::Suggestions => ["Suggestions", "Vorschläge"],
+            Text::Supply => ["Supply", "Versorgung"],
+            Text::TargetVout => ["Target Vout", "Ziel-Vout"],
+            Text::Tcr => ["TCR", "TK"],
+            Text::Temperature => ["Temperature", "Temperatur"],
+            Text::Time => ["Time", "Zeit"],
+            Text::Tolerance => ["Tolerance", "Toleranz"],
+            Text::Total => ["Total", "Gesamt"],
+            Text::Value => ["Value", "Wert"],
+            Text::ValueAndTolerance => ["Value (Ω) and tolerance (%)", "Wert (Ω) und Toleranz (%)"],
+            Text::Values => ["Values", "Werte"],
+            Text::Voltage => ["Voltage", "Spannung"],
+            Text::Within => ["Within", "Innerhalb"],
+            Text::WrongAnswer => ["wrong", "falsch"],
+            Text::AnnounceBand => ["Band {} set to {}, {}", "Ring {} auf {} gesetzt, {}"],
+            Text::AnnounceBandSelected => {
+                ["Band {} selected, {}, {}", "Ring {} ausgewählt, {}, {}"]
+            }
+            Text::AnnounceResistor => [
+                "resistor reads {} ±{}%{}, bands {}",
+                "Widerstand beträgt {} ±{}%{}, Ringe {}",
+            ],
+            Text::AnnounceTab => ["Tab {}", "Tab {}"],
+            Text::QuizScore => [
+                "{}/{} correct, streak {}, best streak {}",
+                "{}/{} richtig, Serie {}, beste Serie {}",
+            ],
+            Text::QuizStats => [
+                "{} answers, {}% correct, {} s per answer, day streak {}, best day streak {}",
+                "{} Antworten, {}% richtig, {} s pro Antwort, Tage in Folge {}, beste {}",
+            ],
+            Text::StockSummary => ["{} values, {} pieces", "{} Werte, {} Stück"],
+            Text::WhichBands => ["Which bands make {}?", "Welche Ringe ergeben {}?"],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Language, Text};
+
+    #[test]
+    fn test_from_locale() {
+        assert_eq!(Language::from_locale("de_DE.UTF-8"), Some(Language::De));
+        assert_eq!(Language::from_locale("en-US"), Some(Language::En));
+        assert_eq!(Language::from_locale("C"), None);
+    }
+
+    #[test]
+    fn test_fill() {
+        assert_eq!(
+            Language::De.fill(Text::StockSummary, &[&3, &12]),
+            "3 Werte, 12 Stück"
+        );
+        assert_eq!(Language::En.text(Text::Tolerance), "Tolerance");
+    }
+
+    #[test]
+    fn test_translations() {
+        for text in Text::ALL {
+            let [en, de] = text.translations();
+            assert!(!de.is_empty(), "{:?}", text);
+            assert_eq!(
+                en.matches("{}").count(),
+                de.matches("{}").count(),
+                "{:?}",
+                text
+            );
+        }
+    }
+}
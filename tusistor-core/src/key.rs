@@ -0,0 +1,79 @@
+/// A key of the keyboard, the frontends translate theirs to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyCode {
+    Char(char),
+    F(u8),
+    Backspace,
+    Enter,
+    Left,
+    Right,
+    Up,
+    Down,
+    Tab,
+    BackTab,
+    Delete,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    Esc,
+    /// A key tusistor has no use for, e.g. Insert or a media key.
+    Other,
+}
+
+/// A pressed key with its modifiers, independent of the crossterm or ratzilla event it comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyInput {
+    pub code: KeyCode,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+}
+
+impl KeyInput {
+    /// The key without modifiers.
+    pub fn new(code: KeyCode) -> KeyInput {
+        KeyInput {
+            code,
+            ctrl: false,
+            alt: false,
+            shift: false,
+        }
+    }
+
+    /// The key with Ctrl, e.g. Ctrl+R.
+    pub fn ctrl(code: KeyCode) -> KeyInput {
+        KeyInput {
+            ctrl: true,
+            ..KeyInput::new(code)
+        }
+    }
+
+    /// The input of a textarea, `None` for the keys it ignores.
+    pub fn textarea_input(&self) -> Option<ratatui_textarea::Input> {
+        let key = match self.code {
+            KeyCode::Char(c) => ratatui_textarea::Key::Char(c),
+            KeyCode::F(n) => ratatui_textarea::Key::F(n),
+            KeyCode::Backspace => ratatui_textarea::Key::Backspace,
+            KeyCode::Enter => ratatui_textarea::Key::Enter,
+            KeyCode::Left => ratatui_textarea::Key::Left,
+            KeyCode::Right => ratatui_textarea::Key::Right,
+            KeyCode::Up => ratatui_textarea::Key::Up,
+            KeyCode::Down => ratatui_textarea::Key::Down,
+            KeyCode::Tab | KeyCode::BackTab => ratatui_textarea::Key::Tab,
+            KeyCode::Delete => ratatui_textarea::Key::Delete,
+            KeyCode::Home => ratatui_textarea::Key::Home,
+            KeyCode::End => ratatui_textarea::Key::End,
+            KeyCode::PageUp => ratatui_textarea::Key::PageUp,
+            KeyCode::PageDown => ratatui_textarea::Key::PageDown,
+            KeyCode::Esc => ratatui_textarea::Key::Esc,
+            KeyCode::Other => return None,
+        };
+        Some(ratatui_textarea::Input {
+            key,
+            ctrl: self.ctrl,
+            alt: self.alt,
+            shift: self.shift,
+        })
+    }
+}
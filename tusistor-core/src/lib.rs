@@ -1,3 +1,11 @@
+pub mod commands;
+#[cfg(feature = "serde")]
+pub mod config;
+pub mod format;
+pub mod i18n;
+pub mod key;
 pub mod model;
+pub mod session;
+pub mod theme;
 pub mod update;
 pub mod view;
@@ -1,10 +1,61 @@
+use std::ops::RangeInclusive;
+
 use ratatui_textarea::{CursorMove, TextArea};
-use rusistor::Resistor;
+use rusistor::{
+    Resistor, ResistorSpecs,
+    capacitor::Capacitor,
+    divider::Divider,
+    eseries::ESeries,
+    inductor::Inductor,
+    inventory::Inventory,
+    led::LedResistor,
+    network::{Combined, Connection},
+    ohms_law::OhmsLaw,
+    power::PowerLimits,
+    random::Rng,
+};
 
-#[derive(Debug, Default)]
+use crate::{
+    format::NumberFormat,
+    i18n::{Language, Text},
+};
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct HistoryEntry {
+    specs: (String, String, String),
+    /// The seconds since the Unix epoch when the specs were added, `None` for older entries.
+    time: Option<u64>,
+}
+
+/// The count of specs inputs kept in the history unless configured otherwise.
+pub const HISTORY_LEN: usize = 100;
+
+#[derive(Debug)]
 pub struct SpecsHistory {
-    history: Vec<(String, String, String)>,
+    history: Vec<HistoryEntry>,
     idx: Option<usize>,
+    /// The count of entries to keep, the oldest are dropped first.
+    max_len: usize,
+    /// The seconds since the Unix epoch, the frontends set it as the web has no system clock.
+    clock: Option<fn() -> u64>,
+}
+
+/// Where a frontend keeps the specs history between runs, a file or the local storage.
+pub trait HistoryStore {
+    fn load(&self) -> Result<SpecsHistory, String>;
+    fn save(&self, history: &SpecsHistory) -> Result<(), String>;
+}
+
+impl Default for SpecsHistory {
+    fn default() -> Self {
+        SpecsHistory {
+            history: Vec::new(),
+            idx: None,
+            max_len: HISTORY_LEN,
+            clock: None,
+        }
+    }
 }
 
 impl SpecsHistory {
@@ -29,26 +80,104 @@ impl SpecsHistory {
     }
 
     pub fn try_get(&self) -> Option<&(String, String, String)> {
-        if let Some(idx) = self.idx {
-            self.history.get(idx)
-        } else {
-            None
-        }
+        self.get(self.idx?)
     }
 
+    /// Adds the specs as the newest entry, an equal older one is removed.
     pub fn add(&mut self, specs: (String, String, String)) {
-        match self.history.last() {
-            Some(x) if *x == specs => (),
-            _ => self.history.push(specs),
+        let time = self.clock.map(|now| now());
+        self.push(specs, time);
+    }
+
+    fn push(&mut self, specs: (String, String, String), time: Option<u64>) {
+        self.history.retain(|entry| entry.specs != specs);
+        self.history.push(HistoryEntry { specs, time });
+        self.truncate();
+    }
+
+    pub fn set_clock(&mut self, clock: fn() -> u64) {
+        self.clock = Some(clock);
+    }
+
+    pub fn set_max_len(&mut self, max_len: usize) {
+        self.max_len = max_len;
+        self.truncate();
+    }
+
+    fn truncate(&mut self) {
+        let excess = self.history.len().saturating_sub(self.max_len);
+        self.history.drain(..excess);
+    }
+
+    /// One line per entry with the tab separated resistance, tolerance and TCR inputs and
+    /// the time it was added, if known.
+    pub fn to_text(&self) -> String {
+        self.history
+            .iter()
+            .map(|entry| {
+                let (resistance, tolerance, tcr) = &entry.specs;
+                match entry.time {
+                    Some(time) => format!("{}\t{}\t{}\t{}\n", resistance, tolerance, tcr, time),
+                    None => format!("{}\t{}\t{}\n", resistance, tolerance, tcr),
+                }
+            })
+            .collect()
+    }
+
+    pub fn from_text(text: &str) -> SpecsHistory {
+        let mut history = SpecsHistory::default();
+        for line in text.lines().filter(|l| !l.trim().is_empty()) {
+            let mut fields = line.split('\t').map(|f| f.trim().to_string());
+            let specs = (
+                fields.next().unwrap_or_default(),
+                fields.next().unwrap_or_default(),
+                fields.next().unwrap_or_default(),
+            );
+            history.push(specs, fields.next().and_then(|time| time.parse().ok()));
         }
+        history
     }
 
     pub fn clear_idx(&mut self) {
         self.idx = None;
     }
+
+    pub fn get(&self, idx: usize) -> Option<&(String, String, String)> {
+        self.history.get(idx).map(|entry| &entry.specs)
+    }
+
+    /// The seconds since the Unix epoch when the entry was added.
+    pub fn time(&self, idx: usize) -> Option<u64> {
+        self.history.get(idx)?.time
+    }
+
+    pub fn len(&self) -> usize {
+        self.history.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.history.is_empty()
+    }
+
+    /// The index of the newest entry before `before` with an input containing `query`.
+    pub fn search(&self, query: &str, before: usize) -> Option<usize> {
+        self.history[..before.min(self.history.len())]
+            .iter()
+            .rposition(|HistoryEntry { specs, .. }| {
+                let (resistance, tolerance, tcr) = specs;
+                [resistance, tolerance, tcr]
+                    .iter()
+                    .any(|input| input.contains(query))
+            })
+    }
 }
 
-#[derive(Debug, Default, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
 pub enum InputFocus {
     #[default]
     Resistance,
@@ -74,28 +203,97 @@ impl InputFocus {
     }
 }
 
-#[derive(Debug, Default, PartialEq)]
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
 pub enum SelectedTab {
     #[default]
+    #[cfg_attr(feature = "serde", serde(rename = "colors"))]
     ColorCodesToSpecs,
+    #[cfg_attr(feature = "serde", serde(rename = "specs"))]
     SpecsToColorCodes,
+    ESeries,
+    Network,
+    OhmsLaw,
+    Divider,
+    Led,
+    Quiz,
+    Capacitor,
+    Inductor,
+    Compare,
+    Inventory,
 }
 
 impl SelectedTab {
-    pub fn toggle(&self) -> SelectedTab {
+    pub const ALL: [SelectedTab; 12] = [
+        SelectedTab::ColorCodesToSpecs,
+        SelectedTab::SpecsToColorCodes,
+        SelectedTab::ESeries,
+        SelectedTab::Network,
+        SelectedTab::OhmsLaw,
+        SelectedTab::Divider,
+        SelectedTab::Led,
+        SelectedTab::Quiz,
+        SelectedTab::Capacitor,
+        SelectedTab::Inductor,
+        SelectedTab::Compare,
+        SelectedTab::Inventory,
+    ];
+
+    /// The name of the tab in the config, the routes and the commands, e.g. "ohmslaw".
+    pub fn name(&self) -> &'static str {
         match self {
-            SelectedTab::ColorCodesToSpecs => SelectedTab::SpecsToColorCodes,
-            SelectedTab::SpecsToColorCodes => SelectedTab::ColorCodesToSpecs,
+            SelectedTab::ColorCodesToSpecs => "colors",
+            SelectedTab::SpecsToColorCodes => "specs",
+            SelectedTab::ESeries => "eseries",
+            SelectedTab::Network => "network",
+            SelectedTab::OhmsLaw => "ohmslaw",
+            SelectedTab::Divider => "divider",
+            SelectedTab::Led => "led",
+            SelectedTab::Quiz => "quiz",
+            SelectedTab::Capacitor => "capacitor",
+            SelectedTab::Inductor => "inductor",
+            SelectedTab::Compare => "compare",
+            SelectedTab::Inventory => "inventory",
         }
     }
-}
 
-impl From<&SelectedTab> for Option<usize> {
-    fn from(selected_tab: &SelectedTab) -> Self {
-        match selected_tab {
-            SelectedTab::ColorCodesToSpecs => Some(0),
-            SelectedTab::SpecsToColorCodes => Some(1),
-        }
+    /// The tab of its name, e.g. "ohmslaw", dashes and case are ignored.
+    pub fn from_name(name: &str) -> Option<SelectedTab> {
+        let name = name.to_lowercase().replace(['-', '_'], "");
+        SelectedTab::ALL.into_iter().find(|tab| tab.name() == name)
+    }
+
+    pub fn title(&self, language: Language) -> String {
+        let text = match self {
+            SelectedTab::ColorCodesToSpecs => Text::TabColorCodes,
+            SelectedTab::SpecsToColorCodes => Text::TabSpecs,
+            SelectedTab::ESeries => Text::TabESeries,
+            SelectedTab::Network => Text::TabNetwork,
+            SelectedTab::OhmsLaw => Text::TabOhmsLaw,
+            SelectedTab::Divider => Text::TabDivider,
+            SelectedTab::Led => Text::TabLed,
+            SelectedTab::Quiz => Text::TabQuiz,
+            SelectedTab::Capacitor => Text::TabCapacitor,
+            SelectedTab::Inductor => Text::TabInductor,
+            SelectedTab::Compare => Text::TabCompare,
+            SelectedTab::Inventory => Text::TabInventory,
+        };
+        format!(" {} ", language.text(text))
+    }
+
+    /// The next of the given tabs, a frontend passes the tabs it supports.
+    pub fn next(&self, tabs: &[SelectedTab]) -> SelectedTab {
+        let idx = tabs.iter().position(|t| t == self).unwrap_or(0);
+        tabs[(idx + 1) % tabs.len()]
+    }
+
+    pub fn prev(&self, tabs: &[SelectedTab]) -> SelectedTab {
+        let idx = tabs.iter().position(|t| t == self).unwrap_or(0);
+        tabs[(idx + tabs.len() - 1) % tabs.len()]
     }
 }
 
@@ -103,8 +301,39 @@ impl From<&SelectedTab> for Option<usize> {
 pub struct ColorCodesToSpecsModel {
     pub selected_band: usize,
     pub resistor: Resistor,
+    /// The resistors before the last edits, the most recent last.
+    pub undo: Vec<Resistor>,
+    /// The resistors of the undone edits, the most recent last.
+    pub redo: Vec<Resistor>,
+    /// Why the last edit wasn't possible, e.g. a flip to invalid bands.
+    pub error: Option<String>,
+    /// The temperature in °C to show the TCR drift of 6-band resistors at.
+    pub temperature: i32,
+    /// A measured resistance to compare with the tolerance range.
+    pub measured: Option<f64>,
+    /// The power rating in W to show the maximum voltage and current for.
+    pub power_rating: Option<f64>,
+}
+
+impl ColorCodesToSpecsModel {
+    /// The maximum load at the power rating, derated at the temperature.
+    pub fn power_limits(&self) -> Option<Result<PowerLimits, String>> {
+        let rating = self.power_rating?;
+        Some(PowerLimits::calculate(
+            self.resistor.specs().ohm,
+            rating,
+            self.temperature as f64,
+        ))
+    }
 }
 
+/// The number of edits of the color codes that can be undone.
+pub const UNDO_LEN: usize = 100;
+
+/// The temperatures in °C the TCR drift can be shown for.
+pub const TEMPERATURES: RangeInclusive<i32> = -55..=155;
+pub const TEMPERATURE_STEP: i32 = 5;
+
 impl Default for ColorCodesToSpecsModel {
     fn default() -> ColorCodesToSpecsModel {
         ColorCodesToSpecsModel {
@@ -117,19 +346,43 @@ impl Default for ColorCodesToSpecsModel {
                 band5: rusistor::Color::Brown,
                 band6: rusistor::Color::Black,
             },
+            undo: Vec::new(),
+            redo: Vec::new(),
+            error: None,
+            temperature: ResistorSpecs::REFERENCE_CELSIUS,
+            measured: None,
+            power_rating: None,
         }
     }
 }
 
+/// An incremental search through the history, like the reverse-i-search of shells.
+#[derive(Debug, Default, PartialEq)]
+pub struct HistorySearch {
+    pub query: String,
+    /// The index of the entry matching the query.
+    pub found: Option<usize>,
+}
+
 #[derive(Debug, Default)]
 pub struct SpecsToColorModel<'a> {
     pub resistance_textarea: TextArea<'a>,
     pub tolerance_textarea: TextArea<'a>,
     pub tcr_textarea: TextArea<'a>,
     pub focus: InputFocus,
+    /// The count of bands to determine, `None` for the fewest possible.
+    pub bands: Option<usize>,
     pub resistor: Option<Resistor>,
+    /// Determines the resistors of all bands counts into `all_resistors`, too.
+    pub show_all: bool,
+    pub all_resistors: Vec<Resistor>,
     pub history: SpecsHistory,
+    pub search: Option<HistorySearch>,
     pub error: Option<String>,
+    /// The input the error is about, `None` if it's about their combination.
+    pub error_input: Option<InputFocus>,
+    /// The decimal and thousands separators the inputs may contain.
+    pub number_format: NumberFormat,
 }
 
 pub(crate) fn set_textarea(textarea: &mut TextArea, content: String, cursormoves: Vec<CursorMove>) {
@@ -140,6 +393,49 @@ pub(crate) fn set_textarea(textarea: &mut TextArea, content: String, cursormoves
 }
 
 impl<'a> SpecsToColorModel<'a> {
+    pub fn input_value(&self, input: &InputFocus) -> &str {
+        match input {
+            InputFocus::Resistance => &self.resistance_textarea.lines()[0],
+            InputFocus::Tolerance => &self.tolerance_textarea.lines()[0],
+            InputFocus::Tcr => &self.tcr_textarea.lines()[0],
+        }
+    }
+
+    /// The value of the input with the separators the parsers take.
+    pub fn normalized_input(&self, input: &InputFocus) -> String {
+        self.number_format.normalize(self.input_value(input))
+    }
+
+    pub fn focused_textarea(&mut self) -> &mut TextArea<'a> {
+        match self.focus {
+            InputFocus::Resistance => &mut self.resistance_textarea,
+            InputFocus::Tolerance => &mut self.tolerance_textarea,
+            InputFocus::Tcr => &mut self.tcr_textarea,
+        }
+    }
+
+    /// The values the focused input can be picked from, the empty one for no tolerance or TCR.
+    pub fn choices(&self) -> Option<Vec<String>> {
+        let values = match self.focus {
+            InputFocus::Tolerance => Resistor::TOLERANCES.map(|t| t.to_string()).to_vec(),
+            InputFocus::Tcr => Resistor::TCRS.map(|t| t.to_string()).to_vec(),
+            InputFocus::Resistance => return None,
+        };
+        Some(std::iter::once(String::new()).chain(values).collect())
+    }
+
+    /// The index of the value of the focused input in its choices.
+    pub fn selected_choice(&self) -> Option<usize> {
+        let value = match self.focus {
+            InputFocus::Tolerance => self.tolerance_textarea.lines()[0].trim(),
+            InputFocus::Tcr => self.tcr_textarea.lines()[0].trim(),
+            InputFocus::Resistance => return None,
+        };
+        self.choices()?.iter().position(|c| {
+            c == value || (!c.is_empty() && c.parse::<f64>().ok() == value.parse::<f64>().ok())
+        })
+    }
+
     pub fn add_specs_to_history(&mut self) {
         let specs = (
             self.resistance_textarea.lines()[0].clone(),
@@ -150,10 +446,490 @@ impl<'a> SpecsToColorModel<'a> {
     }
 
     pub fn set_specs_from_history(&mut self) {
-        if let Some((a, b, c)) = self.history.try_get() {
-            set_textarea(&mut self.resistance_textarea, a.to_string(), vec![]);
-            set_textarea(&mut self.tolerance_textarea, b.to_string(), vec![]);
-            set_textarea(&mut self.tcr_textarea, c.to_string(), vec![]);
+        if let Some(specs) = self.history.try_get() {
+            self.set_specs(specs.clone());
+        }
+    }
+
+    pub fn set_specs(&mut self, (resistance, tolerance, tcr): (String, String, String)) {
+        set_textarea(&mut self.resistance_textarea, resistance, vec![]);
+        set_textarea(&mut self.tolerance_textarea, tolerance, vec![]);
+        set_textarea(&mut self.tcr_textarea, tcr, vec![]);
+    }
+}
+
+pub const ESERIES_DECADES: std::ops::RangeInclusive<i32> = -1..=9;
+
+#[derive(Debug)]
+pub struct ESeriesModel<'a> {
+    pub series: ESeries,
+    pub decade: i32,
+    pub filter_textarea: TextArea<'a>,
+    pub selected_value: usize,
+}
+
+impl<'a> Default for ESeriesModel<'a> {
+    fn default() -> ESeriesModel<'a> {
+        ESeriesModel {
+            series: ESeries::default(),
+            decade: 2,
+            filter_textarea: TextArea::default(),
+            selected_value: 0,
+        }
+    }
+}
+
+impl<'a> ESeriesModel<'a> {
+    /// The values of the selected series and decade matching the filter.
+    /// The filter matches the significant digits, so "47" finds 0.47, 4.7 and 470.
+    pub fn values(&self) -> Vec<f64> {
+        let filter = self.filter_textarea.lines()[0]
+            .chars()
+            .filter(|c| c.is_ascii_digit())
+            .collect::<String>();
+        self.series
+            .values_in_decade(self.decade)
+            .into_iter()
+            .filter(|v| {
+                let digits = v.to_string().replace('.', "");
+                digits.trim_start_matches('0').contains(&filter)
+            })
+            .collect()
+    }
+
+    pub fn selected_resistor(&self) -> Option<Resistor> {
+        let value = *self.values().get(self.selected_value)?;
+        self.series.resistor(value).ok()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct NetworkGroup {
+    pub connection: Connection,
+    pub parts: Vec<Combined>,
+}
+
+impl NetworkGroup {
+    pub fn combined(&self) -> Option<Combined> {
+        Combined::combine(self.connection, &self.parts)
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub enum NetworkFocus {
+    #[default]
+    Bands,
+    Value,
+}
+
+/// Groups of resistors, the groups are connected in series.
+#[derive(Debug)]
+pub struct NetworkModel<'a> {
+    pub groups: Vec<NetworkGroup>,
+    pub selected_group: usize,
+    pub focus: NetworkFocus,
+    pub bands: ColorCodesToSpecsModel,
+    pub value_textarea: TextArea<'a>,
+    pub error: Option<String>,
+}
+
+impl<'a> Default for NetworkModel<'a> {
+    fn default() -> NetworkModel<'a> {
+        NetworkModel {
+            groups: vec![NetworkGroup::default()],
+            selected_group: 0,
+            focus: NetworkFocus::default(),
+            bands: ColorCodesToSpecsModel::default(),
+            value_textarea: TextArea::default(),
+            error: None,
+        }
+    }
+}
+
+impl<'a> NetworkModel<'a> {
+    pub fn total(&self) -> Option<Combined> {
+        let groups = self
+            .groups
+            .iter()
+            .filter_map(|g| g.combined())
+            .collect::<Vec<Combined>>();
+        Combined::combine(Connection::Series, &groups)
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub enum OhmsLawFocus {
+    #[default]
+    Voltage,
+    Current,
+    Resistance,
+    Power,
+}
+
+impl OhmsLawFocus {
+    pub fn next(&self) -> OhmsLawFocus {
+        match self {
+            OhmsLawFocus::Voltage => OhmsLawFocus::Current,
+            OhmsLawFocus::Current => OhmsLawFocus::Resistance,
+            OhmsLawFocus::Resistance => OhmsLawFocus::Power,
+            OhmsLawFocus::Power => OhmsLawFocus::Voltage,
+        }
+    }
+
+    pub fn prev(&self) -> OhmsLawFocus {
+        match self {
+            OhmsLawFocus::Voltage => OhmsLawFocus::Power,
+            OhmsLawFocus::Current => OhmsLawFocus::Voltage,
+            OhmsLawFocus::Resistance => OhmsLawFocus::Current,
+            OhmsLawFocus::Power => OhmsLawFocus::Resistance,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct OhmsLawModel<'a> {
+    pub focus: OhmsLawFocus,
+    pub voltage_textarea: TextArea<'a>,
+    pub current_textarea: TextArea<'a>,
+    pub resistance_textarea: TextArea<'a>,
+    pub power_textarea: TextArea<'a>,
+    /// Whether the resistance follows the resistor of the color codes tab.
+    pub linked: bool,
+    pub result: Option<OhmsLaw>,
+    pub error: Option<String>,
+}
+
+impl<'a> OhmsLawModel<'a> {
+    pub fn focused_textarea(&mut self) -> &mut TextArea<'a> {
+        match self.focus {
+            OhmsLawFocus::Voltage => &mut self.voltage_textarea,
+            OhmsLawFocus::Current => &mut self.current_textarea,
+            OhmsLawFocus::Resistance => &mut self.resistance_textarea,
+            OhmsLawFocus::Power => &mut self.power_textarea,
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub enum DividerFocus {
+    #[default]
+    Vin,
+    Vout,
+}
+
+#[derive(Debug, Default)]
+pub struct DividerModel<'a> {
+    pub focus: DividerFocus,
+    pub vin_textarea: TextArea<'a>,
+    pub vout_textarea: TextArea<'a>,
+    pub series: ESeries,
+    /// The voltages the suggestions were calculated for.
+    pub voltages: Option<(f64, f64)>,
+    pub suggestions: Vec<Divider>,
+    pub selected_suggestion: usize,
+    pub error: Option<String>,
+}
+
+impl<'a> DividerModel<'a> {
+    pub fn selected_resistors(&self) -> Option<(Resistor, Resistor)> {
+        let divider = self.suggestions.get(self.selected_suggestion)?;
+        let r1 = self.series.resistor(divider.r1).ok()?;
+        let r2 = self.series.resistor(divider.r2).ok()?;
+        Some((r1, r2))
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub enum LedFocus {
+    #[default]
+    Supply,
+    ForwardVoltage,
+    Current,
+}
+
+impl LedFocus {
+    pub fn next(&self) -> LedFocus {
+        match self {
+            LedFocus::Supply => LedFocus::ForwardVoltage,
+            LedFocus::ForwardVoltage => LedFocus::Current,
+            LedFocus::Current => LedFocus::Supply,
+        }
+    }
+
+    pub fn prev(&self) -> LedFocus {
+        match self {
+            LedFocus::Supply => LedFocus::Current,
+            LedFocus::ForwardVoltage => LedFocus::Supply,
+            LedFocus::Current => LedFocus::ForwardVoltage,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct LedModel<'a> {
+    pub focus: LedFocus,
+    pub supply_textarea: TextArea<'a>,
+    pub forward_voltage_textarea: TextArea<'a>,
+    /// The desired current in mA.
+    pub current_textarea: TextArea<'a>,
+    pub series: ESeries,
+    pub result: Option<LedResistor>,
+    pub error: Option<String>,
+}
+
+impl<'a> LedModel<'a> {
+    pub fn focused_textarea(&mut self) -> &mut TextArea<'a> {
+        match self.focus {
+            LedFocus::Supply => &mut self.supply_textarea,
+            LedFocus::ForwardVoltage => &mut self.forward_voltage_textarea,
+            LedFocus::Current => &mut self.current_textarea,
+        }
+    }
+
+    pub fn resistor(&self) -> Option<Resistor> {
+        self.series.resistor(self.result?.standard).ok()
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub enum QuizMode {
+    /// Shows the bands and asks for the resistance.
+    #[default]
+    ColorsToValue,
+    /// Shows the specs and asks for the bands.
+    ValueToColors,
+}
+
+#[derive(Debug)]
+pub struct QuizModel<'a> {
+    pub rng: Rng,
+    pub mode: QuizMode,
+    pub question: Resistor,
+    pub answer_textarea: TextArea<'a>,
+    pub answer_bands: ColorCodesToSpecsModel,
+    pub asked: u32,
+    pub correct: u32,
+    pub streak: u32,
+    pub best_streak: u32,
+    /// Whether the last answer was correct and the solution of the last question.
+    pub last_result: Option<(bool, String)>,
+}
+
+impl<'a> QuizModel<'a> {
+    pub fn new(seed: u64) -> QuizModel<'a> {
+        let mut rng = Rng::new(seed);
+        let question = Resistor::random(&mut rng);
+        QuizModel {
+            rng,
+            mode: QuizMode::default(),
+            question,
+            answer_textarea: TextArea::default(),
+            answer_bands: ColorCodesToSpecsModel::default(),
+            asked: 0,
+            correct: 0,
+            streak: 0,
+            best_streak: 0,
+            last_result: None,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct CapacitorModel {
+    pub selected_band: usize,
+    pub capacitor: Capacitor,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum CompareSide {
+    #[default]
+    A,
+    B,
+}
+
+/// Two resistors side by side, e.g. a part and its substitute.
+#[derive(Debug, Default)]
+pub struct CompareModel {
+    pub a: ColorCodesToSpecsModel,
+    pub b: ColorCodesToSpecsModel,
+    pub focus: CompareSide,
+}
+
+impl CompareModel {
+    pub fn focused(&self) -> &ColorCodesToSpecsModel {
+        match self.focus {
+            CompareSide::A => &self.a,
+            CompareSide::B => &self.b,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct InductorModel {
+    pub selected_band: usize,
+    pub inductor: Inductor,
+}
+
+#[derive(Debug, Default)]
+pub struct InventoryModel<'a> {
+    pub inventory: Inventory,
+    /// A resistance with an optional tolerance in %, e.g. "3k3 5".
+    pub input_textarea: TextArea<'a>,
+    /// The range of the last search, all parts are listed without one.
+    pub search: Option<Combined>,
+    pub selected_part: usize,
+    pub error: Option<String>,
+}
+
+impl<'a> InventoryModel<'a> {
+    /// The indices of the listed parts.
+    pub fn listed(&self) -> Vec<usize> {
+        match &self.search {
+            Some(c) => self.inventory.search(c.ohm, c.min_ohm, c.max_ohm),
+            None => (0..self.inventory.parts.len()).collect(),
+        }
+    }
+
+    /// The index of the selected part in the inventory.
+    pub fn selected_part_idx(&self) -> Option<usize> {
+        self.listed().get(self.selected_part).copied()
+    }
+}
+
+/// The tabs the models of both frontends have, for the keys and messages they share.
+pub trait AppModel<'a> {
+    /// The tabs of the frontend, in the order `NextTab` goes through them.
+    fn tabs(&self) -> &[SelectedTab];
+    fn selected_tab(&self) -> SelectedTab;
+    fn set_selected_tab(&mut self, tab: SelectedTab);
+    fn color_codes_to_specs_mut(&mut self) -> &mut ColorCodesToSpecsModel;
+    fn specs_to_color(&self) -> &SpecsToColorModel<'a>;
+    fn specs_to_color_mut(&mut self) -> &mut SpecsToColorModel<'a>;
+    fn quiz(&self) -> &QuizModel<'a>;
+    fn quiz_mut(&mut self) -> &mut QuizModel<'a>;
+}
+
+/// The serialization of the models, without the undo stacks, errors and other transient state.
+#[cfg(feature = "serde")]
+mod state {
+    use rusistor::{Color, Resistor};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+    use super::{
+        ColorCodesToSpecsModel, HistoryEntry, InputFocus, SpecsHistory, SpecsToColorModel,
+    };
+
+    impl Serialize for SpecsHistory {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.history.serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for SpecsHistory {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let mut history = SpecsHistory {
+                history: Vec::<HistoryEntry>::deserialize(deserializer)?,
+                ..SpecsHistory::default()
+            };
+            history.truncate();
+            Ok(history)
+        }
+    }
+
+    /// The resistors are saved as their bands and validated by `Resistor::try_create`.
+    fn bands(resistor: &Resistor) -> Vec<Color> {
+        resistor.bands().into_iter().cloned().collect()
+    }
+
+    fn resistor<E: Error>(bands: Vec<Color>) -> Result<Resistor, E> {
+        Resistor::try_create(bands).map_err(E::custom)
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct ColorCodesState {
+        selected_band: usize,
+        bands: Vec<Color>,
+        temperature: i32,
+        measured: Option<f64>,
+        power_rating: Option<f64>,
+    }
+
+    impl Serialize for ColorCodesToSpecsModel {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            ColorCodesState {
+                selected_band: self.selected_band,
+                bands: bands(&self.resistor),
+                temperature: self.temperature,
+                measured: self.measured,
+                power_rating: self.power_rating,
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for ColorCodesToSpecsModel {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let state = ColorCodesState::deserialize(deserializer)?;
+            let resistor = resistor(state.bands)?;
+            Ok(ColorCodesToSpecsModel {
+                selected_band: state.selected_band.min(resistor.bands().len() - 1),
+                resistor,
+                temperature: state.temperature,
+                measured: state.measured,
+                power_rating: state.power_rating,
+                ..ColorCodesToSpecsModel::default()
+            })
+        }
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct SpecsState<H> {
+        resistance: String,
+        tolerance: String,
+        tcr: String,
+        focus: InputFocus,
+        bands: Option<usize>,
+        resistor: Option<Vec<Color>>,
+        show_all: bool,
+        all_resistors: Vec<Vec<Color>>,
+        history: H,
+    }
+
+    impl Serialize for SpecsToColorModel<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            SpecsState {
+                resistance: self.resistance_textarea.lines()[0].clone(),
+                tolerance: self.tolerance_textarea.lines()[0].clone(),
+                tcr: self.tcr_textarea.lines()[0].clone(),
+                focus: self.focus.clone(),
+                bands: self.bands,
+                resistor: self.resistor.as_ref().map(bands),
+                show_all: self.show_all,
+                all_resistors: self.all_resistors.iter().map(bands).collect(),
+                history: &self.history,
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for SpecsToColorModel<'_> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let state = SpecsState::<SpecsHistory>::deserialize(deserializer)?;
+            let mut model = SpecsToColorModel {
+                focus: state.focus,
+                bands: state.bands.filter(|bands| (4..=6).contains(bands)),
+                resistor: state.resistor.map(resistor).transpose()?,
+                show_all: state.show_all,
+                all_resistors: state
+                    .all_resistors
+                    .into_iter()
+                    .map(resistor)
+                    .collect::<Result<_, _>>()?,
+                history: state.history,
+                ..SpecsToColorModel::default()
+            };
+            model.set_specs((state.resistance, state.tolerance, state.tcr));
+            Ok(model)
         }
     }
 }
@@ -0,0 +1,406 @@
+use rusistor::{Color, Resistor};
+
+use crate::{
+    model::{ColorCodesToSpecsModel, SelectedTab, SpecsToColorModel},
+    update::{try_determine_all, try_determine_resistor},
+    view::{band_code, parse_band},
+};
+
+/// The version of the format of saved sessions, increased on incompatible changes of `Session`.
+pub const SESSION_VERSION: u32 = 1;
+
+/// A saved state with the version of its format, both frontends save their sessions in it.
+#[cfg(feature = "serde")]
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Versioned<T> {
+    pub version: u32,
+    pub state: T,
+}
+
+#[cfg(feature = "serde")]
+impl Versioned<Session> {
+    /// The session, an error for the ones of other versions.
+    pub fn into_session(self) -> Result<Session, String> {
+        if self.version == SESSION_VERSION {
+            Ok(self.state)
+        } else {
+            Err(format!("unsupported session version {}", self.version))
+        }
+    }
+}
+
+/// The state of the color codes and specs tabs that is restored on the next launch,
+/// the specs history is persisted on its own.
+#[derive(Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Session {
+    pub tab: SelectedTab,
+    pub bands: Vec<Color>,
+    pub selected_band: usize,
+    pub resistance: String,
+    pub tolerance: String,
+    pub tcr: String,
+    pub specs_bands: Option<usize>,
+    pub show_all: bool,
+}
+
+impl Session {
+    pub fn new(
+        tab: SelectedTab,
+        color_codes: &ColorCodesToSpecsModel,
+        specs: &SpecsToColorModel,
+    ) -> Session {
+        Session {
+            tab,
+            bands: color_codes.resistor.bands().into_iter().cloned().collect(),
+            selected_band: color_codes.selected_band,
+            resistance: specs.resistance_textarea.lines()[0].clone(),
+            tolerance: specs.tolerance_textarea.lines()[0].clone(),
+            tcr: specs.tcr_textarea.lines()[0].clone(),
+            specs_bands: specs.bands,
+            show_all: specs.show_all,
+        }
+    }
+
+    /// The session with the version of its format, to save it.
+    #[cfg(feature = "serde")]
+    pub fn versioned(&self) -> Versioned<&Session> {
+        Versioned {
+            version: SESSION_VERSION,
+            state: self,
+        }
+    }
+
+    /// The session as TOML with its version, for the saved state of the web app.
+    #[cfg(feature = "serde")]
+    pub fn to_toml(&self) -> Result<String, String> {
+        toml::to_string(&self.versioned()).map_err(|e| e.to_string())
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn from_toml(text: &str) -> Result<Session, String> {
+        toml::from_str::<Versioned<Session>>(text)
+            .map_err(|e| e.to_string())?
+            .into_session()
+    }
+
+    /// Restores the models and returns the tab, invalid bands keep the current resistor.
+    pub fn restore(
+        &self,
+        color_codes: &mut ColorCodesToSpecsModel,
+        specs: &mut SpecsToColorModel,
+    ) -> SelectedTab {
+        self.restore_resistor(color_codes);
+        specs.set_specs((
+            self.resistance.clone(),
+            self.tolerance.clone(),
+            self.tcr.clone(),
+        ));
+        specs.bands = self.specs_bands.filter(|bands| (4..=6).contains(bands));
+        specs.show_all = self.show_all;
+        // restoring isn't a determination to add to the history
+        if !self.resistance.trim().is_empty() {
+            specs.resistor =
+                try_determine_resistor(&self.resistance, &self.tolerance, &self.tcr, specs.bands)
+                    .ok();
+            if specs.show_all {
+                specs.all_resistors =
+                    try_determine_all(&self.resistance, &self.tolerance, &self.tcr);
+            }
+        }
+        self.tab
+    }
+
+    /// The tab as the route and the bands and the specs inputs as the query of the fragment
+    /// of a URL without the `#`, e.g. "/specs?colors=bn-bk-rd-gd&resistance=4k7".
+    pub fn to_fragment(&self) -> String {
+        let tab = self.tab.name();
+        let colors = self
+            .bands
+            .iter()
+            .map(|band| band_code(band).to_lowercase())
+            .collect::<Vec<_>>()
+            .join("-");
+        let query = [
+            ("colors", colors.as_str()),
+            ("resistance", self.resistance.as_str()),
+            ("tolerance", self.tolerance.as_str()),
+            ("tcr", self.tcr.as_str()),
+        ]
+        .iter()
+        .filter(|(_, value)| !value.is_empty())
+        .map(|(key, value)| format!("{}={}", key, percent_encode(value)))
+        .collect::<Vec<_>>()
+        .join("&");
+        if query.is_empty() {
+            format!("/{}", tab)
+        } else {
+            format!("/{}?{}", tab, query)
+        }
+    }
+
+    /// The session of a URL fragment, unknown keys and invalid values are left out.
+    /// The tab is read from the route or from the `tab` key of older links.
+    pub fn from_fragment(fragment: &str) -> Session {
+        let mut session = Session::default();
+        let fragment = fragment.trim_start_matches('#');
+        let query = match fragment.strip_prefix('/') {
+            Some(route) => {
+                let (route, query) = route.split_once('?').unwrap_or((route, ""));
+                session.tab = parse_tab(route);
+                query
+            }
+            None => fragment,
+        };
+        for pair in query.split('&') {
+            let Some((key, value)) = pair.split_once('=') else {
+                continue;
+            };
+            let value = percent_decode(value);
+            match key {
+                "tab" => session.tab = parse_tab(&value),
+                "colors" => session.bands = parse_bands(&value, '-'),
+                "resistance" => session.resistance = value,
+                "tolerance" => session.tolerance = value,
+                "tcr" => session.tcr = value,
+                _ => (),
+            }
+        }
+        session
+    }
+
+    /// The session of the query of a URL to link to a resistor from other pages, `None` if it
+    /// has neither bands nor specs. "?r=4.7k&tol=5&tcr=50" opens the specs tab with the specs,
+    /// "?bands=br,bk,rd,gd" the color codes tab with the bands.
+    pub fn from_query(query: &str) -> Option<Session> {
+        let mut session = Session::default();
+        for pair in query.trim_start_matches('?').split('&') {
+            let Some((key, value)) = pair.split_once('=') else {
+                continue;
+            };
+            let value = percent_decode(&value.replace('+', " "));
+            match key {
+                "bands" => session.bands = parse_bands(&value, ','),
+                "r" => session.resistance = value,
+                "tol" => session.tolerance = value,
+                "tcr" => session.tcr = value,
+                _ => (),
+            }
+        }
+        if !session.resistance.is_empty() {
+            session.tab = SelectedTab::SpecsToColorCodes;
+        } else if session.bands.is_empty() {
+            return None;
+        }
+        Some(session)
+    }
+
+    /// Restores only the resistor of the color codes tab, invalid bands keep the current one.
+    pub fn restore_resistor(&self, color_codes: &mut ColorCodesToSpecsModel) {
+        if let Ok(resistor) = Resistor::try_create(self.bands.clone()) {
+            color_codes.selected_band = self.selected_band.min(resistor.bands().len() - 1);
+            color_codes.resistor = resistor;
+        }
+    }
+}
+
+/// The bands of their codes, names or unique starts of names, e.g. "bn-bk-rd-gd" or
+/// "br,bk,rd,gd", empty if one is unknown.
+fn parse_bands(text: &str, separator: char) -> Vec<Color> {
+    text.split(separator)
+        .map(parse_band)
+        .collect::<Option<_>>()
+        .unwrap_or_default()
+}
+
+/// The tab of its config name, the default one for unknown names.
+fn parse_tab(name: &str) -> SelectedTab {
+    SelectedTab::from_name(name).unwrap_or_default()
+}
+
+/// Escapes all but the unreserved characters of URLs.
+fn percent_encode(text: &str) -> String {
+    text.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// Unescapes `%XX`, invalid escapes are kept as they are.
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'%')
+            .then(|| text.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(b) => {
+                decoded.push(b);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Session;
+    use crate::{
+        model::{ColorCodesToSpecsModel, SelectedTab, SpecsToColorModel},
+        update::{ColorCodesMsg, SpecsMsg, update_on_colorcodemsg, update_on_specsmsg},
+    };
+    use rusistor::Color;
+
+    #[test]
+    fn test_restore() {
+        let mut color_codes = ColorCodesToSpecsModel::default();
+        update_on_colorcodemsg(&mut color_codes, ColorCodesMsg::FiveBands);
+        update_on_colorcodemsg(&mut color_codes, ColorCodesMsg::NextColor);
+        let mut specs = SpecsToColorModel::default();
+        specs.resistance_textarea.insert_str("4k7");
+        update_on_specsmsg(&mut specs, SpecsMsg::Determine);
+        let session = Session::new(SelectedTab::SpecsToColorCodes, &color_codes, &specs);
+
+        let mut restored_color_codes = ColorCodesToSpecsModel::default();
+        let mut restored_specs = SpecsToColorModel::default();
+        let tab = session.restore(&mut restored_color_codes, &mut restored_specs);
+        assert_eq!(tab, SelectedTab::SpecsToColorCodes);
+        assert_eq!(restored_color_codes.resistor, color_codes.resistor);
+        assert_eq!(restored_specs.resistance_textarea.lines()[0], "4k7");
+        assert_eq!(restored_specs.resistor, specs.resistor);
+        assert!(restored_specs.history.is_empty());
+
+        let mut restored_color_codes = ColorCodesToSpecsModel::default();
+        session.restore_resistor(&mut restored_color_codes);
+        assert_eq!(restored_color_codes.resistor, color_codes.resistor);
+        let invalid = Session {
+            bands: vec![],
+            ..Session::default()
+        };
+        invalid.restore_resistor(&mut restored_color_codes);
+        assert_eq!(restored_color_codes.resistor, color_codes.resistor);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_toml() {
+        let session = Session {
+            tab: SelectedTab::SpecsToColorCodes,
+            bands: vec![Color::Brown, Color::Black, Color::Red, Color::Gold],
+            selected_band: 2,
+            resistance: "4k7".to_string(),
+            specs_bands: Some(5),
+            show_all: true,
+            ..Session::default()
+        };
+        let text = session.to_toml().unwrap();
+        assert!(text.starts_with("version = 1\n"), "{}", text);
+        assert_eq!(Session::from_toml(&text), Ok(session));
+        assert_eq!(
+            Session::from_toml("version = 2\n[state]\ntab = \"specs\""),
+            Err("unsupported session version 2".to_string())
+        );
+        assert!(Session::from_toml("tab = \"specs\"").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_models_toml() {
+        let mut color_codes = ColorCodesToSpecsModel::default();
+        update_on_colorcodemsg(&mut color_codes, ColorCodesMsg::FourBands);
+        update_on_colorcodemsg(&mut color_codes, ColorCodesMsg::NextBand);
+        color_codes.measured = Some(9.5);
+        let text = toml::to_string(&color_codes).unwrap();
+        let restored = toml::from_str::<ColorCodesToSpecsModel>(&text).unwrap();
+        assert_eq!(restored.resistor, color_codes.resistor);
+        assert_eq!(restored.selected_band, 1);
+        assert_eq!(restored.measured, Some(9.5));
+
+        let mut specs = SpecsToColorModel::default();
+        specs.resistance_textarea.insert_str("4k7");
+        update_on_specsmsg(&mut specs, SpecsMsg::Determine);
+        update_on_specsmsg(&mut specs, SpecsMsg::NextSpecInput);
+        let text = toml::to_string(&specs).unwrap();
+        let restored = toml::from_str::<SpecsToColorModel>(&text).unwrap();
+        assert_eq!(restored.resistance_textarea.lines()[0], "4k7");
+        assert_eq!(restored.focus, specs.focus);
+        assert_eq!(restored.resistor, specs.resistor);
+        assert_eq!(restored.history.get(0), specs.history.get(0));
+        assert_eq!(restored.history.len(), 1);
+
+        let corrupt = text.replacen("yellow", "black", 1);
+        assert!(toml::from_str::<SpecsToColorModel>(&corrupt).is_err());
+        let corrupt = "selected_band = 0\nbands = [\"brown\", \"black\", \"red\", \"pink\"]\ntemperature = 25";
+        assert!(toml::from_str::<ColorCodesToSpecsModel>(corrupt).is_err());
+        let valid = corrupt.replace("pink", "gold");
+        assert!(toml::from_str::<ColorCodesToSpecsModel>(&valid).is_ok());
+
+        for tab in SelectedTab::ALL {
+            let name = toml::Value::try_from(tab).unwrap();
+            assert_eq!(name.as_str(), Some(tab.name()));
+        }
+    }
+
+    #[test]
+    fn test_fragment() {
+        let session = Session {
+            tab: SelectedTab::SpecsToColorCodes,
+            bands: vec![Color::Brown, Color::Black, Color::Red, Color::Gold],
+            resistance: "4k7".to_string(),
+            tolerance: "±1%".to_string(),
+            ..Session::default()
+        };
+        let fragment = session.to_fragment();
+        assert_eq!(
+            fragment,
+            "/specs?colors=bn-bk-rd-gd&resistance=4k7&tolerance=%C2%B11%25"
+        );
+        assert_eq!(Session::from_fragment(&format!("#{}", fragment)), session);
+        let route = Session::from_fragment("#/specs");
+        assert_eq!(route.tab, SelectedTab::SpecsToColorCodes);
+        assert_eq!(Session::from_fragment(&route.to_fragment()), route);
+        assert_eq!(route.to_fragment(), "/specs");
+
+        let session = Session::from_fragment("colors=Yellow-VT-rd-xx&tab=nope&bands=4");
+        assert_eq!(session, Session::default());
+        let session = Session::from_fragment("tab=colors&colors=yellow-vt-rd-gd&tcr=50%");
+        assert_eq!(session.bands[..2], [Color::Yellow, Color::Violet]);
+        assert_eq!(session.tcr, "50%");
+    }
+
+    #[test]
+    fn test_query() {
+        let session = Session::from_query("?r=4.7k&tol=5&tcr=50").unwrap();
+        assert_eq!(session.tab, SelectedTab::SpecsToColorCodes);
+        assert_eq!(
+            (session.resistance, session.tolerance, session.tcr),
+            ("4.7k".to_string(), "5".to_string(), "50".to_string())
+        );
+        let session = Session::from_query("bands=br,bk,rd,gd").unwrap();
+        assert_eq!(session.tab, SelectedTab::ColorCodesToSpecs);
+        assert_eq!(
+            session.bands,
+            [Color::Brown, Color::Black, Color::Red, Color::Gold]
+        );
+        assert_eq!(Session::from_query("?backend=webgl2"), None);
+        assert_eq!(Session::from_query("?bands=br,bk,xx"), None);
+        // "b" starts black, brown and blue
+        assert_eq!(Session::from_query("?bands=b,bk,rd"), None);
+    }
+}
@@ -0,0 +1,134 @@
+use std::{collections::HashMap, str::FromStr};
+
+use ratatui_core::style::{Color, Modifier, Style};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum Theme {
+    #[default]
+    Dark,
+    Light,
+}
+
+impl Theme {
+    pub const ALL: [Theme; 2] = [Theme::Dark, Theme::Light];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Theme::Dark => "dark",
+            Theme::Light => "light",
+        }
+    }
+
+    pub fn palette(&self) -> Palette {
+        match self {
+            Theme::Dark => Palette::dark(),
+            Theme::Light => Palette::light(),
+        }
+    }
+
+    pub fn next(&self) -> Theme {
+        match self {
+            Theme::Dark => Theme::Light,
+            Theme::Light => Theme::Dark,
+        }
+    }
+}
+
+impl FromStr for Theme {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Theme::ALL
+            .into_iter()
+            .find(|theme| theme.name().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| format!("invalid theme {}", s))
+    }
+}
+
+/// The colors and styles the views are drawn with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Palette {
+    pub accent: Color,
+    /// The style of the selected entry of a list.
+    pub highlight: Style,
+    /// Colors to show the bands with instead of the default ones.
+    pub band_colors: HashMap<rusistor::Color, Color>,
+    /// Conveys the band colors with names, codes and patterns instead of colors.
+    pub monochrome: bool,
+    /// Adds the codes and patterns of the bands to their colors.
+    pub accessible: bool,
+    /// Adds the hex values and the other names of the colors to the band lists.
+    pub color_details: bool,
+}
+
+impl Palette {
+    pub fn dark() -> Palette {
+        Palette::with_accent(Color::Yellow)
+    }
+
+    pub fn light() -> Palette {
+        Palette::with_accent(Color::Blue)
+    }
+
+    fn with_accent(accent: Color) -> Palette {
+        Palette {
+            accent,
+            highlight: Style::default().fg(accent).add_modifier(Modifier::BOLD),
+            band_colors: HashMap::new(),
+            monochrome: false,
+            accessible: false,
+            color_details: false,
+        }
+    }
+
+    pub fn with_overrides(mut self, overrides: &PaletteOverrides) -> Palette {
+        if let Some(accent) = overrides.accent {
+            self.accent = accent;
+            self.highlight = self.highlight.fg(accent);
+        }
+        if let Some(highlight) = overrides.highlight {
+            self.highlight = self.highlight.fg(highlight);
+        }
+        self.band_colors.extend(overrides.band_colors.iter());
+        self
+    }
+
+    /// The color to show a band with.
+    pub fn band_color(&self, color: &rusistor::Color) -> Color {
+        self.band_colors
+            .get(color)
+            .copied()
+            .unwrap_or_else(|| default_band_color(color))
+    }
+}
+
+/// User defined colors that replace the ones of a palette.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PaletteOverrides {
+    pub accent: Option<Color>,
+    pub highlight: Option<Color>,
+    pub band_colors: HashMap<rusistor::Color, Color>,
+}
+
+pub fn default_band_color(color: &rusistor::Color) -> Color {
+    match color {
+        rusistor::Color::Black => Color::Black,
+        rusistor::Color::Brown => Color::Rgb(165, 42, 42),
+        rusistor::Color::Red => Color::Red,
+        rusistor::Color::Orange => Color::Rgb(255, 165, 0),
+        rusistor::Color::Yellow => Color::Yellow,
+        rusistor::Color::Green => Color::Green,
+        rusistor::Color::Blue => Color::Blue,
+        rusistor::Color::Violet => Color::Rgb(148, 0, 211),
+        rusistor::Color::Grey => Color::Gray,
+        rusistor::Color::White => Color::White,
+        rusistor::Color::Gold => Color::Rgb(255, 215, 0),
+        rusistor::Color::Silver => Color::Rgb(192, 192, 192),
+        rusistor::Color::Pink => Color::Rgb(255, 105, 180),
+    }
+}
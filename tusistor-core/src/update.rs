@@ -1,9 +1,37 @@
 use std::str::FromStr;
 
-use rusistor::{Color, Resistor};
+use rusistor::{
+    Color, ColorCoded, Resistor,
+    capacitor::Capacitor,
+    divider::Divider,
+    led::LedResistor,
+    network::{Combined, Connection},
+    ohms_law::OhmsLaw,
+    power::POWER_RATINGS,
+};
 
-use crate::model::{ColorCodesToSpecsModel, InputFocus, SpecsToColorModel};
+use crate::key::{KeyCode, KeyInput};
+use crate::model::{
+    AppModel, CapacitorModel, ColorCodesToSpecsModel, CompareModel, CompareSide, DividerFocus,
+    DividerModel, ESERIES_DECADES, ESeriesModel, HistorySearch, InductorModel, InputFocus,
+    InventoryModel, LedModel, NetworkFocus, NetworkGroup, NetworkModel, OhmsLawModel, QuizMode,
+    QuizModel, SelectedTab, SpecsToColorModel, TEMPERATURE_STEP, TEMPERATURES, UNDO_LEN,
+    set_textarea,
+};
 
+/// The messages both frontends share, each wraps them in its own `Msg` next to the ones
+/// only it has and applies them with `update_on_msg`.
+#[derive(Debug)]
+pub enum Msg {
+    NextTab,
+    PrevTab,
+    SelectTab { tab: SelectedTab },
+    SpecsMsg { msg: SpecsMsg },
+    ColorCodesMsg { msg: ColorCodesMsg },
+    QuizMsg { msg: QuizMsg },
+}
+
+#[derive(Debug)]
 pub enum ColorCodesMsg {
     ThreeBands,
     FourBands,
@@ -13,8 +41,41 @@ pub enum ColorCodesMsg {
     PrevBand,
     NextColor,
     PrevColor,
+    SelectBand {
+        band_idx: usize,
+    },
+    /// Focuses the band and selects the color if it's valid in that band.
+    SelectColor {
+        band_idx: usize,
+        color: Color,
+    },
+    /// Reverses the bands, to read a part from the other side.
+    Flip,
+    /// Selects the next color of the focused band starting with the letter, `k` is black.
+    JumpToColor {
+        letter: char,
+    },
+    /// Replaces the resistor, e.g. with a recalled one.
+    SetResistor {
+        resistor: Resistor,
+    },
+    /// Restores the resistor before the last edit, including changes of the bands count.
+    Undo,
+    Redo,
+    /// Raises the temperature of the TCR drift by `TEMPERATURE_STEP`.
+    Warmer,
+    Cooler,
+    SetMeasured {
+        ohm: Option<f64>,
+    },
+    /// Cycles through the common power ratings and none.
+    NextPowerRating,
+    SetPowerRating {
+        watts: Option<f64>,
+    },
 }
 
+#[derive(Debug)]
 pub enum SpecsMsg {
     Determine,
     NextSpecInput,
@@ -22,9 +83,273 @@ pub enum SpecsMsg {
     PrevHistory,
     NextHistory,
     Reset,
+    /// Cycles through the fewest possible bands and 4, 5 or 6 bands.
+    NextBandsCount,
+    /// Shows the resistors of all bands counts or only the determined one.
+    ToggleShowAll,
+    DismissError,
+    /// Picks the next valid value of the focused input.
+    NextChoice,
+    PrevChoice,
+    /// Starts a search through the history or finds the next older match.
+    SearchHistory,
+    SearchInput {
+        c: char,
+    },
+    SearchBackspace,
+    /// Takes over the inputs of the found entry and ends the search.
+    AcceptSearch,
+    CancelSearch,
+    /// Replaces the inputs, e.g. with the ones of a command.
+    SetSpecs {
+        resistance: String,
+        tolerance: String,
+        tcr: String,
+    },
+}
+
+#[derive(Debug)]
+pub enum ESeriesMsg {
+    NextSeries,
+    PrevSeries,
+    NextDecade,
+    PrevDecade,
+    NextValue,
+    PrevValue,
+    FilterChanged,
+}
+
+#[derive(Debug)]
+pub enum NetworkMsg {
+    BandsMsg {
+        msg: ColorCodesMsg,
+    },
+    ToggleFocus,
+    AddPart,
+    /// Adds a part of another tab to the selected group.
+    Add {
+        part: Combined,
+    },
+    RemovePart,
+    AddGroup,
+    ToggleConnection,
+    NextGroup,
+    PrevGroup,
+    Clear,
+}
+
+#[derive(Debug)]
+pub enum CompareMsg {
+    /// Focuses the bands of resistor A or B.
+    Focus {
+        side: CompareSide,
+    },
+    BandsMsg {
+        msg: ColorCodesMsg,
+    },
+}
+
+#[derive(Debug)]
+pub enum OhmsLawMsg {
+    Calculate,
+    NextInput,
+    PrevInput,
+    /// Links or unlinks the resistance to the given resistance of the color codes tab.
+    ToggleLink {
+        ohm: f64,
+    },
+    LinkedResistanceChanged {
+        ohm: f64,
+    },
+    Reset,
+}
+
+#[derive(Debug)]
+pub enum DividerMsg {
+    Calculate,
+    ToggleInput,
+    NextSeries,
+    PrevSeries,
+    NextSuggestion,
+    PrevSuggestion,
+}
+
+#[derive(Debug)]
+pub enum LedMsg {
+    Calculate,
+    NextInput,
+    PrevInput,
+    NextSeries,
+    PrevSeries,
+}
+
+#[derive(Debug)]
+pub enum QuizMsg {
+    BandsMsg { msg: ColorCodesMsg },
+    Check,
+    Skip,
+    ToggleMode,
+}
+
+#[derive(Debug)]
+pub enum CapacitorMsg {
+    FourBands,
+    FiveBands,
+    NextBand,
+    PrevBand,
+    NextColor,
+    PrevColor,
+    SelectBand {
+        band_idx: usize,
+    },
+    /// Focuses the band and selects the color if it's valid in that band.
+    SelectColor {
+        band_idx: usize,
+        color: Color,
+    },
+}
+
+#[derive(Debug)]
+pub enum InductorMsg {
+    NextBand,
+    PrevBand,
+    NextColor,
+    PrevColor,
+    SelectBand {
+        band_idx: usize,
+    },
+    /// Focuses the band and selects the color if it's valid in that band.
+    SelectColor {
+        band_idx: usize,
+        color: Color,
+    },
+}
+
+#[derive(Debug)]
+pub enum InventoryMsg {
+    /// Adds a resistor, the tolerance is expected as a fraction.
+    Add {
+        ohm: f64,
+        tolerance: f64,
+    },
+    AddInput,
+    Search,
+    ShowAll,
+    NextPart,
+    PrevPart,
+    Increment,
+    Decrement,
+    Remove,
+}
+
+impl InventoryMsg {
+    /// Whether the stock is changed by the message and needs to be stored.
+    pub fn changes_stock(&self) -> bool {
+        matches!(
+            self,
+            InventoryMsg::Add { .. }
+                | InventoryMsg::AddInput
+                | InventoryMsg::Increment
+                | InventoryMsg::Decrement
+                | InventoryMsg::Remove
+        )
+    }
+}
+
+/// The message of a key that means the same in both frontends, `None` for the other keys.
+/// The frontends check their own keys, e.g. the ones to switch the tabs, first.
+pub fn handle_key<'a>(model: &impl AppModel<'a>, key: &KeyInput) -> Option<Msg> {
+    let specs_msg = |msg| Some(Msg::SpecsMsg { msg });
+    let color_codes_msg = |msg| Some(Msg::ColorCodesMsg { msg });
+    let quiz_bands_msg = |msg| {
+        Some(Msg::QuizMsg {
+            msg: QuizMsg::BandsMsg { msg },
+        })
+    };
+    match (model.selected_tab(), key.code) {
+        (SelectedTab::ColorCodesToSpecs, KeyCode::Up) => color_codes_msg(ColorCodesMsg::PrevColor),
+        (SelectedTab::ColorCodesToSpecs, KeyCode::Down) => {
+            color_codes_msg(ColorCodesMsg::NextColor)
+        }
+        (SelectedTab::ColorCodesToSpecs, KeyCode::Left) => color_codes_msg(ColorCodesMsg::PrevBand),
+        (SelectedTab::ColorCodesToSpecs, KeyCode::Right) => {
+            color_codes_msg(ColorCodesMsg::NextBand)
+        }
+        (SelectedTab::ColorCodesToSpecs, KeyCode::Char('u')) => {
+            color_codes_msg(ColorCodesMsg::Undo)
+        }
+        (SelectedTab::ColorCodesToSpecs, KeyCode::Char('r')) if key.ctrl => {
+            color_codes_msg(ColorCodesMsg::Redo)
+        }
+        (SelectedTab::ColorCodesToSpecs, KeyCode::Char('+')) => {
+            color_codes_msg(ColorCodesMsg::Warmer)
+        }
+        (SelectedTab::ColorCodesToSpecs, KeyCode::Char('-')) => {
+            color_codes_msg(ColorCodesMsg::Cooler)
+        }
+        (SelectedTab::ColorCodesToSpecs, KeyCode::Char('W')) => {
+            color_codes_msg(ColorCodesMsg::NextPowerRating)
+        }
+        (SelectedTab::ColorCodesToSpecs, KeyCode::Char('f')) => {
+            color_codes_msg(ColorCodesMsg::Flip)
+        }
+        (SelectedTab::ColorCodesToSpecs, KeyCode::Char(letter))
+            if letter.is_ascii_lowercase() && !key.ctrl =>
+        {
+            color_codes_msg(ColorCodesMsg::JumpToColor { letter })
+        }
+        (SelectedTab::ColorCodesToSpecs, KeyCode::Char(c @ '3'..='6')) => {
+            color_codes_msg(bands_count_msg(c))
+        }
+        (SelectedTab::SpecsToColorCodes, KeyCode::Enter | KeyCode::Esc)
+            if model.specs_to_color().error.is_some() =>
+        {
+            specs_msg(SpecsMsg::DismissError)
+        }
+        (SelectedTab::SpecsToColorCodes, KeyCode::Enter) => specs_msg(SpecsMsg::Determine),
+        (SelectedTab::SpecsToColorCodes, KeyCode::Up) => specs_msg(SpecsMsg::PrevHistory),
+        (SelectedTab::SpecsToColorCodes, KeyCode::Down) => specs_msg(SpecsMsg::NextHistory),
+        (SelectedTab::SpecsToColorCodes, KeyCode::Char('X')) => specs_msg(SpecsMsg::Reset),
+        (SelectedTab::Quiz, KeyCode::Enter) => Some(Msg::QuizMsg {
+            msg: QuizMsg::Check,
+        }),
+        (SelectedTab::Quiz, code) if model.quiz().mode == QuizMode::ValueToColors => match code {
+            KeyCode::Up => quiz_bands_msg(ColorCodesMsg::PrevColor),
+            KeyCode::Down => quiz_bands_msg(ColorCodesMsg::NextColor),
+            KeyCode::Left => quiz_bands_msg(ColorCodesMsg::PrevBand),
+            KeyCode::Right => quiz_bands_msg(ColorCodesMsg::NextBand),
+            KeyCode::Char(c @ '3'..='6') => quiz_bands_msg(bands_count_msg(c)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The message of the bands count of a digit from 3 to 6.
+fn bands_count_msg(digit: char) -> ColorCodesMsg {
+    match digit {
+        '3' => ColorCodesMsg::ThreeBands,
+        '4' => ColorCodesMsg::FourBands,
+        '5' => ColorCodesMsg::FiveBands,
+        _ => ColorCodesMsg::SixBands,
+    }
+}
+
+/// Applies a shared message, the frontend does its own follow-ups like saving the history.
+pub fn update_on_msg<'a>(model: &mut impl AppModel<'a>, msg: Msg) {
+    match msg {
+        Msg::NextTab => model.set_selected_tab(model.selected_tab().next(model.tabs())),
+        Msg::PrevTab => model.set_selected_tab(model.selected_tab().prev(model.tabs())),
+        Msg::SelectTab { tab } => model.set_selected_tab(tab),
+        Msg::SpecsMsg { msg } => update_on_specsmsg(model.specs_to_color_mut(), msg),
+        Msg::ColorCodesMsg { msg } => update_on_colorcodemsg(model.color_codes_to_specs_mut(), msg),
+        Msg::QuizMsg { msg } => update_on_quizmsg(model.quiz_mut(), msg),
+    }
 }
 
 pub fn update_on_colorcodemsg(model: &mut ColorCodesToSpecsModel, msg: ColorCodesMsg) {
+    let previous = model.resistor.clone();
+    model.error = None;
     match msg {
         ColorCodesMsg::ThreeBands => {
             model.resistor = Resistor::ThreeBand {
@@ -71,90 +396,137 @@ pub fn update_on_colorcodemsg(model: &mut ColorCodesToSpecsModel, msg: ColorCode
             let bands_count = model.resistor.bands().len();
             model.selected_band = (model.selected_band + (bands_count - 1)) % bands_count
         }
-        ColorCodesMsg::NextColor => {
-            let current_idx: usize = *model.resistor.bands()[model.selected_band] as usize;
-            let mut i: usize = 0;
-            let mut resistor = Err("".to_string());
-            while resistor.is_err() {
-                i += 1;
-                let next_color = Color::from((current_idx + i) % 13);
-                resistor = model.resistor.with_color(next_color, model.selected_band);
+        ColorCodesMsg::NextColor | ColorCodesMsg::PrevColor => {
+            let forward = matches!(msg, ColorCodesMsg::NextColor);
+            if let Some(resistor) = cycle_color(&model.resistor, model.selected_band, forward) {
+                model.resistor = resistor;
             }
-            model.resistor = resistor.unwrap();
         }
-        ColorCodesMsg::PrevColor => {
-            let current_idx = *model.resistor.bands()[model.selected_band] as usize;
-            let mut i: usize = 13;
-            let mut resistor = Err("".to_string());
-            while resistor.is_err() {
-                i -= 1;
-                let next_color = Color::from((current_idx + i) % 13);
-                resistor = model.resistor.with_color(next_color, model.selected_band);
+        ColorCodesMsg::SelectBand { band_idx } => {
+            if band_idx < model.resistor.bands().len() {
+                model.selected_band = band_idx
             }
-            model.resistor = resistor.unwrap();
         }
+        ColorCodesMsg::SelectColor { band_idx, color } => {
+            if band_idx < model.resistor.bands().len() {
+                model.selected_band = band_idx
+            }
+            if let Ok(resistor) = model.resistor.with_color(color, band_idx) {
+                model.resistor = resistor
+            }
+        }
+        ColorCodesMsg::Flip => {
+            let bands = model.resistor.bands().into_iter().rev().copied().collect();
+            match Resistor::try_create(bands) {
+                Ok(resistor) => {
+                    model.selected_band = resistor.bands().len() - 1 - model.selected_band;
+                    model.resistor = resistor;
+                }
+                Err(e) => model.error = Some(format!("the reversed bands are invalid: {}", e)),
+            }
+        }
+        ColorCodesMsg::JumpToColor { letter } => {
+            let band_idx = model.selected_band;
+            let current = Color::ALL
+                .iter()
+                .position(|c| c == model.resistor.bands()[band_idx])
+                .unwrap_or_default();
+            let starts_with_letter = |c: &Color| {
+                c.to_string().starts_with(letter) || (letter == 'k' && *c == Color::Black)
+            };
+            if let Some(resistor) = (1..=Color::ALL.len())
+                .map(|i| Color::ALL[(current + i) % Color::ALL.len()])
+                .filter(starts_with_letter)
+                .find_map(|color| model.resistor.with_color(color, band_idx).ok())
+            {
+                model.resistor = resistor
+            }
+        }
+        ColorCodesMsg::SetResistor { resistor } => {
+            model.resistor = resistor;
+            model.selected_band = 0;
+        }
+        ColorCodesMsg::Undo | ColorCodesMsg::Redo => {
+            let (from, to) = if matches!(msg, ColorCodesMsg::Undo) {
+                (&mut model.undo, &mut model.redo)
+            } else {
+                (&mut model.redo, &mut model.undo)
+            };
+            if let Some(resistor) = from.pop() {
+                to.push(std::mem::replace(&mut model.resistor, resistor));
+                model.selected_band = model
+                    .selected_band
+                    .min(model.resistor.bands().len().saturating_sub(1));
+            }
+            return;
+        }
+        ColorCodesMsg::Warmer => {
+            model.temperature = (model.temperature + TEMPERATURE_STEP).min(*TEMPERATURES.end())
+        }
+        ColorCodesMsg::Cooler => {
+            model.temperature = (model.temperature - TEMPERATURE_STEP).max(*TEMPERATURES.start())
+        }
+        ColorCodesMsg::SetMeasured { ohm } => model.measured = ohm,
+        ColorCodesMsg::NextPowerRating => {
+            model.power_rating = match model.power_rating {
+                None => Some(POWER_RATINGS[0]),
+                Some(watts) => POWER_RATINGS.iter().find(|r| **r > watts).copied(),
+            }
+        }
+        ColorCodesMsg::SetPowerRating { watts } => model.power_rating = watts,
+    }
+    if model.resistor != previous {
+        model.undo.push(previous);
+        if model.undo.len() > UNDO_LEN {
+            model.undo.remove(0);
+        }
+        model.redo.clear();
     }
 }
 pub fn update_on_specsmsg(model: &mut SpecsToColorModel, msg: SpecsMsg) {
     match msg {
         SpecsMsg::Determine => {
-            match try_determine_resistor(
-                &model.resistance_textarea.lines()[0],
-                &model.tolerance_textarea.lines()[0],
-                &model.tcr_textarea.lines()[0],
-            ) {
+            let [resistance, tolerance, tcr] = [
+                InputFocus::Resistance,
+                InputFocus::Tolerance,
+                InputFocus::Tcr,
+            ]
+            .map(|input| model.normalized_input(&input));
+            match try_determine_resistor(&resistance, &tolerance, &tcr, model.bands) {
                 Ok(resistor) => {
                     model.resistor = Some(resistor);
                     model.error = None;
-                    model.history.add((
-                        model.resistance_textarea.lines()[0].clone(),
-                        model.tolerance_textarea.lines()[0].clone(),
-                        model.tcr_textarea.lines()[0].clone(),
-                    ));
+                    model.error_input = None;
+                    model.all_resistors = if model.show_all {
+                        try_determine_all(&resistance, &tolerance, &tcr)
+                    } else {
+                        Vec::new()
+                    };
                     model.add_specs_to_history();
                     model.history.clear_idx();
                 }
+                // the last resistor stays visible below the error
                 Err(e) => {
-                    model.resistor = None;
                     model.error = Some(e);
+                    model.error_input = [
+                        InputFocus::Resistance,
+                        InputFocus::Tolerance,
+                        InputFocus::Tcr,
+                    ]
+                    .into_iter()
+                    .find(|input| validate_input(model, input).is_some());
                 }
             }
         }
         SpecsMsg::NextSpecInput | SpecsMsg::PrevSpecInput => {
-            model.error = match model.focus {
-                InputFocus::Resistance => {
-                    let value = &model.resistance_textarea.lines()[0];
-                    if value.trim().is_empty() {
-                        None
-                    } else {
-                        try_parse_resistance(value).err().map(|err| err.to_string())
-                    }
-                }
-                InputFocus::Tolerance => {
-                    let value = &model.tolerance_textarea.lines()[0];
-                    if value.trim().is_empty() {
-                        None
-                    } else {
-                        value.parse::<f64>().err().map(|err| err.to_string())
-                    }
-                }
-                InputFocus::Tcr => {
-                    let value = &model.tcr_textarea.lines()[0];
-                    if value.trim().is_empty() {
-                        None
-                    } else {
-                        value.parse::<u32>().err().map(|err| err.to_string())
-                    }
-                }
-            };
+            model.error = validate_input(model, &model.focus);
             if model.error.is_none() {
                 model.focus = match msg {
                     SpecsMsg::NextSpecInput => model.focus.next(),
                     _ => model.focus.prev(),
                 };
-            } else {
-                model.resistor = None;
             }
+            model.error_input = model.error.as_ref().map(|_| model.focus.clone());
         }
         SpecsMsg::PrevHistory => {
             model.history.prev();
@@ -164,10 +536,568 @@ pub fn update_on_specsmsg(model: &mut SpecsToColorModel, msg: SpecsMsg) {
             model.history.next();
             model.set_specs_from_history();
         }
-        SpecsMsg::Reset => *model = SpecsToColorModel::default(),
+        SpecsMsg::Reset => {
+            *model = SpecsToColorModel {
+                bands: model.bands,
+                show_all: model.show_all,
+                // the history is persisted, resetting the inputs must not lose it
+                history: std::mem::take(&mut model.history),
+                ..SpecsToColorModel::default()
+            }
+        }
+        SpecsMsg::NextChoice | SpecsMsg::PrevChoice => {
+            if let Some(choices) = model.choices() {
+                let idx = match (model.selected_choice(), msg) {
+                    (None, _) => 0,
+                    (Some(idx), SpecsMsg::NextChoice) => (idx + 1) % choices.len(),
+                    (Some(idx), _) => (idx + choices.len() - 1) % choices.len(),
+                };
+                let textarea = match model.focus {
+                    InputFocus::Tolerance => &mut model.tolerance_textarea,
+                    InputFocus::Tcr => &mut model.tcr_textarea,
+                    InputFocus::Resistance => return,
+                };
+                set_textarea(textarea, choices[idx].clone(), vec![]);
+                model.error = None;
+            }
+        }
+        SpecsMsg::SearchHistory => match &mut model.search {
+            None => model.search = Some(HistorySearch::default()),
+            Some(search) if !search.query.is_empty() => {
+                let before = search.found.unwrap_or(model.history.len());
+                search.found = model.history.search(&search.query, before).or(search.found);
+            }
+            Some(_) => (),
+        },
+        SpecsMsg::SearchInput { c } => {
+            if let Some(search) = &mut model.search {
+                search.query.push(c);
+                search.found = model.history.search(&search.query, model.history.len());
+            }
+        }
+        SpecsMsg::SearchBackspace => {
+            if let Some(search) = &mut model.search {
+                search.query.pop();
+                search.found = if search.query.is_empty() {
+                    None
+                } else {
+                    model.history.search(&search.query, model.history.len())
+                };
+            }
+        }
+        SpecsMsg::AcceptSearch => {
+            if let Some(specs) = model
+                .search
+                .take()
+                .and_then(|search| search.found)
+                .and_then(|idx| model.history.get(idx).cloned())
+            {
+                model.set_specs(specs);
+            }
+        }
+        SpecsMsg::CancelSearch => model.search = None,
+        SpecsMsg::SetSpecs {
+            resistance,
+            tolerance,
+            tcr,
+        } => model.set_specs((resistance, tolerance, tcr)),
+        SpecsMsg::NextBandsCount => {
+            model.bands = match model.bands {
+                None => Some(4),
+                Some(bands) if bands < 6 => Some(bands + 1),
+                Some(_) => None,
+            };
+            if model.resistor.is_some() || model.error.is_some() {
+                update_on_specsmsg(model, SpecsMsg::Determine);
+            }
+        }
+        SpecsMsg::DismissError => {
+            model.error = None;
+            model.error_input = None;
+        }
+        SpecsMsg::ToggleShowAll => {
+            model.show_all = !model.show_all;
+            if model.resistor.is_some() || model.error.is_some() {
+                update_on_specsmsg(model, SpecsMsg::Determine);
+            }
+        }
     }
 }
 
+pub fn update_on_eseriesmsg(model: &mut ESeriesModel, msg: ESeriesMsg) {
+    match msg {
+        ESeriesMsg::NextSeries => {
+            model.series = model.series.next();
+            model.selected_value = 0;
+        }
+        ESeriesMsg::PrevSeries => {
+            model.series = model.series.prev();
+            model.selected_value = 0;
+        }
+        ESeriesMsg::NextDecade => {
+            model.decade = (model.decade + 1).min(*ESERIES_DECADES.end());
+        }
+        ESeriesMsg::PrevDecade => {
+            model.decade = (model.decade - 1).max(*ESERIES_DECADES.start());
+        }
+        ESeriesMsg::NextValue => {
+            let count = model.values().len();
+            model.selected_value = (model.selected_value + 1).min(count.saturating_sub(1));
+        }
+        ESeriesMsg::PrevValue => {
+            model.selected_value = model.selected_value.saturating_sub(1);
+        }
+        ESeriesMsg::FilterChanged => {
+            let count = model.values().len();
+            model.selected_value = model.selected_value.min(count.saturating_sub(1));
+        }
+    }
+}
+
+pub fn update_on_networkmsg(model: &mut NetworkModel, msg: NetworkMsg) {
+    match msg {
+        NetworkMsg::BandsMsg { msg } => update_on_colorcodemsg(&mut model.bands, msg),
+        NetworkMsg::ToggleFocus => {
+            model.focus = match model.focus {
+                NetworkFocus::Bands => NetworkFocus::Value,
+                NetworkFocus::Value => NetworkFocus::Bands,
+            }
+        }
+        NetworkMsg::AddPart => {
+            let part = match model.focus {
+                NetworkFocus::Bands => Ok(Combined::from(&model.bands.resistor.specs())),
+                NetworkFocus::Value => try_parse_part(&model.value_textarea.lines()[0]),
+            };
+            match part {
+                Ok(part) => {
+                    model.groups[model.selected_group].parts.push(part);
+                    model.error = None;
+                }
+                Err(e) => model.error = Some(e),
+            }
+        }
+        NetworkMsg::Add { part } => {
+            model.groups[model.selected_group].parts.push(part);
+            model.error = None;
+        }
+        NetworkMsg::RemovePart => {
+            let group = &mut model.groups[model.selected_group];
+            if group.parts.pop().is_none() && model.groups.len() > 1 {
+                model.groups.remove(model.selected_group);
+                model.selected_group = model.selected_group.min(model.groups.len() - 1);
+            }
+        }
+        NetworkMsg::AddGroup => {
+            model.groups.push(NetworkGroup {
+                connection: Connection::Parallel,
+                parts: vec![],
+            });
+            model.selected_group = model.groups.len() - 1;
+        }
+        NetworkMsg::ToggleConnection => {
+            let group = &mut model.groups[model.selected_group];
+            group.connection = group.connection.toggle();
+        }
+        NetworkMsg::NextGroup => {
+            model.selected_group = (model.selected_group + 1).min(model.groups.len() - 1);
+        }
+        NetworkMsg::PrevGroup => {
+            model.selected_group = model.selected_group.saturating_sub(1);
+        }
+        NetworkMsg::Clear => {
+            model.groups = vec![NetworkGroup::default()];
+            model.selected_group = 0;
+            model.error = None;
+        }
+    }
+}
+
+pub fn update_on_ohmslawmsg(model: &mut OhmsLawModel, msg: OhmsLawMsg) {
+    match msg {
+        OhmsLawMsg::Calculate => {
+            let parse = |input: &str, name: &str| {
+                let input = input.trim();
+                if input.is_empty() {
+                    Ok(None)
+                } else {
+                    input
+                        .parse::<f64>()
+                        .map(Some)
+                        .map_err(|e| format!("invalid input for {}: {}", name, e))
+                }
+            };
+            let resistance = &model.resistance_textarea.lines()[0];
+            let resistance = if resistance.trim().is_empty() {
+                Ok(None)
+            } else {
+                try_parse_resistance(resistance.trim()).map(Some)
+            };
+            let result = parse(&model.voltage_textarea.lines()[0], "voltage").and_then(|v| {
+                let i = parse(&model.current_textarea.lines()[0], "current")?;
+                let p = parse(&model.power_textarea.lines()[0], "power")?;
+                OhmsLaw::solve(v, i, resistance?, p)
+            });
+            match result {
+                Ok(result) => {
+                    model.result = Some(result);
+                    model.error = None;
+                }
+                Err(e) => {
+                    model.result = None;
+                    model.error = Some(e);
+                }
+            }
+        }
+        OhmsLawMsg::NextInput => model.focus = model.focus.next(),
+        OhmsLawMsg::PrevInput => model.focus = model.focus.prev(),
+        OhmsLawMsg::ToggleLink { ohm } => {
+            model.linked = !model.linked;
+            let content = if model.linked {
+                ohm.to_string()
+            } else {
+                String::new()
+            };
+            set_textarea(&mut model.resistance_textarea, content, vec![]);
+        }
+        OhmsLawMsg::LinkedResistanceChanged { ohm } => {
+            if model.linked {
+                set_textarea(&mut model.resistance_textarea, ohm.to_string(), vec![]);
+            }
+        }
+        OhmsLawMsg::Reset => *model = OhmsLawModel::default(),
+    }
+}
+
+pub fn update_on_dividermsg(model: &mut DividerModel, msg: DividerMsg) {
+    match msg {
+        DividerMsg::Calculate => {
+            let parse = |input: &str, name: &str| {
+                input
+                    .trim()
+                    .parse::<f64>()
+                    .map_err(|e| format!("invalid input for {}: {}", name, e))
+            };
+            let result = parse(&model.vin_textarea.lines()[0], "input voltage").and_then(|vin| {
+                let vout = parse(&model.vout_textarea.lines()[0], "output voltage")?;
+                let suggestions = Divider::suggest(vin, vout, model.series)?;
+                Ok(((vin, vout), suggestions))
+            });
+            match result {
+                Ok((voltages, suggestions)) => {
+                    model.voltages = Some(voltages);
+                    model.suggestions = suggestions;
+                    model.error = None;
+                }
+                Err(e) => {
+                    model.voltages = None;
+                    model.suggestions = vec![];
+                    model.error = Some(e);
+                }
+            }
+            model.selected_suggestion = 0;
+        }
+        DividerMsg::ToggleInput => {
+            model.focus = match model.focus {
+                DividerFocus::Vin => DividerFocus::Vout,
+                DividerFocus::Vout => DividerFocus::Vin,
+            }
+        }
+        DividerMsg::NextSeries | DividerMsg::PrevSeries => {
+            model.series = match msg {
+                DividerMsg::NextSeries => model.series.next(),
+                _ => model.series.prev(),
+            };
+            if model.voltages.is_some() {
+                update_on_dividermsg(model, DividerMsg::Calculate);
+            }
+        }
+        DividerMsg::NextSuggestion => {
+            let last = model.suggestions.len().saturating_sub(1);
+            model.selected_suggestion = (model.selected_suggestion + 1).min(last);
+        }
+        DividerMsg::PrevSuggestion => {
+            model.selected_suggestion = model.selected_suggestion.saturating_sub(1);
+        }
+    }
+}
+
+pub fn update_on_ledmsg(model: &mut LedModel, msg: LedMsg) {
+    match msg {
+        LedMsg::Calculate => {
+            let parse = |input: &str, name: &str| {
+                input
+                    .trim()
+                    .parse::<f64>()
+                    .map_err(|e| format!("invalid input for {}: {}", name, e))
+            };
+            let result =
+                parse(&model.supply_textarea.lines()[0], "supply voltage").and_then(|vs| {
+                    let vf = parse(
+                        &model.forward_voltage_textarea.lines()[0],
+                        "forward voltage",
+                    )?;
+                    let ma = parse(&model.current_textarea.lines()[0], "current")?;
+                    LedResistor::calculate(vs, vf, ma / 1000.0, model.series)
+                });
+            match result {
+                Ok(result) => {
+                    model.result = Some(result);
+                    model.error = None;
+                }
+                Err(e) => {
+                    model.result = None;
+                    model.error = Some(e);
+                }
+            }
+        }
+        LedMsg::NextInput => model.focus = model.focus.next(),
+        LedMsg::PrevInput => model.focus = model.focus.prev(),
+        LedMsg::NextSeries | LedMsg::PrevSeries => {
+            model.series = match msg {
+                LedMsg::NextSeries => model.series.next(),
+                _ => model.series.prev(),
+            };
+            if model.result.is_some() {
+                update_on_ledmsg(model, LedMsg::Calculate);
+            }
+        }
+    }
+}
+
+pub fn update_on_quizmsg(model: &mut QuizModel, msg: QuizMsg) {
+    match msg {
+        QuizMsg::BandsMsg { msg } => update_on_colorcodemsg(&mut model.answer_bands, msg),
+        QuizMsg::Check => {
+            let expected = model.question.specs();
+            let is_correct = match model.mode {
+                QuizMode::ColorsToValue => {
+                    match try_parse_resistance(model.answer_textarea.lines()[0].trim()) {
+                        Ok(ohm) => (ohm - expected.ohm).abs() <= expected.ohm * 1e-9,
+                        Err(e) => {
+                            model.last_result = Some((false, e));
+                            return;
+                        }
+                    }
+                }
+                QuizMode::ValueToColors => {
+                    let answer = model.answer_bands.resistor.specs();
+                    (answer.ohm - expected.ohm).abs() <= expected.ohm * 1e-9
+                        && answer.tolerance == expected.tolerance
+                        && answer.tcr == expected.tcr
+                }
+            };
+            let solution = model
+                .question
+                .bands()
+                .iter()
+                .map(|c| c.to_string())
+                .collect::<Vec<String>>()
+                .join(" ");
+            let solution = format!(
+                "{} is {}Ω ±{}%",
+                solution,
+                expected.ohm,
+                expected.tolerance * 100.0
+            );
+            model.asked += 1;
+            if is_correct {
+                model.correct += 1;
+                model.streak += 1;
+                model.best_streak = model.best_streak.max(model.streak);
+            } else {
+                model.streak = 0;
+            }
+            model.last_result = Some((is_correct, solution));
+            next_question(model);
+        }
+        QuizMsg::Skip => {
+            model.streak = 0;
+            model.last_result = None;
+            next_question(model);
+        }
+        QuizMsg::ToggleMode => {
+            model.mode = match model.mode {
+                QuizMode::ColorsToValue => QuizMode::ValueToColors,
+                QuizMode::ValueToColors => QuizMode::ColorsToValue,
+            };
+            model.last_result = None;
+            next_question(model);
+        }
+    }
+}
+
+fn next_question(model: &mut QuizModel) {
+    model.question = Resistor::random(&mut model.rng);
+    set_textarea(&mut model.answer_textarea, String::new(), vec![]);
+}
+
+pub fn update_on_capacitormsg(model: &mut CapacitorModel, msg: CapacitorMsg) {
+    match msg {
+        CapacitorMsg::FourBands => {
+            model.capacitor = Capacitor::default();
+            model.selected_band = model.selected_band.min(3)
+        }
+        CapacitorMsg::FiveBands => {
+            let mut bands = model
+                .capacitor
+                .bands()
+                .into_iter()
+                .copied()
+                .collect::<Vec<Color>>();
+            bands.truncate(4);
+            bands.push(Color::Brown);
+            model.capacitor = Capacitor::try_create(bands).unwrap_or_default();
+        }
+        CapacitorMsg::NextBand => {
+            model.selected_band = (model.selected_band + 1) % model.capacitor.bands().len()
+        }
+        CapacitorMsg::PrevBand => {
+            let bands_count = model.capacitor.bands().len();
+            model.selected_band = (model.selected_band + (bands_count - 1)) % bands_count
+        }
+        CapacitorMsg::NextColor | CapacitorMsg::PrevColor => {
+            let forward = matches!(msg, CapacitorMsg::NextColor);
+            if let Some(capacitor) = cycle_color(&model.capacitor, model.selected_band, forward) {
+                model.capacitor = capacitor;
+            }
+        }
+        CapacitorMsg::SelectBand { band_idx } => {
+            if band_idx < model.capacitor.bands().len() {
+                model.selected_band = band_idx
+            }
+        }
+        CapacitorMsg::SelectColor { band_idx, color } => {
+            if band_idx < model.capacitor.bands().len() {
+                model.selected_band = band_idx
+            }
+            if let Ok(capacitor) = model.capacitor.with_color(color, band_idx) {
+                model.capacitor = capacitor
+            }
+        }
+    }
+}
+
+pub fn update_on_comparemsg(model: &mut CompareModel, msg: CompareMsg) {
+    match msg {
+        CompareMsg::Focus { side } => model.focus = side,
+        CompareMsg::BandsMsg { msg } => {
+            let bands = match model.focus {
+                CompareSide::A => &mut model.a,
+                CompareSide::B => &mut model.b,
+            };
+            update_on_colorcodemsg(bands, msg)
+        }
+    }
+}
+
+pub fn update_on_inductormsg(model: &mut InductorModel, msg: InductorMsg) {
+    match msg {
+        InductorMsg::NextBand => {
+            model.selected_band = (model.selected_band + 1) % model.inductor.bands().len()
+        }
+        InductorMsg::PrevBand => {
+            let bands_count = model.inductor.bands().len();
+            model.selected_band = (model.selected_band + (bands_count - 1)) % bands_count
+        }
+        InductorMsg::NextColor | InductorMsg::PrevColor => {
+            let forward = matches!(msg, InductorMsg::NextColor);
+            if let Some(inductor) = cycle_color(&model.inductor, model.selected_band, forward) {
+                model.inductor = inductor;
+            }
+        }
+        InductorMsg::SelectBand { band_idx } => {
+            if band_idx < model.inductor.bands().len() {
+                model.selected_band = band_idx
+            }
+        }
+        InductorMsg::SelectColor { band_idx, color } => {
+            if band_idx < model.inductor.bands().len() {
+                model.selected_band = band_idx
+            }
+            if let Ok(inductor) = model.inductor.with_color(color, band_idx) {
+                model.inductor = inductor
+            }
+        }
+    }
+}
+
+pub fn update_on_inventorymsg(model: &mut InventoryModel, msg: InventoryMsg) {
+    match msg {
+        InventoryMsg::Add { ohm, tolerance } => {
+            model.inventory.add(ohm, tolerance, 1);
+            model.error = None;
+        }
+        InventoryMsg::AddInput => match try_parse_part(&model.input_textarea.lines()[0]) {
+            Ok(part) => {
+                model.inventory.add(part.ohm, part.tolerance(), 1);
+                set_textarea(&mut model.input_textarea, String::new(), vec![]);
+                model.error = None;
+            }
+            Err(e) => model.error = Some(e),
+        },
+        InventoryMsg::Search => match try_parse_part(&model.input_textarea.lines()[0]) {
+            Ok(part) => {
+                model.search = Some(part);
+                model.selected_part = 0;
+                model.error = None;
+            }
+            Err(e) => model.error = Some(e),
+        },
+        InventoryMsg::ShowAll => {
+            model.search = None;
+            model.selected_part = 0;
+            model.error = None;
+        }
+        InventoryMsg::NextPart => {
+            let last = model.listed().len().saturating_sub(1);
+            model.selected_part = (model.selected_part + 1).min(last);
+        }
+        InventoryMsg::PrevPart => {
+            model.selected_part = model.selected_part.saturating_sub(1);
+        }
+        InventoryMsg::Increment => {
+            if let Some(idx) = model.selected_part_idx() {
+                model.inventory.parts[idx].quantity += 1;
+            }
+        }
+        InventoryMsg::Decrement | InventoryMsg::Remove => {
+            if let Some(idx) = model.selected_part_idx() {
+                let quantity = match msg {
+                    InventoryMsg::Decrement => 1,
+                    _ => u32::MAX,
+                };
+                model.inventory.take(idx, quantity);
+                let last = model.listed().len().saturating_sub(1);
+                model.selected_part = model.selected_part.min(last);
+            }
+        }
+    }
+}
+
+/// The component with the next (or previous) valid color in the given band.
+fn cycle_color<T: ColorCoded>(component: &T, band_idx: usize, forward: bool) -> Option<T> {
+    let count = Color::ALL.len();
+    let current_idx = *component.bands()[band_idx] as usize;
+    (1..count).find_map(|i| {
+        let offset = if forward { i } else { count - i };
+        let color = Color::from((current_idx + offset) % count);
+        component.with_color(color, band_idx).ok()
+    })
+}
+
+/// Parses a resistance with an optional tolerance in %, e.g. "4k7 1".
+/// Without a tolerance the resistance is taken as exact.
+pub fn try_parse_part(input: &str) -> Result<Combined, String> {
+    let mut tokens = input.split_whitespace();
+    let resistance = tokens.next().unwrap_or_default();
+    let tolerance = tokens.next().unwrap_or_default();
+    if let Some(token) = tokens.next() {
+        return Err(format!("unexpected input: {}", token));
+    }
+    let (resistance, tolerance, _) = try_parse_specs(resistance, tolerance, "")?;
+    Ok(Combined::new(resistance, tolerance.unwrap_or(0.0) / 100.0))
+}
+
 pub fn try_parse_resistance(input: &str) -> Result<f64, String> {
     match input.parse::<f64>() {
         Ok(t) => Ok(t),
@@ -182,40 +1112,52 @@ pub fn try_parse_resistance(input: &str) -> Result<f64, String> {
     }
 }
 
-pub fn try_determine_resistor(
+/// Parses a tolerance in %, the sign and the unit are optional, e.g. "±1%".
+pub fn try_parse_tolerance(input: &str) -> Result<f64, String> {
+    let input = input.trim();
+    let input = input
+        .strip_prefix('±')
+        .or_else(|| input.strip_prefix("+/-"))
+        .unwrap_or(input);
+    let input = input.trim().strip_suffix('%').unwrap_or(input).trim();
+    input
+        .parse::<f64>()
+        .map_err(|e| format!("invalid input for tolerance: {}", e))
+}
+
+/// Parses a TCR in ppm/K, the unit is optional, e.g. "50 ppm/K".
+pub fn try_parse_tcr(input: &str) -> Result<u32, String> {
+    let input = input.trim().to_lowercase();
+    let input = ["ppm/k", "ppm/°c", "ppm"]
+        .iter()
+        .find_map(|unit| input.strip_suffix(unit))
+        .unwrap_or(&input)
+        .trim();
+    input
+        .parse::<u32>()
+        .map_err(|e| format!("invalid input for tcr: {}", e))
+}
+
+pub fn try_parse_specs(
     resistance_input: &str,
     tolerance_input: &str,
     tcr_input: &str,
-) -> Result<Resistor, String> {
+) -> Result<(f64, Option<f64>, Option<u32>), String> {
     let resistance = try_parse_resistance(resistance_input);
     let tolerance = if tolerance_input.is_empty() {
         Ok(None)
     } else {
-        match tolerance_input.parse::<f64>() {
-            Ok(t) => Ok(Some(t)),
-            Err(e) => Err(format!("invalid input for tolerance: {}", e)),
-        }
+        try_parse_tolerance(tolerance_input).map(Some)
     };
 
     let tcr = if tcr_input.is_empty() {
         Ok(None)
     } else {
-        match tcr_input.parse::<u32>() {
-            Ok(t) => Ok(Some(t)),
-            Err(e) => Err(format!("invalid input for tcr: {}", e)),
-        }
+        try_parse_tcr(tcr_input).map(Some)
     };
 
     match (resistance, tolerance, tcr) {
-        (Ok(resistance), Ok(tolerance), Ok(tcr)) => {
-            match Resistor::determine(resistance, tolerance, tcr) {
-                Ok(resistor) => Ok(resistor),
-                Err(e) => Err(format!(
-                    "could not determine a resistor for these inputs: {}",
-                    e
-                )),
-            }
-        }
+        (Ok(resistance), Ok(tolerance), Ok(tcr)) => Ok((resistance, tolerance, tcr)),
         (res, tol, tcr) => {
             let mut error_msg: String = String::from("");
             if let Err(res_error) = res {
@@ -238,13 +1180,227 @@ pub fn try_determine_resistor(
     }
 }
 
+pub fn try_determine_resistor(
+    resistance_input: &str,
+    tolerance_input: &str,
+    tcr_input: &str,
+    bands: Option<usize>,
+) -> Result<Resistor, String> {
+    let (resistance, tolerance, tcr) =
+        try_parse_specs(resistance_input, tolerance_input, tcr_input)?;
+    let resistor = match bands {
+        Some(bands) => Resistor::determine_with_bands(resistance, tolerance, tcr, bands),
+        None => Resistor::determine(resistance, tolerance, tcr),
+    };
+    match resistor {
+        Ok(resistor) => Ok(resistor),
+        Err(e) => Err(format!(
+            "could not determine a resistor for these inputs: {}",
+            e
+        )),
+    }
+}
+
+/// Why the value of the input is invalid, `None` if it's valid or empty.
+pub fn validate_input(model: &SpecsToColorModel, input: &InputFocus) -> Option<String> {
+    let value = &model.normalized_input(input);
+    if value.trim().is_empty() {
+        return None;
+    }
+    match input {
+        InputFocus::Resistance => try_parse_resistance(value).err(),
+        InputFocus::Tolerance => match try_parse_tolerance(value) {
+            Ok(t) if Resistor::TOLERANCES.contains(&t) => None,
+            Ok(_) => Some(String::from("not a valid tolerance value")),
+            Err(e) => Some(e),
+        },
+        InputFocus::Tcr => match try_parse_tcr(value) {
+            Ok(t) if Resistor::TCRS.contains(&t) => None,
+            Ok(_) => Some(String::from("not a valid tcr value")),
+            Err(e) => Some(e),
+        },
+    }
+}
+
+/// The 4, 5 and 6 band resistors of the inputs, none if they can't be parsed.
+pub fn try_determine_all(
+    resistance_input: &str,
+    tolerance_input: &str,
+    tcr_input: &str,
+) -> Vec<Resistor> {
+    try_parse_specs(resistance_input, tolerance_input, tcr_input)
+        .map(|(resistance, tolerance, tcr)| Resistor::determine_all(resistance, tolerance, tcr))
+        .unwrap_or_default()
+}
+
 #[cfg(test)]
 mod tests {
     use super::ColorCodesMsg;
     use crate::{
-        model::{ColorCodesToSpecsModel, SpecsToColorModel, set_textarea},
-        update::{SpecsMsg, update_on_colorcodemsg, update_on_specsmsg},
+        format::NumberFormat,
+        key::{KeyCode, KeyInput},
+        model::{
+            AppModel, CapacitorModel, ColorCodesToSpecsModel, CompareModel, CompareSide,
+            DividerModel, ESeriesModel, InductorModel, InputFocus, InventoryModel, LedModel,
+            NetworkFocus, NetworkModel, OhmsLawModel, QuizMode, QuizModel, SelectedTab,
+            SpecsHistory, SpecsToColorModel, set_textarea,
+        },
+        update::{
+            CapacitorMsg, CompareMsg, DividerMsg, ESeriesMsg, InductorMsg, InventoryMsg, LedMsg,
+            NetworkMsg, OhmsLawMsg, QuizMsg, SpecsMsg, try_parse_specs, try_parse_tcr,
+            try_parse_tolerance, update_on_capacitormsg, update_on_colorcodemsg,
+            update_on_comparemsg, update_on_dividermsg, update_on_eseriesmsg,
+            update_on_inductormsg, update_on_inventorymsg, update_on_ledmsg, update_on_networkmsg,
+            update_on_ohmslawmsg, update_on_quizmsg, update_on_specsmsg, validate_input,
+        },
+        update::{Msg, handle_key, update_on_msg},
     };
+    use rusistor::{Color, eseries::ESeries};
+
+    struct TestModel<'a> {
+        selected_tab: SelectedTab,
+        color_codes_to_specs: ColorCodesToSpecsModel,
+        specs_to_color: SpecsToColorModel<'a>,
+        quiz: QuizModel<'a>,
+    }
+
+    impl<'a> AppModel<'a> for TestModel<'a> {
+        fn tabs(&self) -> &[SelectedTab] {
+            &[
+                SelectedTab::ColorCodesToSpecs,
+                SelectedTab::SpecsToColorCodes,
+                SelectedTab::Quiz,
+            ]
+        }
+
+        fn selected_tab(&self) -> SelectedTab {
+            self.selected_tab
+        }
+
+        fn set_selected_tab(&mut self, tab: SelectedTab) {
+            self.selected_tab = tab;
+        }
+
+        fn color_codes_to_specs_mut(&mut self) -> &mut ColorCodesToSpecsModel {
+            &mut self.color_codes_to_specs
+        }
+
+        fn specs_to_color(&self) -> &SpecsToColorModel<'a> {
+            &self.specs_to_color
+        }
+
+        fn specs_to_color_mut(&mut self) -> &mut SpecsToColorModel<'a> {
+            &mut self.specs_to_color
+        }
+
+        fn quiz(&self) -> &QuizModel<'a> {
+            &self.quiz
+        }
+
+        fn quiz_mut(&mut self) -> &mut QuizModel<'a> {
+            &mut self.quiz
+        }
+    }
+
+    fn test_model<'a>() -> TestModel<'a> {
+        TestModel {
+            selected_tab: SelectedTab::ColorCodesToSpecs,
+            color_codes_to_specs: ColorCodesToSpecsModel::default(),
+            specs_to_color: SpecsToColorModel::default(),
+            quiz: QuizModel::new(42),
+        }
+    }
+
+    #[test]
+    fn test_update_on_msg() {
+        let mut model = test_model();
+        update_on_msg(&mut model, Msg::PrevTab);
+        assert_eq!(model.selected_tab, SelectedTab::Quiz);
+        update_on_msg(&mut model, Msg::NextTab);
+        assert_eq!(model.selected_tab, SelectedTab::ColorCodesToSpecs);
+        update_on_msg(
+            &mut model,
+            Msg::SelectTab {
+                tab: SelectedTab::SpecsToColorCodes,
+            },
+        );
+        assert_eq!(model.selected_tab, SelectedTab::SpecsToColorCodes);
+
+        update_on_msg(
+            &mut model,
+            Msg::ColorCodesMsg {
+                msg: ColorCodesMsg::ThreeBands,
+            },
+        );
+        assert_eq!(model.color_codes_to_specs.resistor.bands().len(), 3);
+
+        model.specs_to_color.resistance_textarea.insert_str("4k7");
+        update_on_msg(
+            &mut model,
+            Msg::SpecsMsg {
+                msg: SpecsMsg::Determine,
+            },
+        );
+        assert_eq!(model.specs_to_color.history.len(), 1);
+
+        model.quiz.streak = 3;
+        update_on_msg(&mut model, Msg::QuizMsg { msg: QuizMsg::Skip });
+        assert_eq!(model.quiz.streak, 0);
+    }
+
+    #[test]
+    fn test_handle_key() {
+        let mut model = test_model();
+        let key = KeyInput::new;
+        assert!(matches!(
+            handle_key(&model, &key(KeyCode::Char('5'))),
+            Some(Msg::ColorCodesMsg {
+                msg: ColorCodesMsg::FiveBands
+            })
+        ));
+        assert!(matches!(
+            handle_key(&model, &key(KeyCode::Char('r'))),
+            Some(Msg::ColorCodesMsg {
+                msg: ColorCodesMsg::JumpToColor { letter: 'r' }
+            })
+        ));
+        assert!(matches!(
+            handle_key(&model, &KeyInput::ctrl(KeyCode::Char('r'))),
+            Some(Msg::ColorCodesMsg {
+                msg: ColorCodesMsg::Redo
+            })
+        ));
+
+        model.selected_tab = SelectedTab::SpecsToColorCodes;
+        assert!(matches!(
+            handle_key(&model, &key(KeyCode::Enter)),
+            Some(Msg::SpecsMsg {
+                msg: SpecsMsg::Determine
+            })
+        ));
+        model.specs_to_color.error = Some("invalid".to_string());
+        assert!(matches!(
+            handle_key(&model, &key(KeyCode::Enter)),
+            Some(Msg::SpecsMsg {
+                msg: SpecsMsg::DismissError
+            })
+        ));
+        // the inputs take the digits
+        assert!(handle_key(&model, &key(KeyCode::Char('5'))).is_none());
+
+        model.selected_tab = SelectedTab::Quiz;
+        model.quiz.mode = QuizMode::ValueToColors;
+        assert!(matches!(
+            handle_key(&model, &key(KeyCode::Left)),
+            Some(Msg::QuizMsg {
+                msg: QuizMsg::BandsMsg {
+                    msg: ColorCodesMsg::PrevBand
+                }
+            })
+        ));
+        model.quiz.mode = QuizMode::ColorsToValue;
+        assert!(handle_key(&model, &key(KeyCode::Left)).is_none());
+    }
 
     #[test]
     fn test_nbands_msg() {
@@ -264,20 +1420,241 @@ mod tests {
         assert_eq!(model.resistor.bands().len(), 6);
     }
 
+    #[test]
+    fn test_jump_to_color() {
+        let mut model = ColorCodesToSpecsModel::default();
+        let jump = |model: &mut ColorCodesToSpecsModel, letter| {
+            update_on_colorcodemsg(model, ColorCodesMsg::JumpToColor { letter });
+            *model.resistor.bands()[model.selected_band]
+        };
+        assert_eq!(jump(&mut model, 'g'), Color::Green);
+        assert_eq!(jump(&mut model, 'g'), Color::Grey);
+        // gold isn't a valid digit
+        assert_eq!(jump(&mut model, 'g'), Color::Green);
+        // black isn't valid in the first band
+        assert_eq!(jump(&mut model, 'b'), Color::Blue);
+        assert_eq!(jump(&mut model, 'b'), Color::Brown);
+        assert_eq!(jump(&mut model, 'k'), Color::Brown);
+        assert_eq!(jump(&mut model, 'q'), Color::Brown);
+        model.selected_band = 1;
+        assert_eq!(jump(&mut model, 'k'), Color::Black);
+    }
+
+    #[test]
+    fn test_flip() {
+        let mut model = ColorCodesToSpecsModel::default();
+        update_on_colorcodemsg(&mut model, ColorCodesMsg::FourBands);
+        update_on_colorcodemsg(&mut model, ColorCodesMsg::NextColor);
+        update_on_colorcodemsg(&mut model, ColorCodesMsg::Flip);
+        assert_eq!(model.resistor.specs().ohm, 10.0);
+        assert_eq!(model.resistor.specs().tolerance, 0.02);
+        assert_eq!(model.selected_band, 3);
+        update_on_colorcodemsg(&mut model, ColorCodesMsg::Flip);
+        assert_eq!(model.resistor.specs().ohm, 20.0);
+
+        update_on_colorcodemsg(&mut model, ColorCodesMsg::SixBands);
+        update_on_colorcodemsg(&mut model, ColorCodesMsg::Flip);
+        assert!(model.error.is_some());
+        assert_eq!(model.resistor.specs().ohm, 100.0);
+    }
+
+    #[test]
+    fn test_undo_redo() {
+        let mut model = ColorCodesToSpecsModel::default();
+        update_on_colorcodemsg(&mut model, ColorCodesMsg::NextColor);
+        let edited = model.resistor.clone();
+        model.selected_band = 5;
+        update_on_colorcodemsg(&mut model, ColorCodesMsg::ThreeBands);
+        assert_eq!(model.resistor.bands().len(), 3);
+        // bands count changes that don't change the resistor aren't recorded
+        update_on_colorcodemsg(&mut model, ColorCodesMsg::ThreeBands);
+        assert_eq!(model.undo.len(), 2);
+
+        update_on_colorcodemsg(&mut model, ColorCodesMsg::Undo);
+        assert_eq!(model.resistor, edited);
+        update_on_colorcodemsg(&mut model, ColorCodesMsg::Undo);
+        assert_eq!(model.resistor, ColorCodesToSpecsModel::default().resistor);
+        update_on_colorcodemsg(&mut model, ColorCodesMsg::Undo);
+        assert_eq!(model.resistor, ColorCodesToSpecsModel::default().resistor);
+
+        update_on_colorcodemsg(&mut model, ColorCodesMsg::Redo);
+        assert_eq!(model.resistor, edited);
+        update_on_colorcodemsg(&mut model, ColorCodesMsg::PrevColor);
+        assert!(model.redo.is_empty());
+    }
+
+    #[test]
+    fn test_temperature() {
+        let mut model = ColorCodesToSpecsModel::default();
+        update_on_colorcodemsg(&mut model, ColorCodesMsg::Warmer);
+        assert_eq!(model.temperature, 30);
+        for _ in 0..100 {
+            update_on_colorcodemsg(&mut model, ColorCodesMsg::Cooler);
+        }
+        assert_eq!(model.temperature, -55);
+        assert!(model.undo.is_empty());
+    }
+
+    #[test]
+    fn test_power_rating() {
+        let mut model = ColorCodesToSpecsModel::default();
+        assert!(model.power_limits().is_none());
+        update_on_colorcodemsg(&mut model, ColorCodesMsg::NextPowerRating);
+        update_on_colorcodemsg(&mut model, ColorCodesMsg::NextPowerRating);
+        assert_eq!(model.power_rating, Some(0.25));
+        assert_eq!(model.power_limits().unwrap().unwrap().voltage, 5.0);
+        update_on_colorcodemsg(
+            &mut model,
+            ColorCodesMsg::SetPowerRating { watts: Some(5.0) },
+        );
+        update_on_colorcodemsg(&mut model, ColorCodesMsg::NextPowerRating);
+        assert_eq!(model.power_rating, None);
+    }
+
     #[test]
     fn test_reset_msg() {
         let mut model = SpecsToColorModel::default();
         model.resistance_textarea.insert_str("z");
         model.tolerance_textarea.insert_str("z");
         model.tcr_textarea.insert_str("z");
+        model
+            .history
+            .add(("z".to_string(), "z".to_string(), "z".to_string()));
 
         update_on_specsmsg(&mut model, SpecsMsg::Reset);
+        assert_eq!(model.history.len(), 1);
 
         assert_eq!(model.resistance_textarea.lines()[0], "");
         assert_eq!(model.tolerance_textarea.lines()[0], "");
         assert_eq!(model.tcr_textarea.lines()[0], "");
     }
 
+    #[test]
+    fn test_choices() {
+        let mut model = SpecsToColorModel::default();
+        update_on_specsmsg(&mut model, SpecsMsg::NextChoice);
+        assert_eq!(model.tolerance_textarea.lines()[0], "");
+
+        model.focus = InputFocus::Tolerance;
+        model.tolerance_textarea.insert_str("5.0");
+        update_on_specsmsg(&mut model, SpecsMsg::NextChoice);
+        assert_eq!(model.tolerance_textarea.lines()[0], "2");
+        update_on_specsmsg(&mut model, SpecsMsg::PrevChoice);
+        update_on_specsmsg(&mut model, SpecsMsg::PrevChoice);
+        update_on_specsmsg(&mut model, SpecsMsg::PrevChoice);
+        assert_eq!(model.tolerance_textarea.lines()[0], "");
+        assert_eq!(model.selected_choice(), Some(0));
+
+        model.focus = InputFocus::Tcr;
+        update_on_specsmsg(&mut model, SpecsMsg::PrevChoice);
+        assert_eq!(model.tcr_textarea.lines()[0], "1");
+    }
+
+    #[test]
+    fn test_show_all() {
+        let mut model = SpecsToColorModel::default();
+        model.resistance_textarea.insert_str("4k7");
+        model.tolerance_textarea.insert_str("5");
+        update_on_specsmsg(&mut model, SpecsMsg::Determine);
+        assert!(model.all_resistors.is_empty());
+        update_on_specsmsg(&mut model, SpecsMsg::ToggleShowAll);
+        assert_eq!(model.all_resistors.len(), 2);
+        update_on_specsmsg(&mut model, SpecsMsg::Reset);
+        assert!(model.show_all);
+    }
+
+    #[test]
+    fn test_bands_count() {
+        let mut model = SpecsToColorModel::default();
+        model.resistance_textarea.insert_str("4k7");
+        model.tolerance_textarea.insert_str("1");
+        update_on_specsmsg(&mut model, SpecsMsg::Determine);
+        assert_eq!(model.resistor.as_ref().map(|r| r.bands().len()), Some(4));
+
+        update_on_specsmsg(&mut model, SpecsMsg::NextBandsCount);
+        update_on_specsmsg(&mut model, SpecsMsg::NextBandsCount);
+        assert_eq!(model.bands, Some(5));
+        assert_eq!(model.resistor.as_ref().map(|r| r.bands().len()), Some(5));
+
+        // the 5 bands resistor stays below the error
+        update_on_specsmsg(&mut model, SpecsMsg::NextBandsCount);
+        assert_eq!(model.resistor.as_ref().map(|r| r.bands().len()), Some(5));
+        assert!(model.error.is_some());
+        assert_eq!(model.error_input, None);
+        update_on_specsmsg(&mut model, SpecsMsg::DismissError);
+        assert!(model.error.is_none());
+
+        update_on_specsmsg(&mut model, SpecsMsg::Reset);
+        assert_eq!(model.bands, Some(6));
+    }
+
+    #[test]
+    fn test_validate_input() {
+        let mut model = SpecsToColorModel::default();
+        assert_eq!(validate_input(&model, &InputFocus::Resistance), None);
+        model.resistance_textarea.insert_str("4k7");
+        assert_eq!(validate_input(&model, &InputFocus::Resistance), None);
+        model.resistance_textarea.insert_str("q");
+        assert!(validate_input(&model, &InputFocus::Resistance).is_some());
+        model.tolerance_textarea.insert_str("7");
+        assert_eq!(
+            validate_input(&model, &InputFocus::Tolerance),
+            Some(String::from("not a valid tolerance value"))
+        );
+        model.tcr_textarea.insert_str("50");
+        assert_eq!(validate_input(&model, &InputFocus::Tcr), None);
+        model.tcr_textarea.insert_str(" ppm/K");
+        assert_eq!(validate_input(&model, &InputFocus::Tcr), None);
+    }
+
+    #[test]
+    fn test_decimal_comma() {
+        let mut model = SpecsToColorModel::default();
+        model.resistance_textarea.insert_str("4,7k");
+        model.tolerance_textarea.insert_str("0,5");
+        assert!(validate_input(&model, &InputFocus::Resistance).is_some());
+        model.number_format = NumberFormat::from_locale("de-DE");
+        assert_eq!(validate_input(&model, &InputFocus::Resistance), None);
+        update_on_specsmsg(&mut model, SpecsMsg::Determine);
+        let specs = model.resistor.unwrap().specs();
+        assert_eq!((specs.ohm, specs.tolerance), (4700.0, 0.005));
+        // the input keeps the text as typed
+        assert_eq!(model.resistance_textarea.lines()[0], "4,7k");
+    }
+
+    #[test]
+    fn test_parse_suffixed_specs() {
+        assert_eq!(try_parse_tolerance("5%"), Ok(5.0));
+        assert_eq!(try_parse_tolerance("±1%"), Ok(1.0));
+        assert_eq!(try_parse_tolerance(" +/- 0.5 % "), Ok(0.5));
+        assert!(try_parse_tolerance("%").is_err());
+        assert_eq!(try_parse_tcr("50ppm"), Ok(50));
+        assert_eq!(try_parse_tcr("50 ppm/K"), Ok(50));
+        assert!(try_parse_tcr("ppm").is_err());
+        assert_eq!(
+            try_parse_specs("4k7", "±5%", "100 ppm/K"),
+            Ok((4700.0, Some(5.0), Some(100)))
+        );
+    }
+
+    #[test]
+    fn test_error_input() {
+        let mut model = SpecsToColorModel::default();
+        model.resistance_textarea.insert_str("4k7");
+        update_on_specsmsg(&mut model, SpecsMsg::Determine);
+        model.tolerance_textarea.insert_str("7");
+        update_on_specsmsg(&mut model, SpecsMsg::Determine);
+        assert!(model.error.is_some());
+        assert_eq!(model.error_input, Some(InputFocus::Tolerance));
+        assert!(model.resistor.is_some());
+
+        update_on_specsmsg(&mut model, SpecsMsg::DismissError);
+        assert_eq!(model.error_input, None);
+        set_textarea(&mut model.tolerance_textarea, "1".into(), vec![]);
+        update_on_specsmsg(&mut model, SpecsMsg::Determine);
+        assert!(model.error.is_none());
+    }
+
     #[test]
     fn test_history() {
         let mut model = SpecsToColorModel::default();
@@ -297,4 +1674,288 @@ mod tests {
         assert_eq!(model.tolerance_textarea.lines()[0], "2");
         assert_eq!(model.tcr_textarea.lines()[0], "5");
     }
+
+    #[test]
+    fn test_history_text() {
+        let mut model = SpecsToColorModel::default();
+        model.history.set_max_len(2);
+        for resistance in ["1k", "2k2", "4k7"] {
+            set_textarea(&mut model.resistance_textarea, resistance.into(), vec![]);
+            set_textarea(&mut model.tolerance_textarea, "5".into(), vec![]);
+            update_on_specsmsg(&mut model, SpecsMsg::Determine);
+        }
+        assert_eq!(model.history.to_text(), "2k2\t5\t\n4k7\t5\t\n");
+
+        model.history = SpecsHistory::from_text(&model.history.to_text());
+        update_on_specsmsg(&mut model, SpecsMsg::PrevHistory);
+        update_on_specsmsg(&mut model, SpecsMsg::PrevHistory);
+        assert_eq!(model.resistance_textarea.lines()[0], "2k2");
+    }
+
+    #[test]
+    fn test_history_dedup() {
+        let mut history = SpecsHistory::from_text("1k\t5\t\t100\n2k2\t5\t\n");
+        assert_eq!(history.time(0), Some(100));
+        assert_eq!(history.time(1), None);
+
+        history.set_clock(|| 200);
+        history.add(("1k".to_string(), "5".to_string(), String::new()));
+        assert_eq!(history.to_text(), "2k2\t5\t\n1k\t5\t\t200\n");
+        assert_eq!(history.time(1), Some(200));
+    }
+
+    #[test]
+    fn test_search_history() {
+        let mut model = SpecsToColorModel {
+            history: SpecsHistory::from_text("4k7\t5\t\n1k\t1\t\n47k\t1\t50\n"),
+            ..Default::default()
+        };
+        update_on_specsmsg(&mut model, SpecsMsg::SearchHistory);
+        update_on_specsmsg(&mut model, SpecsMsg::SearchInput { c: '7' });
+        assert_eq!(model.search.as_ref().and_then(|s| s.found), Some(2));
+        update_on_specsmsg(&mut model, SpecsMsg::SearchHistory);
+        assert_eq!(model.search.as_ref().and_then(|s| s.found), Some(0));
+        update_on_specsmsg(&mut model, SpecsMsg::SearchHistory);
+        assert_eq!(model.search.as_ref().and_then(|s| s.found), Some(0));
+
+        update_on_specsmsg(&mut model, SpecsMsg::AcceptSearch);
+        assert!(model.search.is_none());
+        assert_eq!(model.resistance_textarea.lines()[0], "4k7");
+        assert_eq!(model.tolerance_textarea.lines()[0], "5");
+    }
+
+    #[test]
+    fn test_eseries() {
+        let mut model = ESeriesModel::default();
+        update_on_eseriesmsg(&mut model, ESeriesMsg::PrevSeries);
+        assert_eq!(model.series, ESeries::E6);
+        update_on_eseriesmsg(&mut model, ESeriesMsg::NextDecade);
+        update_on_eseriesmsg(&mut model, ESeriesMsg::NextValue);
+        update_on_eseriesmsg(&mut model, ESeriesMsg::NextValue);
+        assert_eq!(model.values()[model.selected_value], 2200.0);
+        assert_eq!(
+            model.selected_resistor().unwrap().bands(),
+            vec![&Color::Red, &Color::Red, &Color::Red]
+        );
+
+        model.filter_textarea.insert_str("4.7");
+        update_on_eseriesmsg(&mut model, ESeriesMsg::FilterChanged);
+        assert_eq!(model.values(), vec![4700.0]);
+        assert_eq!(model.selected_value, 0);
+    }
+
+    #[test]
+    fn test_network() {
+        let mut model = NetworkModel::default();
+        // default bands: brown black black black brown black, 100Ω ±1%
+        update_on_networkmsg(&mut model, NetworkMsg::AddPart);
+        update_on_networkmsg(&mut model, NetworkMsg::AddGroup);
+        update_on_networkmsg(&mut model, NetworkMsg::ToggleFocus);
+        assert_eq!(model.focus, NetworkFocus::Value);
+        model.value_textarea.insert_str("1k 1");
+        update_on_networkmsg(&mut model, NetworkMsg::AddPart);
+        update_on_networkmsg(&mut model, NetworkMsg::AddPart);
+        let total = model.total().unwrap();
+        assert_eq!(total.ohm, 600.0);
+        assert_eq!(total.min_ohm, 99.0 + 495.0);
+
+        model.value_textarea.insert_str("x");
+        update_on_networkmsg(&mut model, NetworkMsg::AddPart);
+        assert!(model.error.is_some());
+
+        update_on_networkmsg(&mut model, NetworkMsg::RemovePart);
+        update_on_networkmsg(&mut model, NetworkMsg::RemovePart);
+        update_on_networkmsg(&mut model, NetworkMsg::RemovePart);
+        assert_eq!(model.groups.len(), 1);
+        assert_eq!(model.selected_group, 0);
+    }
+
+    #[test]
+    fn test_ohmslaw() {
+        let mut model = OhmsLawModel::default();
+        set_textarea(&mut model.voltage_textarea, "5".into(), vec![]);
+        update_on_ohmslawmsg(&mut model, OhmsLawMsg::ToggleLink { ohm: 1000.0 });
+        update_on_ohmslawmsg(&mut model, OhmsLawMsg::Calculate);
+        let result = model.result.unwrap();
+        assert_eq!(result.current, 0.005);
+        assert_eq!(result.power, 0.025);
+
+        update_on_ohmslawmsg(
+            &mut model,
+            OhmsLawMsg::LinkedResistanceChanged { ohm: 500.0 },
+        );
+        assert_eq!(model.resistance_textarea.lines()[0], "500");
+        set_textarea(&mut model.power_textarea, "1".into(), vec![]);
+        update_on_ohmslawmsg(&mut model, OhmsLawMsg::Calculate);
+        assert!(model.result.is_none());
+        assert!(model.error.is_some());
+    }
+
+    #[test]
+    fn test_divider() {
+        let mut model = DividerModel::default();
+        set_textarea(&mut model.vin_textarea, "5".into(), vec![]);
+        set_textarea(&mut model.vout_textarea, "2.5".into(), vec![]);
+        update_on_dividermsg(&mut model, DividerMsg::Calculate);
+        assert_eq!(model.suggestions.len(), 12);
+        let (r1, r2) = model.selected_resistors().unwrap();
+        assert_eq!(r1, r2);
+
+        update_on_dividermsg(&mut model, DividerMsg::NextSeries);
+        assert_eq!(model.suggestions.len(), 24);
+
+        set_textarea(&mut model.vout_textarea, "7".into(), vec![]);
+        update_on_dividermsg(&mut model, DividerMsg::Calculate);
+        assert!(model.suggestions.is_empty());
+        assert!(model.error.is_some());
+    }
+
+    #[test]
+    fn test_led() {
+        let mut model = LedModel::default();
+        set_textarea(&mut model.supply_textarea, "12".into(), vec![]);
+        set_textarea(&mut model.forward_voltage_textarea, "2".into(), vec![]);
+        set_textarea(&mut model.current_textarea, "20".into(), vec![]);
+        update_on_ledmsg(&mut model, LedMsg::Calculate);
+        assert_eq!(model.result.unwrap().standard, 470.0);
+        assert_eq!(
+            model.resistor().unwrap().bands(),
+            vec![
+                &Color::Yellow,
+                &Color::Violet,
+                &Color::Brown,
+                &Color::Silver
+            ]
+        );
+
+        update_on_ledmsg(&mut model, LedMsg::NextSeries);
+        assert_eq!(model.result.unwrap().standard, 510.0);
+    }
+
+    #[test]
+    fn test_quiz() {
+        let mut model = QuizModel::new(42);
+        let ohm = model.question.specs().ohm;
+        model.answer_textarea.insert_str(ohm.to_string());
+        update_on_quizmsg(&mut model, QuizMsg::Check);
+        assert_eq!((model.asked, model.correct, model.streak), (1, 1, 1));
+        assert!(model.last_result.as_ref().unwrap().0);
+        assert!(model.answer_textarea.lines()[0].is_empty());
+
+        update_on_quizmsg(&mut model, QuizMsg::ToggleMode);
+        assert_eq!(model.mode, QuizMode::ValueToColors);
+        let bands = model.question.bands().into_iter().copied().collect();
+        model.answer_bands.resistor = rusistor::Resistor::try_create(bands).unwrap();
+        update_on_quizmsg(&mut model, QuizMsg::Check);
+        assert_eq!(model.streak, 2);
+
+        // the default answer bands are 100Ω ±1% with a TCR, a random question hardly matches
+        while model.question.specs() == model.answer_bands.resistor.specs() {
+            update_on_quizmsg(&mut model, QuizMsg::Skip);
+        }
+        update_on_quizmsg(&mut model, QuizMsg::Check);
+        assert_eq!((model.asked, model.correct, model.streak), (3, 2, 0));
+        assert_eq!(model.best_streak, 2);
+    }
+
+    #[test]
+    fn test_capacitor() {
+        let mut model = CapacitorModel::default();
+        update_on_capacitormsg(&mut model, CapacitorMsg::PrevBand);
+        update_on_capacitormsg(&mut model, CapacitorMsg::NextColor);
+        // white (10%) is followed by black (20%)
+        assert_eq!(model.capacitor.specs().tolerance, 0.2);
+        update_on_capacitormsg(&mut model, CapacitorMsg::PrevColor);
+        assert_eq!(model.capacitor.specs().tolerance, 0.1);
+
+        update_on_capacitormsg(&mut model, CapacitorMsg::FiveBands);
+        update_on_capacitormsg(&mut model, CapacitorMsg::NextBand);
+        update_on_capacitormsg(&mut model, CapacitorMsg::NextColor);
+        assert_eq!(model.capacitor.specs().voltage, Some(250));
+        assert_eq!(model.capacitor.specs().picofarad, 10_000.0);
+    }
+
+    #[test]
+    fn test_compare() {
+        let mut model = CompareModel::default();
+        update_on_comparemsg(
+            &mut model,
+            CompareMsg::Focus {
+                side: CompareSide::B,
+            },
+        );
+        update_on_comparemsg(
+            &mut model,
+            CompareMsg::BandsMsg {
+                msg: ColorCodesMsg::NextColor,
+            },
+        );
+        assert_eq!(model.a.resistor.specs().ohm, 100.0);
+        assert_eq!(model.b.resistor.specs().ohm, 200.0);
+        assert_eq!(model.focused().resistor.specs().ohm, 200.0);
+        assert!(!model.a.resistor.specs().overlaps(&model.b.resistor.specs()));
+    }
+
+    #[test]
+    fn test_inductor() {
+        let mut model = InductorModel::default();
+        update_on_inductormsg(&mut model, InductorMsg::PrevBand);
+        update_on_inductormsg(&mut model, InductorMsg::PrevColor);
+        // silver (10%) is preceded by gold (5%)
+        assert_eq!(model.inductor.specs().tolerance, 0.05);
+        update_on_inductormsg(&mut model, InductorMsg::PrevBand);
+        update_on_inductormsg(&mut model, InductorMsg::NextColor);
+        update_on_inductormsg(&mut model, InductorMsg::NextColor);
+        assert_eq!(model.inductor.specs().microhenry, 1000.0);
+    }
+
+    #[test]
+    fn test_inventory() {
+        let mut model = InventoryModel::default();
+        update_on_inventorymsg(
+            &mut model,
+            InventoryMsg::Add {
+                ohm: 3300.0,
+                tolerance: 0.05,
+            },
+        );
+        set_textarea(&mut model.input_textarea, "10k".to_string(), vec![]);
+        update_on_inventorymsg(&mut model, InventoryMsg::AddInput);
+        set_textarea(&mut model.input_textarea, "10k".to_string(), vec![]);
+        update_on_inventorymsg(&mut model, InventoryMsg::AddInput);
+        assert_eq!(model.inventory.parts.len(), 2);
+        assert_eq!(model.inventory.parts[1].quantity, 2);
+
+        set_textarea(&mut model.input_textarea, "3k2 5".to_string(), vec![]);
+        update_on_inventorymsg(&mut model, InventoryMsg::Search);
+        assert_eq!(model.listed(), vec![0]);
+        update_on_inventorymsg(&mut model, InventoryMsg::Remove);
+        assert!(model.listed().is_empty());
+        update_on_inventorymsg(&mut model, InventoryMsg::ShowAll);
+        assert_eq!(model.inventory.parts[0].ohm, 10000.0);
+    }
+
+    #[test]
+    fn test_select_color() {
+        let mut model = ColorCodesToSpecsModel::default();
+        update_on_colorcodemsg(
+            &mut model,
+            ColorCodesMsg::SelectColor {
+                band_idx: 3,
+                color: Color::Red,
+            },
+        );
+        assert_eq!(model.selected_band, 3);
+        assert_eq!(*model.resistor.bands()[3], Color::Red);
+        // pink isn't a valid digit, only the band is focused
+        update_on_colorcodemsg(
+            &mut model,
+            ColorCodesMsg::SelectColor {
+                band_idx: 0,
+                color: Color::Pink,
+            },
+        );
+        assert_eq!(model.selected_band, 0);
+        assert_eq!(*model.resistor.bands()[0], Color::Brown);
+    }
 }
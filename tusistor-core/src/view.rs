@@ -1,40 +1,520 @@
+use ratatui_core::style::Color;
+use rusistor::{BandRole, Resistor, ResistorSpecs};
+
+use crate::{
+    format::NumberFormat,
+    i18n::{Language, Text},
+    theme::default_band_color,
+};
+
+/// The styles of the print view, the swatches keep their colors on paper.
+const PRINT_CSS: &str = "body { font-family: sans-serif; color: #000; background: #fff; } \
+    .bands { display: flex; gap: 1em; } \
+    .swatch { height: 4em; border: 1px solid #000; print-color-adjust: exact; \
+    -webkit-print-color-adjust: exact; } \
+    table { border-collapse: collapse; margin-top: 1em; } \
+    th, td { border: 1px solid #999; padding: 0.25em 0.75em; text-align: left; }";
+
+/// What a band stands for and what its color means there.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BandInfo {
+    pub role: BandRole,
+    pub value: BandValue,
+}
+
+/// The meaning of the color of a band.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BandValue {
+    Digit(u32),
+    /// The power of ten of the multiplier band of a resistor.
+    Exponent(i32),
+    /// The factor of the multiplier band of a capacitor or an inductor.
+    Factor(f64),
+    /// The tolerance as a fraction, e.g. 0.05 for 5%.
+    Tolerance(f64),
+    /// The temperature coefficient in ppm/K.
+    Tcr(u32),
+    /// The rated voltage in V.
+    Voltage(u32),
+    /// The color means nothing in the band.
+    Invalid,
+}
+
+impl BandInfo {
+    /// The value right-aligned in `width`, blank if the color is invalid or the leading digit 0.
+    pub fn value_text(&self, width: usize) -> String {
+        let text = match self.value {
+            BandValue::Digit(0) if self.role == BandRole::Digit(0) => String::new(),
+            BandValue::Digit(d) => d.to_string(),
+            BandValue::Exponent(e) => format!("10^{}", e),
+            BandValue::Factor(f) => f.to_string(),
+            BandValue::Tolerance(t) => (t * 100.0).to_string(),
+            BandValue::Tcr(tcr) => tcr.to_string(),
+            BandValue::Voltage(v) => v.to_string(),
+            BandValue::Invalid => String::new(),
+        };
+        format!("{:>width$}", text)
+    }
+}
+
+/// The role of a band of a resistor with `bands` bands and the meaning of its color,
+/// `None` past the last band.
+pub fn band_info(bands: usize, band_idx: usize, color: &rusistor::Color) -> Option<BandInfo> {
+    let role = Resistor::band_role(bands, band_idx)?;
+    let value = match role {
+        BandRole::Digit(_) => color.as_digit().map(|d| BandValue::Digit(d as u32)),
+        BandRole::Multiplier => Some(BandValue::Exponent(color.as_digit_or_exponent() as i32)),
+        BandRole::Tolerance => color.as_tolerance().map(BandValue::Tolerance),
+        BandRole::Tcr => color.as_tcr().map(BandValue::Tcr),
+        BandRole::Voltage => None,
+    };
+    Some(BandInfo {
+        role,
+        value: value.unwrap_or(BandValue::Invalid),
+    })
+}
+
+pub fn capacitor_band_info(band_idx: usize, color: &rusistor::Color) -> Option<BandInfo> {
+    use rusistor::capacitor::Capacitor;
+    let role = Capacitor::band_role(band_idx)?;
+    let value = match role {
+        BandRole::Digit(_) => Capacitor::digit(color).map(BandValue::Digit),
+        BandRole::Multiplier => Capacitor::multiplier(color).map(BandValue::Factor),
+        BandRole::Tolerance => Capacitor::tolerance(color).map(BandValue::Tolerance),
+        BandRole::Voltage => Capacitor::voltage(color).map(BandValue::Voltage),
+        BandRole::Tcr => None,
+    };
+    Some(BandInfo {
+        role,
+        value: value.unwrap_or(BandValue::Invalid),
+    })
+}
+
+pub fn inductor_band_info(band_idx: usize, color: &rusistor::Color) -> Option<BandInfo> {
+    use rusistor::inductor::Inductor;
+    let role = Inductor::band_role(band_idx)?;
+    let value = match role {
+        BandRole::Digit(_) => Inductor::digit(color).map(BandValue::Digit),
+        BandRole::Multiplier => Inductor::multiplier(color).map(BandValue::Factor),
+        BandRole::Tolerance => Inductor::tolerance(color).map(BandValue::Tolerance),
+        BandRole::Tcr | BandRole::Voltage => None,
+    };
+    Some(BandInfo {
+        role,
+        value: value.unwrap_or(BandValue::Invalid),
+    })
+}
+
+/// The name of a band role, e.g. "Digit 1".
+pub fn band_role_text(role: BandRole, language: Language) -> String {
+    match role {
+        BandRole::Digit(i) => language.fill(Text::Digit, &[&(i + 1)]),
+        BandRole::Multiplier => language.text(Text::Multiplier).to_string(),
+        BandRole::Tolerance => language.text(Text::Tolerance).to_string(),
+        BandRole::Tcr => language.text(Text::Tcr).to_string(),
+        BandRole::Voltage => language.text(Text::Voltage).to_string(),
+    }
+}
+
 pub fn band_numeric_info(bands: usize, band_idx: usize, color: &rusistor::Color) -> String {
-    match (bands, band_idx) {
-        (3, i) | (4, i) if i <= 1 => {
-            if i == 0 && *color == rusistor::Color::Black {
-                " ".to_string()
-            } else {
-                color.as_digit().map_or(" ".to_string(), |s| s.to_string())
-            }
-        }
-        (5, i) | (6, i) if i <= 2 => {
-            if i == 0 && *color == rusistor::Color::Black {
-                " ".to_string()
-            } else {
-                color.as_digit().map_or(" ".to_string(), |s| s.to_string())
-            }
-        }
-        (3, 2) | (4, 2) | (5, 3) | (6, 3) => {
-            format!("10^{}", color.as_digit_or_exponent())
+    band_info(bands, band_idx, color).map_or(String::new(), |info| {
+        let width = match info.role {
+            BandRole::Tolerance => 4,
+            BandRole::Tcr => 3,
+            _ => 1,
+        };
+        info.value_text(width)
+    })
+}
+
+pub fn band_semantic_info(bands: usize, band_idx: usize, language: Language) -> String {
+    Resistor::band_role(bands, band_idx)
+        .map_or(String::new(), |role| band_role_text(role, language))
+}
+
+pub fn capacitor_band_numeric_info(band_idx: usize, color: &rusistor::Color) -> String {
+    capacitor_band_info(band_idx, color).map_or(String::new(), |info| {
+        let width = match info.role {
+            BandRole::Multiplier => 6,
+            BandRole::Tolerance => 2,
+            BandRole::Voltage => 3,
+            _ => 1,
+        };
+        info.value_text(width)
+    })
+}
+
+pub fn capacitor_band_semantic_info(band_idx: usize, language: Language) -> String {
+    rusistor::capacitor::Capacitor::band_role(band_idx)
+        .map_or(String::new(), |role| band_role_text(role, language))
+}
+
+pub fn inductor_band_numeric_info(band_idx: usize, color: &rusistor::Color) -> String {
+    inductor_band_info(band_idx, color).map_or(String::new(), |info| {
+        let width = match info.role {
+            BandRole::Multiplier => 4,
+            BandRole::Tolerance => 2,
+            _ => 1,
+        };
+        info.value_text(width)
+    })
+}
+
+pub fn inductor_band_semantic_info(band_idx: usize, language: Language) -> String {
+    rusistor::inductor::Inductor::band_role(band_idx)
+        .map_or(String::new(), |role| band_role_text(role, language))
+}
+
+/// Formats a resistance with an SI prefix, e.g. "4.7 kΩ".
+pub fn format_ohm(ohm: f64) -> String {
+    NumberFormat::default().ohm(ohm)
+}
+
+/// A gauge of the tolerance range `━` with `┃` at the nominal resistance and `●` at the
+/// measured one, a measured resistance outside of the range extends the gauge with `─`.
+pub fn tolerance_gauge(specs: &ResistorSpecs, measured: Option<f64>, width: usize) -> String {
+    let low = measured.map_or(specs.min_ohm, |m| m.min(specs.min_ohm));
+    let high = measured.map_or(specs.max_ohm, |m| m.max(specs.max_ohm));
+    let position = |ohm: f64| {
+        if high > low {
+            ((ohm - low) / (high - low) * (width.saturating_sub(1)) as f64).round() as usize
+        } else {
+            width / 2
         }
-        (4, 3) | (5, 4) | (6, 4) => color
-            .as_tolerance()
-            .map_or("    ".to_string(), |s| format!("{:>4}", (s * 100.0))),
-        (6, 5) => color
-            .as_tcr()
-            .map_or("   ".to_string(), |s| format!("{:>3}", s.to_string())),
-        _ => "".to_string(),
-    }
-}
-
-pub fn band_semantic_info(bands: usize, band_idx: usize) -> String {
-    match (bands, band_idx) {
-        (1, 0) => format!("Digit {}", band_idx + 1),
-        (3, i) | (4, i) if i <= 1 => format!("Digit {}", band_idx + 1),
-        (5, i) | (6, i) if i <= 2 => format!("Digit {}", band_idx + 1),
-        (3, 2) | (4, 2) | (5, 3) | (6, 3) => "Multiplier".to_string(),
-        (4, 3) | (5, 4) | (6, 4) => "Tolerance".to_string(),
-        (6, 5) => "TCR".to_string(),
-        _ => "".to_string(),
+    };
+    let range = position(specs.min_ohm)..=position(specs.max_ohm);
+    (0..width)
+        .map(|i| match i {
+            i if measured.is_some_and(|m| position(m) == i) => '●',
+            i if position(specs.ohm) == i => '┃',
+            i if range.contains(&i) => '━',
+            _ => '─',
+        })
+        .collect()
+}
+
+/// A one-line summary to share, e.g. "4.7 kΩ ±5% — yellow violet red gold".
+pub fn share_text(resistor: &rusistor::Resistor) -> String {
+    summary(resistor, " — ")
+}
+
+/// A one-line summary of a resistor, e.g. "1 kΩ ±1%, brown black black brown brown".
+pub fn resistor_summary(resistor: &rusistor::Resistor) -> String {
+    summary(resistor, ", ")
+}
+
+fn summary(resistor: &rusistor::Resistor, separator: &str) -> String {
+    let specs = resistor.specs();
+    let tcr = specs
+        .tcr
+        .map(|tcr| format!(" {}ppm/K", tcr))
+        .unwrap_or_default();
+    let bands = resistor
+        .bands()
+        .iter()
+        .map(|c| c.to_string())
+        .collect::<Vec<String>>()
+        .join(" ");
+    format!(
+        "{} ±{}%{}{}{}",
+        format_ohm(specs.ohm),
+        specs.tolerance * 100.0,
+        tcr,
+        separator,
+        bands
+    )
+}
+
+/// The resistor as a static HTML page for the print dialog of browsers, with a swatch
+/// per band and a table of the specs.
+pub fn print_html(resistor: &rusistor::Resistor, language: Language) -> String {
+    let specs = resistor.specs();
+    let bands = resistor.bands();
+    let swatches = bands
+        .iter()
+        .enumerate()
+        .map(|(idx, color)| {
+            format!(
+                "<figure><div class=\"swatch\" style=\"background: {}\"></div>\
+                 <figcaption>{} {}: {}<br>{}: {}</figcaption></figure>\n",
+                css_band_color(color),
+                language.text(Text::BandTitle),
+                idx + 1,
+                language.color(color),
+                band_semantic_info(bands.len(), idx, language),
+                band_numeric_info(bands.len(), idx, color).trim()
+            )
+        })
+        .collect::<String>();
+    let mut rows = vec![
+        (Text::Resistance, format_ohm(specs.ohm)),
+        (
+            Text::Tolerance,
+            format!("±{}", NumberFormat::default().percent(specs.tolerance)),
+        ),
+        (
+            Text::Range,
+            format!(
+                "{} – {}",
+                format_ohm(specs.min_ohm),
+                format_ohm(specs.max_ohm)
+            ),
+        ),
+    ];
+    if let Some(tcr) = specs.tcr {
+        rows.push((Text::Tcr, NumberFormat::default().ppm(tcr)));
+    }
+    let rows = rows
+        .into_iter()
+        .map(|(title, value)| {
+            format!(
+                "<tr><th>{}</th><td>{}</td></tr>\n",
+                language.text(title),
+                value
+            )
+        })
+        .collect::<String>();
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>tusistor: {}</title>\n<style>{}</style>\n</head>\n<body>\n\
+         <h1>{}</h1>\n<div class=\"bands\">\n{}</div>\n<table>\n{}</table>\n</body>\n</html>\n",
+        share_text(resistor),
+        PRINT_CSS,
+        resistor_summary(resistor),
+        swatches,
+        rows
+    )
+}
+
+/// The CSS color of a band, the RGB one of the terminal if it has one, e.g. for violet.
+fn css_band_color(color: &rusistor::Color) -> String {
+    match default_band_color(color) {
+        Color::Rgb(r, g, b) => format!("#{:02x}{:02x}{:02x}", r, g, b),
+        // the names of the other colors are CSS ones, too
+        _ => color.to_string(),
+    }
+}
+
+/// The color of its code, its name or the unique start of its name, e.g. "BN", "brown" or "br".
+pub fn parse_band(text: &str) -> Option<rusistor::Color> {
+    let text = text.trim().to_lowercase();
+    let named = rusistor::Color::ALL.into_iter().find(|c| {
+        band_code(c).eq_ignore_ascii_case(&text) || c.to_string().eq_ignore_ascii_case(&text)
+    });
+    let mut started = rusistor::Color::ALL
+        .into_iter()
+        .filter(|c| !text.is_empty() && c.to_string().starts_with(&text));
+    named.or_else(|| started.next().filter(|_| started.next().is_none()))
+}
+
+/// Two-letter color code according to IEC 60062.
+pub fn band_code(color: &rusistor::Color) -> &'static str {
+    match color {
+        rusistor::Color::Black => "BK",
+        rusistor::Color::Brown => "BN",
+        rusistor::Color::Red => "RD",
+        rusistor::Color::Orange => "OG",
+        rusistor::Color::Yellow => "YE",
+        rusistor::Color::Green => "GN",
+        rusistor::Color::Blue => "BU",
+        rusistor::Color::Violet => "VT",
+        rusistor::Color::Grey => "GY",
+        rusistor::Color::White => "WH",
+        rusistor::Color::Gold => "GD",
+        rusistor::Color::Silver => "SR",
+        rusistor::Color::Pink => "PK",
+    }
+}
+
+/// A distinct fill character per color, for output without colors.
+pub fn band_pattern(color: &rusistor::Color) -> char {
+    match color {
+        rusistor::Color::Black => '█',
+        rusistor::Color::Brown => '▓',
+        rusistor::Color::Red => '▒',
+        rusistor::Color::Orange => '░',
+        rusistor::Color::Yellow => '=',
+        rusistor::Color::Green => '+',
+        rusistor::Color::Blue => '#',
+        rusistor::Color::Violet => '%',
+        rusistor::Color::Grey => ':',
+        rusistor::Color::White => '.',
+        rusistor::Color::Gold => '*',
+        rusistor::Color::Silver => '~',
+        rusistor::Color::Pink => '^',
+    }
+}
+
+/// A band of the chart of a resistor, the frontends pick the shade of `color` from their palette.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartBand {
+    pub color: rusistor::Color,
+    /// The name of the color in the language of the UI, e.g. "rot".
+    pub name: String,
+    /// The role of the band, e.g. "Multiplier".
+    pub semantic_info: String,
+    /// The value of the color in that role, e.g. "10^2".
+    pub numeric_info: String,
+}
+
+impl ChartBand {
+    /// The label below the bar, e.g. "Multiplier: 10^2".
+    pub fn label(&self) -> String {
+        format!("{}: {}", self.semantic_info, self.numeric_info.trim())
+    }
+}
+
+/// The bands of the chart of a resistor, from left to right.
+pub fn chart_bands(bands: &[&rusistor::Color], language: Language) -> Vec<ChartBand> {
+    bands
+        .iter()
+        .enumerate()
+        .map(|(idx, color)| ChartBand {
+            color: **color,
+            name: language.color(color).to_string(),
+            semantic_info: band_semantic_info(bands.len(), idx, language),
+            numeric_info: band_numeric_info(bands.len(), idx, color),
+        })
+        .collect()
+}
+
+/// The title of the chart of a resistor, e.g. " Resistance: 4700Ω - Tolerance: ±5% ".
+pub fn chart_title(specs: &ResistorSpecs, language: Language, format: NumberFormat) -> String {
+    let tcr = specs
+        .tcr
+        .map(|tcr| format!(" - {}: {}(ppm/K)", language.text(Text::Tcr), tcr))
+        .unwrap_or_default();
+    format!(
+        " {}: {}Ω - {}: ±{}%{} ",
+        language.text(Text::Resistance),
+        format.format(specs.ohm),
+        language.text(Text::Tolerance),
+        format.format(specs.tolerance * 100.0),
+        tcr
+    )
+}
+
+/// The title of the color list of a band, the focused one is marked with a `*`.
+pub fn band_list_title(
+    band_idx: usize,
+    semantic_info: &str,
+    is_focused: bool,
+    language: Language,
+) -> String {
+    format!(
+        " {} {}: {}{}",
+        language.text(Text::BandTitle),
+        band_idx + 1,
+        semantic_info,
+        if is_focused { "* " } else { " " }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        BandInfo, BandValue, band_info, band_numeric_info, band_semantic_info, capacitor_band_info,
+        capacitor_band_numeric_info, chart_bands, chart_title, format_ohm, print_html,
+        resistor_summary, share_text, tolerance_gauge,
+    };
+    use crate::{format::NumberFormat, i18n::Language};
+    use rusistor::{BandRole, Color, Resistor};
+
+    #[test]
+    fn test_band_info() {
+        assert_eq!(
+            band_info(4, 2, &Color::Gold),
+            Some(BandInfo {
+                role: BandRole::Multiplier,
+                value: BandValue::Exponent(-1)
+            })
+        );
+        assert_eq!(
+            band_info(6, 5, &Color::Gold).map(|info| info.value),
+            Some(BandValue::Invalid)
+        );
+        assert_eq!(band_info(4, 4, &Color::Gold), None);
+        assert_eq!(
+            capacitor_band_info(4, &Color::Red),
+            Some(BandInfo {
+                role: BandRole::Voltage,
+                value: BandValue::Voltage(250)
+            })
+        );
+
+        assert_eq!(band_numeric_info(5, 0, &Color::Black), " ");
+        assert_eq!(band_numeric_info(5, 4, &Color::Gold), "   5");
+        assert_eq!(band_numeric_info(6, 5, &Color::Gold), "   ");
+        assert_eq!(capacitor_band_numeric_info(2, &Color::Orange), "  1000");
+        assert_eq!(band_semantic_info(6, 2, Language::En), "Digit 3");
+    }
+
+    #[test]
+    fn test_tolerance_gauge() {
+        let specs = Resistor::determine(100.0, Some(10.0), None)
+            .unwrap()
+            .specs();
+        assert_eq!(tolerance_gauge(&specs, None, 11), "━━━━━┃━━━━━");
+        assert_eq!(tolerance_gauge(&specs, Some(95.0), 11), "━━━●━┃━━━━━");
+        assert_eq!(tolerance_gauge(&specs, Some(130.0), 11), "━━━┃━━────●");
+    }
+
+    #[test]
+    fn test_format_ohm() {
+        assert_eq!(format_ohm(0.47), "0.47 Ω");
+        assert_eq!(format_ohm(4700.0), "4.7 kΩ");
+        assert_eq!(format_ohm(2_200_000.0), "2.2 MΩ");
+    }
+
+    #[test]
+    fn test_resistor_summary() {
+        let resistor = Resistor::try_create(vec![
+            Color::Brown,
+            Color::Black,
+            Color::Black,
+            Color::Brown,
+            Color::Brown,
+        ])
+        .unwrap();
+        assert_eq!(
+            resistor_summary(&resistor),
+            "1 kΩ ±1%, brown black black brown brown"
+        );
+        let resistor =
+            Resistor::try_create(vec![Color::Yellow, Color::Violet, Color::Red, Color::Gold])
+                .unwrap();
+        assert_eq!(share_text(&resistor), "4.7 kΩ ±5% — yellow violet red gold");
+    }
+
+    #[test]
+    fn test_chart_bands() {
+        let resistor =
+            Resistor::try_create(vec![Color::Yellow, Color::Violet, Color::Red, Color::Gold])
+                .unwrap();
+        let bands = chart_bands(&resistor.bands(), Language::De);
+        assert_eq!(bands[1].name, "violett");
+        assert_eq!(bands[2].color, Color::Red);
+        assert_eq!(bands[2].label(), "Multiplikator: 10^2");
+        assert_eq!(
+            chart_title(&resistor.specs(), Language::En, NumberFormat::default()),
+            " Resistance: 4700Ω - Tolerance: ±5% "
+        );
+    }
+
+    #[test]
+    fn test_print_html() {
+        let resistor =
+            Resistor::try_create(vec![Color::Yellow, Color::Violet, Color::Red, Color::Gold])
+                .unwrap();
+        let html = print_html(&resistor, Language::En);
+        assert!(html.contains("<h1>4.7 kΩ ±5%, yellow violet red gold</h1>"));
+        assert!(html.contains(
+            "<div class=\"swatch\" style=\"background: #9400d3\"></div>\
+             <figcaption>Band 2: violet<br>Digit 2: 7</figcaption>"
+        ));
+        assert!(html.contains("<tr><th>Tolerance</th><td>±5%</td></tr>"));
+        assert!(!html.contains("ppm/K"));
+        let html = print_html(&resistor, Language::De);
+        assert!(html.contains("<figcaption>Ring 1: gelb<br>"));
     }
 }
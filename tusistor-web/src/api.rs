@@ -0,0 +1,56 @@
+//! The engine for the hosting page and other scripts, trunk puts these functions on
+//! `window.wasmBindings`, e.g. `wasmBindings.decode(["br", "bk", "rd", "gd"])`.
+
+use rusistor::Resistor;
+use tusistor_core::{
+    update::try_determine_resistor,
+    view::{parse_band, share_text},
+};
+use wasm_bindgen::prelude::*;
+use web_sys::js_sys::{Array, Object, Reflect};
+
+/// The resistor of the specs like in the specs tab, e.g. `determine("4k7", "5", "")`.
+#[wasm_bindgen]
+pub fn determine(resistance: &str, tolerance: &str, tcr: &str) -> Result<JsValue, JsError> {
+    let resistor =
+        try_determine_resistor(resistance, tolerance, tcr, None).map_err(|e| JsError::new(&e))?;
+    Ok(resistor_object(&resistor))
+}
+
+/// The resistor of the bands given by their codes or names, e.g. `decode(["yellow", "vt", "rd", "gd"])`.
+#[wasm_bindgen]
+pub fn decode(bands: Vec<String>) -> Result<JsValue, JsError> {
+    let resistor = bands
+        .iter()
+        .map(|band| parse_band(band).ok_or_else(|| format!("invalid color: {}", band)))
+        .collect::<Result<Vec<_>, _>>()
+        .and_then(Resistor::try_create)
+        .map_err(|e| JsError::new(&e))?;
+    Ok(resistor_object(&resistor))
+}
+
+/// The bands and specs of the resistor, e.g. `{ bands: ["yellow", "violet", "red", "gold"],
+/// ohm: 4700, tolerance: 5, minOhm: 4465, maxOhm: 4935, tcr: null, summary: "4.7 kΩ ±5% — ..." }`.
+fn resistor_object(resistor: &Resistor) -> JsValue {
+    let specs = resistor.specs();
+    let bands = resistor
+        .bands()
+        .iter()
+        .map(|band| JsValue::from_str(&band.to_string()))
+        .collect::<Array>();
+    let tcr = specs.tcr.map_or(JsValue::NULL, JsValue::from);
+    let object = Object::new();
+    for (key, value) in [
+        ("bands", bands.into()),
+        ("ohm", JsValue::from(specs.ohm)),
+        // in percent like the inputs
+        ("tolerance", JsValue::from(specs.tolerance * 100.0)),
+        ("minOhm", JsValue::from(specs.min_ohm)),
+        ("maxOhm", JsValue::from(specs.max_ohm)),
+        ("tcr", tcr),
+        ("summary", JsValue::from_str(&share_text(resistor))),
+    ] {
+        let _ = Reflect::set(&object, &JsValue::from_str(key), &value);
+    }
+    object.into()
+}
@@ -0,0 +1,113 @@
+use tusistor_core::session::Session;
+use web_sys::wasm_bindgen::{JsCast, JsValue, closure::Closure};
+
+/// The session of the fragment of the URL, `None` if it has none.
+pub fn load_session() -> Option<Session> {
+    let hash = web_sys::window()?.location().hash().ok()?;
+    parse_hash(&hash)
+}
+
+/// The session of the `#` and the fragment of a URL, `None` if the fragment is empty.
+fn parse_hash(hash: &str) -> Option<Session> {
+    (!hash.trim_start_matches('#').is_empty()).then(|| Session::from_fragment(hash))
+}
+
+/// The `#` and the fragment of the URL of the session.
+fn hash(session: &Session) -> String {
+    format!("#{}", session.to_fragment())
+}
+
+/// The session of the query of the URL to prefill the app from other pages, `None` if it
+/// has neither bands nor specs, e.g. `?r=4.7k&tol=5&tcr=50` or `?bands=br,bk,rd,gd`.
+pub fn load_query() -> Option<Session> {
+    let search = web_sys::window()?.location().search().ok()?;
+    Session::from_query(&search)
+}
+
+/// The value of a parameter of the query of the URL, e.g. "webgl2" of `?backend=webgl2`.
+pub fn query_param(name: &str) -> Option<String> {
+    let search = web_sys::window()?.location().search().ok()?;
+    param(&search, name)
+}
+
+fn param(search: &str, name: &str) -> Option<String> {
+    search
+        .trim_start_matches('?')
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value.to_string())
+}
+
+/// Replaces the fragment of the URL with the session, to share a link to it.
+/// A new tab adds an entry to the browser history, so the back button switches back to the last one.
+pub fn save_session(session: &Session) {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    if let Ok(history) = window.history() {
+        let url = hash(session);
+        let tab_changed = load_session().is_some_and(|current| current.tab != session.tab);
+        // replaced instead of pushed to not add an entry to the browser history per key
+        let _ = if tab_changed {
+            history.push_state_with_url(&JsValue::NULL, "", Some(&url))
+        } else {
+            history.replace_state_with_url(&JsValue::NULL, "", Some(&url))
+        };
+    }
+}
+
+/// Calls `on_change` with the session of the URL when the browser goes back or forward.
+pub fn on_history_change(mut on_change: impl FnMut(Session) + 'static) {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let listener = Closure::<dyn FnMut()>::new(move || {
+        if let Some(session) = load_session() {
+            on_change(session);
+        }
+    });
+    let _ = window.add_event_listener_with_callback("popstate", listener.as_ref().unchecked_ref());
+    // the listener lives as long as the page
+    listener.forget();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hash, param, parse_hash};
+    use rusistor::Color;
+    use tusistor_core::{model::SelectedTab, session::Session};
+
+    #[test]
+    fn test_hash() {
+        let session = Session {
+            tab: SelectedTab::SpecsToColorCodes,
+            bands: vec![
+                Color::Brown,
+                Color::Black,
+                Color::Black,
+                Color::Red,
+                Color::Brown,
+            ],
+            resistance: "4k7".to_string(),
+            tolerance: "1".to_string(),
+            tcr: "50".to_string(),
+            ..Session::default()
+        };
+        let hash = hash(&session);
+        assert!(hash.starts_with("#/specs?"), "{}", hash);
+        assert_eq!(parse_hash(&hash), Some(session));
+        assert_eq!(parse_hash("#"), None);
+        assert_eq!(parse_hash(""), None);
+    }
+
+    #[test]
+    fn test_param() {
+        assert_eq!(
+            param("?r=4.7k&backend=webgl2", "backend").as_deref(),
+            Some("webgl2")
+        );
+        assert_eq!(param("?backend", "backend"), None);
+        assert_eq!(param("", "backend"), None);
+    }
+}
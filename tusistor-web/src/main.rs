@@ -1,26 +1,144 @@
+pub mod api;
+pub mod link;
 pub mod model;
+pub mod page;
+pub mod storage;
 pub mod update;
 pub mod view;
 
 use model::Model;
-use ratzilla::{DomBackend, WebRenderer};
-use std::{cell::RefCell, io, rc::Rc};
-use update::handle_event;
+use ratzilla::{
+    CanvasBackend, DomBackend, WebEventHandler, WebGl2Backend, WebRenderer,
+    ratatui::{Terminal, backend::Backend},
+    web_sys::wasm_bindgen::closure::Closure,
+};
+use std::{cell::RefCell, io, mem, rc::Rc};
+use tusistor_core::model::{HISTORY_LEN, HistoryStore};
+use update::{Msg, handle_event, handle_mouse_event, update};
 use view::view;
 
+type SharedModel = Rc<RefCell<Model<'static>>>;
+
+/// A backend that can follow the size of the browser window.
+trait FitWindow: Backend<Error = io::Error> + WebEventHandler + Sized + 'static {
+    /// Fits the terminal to the window after it was resized.
+    fn fit_window(terminal: &mut Terminal<Self>, model: &SharedModel) -> io::Result<()>;
+}
+
+impl FitWindow for DomBackend {
+    fn fit_window(_: &mut Terminal<Self>, _: &SharedModel) -> io::Result<()> {
+        // the DOM backend rebuilds its grid on resize by itself
+        Ok(())
+    }
+}
+
+impl FitWindow for WebGl2Backend {
+    fn fit_window(terminal: &mut Terminal<Self>, _: &SharedModel) -> io::Result<()> {
+        Ok(terminal.backend_mut().resize_canvas()?)
+    }
+}
+
+impl FitWindow for CanvasBackend {
+    fn fit_window(terminal: &mut Terminal<Self>, model: &SharedModel) -> io::Result<()> {
+        // the canvas keeps the size it was created with, a new one replaces it
+        page::remove_canvas();
+        *terminal.backend_mut() = CanvasBackend::new()?;
+        page::focus_canvas();
+        listen(terminal, model)?;
+        terminal.clear()
+    }
+}
+
 fn main() -> io::Result<()> {
-    let backend = DomBackend::new()?;
-    let mut terminal = ratzilla::ratatui::Terminal::new(backend)?;
-    let model = Rc::new(RefCell::new(Model::default()));
+    // the canvas and WebGL2 backends render large windows faster than the DOM one
+    match link::query_param("backend").as_deref() {
+        Some("canvas") => run(CanvasBackend::new()?),
+        Some("webgl2") => run(WebGl2Backend::new()?),
+        _ => run(DomBackend::new()?),
+    }
+}
 
-    terminal.on_key_event({
+fn run<B: FitWindow>(backend: B) -> io::Result<()> {
+    let mut terminal = Terminal::new(backend)?;
+    let config = storage::load_config();
+    let mut model = Model::new(&config);
+    page::apply_theme(model.theme);
+    if model.restore_resistor
+        && let Some(resistor) = storage::load_resistor()
+    {
+        model.color_codes_to_specs.resistor = resistor;
+    }
+    let session = model.restore_session.then(storage::load_session).flatten();
+    // a shared link takes precedence, the fragment of one tracks the changes since its query
+    if let Some(session) = link::load_session().or_else(link::load_query).or(session) {
+        let tab = session.restore(&mut model.color_codes_to_specs, &mut model.specs_to_color);
+        if model::TABS.contains(&tab) {
+            model.selected_tab = tab;
+        }
+    }
+    let history = &mut model.specs_to_color.history;
+    *history = storage::HistoryStorage.load().unwrap_or_default();
+    history.set_max_len(config.history_len.unwrap_or(HISTORY_LEN));
+    history.set_clock(storage::now);
+    storage::load_quiz_score(&mut model.quiz);
+    let model = Rc::new(RefCell::new(model));
+    listen(&mut terminal, &model)?;
+
+    // the back and forward buttons of the browser switch the tabs
+    link::on_history_change({
         let model = model.clone();
-        move |key_event| handle_event(&mut model.borrow_mut(), key_event)
-    })?;
+        move |session| {
+            if model::TABS.contains(&session.tab) {
+                model.borrow_mut().selected_tab = session.tab;
+            }
+        }
+    });
 
-    terminal.draw_web(move |frame| {
-        view(&mut model.borrow_mut(), frame);
+    page::on_paste({
+        let model = model.clone();
+        move |text| update(&mut model.borrow_mut(), Msg::Paste { text })
+    });
+
+    page::on_resize({
+        let model = model.clone();
+        move || update(&mut model.borrow_mut(), Msg::Resize)
     });
 
+    render(terminal, model);
     Ok(())
 }
+
+/// Passes the key and mouse events of the backend to the model.
+fn listen<B: FitWindow>(terminal: &mut Terminal<B>, model: &SharedModel) -> io::Result<()> {
+    terminal.on_key_event({
+        let model = model.clone();
+        move |key_event| handle_event(&mut model.borrow_mut(), key_event)
+    })?;
+    terminal.on_mouse_event({
+        let model = model.clone();
+        move |mouse_event| handle_mouse_event(&mut model.borrow_mut(), mouse_event)
+    })?;
+    Ok(())
+}
+
+/// Draws the model on every animation frame like `draw_web`, after fitting the terminal
+/// to the window if it was resized.
+fn render<B: FitWindow>(mut terminal: Terminal<B>, model: SharedModel) {
+    let on_frame = Rc::new(RefCell::new(None::<Closure<dyn FnMut()>>));
+    *on_frame.borrow_mut() = Some(Closure::new({
+        let on_frame = on_frame.clone();
+        move || {
+            let resized = mem::take(&mut model.borrow_mut().resized);
+            if resized {
+                let _ = B::fit_window(&mut terminal, &model);
+            }
+            let _ = terminal.draw(|frame| view(&mut model.borrow_mut(), frame));
+            if let Some(on_frame) = on_frame.borrow().as_ref() {
+                Terminal::<B>::request_animation_frame(on_frame);
+            }
+        }
+    }));
+    if let Some(on_frame) = on_frame.borrow().as_ref() {
+        Terminal::<B>::request_animation_frame(on_frame);
+    }
+}
@@ -1,8 +1,137 @@
-use tusistor_core::model::{ColorCodesToSpecsModel, SelectedTab, SpecsToColorModel};
+use crate::page;
+use ratzilla::{event::KeyEvent, ratatui::layout::Rect};
+use tusistor_core::{
+    config::Config,
+    format::NumberFormat,
+    i18n::Language,
+    model::{AppModel, ColorCodesToSpecsModel, QuizModel, SelectedTab, SpecsToColorModel},
+    theme::Theme,
+    update::update_on_colorcodemsg,
+};
 
+/// The tabs available on the web, the E-series browser is not ported yet.
+pub const TABS: [SelectedTab; 3] = [
+    SelectedTab::ColorCodesToSpecs,
+    SelectedTab::SpecsToColorCodes,
+    SelectedTab::Quiz,
+];
+
+/// The areas of the last frame that react to clicks and taps.
 #[derive(Debug, Default)]
+pub struct HitAreas {
+    pub tabs: Vec<(Rect, SelectedTab)>,
+    /// The area and the scroll offset of each band list.
+    pub bands: Vec<(Rect, usize)>,
+    /// The buttons of the bands counts.
+    pub band_counts: Vec<(Rect, usize)>,
+    /// The button that shows the help.
+    pub help: Option<Rect>,
+    /// The button that switches the theme.
+    pub theme: Option<Rect>,
+    /// The button that shares the resistor of the tab.
+    pub share: Option<Rect>,
+    /// The button that opens the print view of the resistor of the tab.
+    pub print: Option<Rect>,
+    /// The buttons of the keypad and the quiz and the keys they press.
+    pub keys: Vec<(Rect, KeyEvent)>,
+}
+
+#[derive(Debug)]
 pub struct Model<'a> {
     pub selected_tab: SelectedTab,
     pub specs_to_color: SpecsToColorModel<'a>,
     pub color_codes_to_specs: ColorCodesToSpecsModel,
+    pub quiz: QuizModel<'a>,
+    pub language: Language,
+    /// The separators of the numbers of the browser's locale.
+    pub number_format: NumberFormat,
+    pub theme: Theme,
+    pub hit_areas: HitAreas,
+    /// Shows the keys of all tabs over the current one.
+    pub show_help: bool,
+    /// The window was resized since the last frame.
+    pub resized: bool,
+    /// The outcome of the last message, e.g. that the resistor was copied.
+    pub status: Option<String>,
+    /// Saves the resistor of the color codes tab to start with it the next time.
+    pub restore_resistor: bool,
+    /// Saves the tab, the bands and the specs inputs to restore them the next time.
+    pub restore_session: bool,
+}
+
+impl<'a> Model<'a> {
+    /// The model with the tab and bands count of the config, if they are available on the web.
+    pub fn new(config: &Config) -> Model<'a> {
+        Model::with_locale(config, page::number_format(), seed())
+    }
+
+    /// The model of `new` with the number format of a locale and the seed of the quiz.
+    pub fn with_locale(config: &Config, number_format: NumberFormat, seed: u64) -> Model<'a> {
+        let mut model = Model {
+            selected_tab: SelectedTab::default(),
+            specs_to_color: SpecsToColorModel {
+                number_format,
+                ..SpecsToColorModel::default()
+            },
+            color_codes_to_specs: ColorCodesToSpecsModel::default(),
+            quiz: QuizModel::new(seed),
+            language: config.language.unwrap_or_default(),
+            number_format,
+            theme: config.theme.unwrap_or_default(),
+            hit_areas: HitAreas::default(),
+            show_help: false,
+            resized: false,
+            status: None,
+            restore_resistor: config.restore_resistor.unwrap_or_default(),
+            restore_session: config.restore_session.unwrap_or_default(),
+        };
+        if let Some(tab) = config.tab.filter(|tab| TABS.contains(tab)) {
+            model.selected_tab = tab;
+        }
+        if let Some(msg) = config.bands_msg() {
+            update_on_colorcodemsg(&mut model.color_codes_to_specs, msg);
+            // the configured bands count isn't an edit to undo
+            model.color_codes_to_specs.undo.clear();
+        }
+        model
+    }
+}
+
+impl<'a> AppModel<'a> for Model<'a> {
+    fn tabs(&self) -> &[SelectedTab] {
+        &TABS
+    }
+
+    fn selected_tab(&self) -> SelectedTab {
+        self.selected_tab
+    }
+
+    fn set_selected_tab(&mut self, tab: SelectedTab) {
+        self.selected_tab = tab;
+    }
+
+    fn color_codes_to_specs_mut(&mut self) -> &mut ColorCodesToSpecsModel {
+        &mut self.color_codes_to_specs
+    }
+
+    fn specs_to_color(&self) -> &SpecsToColorModel<'a> {
+        &self.specs_to_color
+    }
+
+    fn specs_to_color_mut(&mut self) -> &mut SpecsToColorModel<'a> {
+        &mut self.specs_to_color
+    }
+
+    fn quiz(&self) -> &QuizModel<'a> {
+        &self.quiz
+    }
+
+    fn quiz_mut(&mut self) -> &mut QuizModel<'a> {
+        &mut self.quiz
+    }
+}
+
+/// The milliseconds since the epoch, the quiz asks other questions on every visit.
+fn seed() -> u64 {
+    web_sys::js_sys::Date::now() as u64
 }
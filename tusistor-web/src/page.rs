@@ -0,0 +1,144 @@
+use ratzilla::ratatui::style::Color;
+use tusistor_core::{format::NumberFormat, theme::Theme};
+use web_sys::{
+    Blob, BlobPropertyBag, ClipboardEvent, Element, HtmlElement, ShareData, Url,
+    js_sys::{Array, Reflect},
+    wasm_bindgen::{JsCast, JsValue, closure::Closure},
+};
+
+/// The colors of the text and of the page of a theme.
+pub fn colors(theme: Theme) -> (Color, Color) {
+    match theme {
+        Theme::Dark => (Color::White, Color::Rgb(0x12, 0x12, 0x12)),
+        Theme::Light => (Color::Black, Color::Rgb(0xfa, 0xfa, 0xfa)),
+    }
+}
+
+/// The theme of the `prefers-color-scheme` of the browser, dark if it prefers none.
+pub fn preferred_theme() -> Option<Theme> {
+    let query = web_sys::window()?
+        .match_media("(prefers-color-scheme: light)")
+        .ok()??;
+    Some(if query.matches() {
+        Theme::Light
+    } else {
+        Theme::Dark
+    })
+}
+
+/// The number format of the browser's locale, the plain one if it has none.
+pub fn number_format() -> NumberFormat {
+    web_sys::window()
+        .and_then(|window| window.navigator().language())
+        .map(|locale| NumberFormat::from_locale(&locale))
+        .unwrap_or_default()
+}
+
+/// Paints the page around the grid in the background color of the theme.
+pub fn apply_theme(theme: Theme) {
+    let Some(body) = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.body())
+    else {
+        return;
+    };
+    if let (_, Color::Rgb(r, g, b)) = colors(theme) {
+        let css = format!("#{:02x}{:02x}{:02x}", r, g, b);
+        let _ = body.style().set_property("background-color", &css);
+    }
+}
+
+/// Calls `on_resize` when the browser window is resized.
+pub fn on_resize(on_resize: impl FnMut() + 'static) {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let listener = Closure::<dyn FnMut()>::new(on_resize);
+    let _ = window.add_event_listener_with_callback("resize", listener.as_ref().unchecked_ref());
+    // the listener lives as long as the page
+    listener.forget();
+}
+
+/// Removes the canvas of the canvas backend before it's replaced by one of the new size.
+pub fn remove_canvas() {
+    if let Some(canvas) = canvas() {
+        canvas.remove();
+    }
+}
+
+/// Focuses the canvas of the canvas backend to send it the key events.
+pub fn focus_canvas() {
+    if let Some(canvas) = canvas().and_then(|canvas| canvas.dyn_into::<HtmlElement>().ok()) {
+        let _ = canvas.focus();
+    }
+}
+
+fn canvas() -> Option<Element> {
+    web_sys::window()?
+        .document()?
+        .query_selector("canvas")
+        .ok()?
+}
+
+/// Calls `on_paste` with the text the browser pastes from the clipboard.
+pub fn on_paste(mut on_paste: impl FnMut(String) + 'static) {
+    let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+        return;
+    };
+    let listener = Closure::<dyn FnMut(ClipboardEvent)>::new(move |event: ClipboardEvent| {
+        if let Some(text) = event
+            .clipboard_data()
+            .and_then(|data| data.get_data("text").ok())
+        {
+            event.prevent_default();
+            on_paste(text);
+        }
+    });
+    let _ = document.add_event_listener_with_callback("paste", listener.as_ref().unchecked_ref());
+    // the listener lives as long as the page
+    listener.forget();
+}
+
+/// How a text was shared.
+#[derive(Debug, PartialEq)]
+pub enum Shared {
+    /// By the share sheet of the browser, e.g. to a messenger on phones.
+    Sheet,
+    /// By the clipboard, for browsers without a share sheet.
+    Clipboard,
+}
+
+/// Shares the text and the link to the page, `None` if the browser can do neither.
+pub fn share(title: &str, text: &str) -> Option<Shared> {
+    let window = web_sys::window()?;
+    let navigator = window.navigator();
+    let url = window.location().href().ok()?;
+    let data = ShareData::new();
+    data.set_title(title);
+    data.set_text(text);
+    data.set_url(&url);
+    // older browsers have no `canShare`
+    let can_share = Reflect::has(&navigator, &JsValue::from_str("canShare")).unwrap_or(false)
+        && navigator.can_share_with_data(&data);
+    if can_share {
+        let _ = navigator.share_with_data(&data);
+        Some(Shared::Sheet)
+    } else {
+        let _ = navigator
+            .clipboard()
+            .write_text(&format!("{} {}", text, url));
+        Some(Shared::Clipboard)
+    }
+}
+
+/// Opens the HTML in a new tab to print it, `None` if the browser blocked the tab.
+pub fn open_html(html: &str) -> Option<()> {
+    let window = web_sys::window()?;
+    let options = BlobPropertyBag::new();
+    options.set_type("text/html");
+    let parts = Array::of1(&JsValue::from_str(html));
+    let blob = Blob::new_with_str_sequence_and_options(&parts, &options).ok()?;
+    let url = Url::create_object_url_with_blob(&blob).ok()?;
+    window.open_with_url_and_target(&url, "_blank").ok()??;
+    Some(())
+}
@@ -0,0 +1,226 @@
+use rusistor::{Color, Resistor};
+use tusistor_core::{
+    config::Config,
+    i18n::Language,
+    model::{HistoryStore, QuizModel, SpecsHistory},
+    session::Session,
+    theme::Theme,
+};
+
+use crate::page;
+
+const HISTORY_KEY: &str = "tusistor-history";
+/// The key of the tab, the bands and the specs inputs, in the format of the URL fragment.
+const SESSION_KEY: &str = "tusistor-session";
+/// The key of the bands of the last resistor of the color codes tab, e.g. "brown black red gold".
+const RESISTOR_KEY: &str = "tusistor-resistor";
+/// The key of the TOML config, the same settings as the `config.toml` of tusistor.
+const CONFIG_KEY: &str = "tusistor-config";
+/// The key of the theme chosen on the page, it takes precedence over the one of the config.
+const THEME_KEY: &str = "tusistor-theme";
+/// The key of the quiz score, the correct answers, the questions, the streak and the best
+/// streak, e.g. "7 10 2 5".
+const QUIZ_KEY: &str = "tusistor-quiz";
+
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+/// Loads the config from the local storage of the browser, an invalid one is ignored.
+pub fn load_config() -> Config {
+    let mut config = local_storage()
+        .and_then(|storage| storage.get_item(CONFIG_KEY).ok()?)
+        .and_then(|text| Config::from_toml(&text).ok())
+        .unwrap_or_default();
+    config.language = config.language.or_else(browser_language);
+    config.theme = load_theme().or(config.theme).or_else(page::preferred_theme);
+    config
+}
+
+fn load_theme() -> Option<Theme> {
+    let text = local_storage()?.get_item(THEME_KEY).ok()??;
+    text.parse().ok()
+}
+
+pub fn save_theme(theme: Theme) {
+    if let Some(storage) = local_storage() {
+        let _ = storage.set_item(THEME_KEY, theme.name());
+    }
+}
+
+/// The language of the browser, if it has a translation.
+fn browser_language() -> Option<Language> {
+    Language::from_locale(&web_sys::window()?.navigator().language()?)
+}
+
+/// The specs history in the local storage of the browser.
+pub struct HistoryStorage;
+
+impl HistoryStore for HistoryStorage {
+    fn load(&self) -> Result<SpecsHistory, String> {
+        let storage = local_storage().ok_or("no local storage")?;
+        let text = storage
+            .get_item(HISTORY_KEY)
+            .map_err(|_| "the local storage is not readable")?;
+        Ok(text
+            .map(|text| SpecsHistory::from_text(&text))
+            .unwrap_or_default())
+    }
+
+    fn save(&self, history: &SpecsHistory) -> Result<(), String> {
+        let storage = local_storage().ok_or("no local storage")?;
+        storage
+            .set_item(HISTORY_KEY, &history.to_text())
+            .map_err(|_| "the local storage is full or disabled".to_string())
+    }
+}
+
+/// The seconds since the Unix epoch, the clock of the history entries.
+pub fn now() -> u64 {
+    (web_sys::js_sys::Date::now() / 1000.0) as u64
+}
+
+/// Loads the last resistor of the color codes tab, `None` if there is no valid one.
+pub fn load_resistor() -> Option<Resistor> {
+    parse_resistor(&local_storage()?.get_item(RESISTOR_KEY).ok()??)
+}
+
+pub fn save_resistor(resistor: &Resistor) {
+    if let Some(storage) = local_storage() {
+        let _ = storage.set_item(RESISTOR_KEY, &resistor_text(resistor));
+    }
+}
+
+/// The bands of the resistor in the format of `RESISTOR_KEY`.
+fn resistor_text(resistor: &Resistor) -> String {
+    resistor
+        .bands()
+        .iter()
+        .map(|band| band.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn parse_resistor(text: &str) -> Option<Resistor> {
+    let bands = text
+        .split_whitespace()
+        .map(|band| band.parse::<Color>())
+        .collect::<Result<Vec<_>, _>>()
+        .ok()?;
+    Resistor::try_create(bands).ok()
+}
+
+/// Loads the session of the last visit, `None` if there is none or it's of another version.
+pub fn load_session() -> Option<Session> {
+    parse_session(&local_storage()?.get_item(SESSION_KEY).ok()??)
+}
+
+fn parse_session(text: &str) -> Option<Session> {
+    if text.starts_with('/') {
+        // older visits saved the fragment of the URL
+        return Some(Session::from_fragment(text));
+    }
+    Session::from_toml(text).ok()
+}
+
+pub fn save_session(session: &Session) {
+    if let (Some(storage), Ok(text)) = (local_storage(), session.to_toml()) {
+        let _ = storage.set_item(SESSION_KEY, &text);
+    }
+}
+
+/// Loads the quiz score of the last visits into the quiz, an invalid one is ignored.
+pub fn load_quiz_score(quiz: &mut QuizModel) {
+    if let Some(text) = local_storage().and_then(|storage| storage.get_item(QUIZ_KEY).ok()?) {
+        parse_quiz_score(&text, quiz);
+    }
+}
+
+pub fn save_quiz_score(quiz: &QuizModel) {
+    if let Some(storage) = local_storage() {
+        let _ = storage.set_item(QUIZ_KEY, &quiz_score_text(quiz));
+    }
+}
+
+/// The score of the quiz in the format of `QUIZ_KEY`.
+fn quiz_score_text(quiz: &QuizModel) -> String {
+    format!(
+        "{} {} {} {}",
+        quiz.correct, quiz.asked, quiz.streak, quiz.best_streak
+    )
+}
+
+fn parse_quiz_score(text: &str, quiz: &mut QuizModel) {
+    let score = text
+        .split_whitespace()
+        .map(|n| n.parse::<u32>())
+        .collect::<Result<Vec<_>, _>>();
+    if let Ok(&[correct, asked, streak, best_streak]) = score.as_deref() {
+        quiz.correct = correct;
+        quiz.asked = asked;
+        quiz.streak = streak;
+        quiz.best_streak = best_streak;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_quiz_score, parse_resistor, parse_session, quiz_score_text, resistor_text};
+    use rusistor::{Color, Resistor};
+    use tusistor_core::{
+        model::{QuizModel, SelectedTab},
+        session::Session,
+    };
+
+    #[test]
+    fn test_resistor() {
+        let resistor =
+            Resistor::try_create(vec![Color::Brown, Color::Black, Color::Red, Color::Gold])
+                .unwrap();
+        let text = resistor_text(&resistor);
+        assert_eq!(text, "brown black red gold");
+        assert_eq!(parse_resistor(&text), Some(resistor));
+        assert_eq!(parse_resistor("brown black red pink"), None);
+        assert_eq!(parse_resistor("black black red gold"), None);
+    }
+
+    #[test]
+    fn test_quiz_score() {
+        let mut quiz = QuizModel::new(1);
+        quiz.correct = 7;
+        quiz.asked = 10;
+        quiz.streak = 2;
+        quiz.best_streak = 5;
+        let text = quiz_score_text(&quiz);
+        assert_eq!(text, "7 10 2 5");
+        let mut restored = QuizModel::new(2);
+        parse_quiz_score(&text, &mut restored);
+        assert_eq!(
+            (
+                restored.correct,
+                restored.asked,
+                restored.streak,
+                restored.best_streak
+            ),
+            (7, 10, 2, 5)
+        );
+        parse_quiz_score("1 2 x 4", &mut restored);
+        assert_eq!(restored.correct, 7);
+    }
+
+    #[test]
+    fn test_session() {
+        let session = Session {
+            tab: SelectedTab::SpecsToColorCodes,
+            bands: vec![Color::Yellow, Color::Violet, Color::Red, Color::Gold],
+            resistance: "4k7".to_string(),
+            tolerance: "5".to_string(),
+            ..Session::default()
+        };
+        assert_eq!(parse_session(&session.to_toml().unwrap()), Some(session));
+        let older = parse_session("/specs?resistance=4k7").unwrap();
+        assert_eq!(older.tab, SelectedTab::SpecsToColorCodes);
+        assert_eq!(older.resistance, "4k7");
+        assert_eq!(parse_session("version = 2"), None);
+    }
+}
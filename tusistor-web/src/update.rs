@@ -1,151 +1,446 @@
-use ratzilla::event::{self, KeyCode};
+use ratatui_textarea::TextArea;
+use ratzilla::{
+    event::{self, MouseButton, MouseEvent, MouseEventKind},
+    ratatui::layout::{Position, Rect},
+};
+use rusistor::Resistor;
 use tusistor_core::{
-    model::{InputFocus, SelectedTab},
-    update::{ColorCodesMsg, SpecsMsg, update_on_colorcodemsg, update_on_specsmsg},
+    key::{KeyCode, KeyInput},
+    model::{HistoryStore, QuizMode, SelectedTab},
+    session::Session,
+    update::{ColorCodesMsg, Msg as SharedMsg, QuizMsg, SpecsMsg, handle_key, update_on_msg},
+    view::{print_html, share_text},
 };
 
-use crate::model::Model;
+use crate::{
+    link,
+    model::{HitAreas, Model},
+    page,
+    storage::{self, HistoryStorage, save_resistor},
+};
 
 pub enum Msg {
-    ToggleTab,
-    SpecsMsg { msg: SpecsMsg },
-    ColorCodesMsg { msg: ColorCodesMsg },
+    /// The messages the TUI has, too.
+    Shared {
+        msg: SharedMsg,
+    },
+    ToggleHelp,
+    /// The text of the clipboard the browser pastes.
+    Paste {
+        text: String,
+    },
+    NextTheme,
+    Resize,
+    /// Shares the resistor of the tab with the link to it.
+    Share,
+    /// Opens the resistor of the tab in a print view.
+    Print,
+}
+
+impl From<SharedMsg> for Msg {
+    fn from(msg: SharedMsg) -> Msg {
+        Msg::Shared { msg }
+    }
 }
 
 pub fn handle_event(model: &mut Model, event: ratzilla::event::KeyEvent) {
-    match (&model.selected_tab, &event.code) {
-        (_, event::KeyCode::Left) if event.shift => update(model, Msg::ToggleTab),
-        (_, event::KeyCode::Right) if event.shift => update(model, Msg::ToggleTab),
-        (SelectedTab::ColorCodesToSpecs, event::KeyCode::Up) => update(
-            model,
-            Msg::ColorCodesMsg {
-                msg: ColorCodesMsg::PrevColor,
-            },
-        ),
-        (SelectedTab::ColorCodesToSpecs, event::KeyCode::Down) => update(
-            model,
-            Msg::ColorCodesMsg {
-                msg: ColorCodesMsg::NextColor,
-            },
-        ),
-        (SelectedTab::ColorCodesToSpecs, event::KeyCode::Left) => update(
-            model,
-            Msg::ColorCodesMsg {
-                msg: ColorCodesMsg::PrevBand,
-            },
-        ),
-        (SelectedTab::ColorCodesToSpecs, event::KeyCode::Right) => update(
-            model,
-            Msg::ColorCodesMsg {
-                msg: ColorCodesMsg::NextBand,
-            },
-        ),
-        (SelectedTab::ColorCodesToSpecs, event::KeyCode::Char('3')) => update(
-            model,
-            Msg::ColorCodesMsg {
-                msg: ColorCodesMsg::ThreeBands,
-            },
-        ),
-        (SelectedTab::ColorCodesToSpecs, event::KeyCode::Char('4')) => update(
-            model,
-            Msg::ColorCodesMsg {
-                msg: ColorCodesMsg::FourBands,
-            },
-        ),
-        (SelectedTab::ColorCodesToSpecs, event::KeyCode::Char('5')) => update(
-            model,
-            Msg::ColorCodesMsg {
-                msg: ColorCodesMsg::FiveBands,
-            },
-        ),
-        (SelectedTab::ColorCodesToSpecs, event::KeyCode::Char('6')) => update(
-            model,
-            Msg::ColorCodesMsg {
-                msg: ColorCodesMsg::SixBands,
-            },
-        ),
-        (SelectedTab::SpecsToColorCodes, event::KeyCode::Enter) => update(
-            model,
-            Msg::SpecsMsg {
-                msg: SpecsMsg::Determine,
-            },
-        ),
-        (SelectedTab::SpecsToColorCodes, event::KeyCode::Left) if event.ctrl => update(
-            model,
-            Msg::SpecsMsg {
+    let key = key_input(&event);
+    // the help takes all keys until it's closed
+    if model.show_help {
+        if matches!(key.code, KeyCode::Esc | KeyCode::Enter | KeyCode::Char('?')) {
+            update(model, Msg::ToggleHelp);
+        }
+        return;
+    }
+    let msg = match (&model.selected_tab, key.code) {
+        // no input takes a `?`
+        (_, KeyCode::Char('?')) => Some(Msg::ToggleHelp),
+        (_, KeyCode::F(2)) => Some(Msg::NextTheme),
+        (_, KeyCode::F(6)) => Some(Msg::Print),
+        // Alt+Shift+←/→ select words in the inputs
+        (_, KeyCode::Left) if key.shift && !key.alt => Some(Msg::Shared {
+            msg: SharedMsg::PrevTab,
+        }),
+        (_, KeyCode::Right) if key.shift && !key.alt => Some(Msg::Shared {
+            msg: SharedMsg::NextTab,
+        }),
+        (SelectedTab::SpecsToColorCodes, KeyCode::Left) if key.ctrl => Some(Msg::Shared {
+            msg: SharedMsg::SpecsMsg {
                 msg: SpecsMsg::PrevSpecInput,
             },
-        ),
-        (SelectedTab::SpecsToColorCodes, event::KeyCode::Right) if event.ctrl => update(
-            model,
-            Msg::SpecsMsg {
+        }),
+        (SelectedTab::SpecsToColorCodes, KeyCode::Right) if key.ctrl => Some(Msg::Shared {
+            msg: SharedMsg::SpecsMsg {
                 msg: SpecsMsg::NextSpecInput,
             },
-        ),
-        (SelectedTab::SpecsToColorCodes, event::KeyCode::Up) => update(
-            model,
-            Msg::SpecsMsg {
-                msg: SpecsMsg::PrevHistory,
-            },
-        ),
-        (SelectedTab::SpecsToColorCodes, event::KeyCode::Down) => update(
-            model,
-            Msg::SpecsMsg {
-                msg: SpecsMsg::NextHistory,
+        }),
+        // Ctrl+N and Ctrl+T of tusistor open windows and tabs in browsers
+        (SelectedTab::Quiz, KeyCode::Esc) => Some(Msg::Shared {
+            msg: SharedMsg::QuizMsg { msg: QuizMsg::Skip },
+        }),
+        (SelectedTab::Quiz, KeyCode::F(4)) => Some(Msg::Shared {
+            msg: SharedMsg::QuizMsg {
+                msg: QuizMsg::ToggleMode,
             },
-        ),
-        (SelectedTab::SpecsToColorCodes, event::KeyCode::Char('X')) => update(
-            model,
-            Msg::SpecsMsg {
-                msg: SpecsMsg::Reset,
-            },
-        ),
-        (SelectedTab::SpecsToColorCodes, _) => {
-            let target_textarea = match model.specs_to_color.focus {
-                InputFocus::Resistance => &mut model.specs_to_color.resistance_textarea,
-                InputFocus::Tolerance => &mut model.specs_to_color.tolerance_textarea,
-                InputFocus::Tcr => &mut model.specs_to_color.tcr_textarea,
+        }),
+        _ => handle_key(&*model, &key).map(Msg::from),
+    };
+    if let Some(msg) = msg {
+        return update(model, msg);
+    }
+    match key.code {
+        KeyCode::Char('a') if key.ctrl => {
+            if let Some(textarea) = focused_textarea(model) {
+                textarea.select_all();
+            }
+        }
+        // the browser pastes the clipboard with a paste event instead
+        KeyCode::Char('v') if key.ctrl => (),
+        _ => {
+            let Some(target_textarea) = focused_textarea(model) else {
+                return;
             };
-
-            if let Some(key) = try_convert_code(event.code) {
-                let input: ratatui_textarea::Input = ratatui_textarea::Input {
-                    key,
-                    ctrl: event.ctrl,
-                    alt: event.alt,
-                    shift: event.shift,
-                };
+            // Ctrl+←/→ switch the inputs, so Alt+←/→ jump over words like on macOS
+            let word_jump = key.alt && matches!(key.code, KeyCode::Left | KeyCode::Right);
+            let key = KeyInput {
+                ctrl: key.ctrl || word_jump,
+                alt: key.alt && !word_jump,
+                ..key
+            };
+            if let Some(input) = key.textarea_input() {
                 target_textarea.input(input);
             }
         }
-        _ => (),
     }
 }
 
-fn try_convert_code(code: KeyCode) -> Option<ratatui_textarea::Key> {
-    match code {
-        KeyCode::Char(c) => Some(ratatui_textarea::Key::Char(c)),
-        KeyCode::F(n) => Some(ratatui_textarea::Key::F(n)),
-        KeyCode::Backspace => Some(ratatui_textarea::Key::Backspace),
-        KeyCode::Enter => Some(ratatui_textarea::Key::Enter),
-        KeyCode::Left => Some(ratatui_textarea::Key::Left),
-        KeyCode::Right => Some(ratatui_textarea::Key::Right),
-        KeyCode::Up => Some(ratatui_textarea::Key::Up),
-        KeyCode::Down => Some(ratatui_textarea::Key::Down),
-        KeyCode::Tab => Some(ratatui_textarea::Key::Tab),
-        KeyCode::Delete => Some(ratatui_textarea::Key::Delete),
-        KeyCode::Home => Some(ratatui_textarea::Key::Home),
-        KeyCode::End => Some(ratatui_textarea::Key::End),
-        KeyCode::PageUp => Some(ratatui_textarea::Key::PageUp),
-        KeyCode::PageDown => Some(ratatui_textarea::Key::PageDown),
-        KeyCode::Esc => Some(ratatui_textarea::Key::Esc),
-        KeyCode::Unidentified => None,
+/// The input that takes the typed keys, `None` in the tabs without one.
+fn focused_textarea<'m, 'a>(model: &'m mut Model<'a>) -> Option<&'m mut TextArea<'a>> {
+    match model.selected_tab {
+        SelectedTab::SpecsToColorCodes => Some(model.specs_to_color.focused_textarea()),
+        SelectedTab::Quiz if model.quiz.mode == QuizMode::ColorsToValue => {
+            Some(&mut model.quiz.answer_textarea)
+        }
+        _ => None,
+    }
+}
+
+/// The resistor of the tab, `None` if it has none.
+fn current_resistor(model: &Model) -> Option<Resistor> {
+    match model.selected_tab {
+        SelectedTab::SpecsToColorCodes => model.specs_to_color.resistor.clone(),
+        SelectedTab::ColorCodesToSpecs => Some(model.color_codes_to_specs.resistor.clone()),
+        // the question of the quiz isn't one, it would give away the answer
+        _ => None,
+    }
+}
+
+/// The message of the bands of the tab, the ones of the answer in the quiz.
+fn bands_msg(tab: SelectedTab, msg: ColorCodesMsg) -> Msg {
+    match tab {
+        SelectedTab::Quiz => Msg::Shared {
+            msg: SharedMsg::QuizMsg {
+                msg: QuizMsg::BandsMsg { msg },
+            },
+        },
+        _ => Msg::Shared {
+            msg: SharedMsg::ColorCodesMsg { msg },
+        },
     }
 }
 
+/// The key of a ratzilla key event.
+fn key_input(event: &event::KeyEvent) -> KeyInput {
+    let code = match event.code {
+        event::KeyCode::Char(c) => KeyCode::Char(c),
+        event::KeyCode::F(n) => KeyCode::F(n),
+        event::KeyCode::Backspace => KeyCode::Backspace,
+        event::KeyCode::Enter => KeyCode::Enter,
+        event::KeyCode::Left => KeyCode::Left,
+        event::KeyCode::Right => KeyCode::Right,
+        event::KeyCode::Up => KeyCode::Up,
+        event::KeyCode::Down => KeyCode::Down,
+        event::KeyCode::Tab => KeyCode::Tab,
+        event::KeyCode::Delete => KeyCode::Delete,
+        event::KeyCode::Home => KeyCode::Home,
+        event::KeyCode::End => KeyCode::End,
+        event::KeyCode::PageUp => KeyCode::PageUp,
+        event::KeyCode::PageDown => KeyCode::PageDown,
+        event::KeyCode::Esc => KeyCode::Esc,
+        event::KeyCode::Unidentified => KeyCode::Other,
+    };
+    KeyInput {
+        code,
+        ctrl: event.ctrl,
+        alt: event.alt,
+        shift: event.shift,
+    }
+}
+
+/// Selects a tab, a bands count, a band or a color by a click, which is a tap on touch screens.
+pub fn handle_mouse_event(model: &mut Model, event: MouseEvent) {
+    if !matches!(event.kind, MouseEventKind::SingleClick(MouseButton::Left)) {
+        return;
+    }
+    let position = Position::new(event.col, event.row);
+    // the keys of the keypad and the quiz are pressed like typed ones, a click only closes the help
+    if !model.show_help
+        && let Some((_, key)) = model
+            .hit_areas
+            .keys
+            .iter()
+            .find(|(rect, _)| rect.contains(position))
+    {
+        return handle_event(model, key.clone());
+    }
+    if let Some(msg) = click_msg(model, position) {
+        update(model, msg);
+    }
+}
+
+/// The message of a click at the position, `None` if it's outside of the areas of the last frame.
+fn click_msg(model: &Model, position: Position) -> Option<Msg> {
+    let contains = |rect: &Option<Rect>| rect.is_some_and(|rect| rect.contains(position));
+    let hit_areas = &model.hit_areas;
+    // any click closes the help
+    if model.show_help || contains(&hit_areas.help) {
+        return Some(Msg::ToggleHelp);
+    }
+    if contains(&hit_areas.theme) {
+        return Some(Msg::NextTheme);
+    }
+    if contains(&hit_areas.share) {
+        return Some(Msg::Share);
+    }
+    if contains(&hit_areas.print) {
+        return Some(Msg::Print);
+    }
+    if let Some((_, tab)) = hit_areas
+        .tabs
+        .iter()
+        .find(|(rect, _)| rect.contains(position))
+    {
+        return Some(Msg::Shared {
+            msg: SharedMsg::SelectTab { tab: *tab },
+        });
+    }
+    if let Some((_, count)) = hit_areas
+        .band_counts
+        .iter()
+        .find(|(rect, _)| rect.contains(position))
+    {
+        let msg = match count {
+            3 => ColorCodesMsg::ThreeBands,
+            4 => ColorCodesMsg::FourBands,
+            5 => ColorCodesMsg::FiveBands,
+            _ => ColorCodesMsg::SixBands,
+        };
+        return Some(bands_msg(model.selected_tab, msg));
+    }
+    let (band_idx, (rect, offset)) = hit_areas
+        .bands
+        .iter()
+        .enumerate()
+        .find(|(_, (rect, _))| rect.contains(position))?;
+    // the first and last rows are the borders of the list
+    let row = (position.y - rect.y) as usize;
+    let color = (1..rect.height as usize - 1)
+        .contains(&row)
+        .then(|| rusistor::Color::ALL.get(row - 1 + offset).copied())
+        .flatten();
+    let msg = match color {
+        Some(color) => ColorCodesMsg::SelectColor { band_idx, color },
+        None => ColorCodesMsg::SelectBand { band_idx },
+    };
+    Some(bands_msg(model.selected_tab, msg))
+}
+
 pub fn update(model: &mut Model, msg: Msg) {
+    // the status is about the last message only
+    if !matches!(msg, Msg::Resize) {
+        model.status = None;
+    }
     match msg {
-        Msg::ToggleTab => model.selected_tab = model.selected_tab.toggle(),
-        Msg::ColorCodesMsg { msg } => update_on_colorcodemsg(&mut model.color_codes_to_specs, msg),
-        Msg::SpecsMsg { msg } => update_on_specsmsg(&mut model.specs_to_color, msg),
+        Msg::Shared { msg } => update_on_shared(model, msg),
+        Msg::ToggleHelp => model.show_help = !model.show_help,
+        Msg::Paste { text } => {
+            if let Some(textarea) = focused_textarea(model) {
+                // the inputs are single lines
+                let line = text.lines().next().unwrap_or_default().trim();
+                textarea.insert_str(line);
+            }
+        }
+        Msg::Resize => {
+            // the areas of the last frame are out of place until the next one
+            model.hit_areas = HitAreas::default();
+            model.resized = true;
+            return;
+        }
+        Msg::Share => {
+            model.status = Some(match current_resistor(model) {
+                Some(resistor) => {
+                    let text = share_text(&resistor);
+                    match page::share("tusistor", &text) {
+                        Some(page::Shared::Sheet) => return,
+                        Some(page::Shared::Clipboard) => format!("copied: {}", text),
+                        None => format!("failed to share: {}", text),
+                    }
+                }
+                None => "nothing to share".to_string(),
+            });
+        }
+        Msg::Print => match current_resistor(model) {
+            Some(resistor) => {
+                if page::open_html(&print_html(&resistor, model.language)).is_none() {
+                    model.status = Some("failed to open the print view".to_string());
+                }
+            }
+            None => model.status = Some("nothing to print".to_string()),
+        },
+        Msg::NextTheme => {
+            model.theme = model.theme.next();
+            page::apply_theme(model.theme);
+            storage::save_theme(model.theme);
+        }
+    }
+    let session = Session::new(
+        model.selected_tab,
+        &model.color_codes_to_specs,
+        &model.specs_to_color,
+    );
+    if model.restore_session {
+        storage::save_session(&session);
+    }
+    link::save_session(&session);
+}
+
+/// Applies a message the TUI has, too, with the follow-ups only the web version has.
+fn update_on_shared(model: &mut Model, msg: SharedMsg) {
+    let changes_resistor = matches!(msg, SharedMsg::ColorCodesMsg { .. });
+    let changes_score =
+        matches!(msg, SharedMsg::QuizMsg { ref msg } if !matches!(msg, QuizMsg::BandsMsg { .. }));
+    let changes_history = matches!(
+        msg,
+        SharedMsg::SpecsMsg {
+            msg: SpecsMsg::Determine
+        }
+    );
+    update_on_msg(model, msg);
+    if changes_resistor && model.restore_resistor {
+        save_resistor(&model.color_codes_to_specs.resistor);
+    }
+    if changes_score {
+        storage::save_quiz_score(&model.quiz);
+    }
+    if changes_history {
+        // a full or disabled storage only loses the history
+        let _ = HistoryStorage.save(&model.specs_to_color.history);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Msg, click_msg};
+    use crate::{model::Model, view::view};
+    use ratzilla::ratatui::{
+        Terminal,
+        backend::TestBackend,
+        layout::{Position, Rect},
+    };
+    use rusistor::Color;
+    use tusistor_core::{
+        config::Config,
+        format::NumberFormat,
+        model::{QuizMode, SelectedTab},
+        update::{ColorCodesMsg, Msg as SharedMsg, QuizMsg},
+    };
+
+    fn model<'a>(tab: SelectedTab) -> Model<'a> {
+        let mut model = Model::with_locale(&Config::default(), NumberFormat::default(), 1);
+        model.selected_tab = tab;
+        model
+    }
+
+    /// Renders a frame for the hit areas of the model.
+    fn render(model: &mut Model) {
+        let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+        terminal.draw(|frame| view(model, frame)).unwrap();
+    }
+
+    fn center(rect: Rect) -> Position {
+        Position::new(rect.x + rect.width / 2, rect.y + rect.height / 2)
+    }
+
+    #[test]
+    fn test_click_msg() {
+        let mut model = model(SelectedTab::ColorCodesToSpecs);
+        render(&mut model);
+        let (tab_rect, _) = model.hit_areas.tabs[1];
+        assert!(matches!(
+            click_msg(&model, center(tab_rect)),
+            Some(Msg::Shared {
+                msg: SharedMsg::SelectTab {
+                    tab: SelectedTab::SpecsToColorCodes
+                }
+            })
+        ));
+        let (count_rect, _) = model.hit_areas.band_counts[1];
+        assert!(matches!(
+            click_msg(&model, center(count_rect)),
+            Some(Msg::Shared {
+                msg: SharedMsg::ColorCodesMsg {
+                    msg: ColorCodesMsg::FourBands
+                }
+            })
+        ));
+        let (band_rect, _) = model.hit_areas.bands[1];
+        // the row of red below the border and black and brown
+        let red = Position::new(band_rect.x + 1, band_rect.y + 3);
+        assert!(matches!(
+            click_msg(&model, red),
+            Some(Msg::Shared {
+                msg: SharedMsg::ColorCodesMsg {
+                    msg: ColorCodesMsg::SelectColor {
+                        band_idx: 1,
+                        color: Color::Red
+                    }
+                }
+            })
+        ));
+        let border = Position::new(band_rect.x + 1, band_rect.y);
+        assert!(matches!(
+            click_msg(&model, border),
+            Some(Msg::Shared {
+                msg: SharedMsg::ColorCodesMsg {
+                    msg: ColorCodesMsg::SelectBand { band_idx: 1 }
+                }
+            })
+        ));
+        let theme = center(model.hit_areas.theme.unwrap());
+        assert!(matches!(click_msg(&model, theme), Some(Msg::NextTheme)));
+        assert!(click_msg(&model, Position::new(0, 39)).is_none());
+        model.show_help = true;
+        assert!(matches!(
+            click_msg(&model, Position::new(0, 39)),
+            Some(Msg::ToggleHelp)
+        ));
+    }
+
+    #[test]
+    fn test_quiz_click_msg() {
+        let mut model = model(SelectedTab::Quiz);
+        model.quiz.mode = QuizMode::ValueToColors;
+        render(&mut model);
+        let (band_rect, _) = model.hit_areas.bands[0];
+        assert!(matches!(
+            click_msg(&model, Position::new(band_rect.x + 1, band_rect.y)),
+            Some(Msg::Shared {
+                msg: SharedMsg::QuizMsg {
+                    msg: QuizMsg::BandsMsg {
+                        msg: ColorCodesMsg::SelectBand { band_idx: 0 }
+                    }
+                }
+            })
+        ));
     }
 }
@@ -1,32 +1,96 @@
-use crate::model::Model;
+use crate::{
+    model::{HitAreas, Model, TABS},
+    page,
+};
+use ratzilla::event::{KeyCode, KeyEvent};
 use ratzilla::ratatui::{
     Frame,
     layout::{Constraint, Direction, Flex, Layout, Rect},
     style::{Color, Modifier, Style},
     symbols,
-    text::{Line, Span, Text},
+    text::{Line, Span, Text as StyledText},
     widgets::{
-        Bar, BarChart, BarGroup, Block, Borders, List, ListDirection, ListItem, ListState, Padding,
-        Paragraph, Tabs,
+        Bar, BarChart, BarGroup, Block, Borders, Clear, LineGauge, List, ListDirection, ListItem,
+        ListState, Padding, Paragraph, Tabs, Wrap,
     },
 };
+use rusistor::{Resistor, ResistorSpecs};
+use std::ops::Range;
 use tusistor_core::{
-    model::{InputFocus, SelectedTab},
-    view::{band_numeric_info, band_semantic_info},
+    i18n::{Language, Text},
+    model::{InputFocus, QuizMode, SelectedTab, TEMPERATURES},
+    theme::default_band_color,
+    update::validate_input,
+    view::{
+        ChartBand, band_list_title, band_numeric_info, band_semantic_info, chart_bands,
+        chart_title, tolerance_gauge,
+    },
 };
 
 const BAR_WIDTH: u16 = 19;
 
-fn tabs<'a>(selected: &SelectedTab) -> Tabs<'a> {
-    let highlight_style = Style::default().fg(Color::Black).bg(Color::White);
-    Tabs::new(vec![" color codes to specs ", " specs to color codes "])
+/// The label, the key and whether Ctrl is pressed of the buttons of the keypad of the specs tab.
+const KEYPAD: [[(&str, KeyCode, bool); 6]; 3] = [
+    [
+        ("7", KeyCode::Char('7'), false),
+        ("8", KeyCode::Char('8'), false),
+        ("9", KeyCode::Char('9'), false),
+        ("k", KeyCode::Char('k'), false),
+        ("M", KeyCode::Char('M'), false),
+        ("⌫", KeyCode::Backspace, false),
+    ],
+    [
+        ("4", KeyCode::Char('4'), false),
+        ("5", KeyCode::Char('5'), false),
+        ("6", KeyCode::Char('6'), false),
+        ("m", KeyCode::Char('m'), false),
+        (".", KeyCode::Char('.'), false),
+        ("◀", KeyCode::Left, true),
+    ],
+    [
+        ("1", KeyCode::Char('1'), false),
+        ("2", KeyCode::Char('2'), false),
+        ("3", KeyCode::Char('3'), false),
+        ("0", KeyCode::Char('0'), false),
+        ("⏎", KeyCode::Enter, false),
+        ("▶", KeyCode::Right, true),
+    ],
+];
+
+/// Narrower frames, e.g. of phones, stack the panels and shrink the band lists and the chart.
+const NARROW_WIDTH: u16 = 80;
+
+/// The height of the band lists with all colors, shorter ones scroll.
+const BAND_LISTS_HEIGHT: u16 = 15;
+
+/// The height of the drawing of the resistor with a row of space below it.
+const DRAWING_HEIGHT: u16 = 4;
+
+/// The color of the body of the drawn resistors, the one of the bands' background.
+const BODY_COLOR: Color = Color::Rgb(0xd2, 0xb4, 0x8c);
+
+fn tabs<'a>(selected: &SelectedTab, lang: Language, selected_style: Style) -> Tabs<'a> {
+    Tabs::new(TABS.iter().map(|t| t.title(lang)))
         .padding(" ", " ")
         .divider(symbols::DOT)
-        .highlight_style(highlight_style)
-        .select(selected)
+        .highlight_style(selected_style)
+        .select(TABS.iter().position(|t| t == selected))
+}
+
+/// The area of each tab, the titles are padded by a space and divided by a dot.
+fn tab_areas(area: Rect, lang: Language) -> Vec<(Rect, SelectedTab)> {
+    let mut x = area.x;
+    TABS.iter()
+        .map(|tab| {
+            let width = tab.title(lang).chars().count() as u16 + 2;
+            let rect = Rect::new(x, area.y, width, 1).intersection(area);
+            x += width + 1;
+            (rect, *tab)
+        })
+        .collect()
 }
 
-fn band_list<'a>(band_idx: usize, bands: usize, is_focused: bool) -> List<'a> {
+fn band_list<'a>(band_idx: usize, bands: usize, is_focused: bool, lang: Language) -> List<'a> {
     let items = [
         rusistor::Color::Black,
         rusistor::Color::Brown,
@@ -45,10 +109,11 @@ fn band_list<'a>(band_idx: usize, bands: usize, is_focused: bool) -> List<'a> {
     .iter()
     .map(|color| {
         let numeric_info = band_numeric_info(bands, band_idx, color);
-        let (color, name) = rusistor_color_to_ratatui_color(color);
+        let name = lang.color(color);
+        let color = default_band_color(color);
         let s = format!(" {numeric_info} {name}");
         let style = if color == Color::Black {
-            Style::default().bg(color)
+            Style::default().bg(color).fg(Color::White)
         } else {
             Style::default().bg(color).fg(Color::Black)
         };
@@ -61,17 +126,12 @@ fn band_list<'a>(band_idx: usize, bands: usize, is_focused: bool) -> List<'a> {
         Style::default()
     };
 
-    let semantic_info = band_semantic_info(bands, band_idx);
+    let semantic_info = band_semantic_info(bands, band_idx, lang);
 
     List::new(items)
         .block(
             Block::bordered()
-                .title(format!(
-                    " Band {}: {}{}",
-                    band_idx + 1,
-                    semantic_info,
-                    if is_focused { "* " } else { " " }
-                ))
+                .title(band_list_title(band_idx, &semantic_info, is_focused, lang))
                 .style(style),
         )
         .highlight_symbol(">> ")
@@ -79,14 +139,14 @@ fn band_list<'a>(band_idx: usize, bands: usize, is_focused: bool) -> List<'a> {
         .direction(ListDirection::TopToBottom)
 }
 
-pub fn view(model: &mut Model, frame: &mut Frame) {
-    fn center_horizontal(area: Rect, width: u16) -> Rect {
-        let [area] = Layout::horizontal([Constraint::Length(width)])
-            .flex(Flex::Center)
-            .areas(area);
-        area
-    }
+fn center_horizontal(area: Rect, width: u16) -> Rect {
+    let [area] = Layout::horizontal([Constraint::Length(width)])
+        .flex(Flex::Center)
+        .areas(area);
+    area
+}
 
+pub fn view(model: &mut Model, frame: &mut Frame) {
     fn apply_title<'a>(
         block: Block<'a>,
         current_focus: &InputFocus,
@@ -103,91 +163,139 @@ pub fn view(model: &mut Model, frame: &mut Frame) {
         }
     }
 
-    let tabs_width = 49;
-    let specs_style = Style::default().fg(Color::Yellow);
+    let lang = model.language;
+    let nf = model.number_format;
+    let tabs_width = TABS
+        .iter()
+        .map(|t| t.title(lang).chars().count() as u16 + 3)
+        .sum::<u16>()
+        - 1;
+    let (fg, bg) = page::colors(model.theme);
+    let base_style = Style::default().fg(fg).bg(bg);
+    // the selected tab and bands count and the buttons
+    let selected_style = Style::default().fg(bg).bg(fg);
+    let specs_style = Style::default().fg(model.theme.palette().accent);
+    model.hit_areas = HitAreas::default();
+    frame.render_widget(Block::new().style(base_style), frame.area());
 
     match model.selected_tab {
         SelectedTab::ColorCodesToSpecs => {
+            let power_limits = model.color_codes_to_specs.power_limits();
+            let narrow = frame.area().width < NARROW_WIDTH;
+            // narrow frames have two rows of specs, the power limits are another one
+            let specs_rows = if narrow { 2 } else { 1 };
+            let specs_height = 3 * specs_rows + if power_limits.is_some() { 3 } else { 0 };
+            let lists_height = frame
+                .area()
+                .height
+                .saturating_sub(9 + specs_height + DRAWING_HEIGHT)
+                .clamp(4, BAND_LISTS_HEIGHT);
             let chunks = Layout::default()
                 .direction(Direction::Vertical)
                 .margin(2)
                 .constraints(
                     [
                         Constraint::Length(2),
-                        Constraint::Length(3),
-                        Constraint::Length(15),
+                        Constraint::Length(specs_height),
+                        Constraint::Length(DRAWING_HEIGHT),
+                        Constraint::Length(lists_height),
+                        Constraint::Length(2),
                         Constraint::Min(1),
                     ]
                     .as_ref(),
                 )
                 .split(frame.area());
             let tabs_rect = center_horizontal(chunks[0], tabs_width);
-            let help_msg_rect = center_horizontal(chunks[3], 95);
-
-            let spec_chuncks = Layout::default()
-                .direction(Direction::Horizontal)
-                .constraints([
-                    Constraint::Ratio(1, 6),
-                    Constraint::Ratio(1, 6),
-                    Constraint::Ratio(1, 6),
-                    Constraint::Ratio(1, 6),
-                    Constraint::Ratio(1, 6),
-                    Constraint::Ratio(1, 6),
-                ])
-                .split(chunks[1]);
-
-            let bands_rect = Layout::default()
-                .direction(Direction::Horizontal)
-                .constraints([
-                    Constraint::Ratio(1, 6),
-                    Constraint::Ratio(1, 6),
-                    Constraint::Ratio(1, 6),
-                    Constraint::Ratio(1, 6),
-                    Constraint::Ratio(1, 6),
-                    Constraint::Ratio(1, 6),
-                ])
-                .split(chunks[2]);
-
-            let tabs = tabs(&model.selected_tab);
-            frame.render_widget(tabs, tabs_rect);
 
-            let specs = model.color_codes_to_specs.resistor.specs();
+            // the resistance, tolerance, range, TCR and temperature panels
+            let [
+                resistance_rect,
+                tolerance_rect,
+                range_rect,
+                tcr_rect,
+                temperature_rect,
+            ] = if narrow {
+                let [first_row, second_row] =
+                    Layout::vertical([Constraint::Length(3); 2]).areas(Rect {
+                        height: 6,
+                        ..chunks[1]
+                    });
+                let [resistance, tolerance, tcr] =
+                    Layout::horizontal([Constraint::Ratio(1, 3); 3]).areas(first_row);
+                let [range, temperature] =
+                    Layout::horizontal([Constraint::Ratio(2, 3), Constraint::Ratio(1, 3)])
+                        .areas(second_row);
+                [resistance, tolerance, range, tcr, temperature]
+            } else {
+                let spec_chuncks = Layout::horizontal([Constraint::Ratio(1, 6); 6]).split(Rect {
+                    height: 3,
+                    ..chunks[1]
+                });
+                [
+                    spec_chuncks[0],
+                    spec_chuncks[1],
+                    spec_chuncks[2].union(spec_chuncks[3]),
+                    spec_chuncks[4],
+                    spec_chuncks[5],
+                ]
+            };
 
-            let resistance_paragraph = Paragraph::new(specs.ohm.to_string())
-                .style(specs_style)
-                .block(
-                    Block::default()
-                        .borders(Borders::ALL)
-                        .title(" Resistance (Ω) "),
-                );
-            frame.render_widget(resistance_paragraph, spec_chuncks[0]);
+            let tabs = tabs(&model.selected_tab, lang, selected_style);
+            frame.render_widget(tabs, tabs_rect);
+            model.hit_areas.tabs = tab_areas(tabs_rect, lang);
 
-            let tolerance_paragraph = Paragraph::new(format!("±{}", (specs.tolerance * 100.0)))
-                .style(specs_style)
-                .block(
-                    Block::default()
-                        .borders(Borders::ALL)
-                        .title(" Tolerance (%) "),
-                );
-            frame.render_widget(tolerance_paragraph, spec_chuncks[1]);
+            let temperature = model.color_codes_to_specs.temperature;
+            let specs = model
+                .color_codes_to_specs
+                .resistor
+                .specs()
+                .at_temperature(temperature);
+            let drifted = specs.tcr.is_some() && temperature != ResistorSpecs::REFERENCE_CELSIUS;
+            let at_temperature = if drifted {
+                format!(" {} {} °C", lang.text(Text::At), temperature)
+            } else {
+                String::new()
+            };
 
-            let min_paragraph = Paragraph::new(specs.min_ohm.to_string())
-                .style(specs_style)
-                .block(
-                    Block::default()
-                        .borders(Borders::ALL)
-                        .title(" Minimum (Ω) "),
-                );
-            frame.render_widget(min_paragraph, spec_chuncks[2]);
+            let resistance_text = if drifted {
+                format!(
+                    "{} ±{}",
+                    nf.ohm(specs.ohm),
+                    nf.ohm(specs.drift_ohm(temperature))
+                )
+            } else {
+                nf.ohm(specs.ohm)
+            };
+            let resistance_paragraph = Paragraph::new(resistance_text).style(specs_style).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!(" {} ", lang.text(Text::Resistance))),
+            );
+            frame.render_widget(resistance_paragraph, resistance_rect);
+
+            let tolerance_paragraph =
+                Paragraph::new(format!("±{}", nf.format(specs.tolerance * 100.0)))
+                    .style(specs_style)
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .title(format!(" {} (%) ", lang.text(Text::Tolerance))),
+                    );
+            frame.render_widget(tolerance_paragraph, tolerance_rect);
 
-            let max_paragraph = Paragraph::new(specs.max_ohm.to_string())
+            let min_text = format!("{} ", nf.ohm(specs.min_ohm));
+            let max_text = format!(" {}", nf.ohm(specs.max_ohm));
+            let gauge_width = (range_rect.width.saturating_sub(2) as usize)
+                .saturating_sub(min_text.chars().count() + max_text.chars().count());
+            let gauge = tolerance_gauge(&specs, model.color_codes_to_specs.measured, gauge_width);
+            let range_paragraph = Paragraph::new(format!("{}{}{}", min_text, gauge, max_text))
                 .style(specs_style)
-                .block(
-                    Block::default()
-                        .borders(Borders::ALL)
-                        .title(" Maximum (Ω) "),
-                );
-            frame.render_widget(max_paragraph, spec_chuncks[3]);
+                .block(Block::bordered().title(format!(
+                    " {}{} ",
+                    lang.text(Text::Range),
+                    at_temperature
+                )));
+            frame.render_widget(range_paragraph, range_rect);
 
             let tcr_paragraph =
                 Paragraph::new(specs.tcr.map(|f| f.to_string()).unwrap_or_default())
@@ -195,96 +303,219 @@ pub fn view(model: &mut Model, frame: &mut Frame) {
                     .block(
                         Block::default()
                             .borders(Borders::ALL)
-                            .title(" TCR (ppm/K) "),
+                            .title(format!(" {} (ppm/K) ", lang.text(Text::Tcr))),
                     );
-            frame.render_widget(tcr_paragraph, spec_chuncks[4]);
+            frame.render_widget(tcr_paragraph, tcr_rect);
 
-            let (msg, style) = (
-                vec![
-                    Span::styled("←/→", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(": prev/next band, "),
-                    Span::styled("↑/↓", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(": prev/next color, "),
-                    Span::styled("3|4|5|6", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(": bands count, "),
-                    Span::styled("Shift ←/→", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(": prev/next tab"),
-                ],
-                Style::default(),
-            );
-            let text = Text::from(Line::from(msg)).style(style);
-            let help_message = Paragraph::new(text);
-            frame.render_widget(help_message, help_msg_rect);
+            if let Some(limits) = &power_limits {
+                let columns = if narrow { 3 } else { 6 };
+                let power_chunks =
+                    Layout::horizontal(vec![Constraint::Ratio(1, columns); columns as usize])
+                        .split(Rect {
+                            y: chunks[1].y + 3 * specs_rows,
+                            height: 3,
+                            ..chunks[1]
+                        });
+                let rating = model.color_codes_to_specs.power_rating.unwrap_or_default();
+                let (power_text, voltage_text, current_text) = match limits {
+                    Ok(limits) => (
+                        if limits.power < rating {
+                            format!(
+                                "{} → {}",
+                                nf.format(rating),
+                                nf.with_precision(3).format(limits.power)
+                            )
+                        } else {
+                            nf.format(rating)
+                        },
+                        nf.with_precision(3).format(limits.voltage),
+                        nf.with_precision(3).format(limits.current * 1000.0),
+                    ),
+                    Err(e) => (nf.format(rating), e.clone(), String::new()),
+                };
+                for (text, title, unit, rect) in [
+                    (power_text, Text::PowerRating, "W", power_chunks[0]),
+                    (voltage_text, Text::MaxVoltage, "V", power_chunks[1]),
+                    (current_text, Text::MaxCurrent, "mA", power_chunks[2]),
+                ] {
+                    let title = format!(" {} ({}) ", lang.text(title), unit);
+                    frame.render_widget(
+                        Paragraph::new(text)
+                            .style(specs_style)
+                            .block(Block::bordered().title(title)),
+                        rect,
+                    );
+                }
+            }
+
+            // the temperature drifts the resistance of 6-band resistors and derates the power
+            if specs.tcr.is_some() || power_limits.is_some() {
+                let ratio = (temperature - TEMPERATURES.start()) as f64
+                    / (TEMPERATURES.end() - TEMPERATURES.start()) as f64;
+                let temperature_gauge = LineGauge::default()
+                    .block(
+                        Block::bordered().title(format!(" {} (°C) ", lang.text(Text::Temperature))),
+                    )
+                    .filled_style(specs_style.add_modifier(Modifier::BOLD))
+                    .unfilled_style(Style::default().add_modifier(Modifier::DIM))
+                    .label(temperature.to_string())
+                    .ratio(ratio);
+                frame.render_widget(temperature_gauge, temperature_rect);
+            }
 
-            let bands = model.color_codes_to_specs.resistor.bands();
-            for i in 0..bands.len() {
-                let mut state = ListState::default().with_selected(Some(*bands[i] as usize));
-                let is_focused = model.color_codes_to_specs.selected_band == i;
-                let list = band_list(i, bands.len(), is_focused);
-                frame.render_stateful_widget(list, bands_rect[i], &mut state);
+            let mut spans = vec![
+                Span::styled("←/→", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!(": {}, ", lang.text(Text::PrevNextBand))),
+                Span::styled("↑/↓", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!(": {}, ", lang.text(Text::PrevNextColor))),
+                Span::styled("3|4|5|6", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!(": {}, ", lang.text(Text::BandsCount))),
+            ];
+            spans.extend([
+                Span::styled("W", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!(": {}, ", lang.text(Text::PowerRatingKey))),
+            ]);
+            if specs.tcr.is_some() || power_limits.is_some() {
+                spans.extend([
+                    Span::styled("+/-", Style::default().add_modifier(Modifier::BOLD)),
+                    Span::raw(format!(": {}, ", lang.text(Text::TemperatureKey))),
+                ]);
             }
+            spans.extend([
+                Span::styled("u/Ctrl+R", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!(": {}, ", lang.text(Text::UndoRedo))),
+                Span::styled("Shift ←/→", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!(": {}, ", lang.text(Text::PrevNextTab))),
+                Span::styled("?", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!(": {}", lang.text(Text::Help))),
+            ]);
+            let help_line = Line::from(spans);
+            let help_msg_rect =
+                center_horizontal(chunks[5], (help_line.width() as u16).min(chunks[5].width));
+            frame.render_widget(
+                Paragraph::new(help_line).wrap(Wrap { trim: true }),
+                help_msg_rect,
+            );
+
+            let color_codes = &model.color_codes_to_specs;
+            // the drawing follows every change of the bands
+            let drawing =
+                Paragraph::new(resistor_drawing(&color_codes.resistor.bands())).centered();
+            frame.render_widget(drawing, chunks[2]);
+            model.hit_areas.band_counts = render_band_counts(
+                frame,
+                chunks[4],
+                color_codes.resistor.bands().len(),
+                lang,
+                selected_style,
+            );
+            model.hit_areas.bands = render_band_lists(
+                frame,
+                chunks[3],
+                &color_codes.resistor,
+                color_codes.selected_band,
+                lang,
+            );
         }
         SelectedTab::SpecsToColorCodes => {
+            // narrow frames stack the inputs
+            let narrow = frame.area().width < NARROW_WIDTH;
+            let inputs_height = if narrow { 9 } else { 3 };
+            // phones show no keyboard for the grid, so narrow frames get a keypad
+            let keypad_height = if narrow { 6 } else { 0 };
+            let help_height = if narrow { 2 } else { 1 };
             let chunks = Layout::default()
                 .direction(Direction::Vertical)
                 .margin(2)
                 .constraints(
                     [
                         Constraint::Length(2),
-                        Constraint::Length(3),
+                        Constraint::Length(inputs_height),
+                        Constraint::Length(keypad_height),
                         Constraint::Min(1),
-                        Constraint::Length(1),
+                        Constraint::Length(help_height),
                     ]
                     .as_ref(),
                 )
                 .split(frame.area());
-            let input_rects = Layout::default()
-                .direction(Direction::Horizontal)
-                .constraints([
-                    Constraint::Ratio(1, 3),
-                    Constraint::Ratio(1, 3),
-                    Constraint::Ratio(1, 3),
-                ])
-                .split(chunks[1]);
+            let input_rects = if narrow {
+                Layout::vertical([Constraint::Length(3); 3]).split(chunks[1])
+            } else {
+                Layout::horizontal([Constraint::Ratio(1, 3); 3]).split(chunks[1])
+            };
 
             let tabs_rect = center_horizontal(chunks[0], tabs_width);
-            let help_msg_rect = center_horizontal(chunks[3], 115);
+            let help_msg_rect = center_horizontal(chunks[4], 115.min(chunks[4].width));
             let resistance_rect = input_rects[0];
             let tolerance_rect = input_rects[1];
             let tcr_rect = input_rects[2];
-            let main_rect = chunks[2];
+            let main_rect = chunks[3];
+            if narrow {
+                model.hit_areas.keys = render_keypad(frame, chunks[2], selected_style);
+            }
 
-            let tabs = tabs(&model.selected_tab);
+            let tabs = tabs(&model.selected_tab, lang, selected_style);
             frame.render_widget(tabs, tabs_rect);
+            model.hit_areas.tabs = tab_areas(tabs_rect, lang);
 
             let (msg, style) = (
                 vec![
                     Span::styled("Ctrl ←/→", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(": prev/next input, "),
+                    Span::raw(format!(": {}, ", lang.text(Text::PrevNextInput))),
                     Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(": calculate color codes, "),
+                    Span::raw(format!(": {}, ", lang.text(Text::CalculateColorCodes))),
                     Span::styled("↑/↓", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(" prev/next history, "),
+                    Span::raw(format!(" {}, ", lang.text(Text::PrevNextHistory))),
                     Span::styled("X", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(": reset, "),
+                    Span::raw(format!(": {}, ", lang.text(Text::Reset))),
                     Span::styled("Shift ←/→", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(": prev/next tab"),
+                    Span::raw(format!(": {}, ", lang.text(Text::PrevNextTab))),
+                    Span::styled("?", Style::default().add_modifier(Modifier::BOLD)),
+                    Span::raw(format!(": {}", lang.text(Text::Help))),
                 ],
                 Style::default(),
             );
-            let text = Text::from(Line::from(msg)).style(style);
-            let help_message = Paragraph::new(text);
+            let text = StyledText::from(Line::from(msg)).style(style);
+            let help_message = Paragraph::new(text).wrap(Wrap { trim: true });
             frame.render_widget(help_message, help_msg_rect);
 
+            // the inputs are validated on every keystroke, `None` is an empty input
+            let error_style = Style::default().fg(Color::Red);
+            let [resistance_valid, tolerance_valid, tcr_valid] = [
+                InputFocus::Resistance,
+                InputFocus::Tolerance,
+                InputFocus::Tcr,
+            ]
+            .map(|input| {
+                let specs = &model.specs_to_color;
+                let invalid = specs.error_input.as_ref() == Some(&input)
+                    || validate_input(specs, &input).is_some();
+                (invalid || !specs.input_value(&input).trim().is_empty()).then_some(!invalid)
+            });
+            let validated = |block: Block<'static>, valid: Option<bool>, hint: &str| match valid {
+                Some(false) => block
+                    .title(Line::from(format!(" ✗ {} ", hint)).right_aligned())
+                    .border_style(error_style),
+                Some(true) => block
+                    .title(Line::from(" ✓ ").right_aligned())
+                    .border_style(Style::default().fg(Color::Green)),
+                None => block,
+            };
+
             // render resistance input
             let resistance_block = Block::default().borders(Borders::ALL).style(specs_style);
             let resistance_block = apply_title(
                 resistance_block,
                 &model.specs_to_color.focus,
                 InputFocus::Resistance,
-                " Resistance (Ω)",
+                &format!(" {} (Ω)", lang.text(Text::Resistance)),
                 specs_style,
             );
+            let resistance_block = validated(
+                resistance_block,
+                resistance_valid,
+                &format!("{} 4k7", lang.text(Text::Eg)),
+            );
             model
                 .specs_to_color
                 .resistance_textarea
@@ -301,9 +532,14 @@ pub fn view(model: &mut Model, frame: &mut Frame) {
                 tolerance_block,
                 &model.specs_to_color.focus,
                 InputFocus::Tolerance,
-                " Tolerance (%)",
+                &format!(" {} (%)", lang.text(Text::Tolerance)),
                 specs_style,
             );
+            let tolerance_block = validated(
+                tolerance_block,
+                tolerance_valid,
+                &format!("{} 1 {} 5", lang.text(Text::Eg), lang.text(Text::Or)),
+            );
             model
                 .specs_to_color
                 .tolerance_textarea
@@ -320,9 +556,14 @@ pub fn view(model: &mut Model, frame: &mut Frame) {
                 tcr_block,
                 &model.specs_to_color.focus,
                 InputFocus::Tcr,
-                " TCR (ppm/K)",
+                &format!(" {} (ppm/K)", lang.text(Text::Tcr)),
                 specs_style,
             );
+            let tcr_block = validated(
+                tcr_block,
+                tcr_valid,
+                &format!("{} 50 {} 100", lang.text(Text::Eg), lang.text(Text::Or)),
+            );
             model.specs_to_color.tcr_textarea.set_block(tcr_block);
             model
                 .specs_to_color
@@ -331,58 +572,532 @@ pub fn view(model: &mut Model, frame: &mut Frame) {
             frame.render_widget(&model.specs_to_color.tcr_textarea, tcr_rect);
 
             if let Some(resistor) = &model.specs_to_color.resistor {
-                let bands = resistor.bands();
-                let band_infos = bands
-                    .iter()
-                    .enumerate()
-                    .map(|(idx, c)| {
-                        let sem_info = band_semantic_info(bands.len(), idx);
-                        let num_info = band_numeric_info(bands.len(), idx, c);
-                        let (color, name) = rusistor_color_to_ratatui_color(c);
-                        (sem_info, num_info, color, name)
-                    })
-                    .collect::<Vec<(String, String, Color, String)>>();
-                let specs = resistor.specs();
-                let chart = barchart(&band_infos, specs.ohm, specs.tolerance, specs.tcr);
-                let chart_length: u16 = {
-                    let bands_len: u16 = (bands.len() as u16).clamp(2, 6); // give title enough space
-                    let bands_widths = bands_len * BAR_WIDTH;
-                    let bands_gaps = bands_len - 1;
-                    let border_plus_margin = 4;
-                    bands_widths + bands_gaps + border_plus_margin
-                };
-                let centered_main_rect = center_horizontal(main_rect, chart_length);
-                frame.render_widget(chart, centered_main_rect);
+                let title = Line::from(chart_title(&resistor.specs(), lang, nf)).centered();
+                render_chart(frame, main_rect, resistor, title, lang);
             }
             if let Some(e) = &model.specs_to_color.error {
-                let text = Text::from(e.to_string());
-                let error_message = Paragraph::new(text).style(Style::default().fg(Color::Red));
-                let centered_main_rect = center_horizontal(main_rect, e.len() as u16);
-                frame.render_widget(error_message, centered_main_rect);
+                // the last resistor stays visible around the popup
+                let width = (e.chars().count() as u16 + 4).max(26).min(main_rect.width);
+                let lines = (e.chars().count() as u16).div_ceil(width.saturating_sub(4).max(1));
+                let [popup_rect] = Layout::vertical([Constraint::Length(lines + 2)])
+                    .flex(Flex::Center)
+                    .areas(main_rect);
+                let popup_rect = center_horizontal(popup_rect, width);
+                let block = Block::bordered()
+                    .title(format!(" {} ", lang.text(Text::Error)))
+                    .title_bottom(
+                        Line::from(format!(" Enter/Esc: {} ", lang.text(Text::Dismiss)))
+                            .right_aligned(),
+                    )
+                    .padding(Padding::horizontal(1))
+                    .style(base_style);
+                let error_message = Paragraph::new(e.to_string())
+                    .style(error_style)
+                    .wrap(Wrap { trim: true })
+                    .block(block);
+                frame.render_widget(Clear, popup_rect);
+                frame.render_widget(error_message, popup_rect);
             }
         }
+        SelectedTab::Quiz => {
+            let narrow = frame.area().width < NARROW_WIDTH;
+            let mode = model.quiz.mode;
+            // narrow frames stack the score and the last answer
+            let status_height = if narrow { 6 } else { 3 };
+            // the band counts of the answer or the keypad for it on phones
+            let answer_height = match mode {
+                QuizMode::ValueToColors => 2,
+                QuizMode::ColorsToValue if narrow => 6,
+                QuizMode::ColorsToValue => 0,
+            };
+            let help_height = if narrow { 2 } else { 1 };
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .margin(2)
+                .constraints(
+                    [
+                        Constraint::Length(2),
+                        Constraint::Length(status_height),
+                        Constraint::Length(3),
+                        Constraint::Length(2),
+                        Constraint::Length(answer_height),
+                        Constraint::Min(1),
+                        Constraint::Length(help_height),
+                    ]
+                    .as_ref(),
+                )
+                .split(frame.area());
+            let status_rects = if narrow {
+                Layout::vertical([Constraint::Length(3); 2]).split(chunks[1])
+            } else {
+                Layout::horizontal([Constraint::Ratio(1, 3), Constraint::Ratio(2, 3)])
+                    .split(chunks[1])
+            };
+
+            let tabs_rect = center_horizontal(chunks[0], tabs_width);
+            let tabs = tabs(&model.selected_tab, lang, selected_style);
+            frame.render_widget(tabs, tabs_rect);
+            model.hit_areas.tabs = tab_areas(tabs_rect, lang);
+
+            let mut spans = vec![
+                Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!(": {}, ", lang.text(Text::Check))),
+                Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!(": {}, ", lang.text(Text::Skip))),
+                Span::styled("F4", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!(": {}, ", lang.text(Text::Mode))),
+            ];
+            if mode == QuizMode::ValueToColors {
+                spans.extend([
+                    Span::styled("←/→", Style::default().add_modifier(Modifier::BOLD)),
+                    Span::raw(format!(": {}, ", lang.text(Text::PrevNextBand))),
+                    Span::styled("↑/↓", Style::default().add_modifier(Modifier::BOLD)),
+                    Span::raw(format!(": {}, ", lang.text(Text::PrevNextColor))),
+                ]);
+            }
+            spans.extend([
+                Span::styled("Shift ←/→", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!(": {}, ", lang.text(Text::PrevNextTab))),
+                Span::styled("?", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!(": {}", lang.text(Text::Help))),
+            ]);
+            let help_line = Line::from(spans);
+            let help_msg_rect =
+                center_horizontal(chunks[6], (help_line.width() as u16).min(chunks[6].width));
+            frame.render_widget(
+                Paragraph::new(help_line).wrap(Wrap { trim: true }),
+                help_msg_rect,
+            );
+
+            let quiz = &mut model.quiz;
+            let score = lang.fill(
+                Text::QuizScore,
+                &[&quiz.correct, &quiz.asked, &quiz.streak, &quiz.best_streak],
+            );
+            let score_paragraph = Paragraph::new(score)
+                .style(specs_style)
+                .block(Block::bordered().title(format!(" {} ", lang.text(Text::Score))));
+            frame.render_widget(score_paragraph, status_rects[0]);
+
+            if let Some((is_correct, solution)) = &quiz.last_result {
+                let (verdict, style) = if *is_correct {
+                    (Text::CorrectAnswer, Style::default().fg(Color::Green))
+                } else {
+                    (Text::WrongAnswer, Style::default().fg(Color::Red))
+                };
+                let result_paragraph =
+                    Paragraph::new(format!("{}: {}", lang.text(verdict), solution))
+                        .style(style)
+                        .block(
+                            Block::bordered().title(format!(" {} ", lang.text(Text::LastAnswer))),
+                        );
+                frame.render_widget(result_paragraph, status_rects[1]);
+            }
+
+            // buttons for the keys of the quiz, phones have none of them
+            let buttons = [
+                (Text::Check, KeyCode::Enter),
+                (Text::Skip, KeyCode::Esc),
+                (Text::Mode, KeyCode::F(4)),
+            ]
+            .map(|(text, code)| (format!(" {} ", lang.text(text)), code));
+            let buttons_width = buttons
+                .iter()
+                .map(|(label, _)| label.chars().count() as u16 + 1)
+                .sum::<u16>()
+                - 1;
+            let buttons_rect = center_horizontal(
+                Rect {
+                    height: 1,
+                    ..chunks[3]
+                },
+                buttons_width,
+            );
+            let mut x = buttons_rect.x;
+            for (label, code) in buttons {
+                let width = label.chars().count() as u16;
+                let rect = Rect::new(x, buttons_rect.y, width, 1).intersection(buttons_rect);
+                frame.render_widget(Paragraph::new(label).style(selected_style), rect);
+                let key = KeyEvent {
+                    code,
+                    ctrl: false,
+                    alt: false,
+                    shift: false,
+                };
+                model.hit_areas.keys.push((rect, key));
+                x += width + 1;
+            }
+
+            let question = quiz.question.specs();
+            match mode {
+                QuizMode::ColorsToValue => {
+                    let answer_block = Block::bordered()
+                        .style(specs_style)
+                        .title(format!(" {}* ", lang.text(Text::ResistanceOfBands)))
+                        .title_style(specs_style.bold());
+                    quiz.answer_textarea.set_block(answer_block);
+                    quiz.answer_textarea.set_cursor_line_style(specs_style);
+                    frame.render_widget(&quiz.answer_textarea, chunks[2]);
+                    if narrow {
+                        let keys = render_keypad(frame, chunks[4], selected_style);
+                        model.hit_areas.keys.extend(keys);
+                    }
+
+                    let title = Line::from(format!(" {} ", lang.text(Text::Question))).centered();
+                    render_chart(frame, chunks[5], &model.quiz.question, title, lang);
+                }
+                QuizMode::ValueToColors => {
+                    let tcr = question
+                        .tcr
+                        .map(|tcr| format!(", {} {}ppm/K", lang.text(Text::Tcr), tcr))
+                        .unwrap_or_default();
+                    let specs = format!(
+                        "{}Ω ±{}%{}",
+                        nf.format(question.ohm),
+                        nf.format(question.tolerance * 100.0),
+                        tcr
+                    );
+                    let text = lang.fill(Text::WhichBands, &[&specs]);
+                    let question_paragraph = Paragraph::new(text)
+                        .style(specs_style)
+                        .block(Block::bordered().title(format!(" {} ", lang.text(Text::Question))));
+                    frame.render_widget(question_paragraph, chunks[2]);
+
+                    let answer = &quiz.answer_bands;
+                    model.hit_areas.band_counts = render_band_counts(
+                        frame,
+                        chunks[4],
+                        answer.resistor.bands().len(),
+                        lang,
+                        selected_style,
+                    );
+                    let lists_rect = Rect {
+                        height: chunks[5].height.min(BAND_LISTS_HEIGHT),
+                        ..chunks[5]
+                    };
+                    model.hit_areas.bands = render_band_lists(
+                        frame,
+                        lists_rect,
+                        &answer.resistor,
+                        answer.selected_band,
+                        lang,
+                    );
+                }
+            }
+        }
+        _ => (),
+    }
+
+    // buttons for sharing, the theme and the help in the top right corner,
+    // phones have no keys at hand
+    let area = frame.area();
+    let share_label = format!(" {} ", lang.text(Text::Share));
+    let share_width = share_label.chars().count() as u16;
+    if area.width >= 14 + share_width {
+        let share_rect = Rect::new(area.right() - 14 - share_width, area.y, share_width, 1);
+        frame.render_widget(
+            Paragraph::new(share_label).style(selected_style),
+            share_rect,
+        );
+        model.hit_areas.share = Some(share_rect);
+    }
+    let print_label = format!(" {} ", lang.text(Text::Print));
+    let print_width = print_label.chars().count() as u16;
+    let buttons_width = 15 + share_width + print_width;
+    if area.width >= buttons_width {
+        let print_rect = Rect::new(area.right() - buttons_width, area.y, print_width, 1);
+        frame.render_widget(
+            Paragraph::new(print_label).style(selected_style),
+            print_rect,
+        );
+        model.hit_areas.print = Some(print_rect);
+    }
+    if let Some(status) = &model.status {
+        let width = area.width.saturating_sub(buttons_width + 2);
+        frame.render_widget(
+            Paragraph::new(status.as_str()),
+            Rect::new(area.x + 2, area.y, width, 1),
+        );
+    }
+    if area.width >= 13 {
+        let theme_rect = Rect::new(area.right() - 13, area.y, 5, 1);
+        frame.render_widget(Paragraph::new("  ◐  ").style(selected_style), theme_rect);
+        model.hit_areas.theme = Some(theme_rect);
+        let help_rect = Rect::new(area.right() - 7, area.y, 5, 1);
+        frame.render_widget(Paragraph::new("  ?  ").style(selected_style), help_rect);
+        model.hit_areas.help = Some(help_rect);
+    }
+    if model.show_help {
+        render_help(frame, lang, base_style);
     }
 }
 
-fn barchart(
-    band_infos: &[(String, String, Color, String)],
-    ohm: f64,
-    tolerance: f64,
-    tcr: Option<u32>,
-) -> BarChart<'_> {
-    let bars: Vec<Bar> = band_infos.iter().map(|i| bar(i)).collect();
-    let tcr = if let Some(tcr) = tcr {
-        format!(" - TCR: {}(ppm/K)", tcr)
-    } else {
-        String::from("")
+/// A resistor with its leads, its caps and its bands as stripes, three rows of half blocks
+/// for twice the vertical resolution.
+fn resistor_drawing(bands: &[&rusistor::Color]) -> Vec<Line<'static>> {
+    let tolerance_idx = match bands.len() {
+        4 | 5 => Some(bands.len() - 1),
+        6 => Some(4),
+        _ => None,
+    };
+    // the colors of the six pixel rows of each column, the caps are taller than the rest
+    let column = |color: Color, rows: Range<usize>| -> [Option<Color>; 6] {
+        std::array::from_fn(|row| rows.contains(&row).then_some(color))
     };
-    let title = format!(
-        " Resistance: {}Ω - Tolerance: ±{}%{} ",
-        ohm,
-        tolerance * 100.0,
-        tcr
+    let cap = [1..5, 0..6, 0..6];
+    let mut columns = cap
+        .iter()
+        .map(|rows| column(BODY_COLOR, rows.clone()))
+        .collect::<Vec<_>>();
+    for (idx, color) in bands.iter().enumerate() {
+        let gap = match idx {
+            _ if Some(idx) == tolerance_idx => 4,
+            0 => 2,
+            _ => 1,
+        };
+        columns.extend((0..gap).map(|_| column(BODY_COLOR, 1..5)));
+        columns.extend((0..2).map(|_| column(default_band_color(color), 1..5)));
+    }
+    columns.extend((0..2).map(|_| column(BODY_COLOR, 1..5)));
+    columns.extend(
+        cap.iter()
+            .rev()
+            .map(|rows| column(BODY_COLOR, rows.clone())),
     );
-    let title = Line::from(title).centered();
+
+    let lead_style = Style::default().fg(Color::Gray);
+    (0..3)
+        .map(|row| {
+            let lead = if row == 1 {
+                "━━━━━━"
+            } else {
+                "      "
+            };
+            let mut spans = vec![Span::styled(lead, lead_style)];
+            spans.extend(columns.iter().map(|pixels| {
+                match (pixels[2 * row], pixels[2 * row + 1]) {
+                    (Some(top), Some(bottom)) => {
+                        Span::styled("▀", Style::default().fg(top).bg(bottom))
+                    }
+                    (Some(top), None) => Span::styled("▀", Style::default().fg(top)),
+                    (None, Some(bottom)) => Span::styled("▄", Style::default().fg(bottom)),
+                    (None, None) => Span::raw(" "),
+                }
+            }));
+            spans.push(Span::styled(lead, lead_style));
+            Line::from(spans)
+        })
+        .collect()
+}
+
+/// Renders the buttons of the bands counts in a row, phones have no number keys,
+/// and returns their areas.
+fn render_band_counts(
+    frame: &mut Frame,
+    area: Rect,
+    bands_len: usize,
+    lang: Language,
+    selected_style: Style,
+) -> Vec<(Rect, usize)> {
+    let labels = (3..=6)
+        .map(|count| format!(" {} ", lang.fill(Text::BandsOf, &[&count])))
+        .collect::<Vec<_>>();
+    let buttons_width = labels.iter().map(|l| l.chars().count() as u16 + 1).sum();
+    let buttons_rect = center_horizontal(Rect { height: 1, ..area }, buttons_width);
+    let mut x = buttons_rect.x;
+    let mut band_counts = Vec::new();
+    for (count, label) in (3..=6).zip(labels) {
+        let width = label.chars().count() as u16;
+        let rect = Rect::new(x, buttons_rect.y, width, 1).intersection(buttons_rect);
+        let style = if count == bands_len {
+            selected_style
+        } else {
+            Style::default().add_modifier(Modifier::REVERSED | Modifier::DIM)
+        };
+        frame.render_widget(Paragraph::new(label).style(style), rect);
+        band_counts.push((rect, count));
+        x += width + 1;
+    }
+    band_counts
+}
+
+/// Renders a list of colors per band of the resistor and returns their areas and scroll offsets.
+fn render_band_lists(
+    frame: &mut Frame,
+    area: Rect,
+    resistor: &Resistor,
+    selected_band: usize,
+    lang: Language,
+) -> Vec<(Rect, usize)> {
+    let bands = resistor.bands();
+    let bands_rect = Layout::horizontal([Constraint::Ratio(1, 6); 6]).split(area);
+    let mut lists = Vec::new();
+    for i in 0..bands.len() {
+        let mut state = ListState::default().with_selected(Some(*bands[i] as usize));
+        let is_focused = selected_band == i;
+        let list = band_list(i, bands.len(), is_focused, lang);
+        frame.render_stateful_widget(list, bands_rect[i], &mut state);
+        lists.push((bands_rect[i], state.offset()));
+    }
+    lists
+}
+
+/// Renders the buttons of the keypad in rows with a gap and returns their key events.
+fn render_keypad(frame: &mut Frame, area: Rect, style: Style) -> Vec<(Rect, KeyEvent)> {
+    let columns = KEYPAD[0].len() as u16;
+    let width = area.width.min(columns * 7 - 1);
+    let button_width = (width + 1) / columns - 1;
+    let area = Rect {
+        width: columns * (button_width + 1) - 1,
+        ..area
+    };
+    let [area] = Layout::horizontal([Constraint::Length(area.width)])
+        .flex(Flex::Center)
+        .areas(area);
+    let mut keys = Vec::new();
+    for (row, buttons) in KEYPAD.iter().enumerate() {
+        for (column, (label, code, ctrl)) in buttons.iter().enumerate() {
+            let rect = Rect::new(
+                area.x + column as u16 * (button_width + 1),
+                area.y + row as u16 * 2,
+                button_width,
+                1,
+            )
+            .intersection(area);
+            frame.render_widget(Paragraph::new(*label).centered().style(style), rect);
+            let key = KeyEvent {
+                code: code.clone(),
+                ctrl: *ctrl,
+                alt: false,
+                shift: false,
+            };
+            keys.push((rect, key));
+        }
+    }
+    keys
+}
+
+/// The keys of the tabs of the web app in a popup over the current tab.
+fn render_help(frame: &mut Frame, lang: Language, style: Style) {
+    let keys = |tab: SelectedTab| -> Vec<(&str, String)> {
+        match tab {
+            SelectedTab::ColorCodesToSpecs => vec![
+                ("←/→", lang.text(Text::PrevNextBand).to_string()),
+                ("↑/↓", lang.text(Text::PrevNextColor).to_string()),
+                ("a-z", lang.text(Text::JumpToColor).to_string()),
+                ("3|4|5|6", lang.text(Text::BandsCount).to_string()),
+                ("W", lang.text(Text::PowerRatingKey).to_string()),
+                ("+/-", lang.text(Text::TemperatureKey).to_string()),
+                ("f", lang.text(Text::Flip).to_string()),
+                ("u/Ctrl+R", lang.text(Text::UndoRedo).to_string()),
+            ],
+            SelectedTab::Quiz => vec![
+                ("Enter", lang.text(Text::Check).to_string()),
+                ("Esc", lang.text(Text::Skip).to_string()),
+                ("F4", lang.text(Text::Mode).to_string()),
+                ("←/→", lang.text(Text::PrevNextBand).to_string()),
+                ("↑/↓", lang.text(Text::PrevNextColor).to_string()),
+                ("3|4|5|6", lang.text(Text::BandsCount).to_string()),
+            ],
+            _ => vec![
+                ("Ctrl ←/→", lang.text(Text::PrevNextInput).to_string()),
+                ("Enter", lang.text(Text::CalculateColorCodes).to_string()),
+                ("↑/↓", lang.text(Text::PrevNextHistory).to_string()),
+                ("X", lang.text(Text::Reset).to_string()),
+                ("Alt ←/→", lang.text(Text::PrevNextWord).to_string()),
+                ("Shift Home/End", lang.text(Text::Select).to_string()),
+                ("Ctrl+A", lang.text(Text::SelectAll).to_string()),
+            ],
+        }
+    };
+    let sections = TABS
+        .iter()
+        .map(|tab| (tab.title(lang).trim().to_string(), keys(*tab)))
+        .chain([(
+            lang.text(Text::Tab).to_string(),
+            vec![
+                ("Shift ←/→", lang.text(Text::PrevNextTab).to_string()),
+                ("F2", lang.text(Text::ThemeKey).to_string()),
+                ("F6", lang.text(Text::Print).to_string()),
+                (
+                    lang.text(Text::ClickOrTap),
+                    format!(
+                        "{} {}/{}/{}",
+                        lang.text(Text::Select),
+                        lang.text(Text::Tab),
+                        lang.text(Text::Band),
+                        lang.text(Text::Color)
+                    ),
+                ),
+                ("?", lang.text(Text::Help).to_string()),
+            ],
+        )])
+        .collect::<Vec<_>>();
+    let key_width = sections
+        .iter()
+        .flat_map(|(_, keys)| keys.iter().map(|(key, _)| key.chars().count()))
+        .max()
+        .unwrap_or_default();
+    let mut lines = Vec::new();
+    for (title, keys) in sections {
+        if !lines.is_empty() {
+            lines.push(Line::default());
+        }
+        lines.push(Line::styled(
+            title,
+            Style::default().add_modifier(Modifier::BOLD),
+        ));
+        lines.extend(keys.into_iter().map(|(key, text)| {
+            Line::from(vec![
+                Span::styled(
+                    format!("{:<width$}", key, width = key_width),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(format!("  {}", text)),
+            ])
+        }));
+    }
+    let area = frame.area();
+    let width = lines
+        .iter()
+        .map(|l| l.width() as u16)
+        .max()
+        .unwrap_or_default()
+        + 4;
+    let [rect] = Layout::vertical([Constraint::Length(lines.len() as u16 + 2)])
+        .flex(Flex::Center)
+        .areas(area);
+    let [rect] = Layout::horizontal([Constraint::Length(width)])
+        .flex(Flex::Center)
+        .areas(rect);
+    let block = Block::bordered()
+        .title(format!(" {} ", lang.text(Text::Keys)))
+        .title_bottom(Line::from(format!(" ?/Esc: {} ", lang.text(Text::Close))).right_aligned())
+        .padding(Padding::horizontal(1))
+        .style(style);
+    frame.render_widget(Clear, rect);
+    frame.render_widget(Paragraph::new(lines).block(block), rect);
+}
+
+/// Renders the bands of the resistor as bars, centered and shrunk to fit the area.
+fn render_chart(frame: &mut Frame, area: Rect, resistor: &Resistor, title: Line, lang: Language) {
+    let bands = resistor.bands();
+    let chart_bands = chart_bands(&bands, lang);
+    let bands_len: u16 = (bands.len() as u16).clamp(2, 6); // give title enough space
+    // the bars shrink to fit narrow frames
+    let bar_width = (area.width.saturating_sub(4 + bands_len - 1) / bands_len).clamp(1, BAR_WIDTH);
+    let chart = barchart(&chart_bands, title, bar_width);
+    let chart_length: u16 = {
+        let bands_widths = bands_len * bar_width;
+        let bands_gaps = bands_len - 1;
+        let border_plus_margin = 4;
+        bands_widths + bands_gaps + border_plus_margin
+    };
+    frame.render_widget(chart, center_horizontal(area, chart_length));
+}
+
+fn barchart<'a>(chart_bands: &[ChartBand], title: Line<'a>, bar_width: u16) -> BarChart<'a> {
+    let bars: Vec<Bar> = chart_bands.iter().map(bar).collect();
     BarChart::default()
         .data(BarGroup::default().bars(&bars))
         .block(
@@ -391,40 +1106,42 @@ fn barchart(
                 .title(title)
                 .borders(Borders::all()),
         )
-        .bar_width(BAR_WIDTH)
+        .bar_width(bar_width)
         .bar_gap(1)
 }
 
-fn bar((sem_info, num_info, color, name): &(String, String, Color, String)) -> Bar<'_> {
+fn bar<'a>(band: &ChartBand) -> Bar<'a> {
     Bar::default()
         .value(100)
-        .text_value(format!(" {} ", name))
+        .text_value(format!(" {} ", band.name))
         .value_style(Style::default().fg(Color::White).bg(Color::Black))
-        .label(Line::from(format!("{}: {}", sem_info, num_info.trim())))
-        .style(bar_style(color))
+        .label(Line::from(band.label()))
+        .style(Style::new().fg(default_band_color(&band.color)))
 }
 
-fn bar_style(color: &Color) -> Style {
-    Style::new().fg(*color)
-}
+#[cfg(test)]
+mod tests {
+    use super::view;
+    use crate::model::{Model, TABS};
+    use ratzilla::ratatui::{Terminal, backend::TestBackend};
+    use tusistor_core::{config::Config, format::NumberFormat};
 
-fn rusistor_color_to_ratatui_color(color: &rusistor::Color) -> (Color, String) {
-    match color {
-        rusistor::Color::Black => (Color::Black, rusistor::Color::Black.to_string()),
-        rusistor::Color::Brown => (Color::Rgb(165, 42, 42), rusistor::Color::Brown.to_string()),
-        rusistor::Color::Red => (Color::Red, rusistor::Color::Red.to_string()),
-        rusistor::Color::Orange => (Color::Rgb(255, 165, 0), rusistor::Color::Orange.to_string()),
-        rusistor::Color::Yellow => (Color::Yellow, rusistor::Color::Yellow.to_string()),
-        rusistor::Color::Green => (Color::Green, rusistor::Color::Green.to_string()),
-        rusistor::Color::Blue => (Color::Blue, rusistor::Color::Blue.to_string()),
-        rusistor::Color::Violet => (Color::Rgb(148, 0, 211), rusistor::Color::Violet.to_string()),
-        rusistor::Color::Grey => (Color::Gray, rusistor::Color::Grey.to_string()),
-        rusistor::Color::White => (Color::White, rusistor::Color::White.to_string()),
-        rusistor::Color::Gold => (Color::Rgb(255, 215, 0), rusistor::Color::Gold.to_string()),
-        rusistor::Color::Silver => (
-            Color::Rgb(192, 192, 192),
-            rusistor::Color::Silver.to_string(),
-        ),
-        rusistor::Color::Pink => (Color::Rgb(255, 105, 180), rusistor::Color::Pink.to_string()),
+    /// The widths below and above `NARROW_WIDTH`, e.g. of phones and desktops.
+    const SIZES: [(u16, u16); 2] = [(60, 40), (120, 40)];
+
+    #[test]
+    fn test_view() {
+        for tab in TABS {
+            for (width, height) in SIZES {
+                let mut model = Model::with_locale(&Config::default(), NumberFormat::default(), 1);
+                model.selected_tab = tab;
+                let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+                terminal.draw(|frame| view(&mut model, frame)).unwrap();
+                insta::assert_snapshot!(
+                    format!("{}_{}x{}", tab.name(), width, height),
+                    terminal.backend()
+                );
+            }
+        }
     }
 }
@@ -0,0 +1,179 @@
+use rusistor::{Color, Resistor};
+use tusistor_core::{
+    i18n::{Language, Text},
+    model::SelectedTab,
+    view::{band_semantic_info, format_ohm},
+};
+
+use crate::{model::Model, update::current_resistor};
+
+/// What the screen reader mode compares before and after a message.
+#[derive(Debug, PartialEq)]
+pub struct Reading {
+    tab: SelectedTab,
+    selected_band: usize,
+    bands: Vec<Color>,
+    text: Option<String>,
+}
+
+impl Reading {
+    pub fn of(model: &Model) -> Reading {
+        let color_codes = &model.color_codes_to_specs;
+        Reading {
+            tab: model.selected_tab,
+            selected_band: color_codes.selected_band,
+            bands: color_codes.resistor.bands().into_iter().copied().collect(),
+            text: reading_text(model),
+        }
+    }
+}
+
+/// The resistor of the selected tab in words, or why there is none.
+fn reading_text(model: &Model) -> Option<String> {
+    if model.selected_tab == SelectedTab::SpecsToColorCodes
+        && let Some(error) = &model.specs_to_color.error
+    {
+        return Some(error.clone());
+    }
+    current_resistor(model).map(|r| describe_resistor(&r, model.language))
+}
+
+/// E.g. "resistor reads 2.2 kΩ ±5%, bands red red red gold".
+pub fn describe_resistor(resistor: &Resistor, language: Language) -> String {
+    let specs = resistor.specs();
+    let tcr = specs
+        .tcr
+        .map(|tcr| format!(" {}ppm/K", tcr))
+        .unwrap_or_default();
+    let bands = resistor
+        .bands()
+        .into_iter()
+        .map(|c| language.color(c))
+        .collect::<Vec<_>>()
+        .join(" ");
+    language.fill(
+        Text::AnnounceResistor,
+        &[
+            &format_ohm(specs.ohm),
+            &(specs.tolerance * 100.0),
+            &tcr,
+            &bands,
+        ],
+    )
+}
+
+/// The changes between the readings and the status in one line, `None` if nothing changed.
+pub fn announcement(before: &Reading, model: &Model) -> Option<String> {
+    let language = model.language;
+    let after = Reading::of(model);
+    let mut parts = Vec::new();
+    let tab_changed = after.tab != before.tab;
+    if tab_changed {
+        parts.push(language.fill(Text::AnnounceTab, &[&after.tab.title(language).trim()]));
+    }
+    if after.tab == SelectedTab::ColorCodesToSpecs
+        && !tab_changed
+        && let Some(color) = after.bands.get(after.selected_band)
+    {
+        let band = after.selected_band;
+        let info = band_semantic_info(after.bands.len(), band, language);
+        if after.bands.len() != before.bands.len() || band != before.selected_band {
+            parts.push(language.fill(
+                Text::AnnounceBandSelected,
+                &[&(band + 1), &language.color(color), &info],
+            ));
+        } else if before.bands.get(band) != Some(color) {
+            parts.push(language.fill(
+                Text::AnnounceBand,
+                &[&(band + 1), &language.color(color), &info],
+            ));
+        }
+    }
+    if let Some(status) = &model.status {
+        parts.push(status.clone());
+    }
+    if let Some(text) = &after.text
+        && (tab_changed || after.text != before.text)
+    {
+        parts.push(text.clone());
+    }
+    (!parts.is_empty()).then(|| parts.join("; "))
+}
+
+/// The tab, the status and the resistor of the tab, to start with.
+pub fn overview(model: &Model) -> String {
+    let language = model.language;
+    let mut parts = vec![language.fill(
+        Text::AnnounceTab,
+        &[&model.selected_tab.title(language).trim()],
+    )];
+    parts.extend(model.status.clone());
+    parts.extend(reading_text(model));
+    parts.join("; ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Reading, announcement, overview};
+    use crate::model::Model;
+    use rusistor::Color;
+    use tusistor_core::{
+        i18n::Language,
+        model::SelectedTab,
+        update::{ColorCodesMsg, update_on_colorcodemsg},
+    };
+
+    #[test]
+    fn test_announcement() {
+        let mut model = Model::default();
+        let before = Reading::of(&model);
+        assert_eq!(announcement(&before, &model), None);
+
+        let before = Reading::of(&model);
+        update_on_colorcodemsg(
+            &mut model.color_codes_to_specs,
+            ColorCodesMsg::SelectBand { band_idx: 2 },
+        );
+        assert_eq!(
+            announcement(&before, &model),
+            Some("Band 3 selected, black, Digit 3".to_string())
+        );
+
+        let before = Reading::of(&model);
+        update_on_colorcodemsg(
+            &mut model.color_codes_to_specs,
+            ColorCodesMsg::SelectColor {
+                band_idx: 2,
+                color: Color::Red,
+            },
+        );
+        assert_eq!(
+            announcement(&before, &model),
+            Some(
+                "Band 3 set to red, Digit 3; resistor reads 102 Ω ±1% 250ppm/K, \
+                 bands brown black red black brown black"
+                    .to_string()
+            )
+        );
+
+        let before = Reading::of(&model);
+        model.selected_tab = SelectedTab::OhmsLaw;
+        model.status = Some("copied".to_string());
+        assert_eq!(
+            announcement(&before, &model),
+            Some("Tab Ohm's law; copied".to_string())
+        );
+
+        model.language = Language::De;
+        let before = Reading::of(&model);
+        model.selected_tab = SelectedTab::ColorCodesToSpecs;
+        model.status = None;
+        let announced = announcement(&before, &model).unwrap();
+        assert_eq!(announced, overview(&model));
+        assert!(
+            announced.starts_with("Tab Farbcode zu Werten; Widerstand beträgt "),
+            "{}",
+            announced
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use std::{fs, path::Path};
+
+use rusistor::Resistor;
+
+/// A resistor line of a bill of materials.
+#[derive(Debug, PartialEq)]
+pub struct BomLine {
+    /// The line number in the file.
+    pub line: usize,
+    /// The reference designators, e.g. "R1,R4".
+    pub references: String,
+    pub value: String,
+    pub quantity: Option<u32>,
+    /// The resistance in Ω and the tolerance in % of the value.
+    pub specs: Result<(f64, Option<f64>), String>,
+}
+
+impl BomLine {
+    /// The resistor with the fewest bands for the value.
+    pub fn resistor(&self) -> Result<Resistor, String> {
+        let (ohm, tolerance) = self.specs.clone()?;
+        Resistor::determine(ohm, tolerance, None)
+    }
+}
+
+const REFERENCE_COLUMNS: [&str; 5] = ["ref", "refs", "reference", "references", "designator"];
+const VALUE_COLUMNS: [&str; 3] = ["value", "val", "comment"];
+const QUANTITY_COLUMNS: [&str; 4] = ["qty", "qnty", "quantity", "count"];
+
+pub fn load(path: &Path) -> color_eyre::Result<Vec<BomLine>> {
+    let content = fs::read_to_string(path)?;
+    parse(&content).map_err(|e| color_eyre::eyre::eyre!("{} in {}", e, path.display()))
+}
+
+/// Parses a CSV BOM, e.g. of KiCad, with a header naming the value column. Without a
+/// references column all lines are taken as resistors, with one those starting with `R`.
+pub fn parse(content: &str) -> Result<Vec<BomLine>, String> {
+    let mut lines = content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'));
+    let (_, header) = lines.next().ok_or("empty BOM")?;
+    let delimiter = [',', ';', '\t']
+        .into_iter()
+        .max_by_key(|d| header.matches(*d).count())
+        .unwrap_or(',');
+    let header = split_fields(header, delimiter);
+    let column = |names: &[&str]| {
+        header
+            .iter()
+            .position(|h| names.contains(&h.to_lowercase().as_str()))
+    };
+    let value_column = column(&VALUE_COLUMNS).ok_or("no value column in the header")?;
+    let reference_column = column(&REFERENCE_COLUMNS);
+    let quantity_column = column(&QUANTITY_COLUMNS);
+
+    let bom_lines = lines
+        .filter_map(|(idx, line)| {
+            let fields = split_fields(line, delimiter);
+            let field = |column: Option<usize>| {
+                column
+                    .and_then(|c| fields.get(c))
+                    .cloned()
+                    .unwrap_or_default()
+            };
+            let references = field(reference_column);
+            if reference_column.is_some() && !is_resistor_reference(&references) {
+                return None;
+            }
+            let value = field(Some(value_column));
+            Some(BomLine {
+                line: idx + 1,
+                references,
+                quantity: field(quantity_column).parse().ok(),
+                specs: parse_value(&value),
+                value,
+            })
+        })
+        .collect();
+    Ok(bom_lines)
+}
+
+/// Splits the fields at the delimiter, except inside double quotes.
+fn split_fields(line: &str, delimiter: char) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                fields.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            c if c == delimiter && !quoted => fields.push(String::new()),
+            c => fields.last_mut().unwrap().push(c),
+        }
+    }
+    fields.iter().map(|f| f.trim().to_string()).collect()
+}
+
+/// Whether the designators are resistors like `R1` or `R1, R4` and not e.g. `RV1`.
+fn is_resistor_reference(references: &str) -> bool {
+    references
+        .split([',', ' '])
+        .filter(|r| !r.is_empty())
+        .all(|r| r.starts_with('R') && r[1..].chars().all(|c| c.is_ascii_digit()) && r.len() > 1)
+}
+
+/// The resistance in Ω and the tolerance in % of a BOM value like "4k7", "4R7", "10K 1%"
+/// or "100 Ohm".
+pub fn parse_value(value: &str) -> Result<(f64, Option<f64>), String> {
+    let mut ohm = None;
+    let mut tolerance = None;
+    for token in value.split(|c: char| c.is_whitespace() || c == '/') {
+        if let Some(percent) = token.strip_suffix('%') {
+            let percent = percent.trim_start_matches('±');
+            tolerance = Some(
+                percent
+                    .parse::<f64>()
+                    .map_err(|_| format!("invalid tolerance: {}", token))?,
+            );
+        } else if ohm.is_none() && !token.is_empty() {
+            ohm = parse_rkm(token);
+        }
+    }
+    match ohm {
+        Some(ohm) => Ok((ohm, tolerance)),
+        None => Err(format!("not a resistance: {}", value)),
+    }
+}
+
+/// Parses a resistance in RKM code, the multiplier letter can be the decimal point.
+fn parse_rkm(token: &str) -> Option<f64> {
+    let token = token.trim_end_matches('Ω');
+    let lower = token.to_lowercase();
+    let token = ["ohms", "ohm"]
+        .iter()
+        .find_map(|unit| lower.strip_suffix(unit).map(|t| &token[..t.len()]))
+        .unwrap_or(token);
+    let Some(idx) = token.find(|c: char| c.is_alphabetic()) else {
+        return token.parse().ok();
+    };
+    let multiplier = match &token[idx..idx + 1] {
+        "R" | "r" => 1.0,
+        "m" => 1e-3,
+        "k" | "K" => 1e3,
+        "M" => 1e6,
+        "G" | "g" => 1e9,
+        _ => return None,
+    };
+    let (integer, fraction) = (&token[..idx], &token[idx + 1..]);
+    let number = match (integer.contains('.'), fraction.is_empty()) {
+        (_, true) => integer.to_string(),
+        (false, false) => format!("{}.{}", integer, fraction),
+        (true, false) => return None,
+    };
+    let number = number.parse::<f64>().ok()?;
+    // e.g. 4.7 * 1e3 is 4700.000000000001
+    Some((number * multiplier * 1e6).round() / 1e6)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, parse_value};
+
+    #[test]
+    fn test_parse_value() {
+        assert_eq!(parse_value("4k7"), Ok((4700.0, None)));
+        assert_eq!(parse_value("4.7K 1%"), Ok((4700.0, Some(1.0))));
+        assert_eq!(parse_value("4R7"), Ok((4.7, None)));
+        assert_eq!(parse_value("100R"), Ok((100.0, None)));
+        assert_eq!(parse_value("1M/5%"), Ok((1_000_000.0, Some(5.0))));
+        assert_eq!(parse_value("220 Ohm"), Ok((220.0, None)));
+        assert_eq!(parse_value("100mΩ"), Ok((0.1, None)));
+        assert!(parse_value("DNP").is_err());
+    }
+
+    #[test]
+    fn test_parse() {
+        let bom = parse(
+            r#""Refs","Value","Footprint","Qty"
+"R1,R4","4k7","R_0805","2"
+"C1","100n","C_0805","1"
+"RV1","10k","Potentiometer","1"
+"R2","10R 1%","R_0805","1"
+"#,
+        )
+        .unwrap();
+        assert_eq!(bom.len(), 2);
+        assert_eq!(bom[0].line, 2);
+        assert_eq!(bom[0].references, "R1,R4");
+        assert_eq!(bom[0].quantity, Some(2));
+        assert_eq!(bom[1].specs, Ok((10.0, Some(1.0))));
+        assert_eq!(bom[1].resistor().unwrap().specs().ohm, 10.0);
+
+        let bom = parse("Value;Qty\n4k7;3\n1k;\n").unwrap();
+        assert_eq!(bom.len(), 2);
+        assert_eq!(bom[1].quantity, None);
+
+        assert!(parse("Refs,Footprint\nR1,R_0805\n").is_err());
+        assert!(parse("").is_err());
+    }
+}
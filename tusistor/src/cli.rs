@@ -0,0 +1,782 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    fs::File,
+    io::{self, BufRead, BufReader, IsTerminal, Write},
+    path::{Path, PathBuf},
+};
+
+use clap::{
+    Arg, CommandFactory, Parser, Subcommand, ValueEnum,
+    builder::{PossibleValue, PossibleValuesParser, StringValueParser, TypedValueParser},
+};
+use clap_complete::Shell;
+use crossterm::style::Stylize;
+use ratatui::backend::IntoCrossterm;
+use rusistor::{Color, Resistor, ResistorSpecs};
+use serde::Serialize;
+use tusistor_core::{
+    config::Config,
+    format::NumberFormat,
+    model::{HISTORY_LEN, HistoryStore, SelectedTab, SpecsHistory},
+    theme::{Theme, default_band_color},
+    update::{SpecsMsg, try_parse_specs, update_on_specsmsg},
+    view::{band_code, band_pattern},
+};
+
+use crate::{
+    announce, bom, commands, config, export, favorites,
+    history::{self, HistoryFile},
+    inventory,
+    model::{BomPopup, Model},
+    quiz_stats, session,
+    update::update,
+};
+
+#[derive(Debug, Parser)]
+#[command(version, about)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+    /// Tab to start with
+    #[arg(long, value_enum)]
+    pub tab: Option<Tab>,
+    /// Bands count of the color codes tab
+    #[arg(long, value_parser = clap::value_parser!(u8).range(3..=6))]
+    pub bands: Option<u8>,
+    /// Resistance to prefill the specs tab with, the color codes are determined right away
+    #[arg(long)]
+    pub resistance: Option<String>,
+    /// Tolerance in % to prefill the specs tab with
+    #[arg(long, requires = "resistance")]
+    pub tolerance: Option<String>,
+    /// TCR in ppm/K to prefill the specs tab with
+    #[arg(long, requires = "resistance")]
+    pub tcr: Option<String>,
+    /// Band colors to select in the color codes tab, e.g. "red red orange gold"
+    #[arg(long)]
+    pub colors: Option<String>,
+    /// CSV BOM file, e.g. of KiCad, to list the resistors of and load them into the specs tab
+    #[arg(long, value_name = "FILE")]
+    pub import: Option<PathBuf>,
+    /// Commands of the command palette to run at the start, one per line, `-` reads them from stdin
+    #[arg(long, value_name = "FILE")]
+    pub script: Option<PathBuf>,
+    /// Color theme
+    #[arg(long, value_parser = PossibleValuesParser::new(Theme::ALL.map(|t| t.name())).try_map(|s| s.parse::<Theme>()))]
+    pub theme: Option<Theme>,
+    /// Convey the band colors with names, codes and patterns instead of colors,
+    /// also enabled by setting the NO_COLOR environment variable
+    #[arg(long, global = true)]
+    pub no_color: bool,
+    /// Show the codes and patterns of the bands next to their colors
+    #[arg(long)]
+    pub accessible: bool,
+    /// Describe every change in plain text on the last row for screen readers, implies --no-color
+    #[arg(long)]
+    pub screen_reader: bool,
+    /// Path to a TOML config file instead of tusistor/config.toml in the user's config directory,
+    /// command line options take precedence
+    #[arg(long)]
+    pub config: Option<PathBuf>,
+    /// Path to the inventory file, defaults to tusistor/inventory.json in the user's data directory
+    #[arg(long)]
+    pub inventory: Option<PathBuf>,
+    /// Export the specs history with the decoded resistors to a CSV file,
+    /// or a JSON file if the path ends with .json
+    #[arg(long, value_name = "PATH")]
+    pub export_history: Option<PathBuf>,
+    /// Read values from stdin and print the results instead of starting the UI,
+    /// this is the default when stdin is not a terminal
+    #[arg(long, conflicts_with = "tui")]
+    pub pipe: bool,
+    /// Start the UI even if stdin is not a terminal
+    #[arg(long)]
+    pub tui: bool,
+    /// Log the messages of this level and more severe ones to tusistor/tusistor.log
+    /// in the user's data directory, `F12` shows the last ones in the UI
+    #[arg(long, value_name = "LEVEL", value_parser = PossibleValuesParser::new(["error", "warn", "info", "debug", "trace"]).try_map(|s| s.parse::<tracing::Level>()))]
+    pub log: Option<tracing::Level>,
+    /// Output format of the subcommands
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Text)]
+    pub output: OutputFormat,
+}
+
+impl Cli {
+    pub fn is_pipe_mode(&self) -> bool {
+        let script_from_stdin = self.script.as_deref() == Some(Path::new("-"));
+        self.pipe || (!self.tui && !script_from_stdin && !io::stdin().is_terminal())
+    }
+
+    pub fn is_monochrome(&self) -> bool {
+        self.no_color || std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty())
+    }
+
+    fn implied_tab(&self) -> Option<Tab> {
+        if self.resistance.is_some() {
+            Some(Tab::Specs)
+        } else if self.colors.is_some() {
+            Some(Tab::Colors)
+        } else {
+            None
+        }
+    }
+
+    /// Creates the model for the interactive UI from the config and the prefill options.
+    pub fn init_model<'a>(&self) -> color_eyre::Result<Model<'a>> {
+        let config = self.load_config()?;
+        let mut model = Model::new(&config);
+        if let Some(path) = config.inventory.or_else(inventory::default_path) {
+            match inventory::load(&path) {
+                Ok(inventory) => {
+                    model.inventory.inventory = inventory;
+                    model.inventory_path = Some(path);
+                }
+                // don't overwrite a file we couldn't read
+                Err(e) => {
+                    model.inventory.error = Some(format!(
+                        "could not load {}: {}, changes won't be saved",
+                        path.display(),
+                        e
+                    ))
+                }
+            }
+        }
+        if let Some(path) = history::default_path() {
+            let file = HistoryFile::new(path);
+            match file.load() {
+                Ok(history) => {
+                    model.specs_to_color.history = history;
+                    model.history_file = Some(file);
+                }
+                Err(e) => {
+                    model.status = Some(format!("could not load {}: {}", file.path().display(), e))
+                }
+            }
+        }
+        if let Some(path) = favorites::default_path() {
+            match favorites::load(&path) {
+                Ok(favorites) => {
+                    model.favorites = favorites;
+                    model.favorites_path = Some(path);
+                }
+                Err(e) => model.status = Some(format!("could not load {}: {}", path.display(), e)),
+            }
+        }
+        if let Some(path) = quiz_stats::default_path() {
+            match quiz_stats::load(&path) {
+                Ok(stats) => {
+                    model.quiz_stats = stats;
+                    model.quiz_stats_path = Some(path);
+                }
+                Err(e) => model.status = Some(format!("could not load {}: {}", path.display(), e)),
+            }
+        }
+        model
+            .specs_to_color
+            .history
+            .set_max_len(config.history_len.unwrap_or(HISTORY_LEN));
+        model.specs_to_color.history.set_clock(history::now);
+        let restore_session = config.restore_session.unwrap_or_default();
+        if (restore_session || config.restore_resistor.unwrap_or_default())
+            && let Some(path) = session::default_path()
+        {
+            match session::load(&path) {
+                Ok(Some(session)) if !restore_session => {
+                    session.restore_resistor(&mut model.color_codes_to_specs);
+                }
+                Ok(Some(session)) => {
+                    let tab =
+                        session.restore(&mut model.color_codes_to_specs, &mut model.specs_to_color);
+                    // a tab of the command line still takes precedence
+                    model.selected_tab = self
+                        .tab
+                        .or(self.implied_tab())
+                        .map(SelectedTab::from)
+                        .unwrap_or(tab);
+                }
+                Ok(None) => (),
+                Err(e) => model.status = Some(format!("could not load {}: {}", path.display(), e)),
+            }
+            model.session_path = Some(path);
+        }
+        if let Some(colors) = &self.colors {
+            let colors = colors
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|c| !c.is_empty())
+                .map(|c| c.to_string())
+                .collect::<Vec<String>>();
+            model.color_codes_to_specs.resistor =
+                decode(&colors).map_err(|e| color_eyre::eyre::eyre!("--colors: {}", e))?;
+        }
+        if let Some(resistance) = &self.resistance {
+            model.specs_to_color.set_specs((
+                resistance.clone(),
+                self.tolerance.clone().unwrap_or_default(),
+                self.tcr.clone().unwrap_or_default(),
+            ));
+            update_on_specsmsg(&mut model.specs_to_color, SpecsMsg::Determine);
+        }
+        if let Some(path) = &self.import {
+            let lines = bom::load(path)?;
+            if lines.is_empty() {
+                return Err(color_eyre::eyre::eyre!(
+                    "no resistors in {}",
+                    path.display()
+                ));
+            }
+            model.bom_popup = Some(BomPopup {
+                path: path.clone(),
+                lines,
+                selected: 0,
+            });
+        }
+        if let Some(path) = &self.script {
+            let script = if path == Path::new("-") {
+                io::read_to_string(io::stdin())
+            } else {
+                std::fs::read_to_string(path)
+            };
+            let msgs = script
+                .map_err(|e| e.to_string())
+                .and_then(|script| commands::parse_script(&script))
+                .map_err(|e| color_eyre::eyre::eyre!("{}: {}", path.display(), e))?;
+            for msg in msgs {
+                update(&mut model, msg);
+            }
+        }
+        if model.screen_reader {
+            model.announcement = Some(announce::overview(&model));
+        }
+        Ok(model)
+    }
+
+    pub fn load_config(&self) -> color_eyre::Result<Config> {
+        let mut config = match &self.config {
+            Some(path) => config::load(path)?,
+            None => config::load_default()?,
+        };
+        config.tab = self
+            .tab
+            .or(self.implied_tab())
+            .map(SelectedTab::from)
+            .or(config.tab);
+        config.bands = self.bands.or(config.bands);
+        config.theme = self.theme.or(config.theme);
+        config.inventory = self.inventory.clone().or(config.inventory);
+        if self.is_monochrome() {
+            config.no_color = Some(true);
+        }
+        if self.accessible {
+            config.accessible = Some(true);
+        }
+        if self.screen_reader {
+            config.screen_reader = Some(true);
+        }
+        config.language = config.language.or_else(config::locale_language);
+        Ok(config)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum Tab {
+    Colors,
+    Specs,
+    Eseries,
+    Network,
+    OhmsLaw,
+    Divider,
+    Led,
+    Quiz,
+    Capacitor,
+    Inductor,
+    Compare,
+    Inventory,
+}
+
+impl From<Tab> for SelectedTab {
+    fn from(tab: Tab) -> Self {
+        match tab {
+            Tab::Colors => SelectedTab::ColorCodesToSpecs,
+            Tab::Specs => SelectedTab::SpecsToColorCodes,
+            Tab::Eseries => SelectedTab::ESeries,
+            Tab::Network => SelectedTab::Network,
+            Tab::OhmsLaw => SelectedTab::OhmsLaw,
+            Tab::Divider => SelectedTab::Divider,
+            Tab::Led => SelectedTab::Led,
+            Tab::Quiz => SelectedTab::Quiz,
+            Tab::Capacitor => SelectedTab::Capacitor,
+            Tab::Inductor => SelectedTab::Inductor,
+            Tab::Compare => SelectedTab::Compare,
+            Tab::Inventory => SelectedTab::Inventory,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Serialize)]
+struct ResistorOutput {
+    bands: Vec<Color>,
+    specs: ResistorSpecs,
+}
+
+impl From<&Resistor> for ResistorOutput {
+    fn from(resistor: &Resistor) -> Self {
+        ResistorOutput {
+            bands: resistor.bands().into_iter().cloned().collect(),
+            specs: resistor.specs(),
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Determine the color codes of a resistor from its specs
+    Determine {
+        /// Resistance in Ω, RKM notation like 4k7 is supported
+        resistance: String,
+        /// Tolerance in %
+        #[arg(long)]
+        tolerance: Option<String>,
+        /// Temperature coefficient in ppm/K
+        #[arg(long)]
+        tcr: Option<String>,
+    },
+    /// Decode the specs of a resistor from its color codes
+    Decode {
+        /// Band colors, e.g. brown black red gold
+        #[arg(required = true, value_parser = ColorNameParser)]
+        colors: Vec<String>,
+    },
+    /// Process one resistance or band list per line, e.g. from a BOM
+    Batch {
+        /// Input file, stdin is read if omitted or "-"
+        file: Option<PathBuf>,
+    },
+    /// Print a shell completion script
+    Completions {
+        #[arg(value_enum)]
+        shell: Shell,
+    },
+}
+
+/// Offers the color names for help and completions, but leaves the validation
+/// to the decoding so invalid names are reported like any other parse error.
+#[derive(Clone)]
+struct ColorNameParser;
+
+impl TypedValueParser for ColorNameParser {
+    type Value = String;
+
+    fn parse_ref(
+        &self,
+        cmd: &clap::Command,
+        arg: Option<&Arg>,
+        value: &std::ffi::OsStr,
+    ) -> Result<Self::Value, clap::Error> {
+        StringValueParser::new().parse_ref(cmd, arg, value)
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        Some(Box::new(Color::ALL.iter().map(|c| {
+            PossibleValue::new(c.to_string()).aliases(c.aliases())
+        })))
+    }
+}
+
+/// Exit codes of the non-interactive modes.
+pub mod exit_code {
+    pub const OK: i32 = 0;
+    /// An input couldn't be parsed, e.g. a malformed number or an unknown color name.
+    pub const PARSE_ERROR: i32 = 1;
+    /// The input was parsed but doesn't describe a valid resistor.
+    pub const UNREPRESENTABLE: i32 = 2;
+    /// Reading the input or writing the output failed.
+    pub const IO_ERROR: i32 = 3;
+    /// The command line arguments are invalid.
+    pub const USAGE_ERROR: i32 = 64;
+}
+
+#[derive(Debug, PartialEq)]
+enum CliError {
+    Parse(String),
+    Unrepresentable(String),
+}
+
+impl CliError {
+    fn exit_code(&self) -> i32 {
+        match self {
+            CliError::Parse(_) => exit_code::PARSE_ERROR,
+            CliError::Unrepresentable(_) => exit_code::UNREPRESENTABLE,
+        }
+    }
+}
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            CliError::Parse(e) | CliError::Unrepresentable(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct BatchLineOutput {
+    line: usize,
+    input: String,
+    #[serde(flatten)]
+    result: Option<ResistorOutput>,
+    error: Option<String>,
+}
+
+/// Runs a subcommand and returns the exit code, diagnostics are written to stderr.
+pub fn run(command: Command, output: OutputFormat, monochrome: bool) -> i32 {
+    let result = match command {
+        Command::Determine {
+            resistance,
+            tolerance,
+            tcr,
+        } => determine(
+            &resistance,
+            tolerance.as_deref().unwrap_or_default(),
+            tcr.as_deref().unwrap_or_default(),
+        )
+        .map_or_else(
+            |e| Ok(report(&e)),
+            |r| print_resistor(&r, output, monochrome),
+        ),
+        Command::Decode { colors } => decode(&colors).map_or_else(
+            |e| Ok(report(&e)),
+            |r| print_resistor(&r, output, monochrome),
+        ),
+        Command::Batch { file } => run_batch(file, output),
+        Command::Completions { shell } => {
+            let mut script = vec![];
+            clap_complete::generate(shell, &mut Cli::command(), "tusistor", &mut script);
+            io::stdout()
+                .write_all(&script)
+                .map(|_| exit_code::OK)
+                .map_err(color_eyre::Report::from)
+        }
+    };
+    result.unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        exit_code::IO_ERROR
+    })
+}
+
+pub fn export_history(path: &Path) -> i32 {
+    let result = history::default_path()
+        .map(|history_path| HistoryFile::new(history_path).load())
+        .unwrap_or_else(|| Ok(SpecsHistory::default()))
+        .map_err(|e| color_eyre::eyre::eyre!(e))
+        .and_then(|history| export::export(path, &history));
+    match result {
+        Ok(count) => {
+            eprintln!("exported {} entries to {}", count, path.display());
+            exit_code::OK
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            exit_code::IO_ERROR
+        }
+    }
+}
+
+fn report(error: &CliError) -> i32 {
+    eprintln!("error: {}", error);
+    error.exit_code()
+}
+
+fn print_resistor(
+    resistor: &Resistor,
+    output: OutputFormat,
+    monochrome: bool,
+) -> color_eyre::Result<i32> {
+    match output {
+        OutputFormat::Text => println!("{}", format_resistor(resistor, monochrome)),
+        OutputFormat::Json => println!(
+            "{}",
+            serde_json::to_string(&ResistorOutput::from(resistor))?
+        ),
+    }
+    Ok(exit_code::OK)
+}
+
+fn run_batch(file: Option<PathBuf>, output: OutputFormat) -> color_eyre::Result<i32> {
+    let reader: Box<dyn BufRead> = match file {
+        Some(path) if path.as_os_str() != "-" => Box::new(BufReader::new(File::open(path)?)),
+        _ => Box::new(BufReader::new(io::stdin())),
+    };
+
+    let mut inputs = vec![];
+    for (idx, line) in reader.lines().enumerate() {
+        let line = line?;
+        let input = line.trim();
+        if !input.is_empty() && !input.starts_with('#') {
+            inputs.push((idx + 1, input.to_string()));
+        }
+    }
+    print_batch(inputs, output)
+}
+
+/// Reads whitespace-separated resistance values from stdin,
+/// a line starting with a color name is taken as one band list.
+pub fn run_pipe(output: OutputFormat) -> i32 {
+    let read_inputs = || -> io::Result<Vec<(usize, String)>> {
+        let mut inputs = vec![];
+        for (idx, line) in io::stdin().lock().lines().enumerate() {
+            let line = line?;
+            let mut values = line.split_whitespace().peekable();
+            match values.peek() {
+                Some(first) if first.parse::<Color>().is_ok() => {
+                    inputs.push((idx + 1, values.collect::<Vec<&str>>().join(" ")))
+                }
+                _ => values.for_each(|v| inputs.push((idx + 1, v.to_string()))),
+            }
+        }
+        Ok(inputs)
+    };
+    read_inputs()
+        .map_err(color_eyre::Report::from)
+        .and_then(|inputs| print_batch(inputs, output))
+        .unwrap_or_else(|e| {
+            eprintln!("error: {}", e);
+            exit_code::IO_ERROR
+        })
+}
+
+/// Prints the results with a row per failed line to stdout and the failed lines to stderr,
+/// the exit code is the most severe of all failures.
+fn print_batch(inputs: Vec<(usize, String)>, output: OutputFormat) -> color_eyre::Result<i32> {
+    let (results, code) = batch_results(inputs);
+    for r in &results {
+        if let Some(error) = &r.error {
+            eprintln!("error: line {} ({}): {}", r.line, r.input, error);
+        }
+    }
+    match output {
+        OutputFormat::Text => println!("{}", format_batch_table(&results)),
+        OutputFormat::Json => println!("{}", serde_json::to_string(&results)?),
+    }
+    Ok(code)
+}
+
+/// The results of the lines and the exit code of the most severe failure.
+fn batch_results(inputs: Vec<(usize, String)>) -> (Vec<BatchLineOutput>, i32) {
+    let mut code = exit_code::OK;
+    let results = inputs
+        .into_iter()
+        .map(|(line, input)| {
+            let (result, error) = match parse_batch_line(&input) {
+                Ok(resistor) => (Some(ResistorOutput::from(&resistor)), None),
+                Err(e) => {
+                    code = code.max(e.exit_code());
+                    (None, Some(e.to_string()))
+                }
+            };
+            BatchLineOutput {
+                line,
+                input,
+                result,
+                error,
+            }
+        })
+        .collect::<Vec<BatchLineOutput>>();
+    (results, code)
+}
+
+/// A batch line is either a band list or a resistance with optional tolerance and TCR,
+/// separated by whitespace, commas or semicolons.
+fn parse_batch_line(line: &str) -> Result<Resistor, CliError> {
+    let fields = line
+        .split(|c: char| c == ',' || c == ';' || c.is_whitespace())
+        .filter(|f| !f.is_empty())
+        .map(|f| f.to_string())
+        .collect::<Vec<String>>();
+    match fields.as_slice() {
+        [] => Err(CliError::Parse(String::from("empty line"))),
+        [first, ..] if first.parse::<Color>().is_ok() => decode(&fields),
+        [resistance] => determine(resistance, "", ""),
+        [resistance, tolerance] => determine(resistance, tolerance, ""),
+        [resistance, tolerance, tcr] => determine(resistance, tolerance, tcr),
+        _ => Err(CliError::Parse(String::from(
+            "expected a band list or resistance, tolerance and tcr",
+        ))),
+    }
+}
+
+fn determine(resistance: &str, tolerance: &str, tcr: &str) -> Result<Resistor, CliError> {
+    let (resistance, tolerance, tcr) =
+        try_parse_specs(resistance, tolerance, tcr).map_err(CliError::Parse)?;
+    Resistor::determine(resistance, tolerance, tcr).map_err(|e| {
+        CliError::Unrepresentable(format!(
+            "could not determine a resistor for these inputs: {}",
+            e
+        ))
+    })
+}
+
+fn decode(colors: &[String]) -> Result<Resistor, CliError> {
+    let bands = colors
+        .iter()
+        .map(|c| c.parse::<Color>())
+        .collect::<Result<Vec<Color>, String>>()
+        .map_err(CliError::Parse)?;
+    Resistor::try_create(bands).map_err(CliError::Unrepresentable)
+}
+
+fn format_batch_table(results: &[BatchLineOutput]) -> String {
+    let nf = NumberFormat::default();
+    let mut rows = vec![format!(
+        "{:>5}  {:<24}  {:<42}  {:>10}  {:>9}  {:>10}  {:>10}  {:>10}",
+        "Line", "Input", "Bands", "Resistance", "Tolerance", "Min", "Max", "TCR"
+    )];
+    for r in results {
+        let row = match &r.result {
+            Some(ResistorOutput { bands, specs }) => format!(
+                "{:>5}  {:<24}  {:<42}  {:>10}  {:>9}  {:>10}  {:>10}  {:>10}",
+                r.line,
+                r.input,
+                bands
+                    .iter()
+                    .map(|b| b.to_string())
+                    .collect::<Vec<String>>()
+                    .join(" "),
+                nf.ohm(specs.ohm),
+                format!("±{}", nf.percent(specs.tolerance)),
+                nf.ohm(specs.min_ohm),
+                nf.ohm(specs.max_ohm),
+                specs.tcr.map(|t| nf.ppm(t)).unwrap_or_default()
+            ),
+            None => format!(
+                "{:>5}  {:<24}  error: {}",
+                r.line,
+                r.input,
+                r.error.as_deref().unwrap_or_default()
+            ),
+        };
+        rows.push(row);
+    }
+    rows.join("\n")
+}
+
+fn format_resistor(resistor: &Resistor, monochrome: bool) -> String {
+    let bands = resistor
+        .bands()
+        .iter()
+        .map(|c| {
+            if monochrome {
+                let pattern = band_pattern(c).to_string().repeat(3);
+                format!("{} {} {}", pattern, band_code(c), c)
+            } else {
+                let color = default_band_color(c);
+                format!("{} {}", "   ".on(color.into_crossterm()), c)
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("  ");
+    let specs = resistor.specs();
+    let nf = NumberFormat::default();
+    let mut lines = vec![
+        format!("Bands:      {}", bands),
+        format!("Resistance: {}", nf.ohm(specs.ohm)),
+        format!("Tolerance:  ±{}", nf.percent(specs.tolerance)),
+        format!("Minimum:    {}", nf.ohm(specs.min_ohm)),
+        format!("Maximum:    {}", nf.ohm(specs.max_ohm)),
+    ];
+    if let Some(tcr) = specs.tcr {
+        lines.push(format!("TCR:        {}", nf.ppm(tcr)));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Cli, CliError, Command, OutputFormat, batch_results, exit_code, format_batch_table,
+        parse_batch_line,
+    };
+    use clap::Parser;
+    use rusistor::Color;
+
+    #[test]
+    fn test_batch_results() {
+        let inputs = ["4k7 5", "4x7", "brown black red gold", "4k71"]
+            .iter()
+            .enumerate()
+            .map(|(idx, input)| (idx + 1, input.to_string()))
+            .collect();
+        let (results, code) = batch_results(inputs);
+        assert_eq!(code, exit_code::UNREPRESENTABLE);
+
+        let table = format_batch_table(&results);
+        let rows = table.lines().collect::<Vec<_>>();
+        assert_eq!(rows.len(), 5);
+        assert!(rows[1].contains("yellow violet red gold"));
+        assert!(rows[2].starts_with("    2  4x7"));
+        assert!(rows[2].contains("error: "));
+        assert!(rows[3].contains("brown black red gold"));
+        assert!(rows[4].starts_with("    4  4k71"));
+        assert!(rows[4].contains("error: could not determine"));
+
+        let json = serde_json::to_value(&results).unwrap();
+        assert_eq!(json[0]["error"], serde_json::Value::Null);
+        assert_eq!(json[1]["line"], 2);
+        assert_eq!(json[1]["input"], "4x7");
+        assert!(json[1]["error"].is_string());
+        assert!(json[1].get("bands").is_none());
+        assert!(json[3]["error"].is_string());
+    }
+
+    #[test]
+    fn test_global_args() {
+        let cli = Cli::try_parse_from(["tusistor", "--output", "json", "decode", "brown", "black"])
+            .unwrap();
+        assert_eq!(cli.output, OutputFormat::Json);
+        assert!(matches!(cli.command, Some(Command::Decode { colors }) if colors.len() == 2));
+
+        let cli = Cli::try_parse_from(["tusistor", "--no-color", "determine", "4k7"]).unwrap();
+        assert!(cli.no_color);
+        assert!(matches!(cli.command, Some(Command::Determine { .. })));
+
+        let cli = Cli::try_parse_from(["tusistor", "decode", "red", "--output", "json"]).unwrap();
+        assert_eq!(cli.output, OutputFormat::Json);
+    }
+
+    #[test]
+    fn test_parse_batch_line() {
+        let r = parse_batch_line("brown, black, red, gold").unwrap();
+        assert_eq!(
+            r.bands(),
+            vec![&Color::Brown, &Color::Black, &Color::Red, &Color::Gold]
+        );
+
+        let r = parse_batch_line("4k7;5").unwrap();
+        assert_eq!(
+            r.bands(),
+            vec![&Color::Yellow, &Color::Violet, &Color::Red, &Color::Gold]
+        );
+
+        assert!(matches!(
+            parse_batch_line("4k7 5 50 1"),
+            Err(CliError::Parse(_))
+        ));
+        assert!(matches!(
+            parse_batch_line("brown mauve red"),
+            Err(CliError::Parse(_))
+        ));
+        assert!(matches!(
+            parse_batch_line("4k71"),
+            Err(CliError::Unrepresentable(_))
+        ));
+        assert!(matches!(
+            parse_batch_line("black black red"),
+            Err(CliError::Unrepresentable(_))
+        ));
+    }
+}
@@ -0,0 +1,33 @@
+use std::fmt::{self, Debug, Formatter};
+
+/// The system clipboard, opened on first use and kept open
+/// because on X11 the copied text is gone once it's closed.
+#[derive(Default)]
+pub struct Clipboard {
+    inner: Option<arboard::Clipboard>,
+}
+
+impl Debug for Clipboard {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Clipboard")
+            .field("open", &self.inner.is_some())
+            .finish()
+    }
+}
+
+impl Clipboard {
+    fn open(&mut self) -> Result<&mut arboard::Clipboard, String> {
+        if self.inner.is_none() {
+            self.inner = Some(arboard::Clipboard::new().map_err(|e| e.to_string())?);
+        }
+        Ok(self.inner.as_mut().unwrap())
+    }
+
+    pub fn set_text(&mut self, text: &str) -> Result<(), String> {
+        self.open()?.set_text(text).map_err(|e| e.to_string())
+    }
+
+    pub fn get_text(&mut self) -> Result<String, String> {
+        self.open()?.get_text().map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,297 @@
+use std::path::{Path, PathBuf};
+
+use tusistor_core::{
+    commands::{self, Command},
+    model::SelectedTab,
+    theme::Theme,
+    update::{ColorCodesMsg, Msg as SharedMsg, try_parse_resistance},
+};
+
+use crate::{
+    export,
+    model::TABS,
+    update::{BomMsg, FavoritesMsg, Msg},
+};
+
+/// The commands only the TUI has, next to the shared ones of `commands::COMMANDS`.
+pub const COMMANDS: [Command; 15] = [
+    Command {
+        name: "measure",
+        args: "[resistance]",
+    },
+    Command {
+        name: "power",
+        args: "[watts]",
+    },
+    Command {
+        name: "theme",
+        args: "dark|light",
+    },
+    Command {
+        name: "import",
+        args: "<bom file>",
+    },
+    Command {
+        name: "export csv",
+        args: "",
+    },
+    Command {
+        name: "export json",
+        args: "",
+    },
+    Command {
+        name: "add to network",
+        args: "",
+    },
+    Command {
+        name: "export svg",
+        args: "",
+    },
+    Command {
+        name: "export html",
+        args: "",
+    },
+    Command {
+        name: "copy",
+        args: "",
+    },
+    Command {
+        name: "share",
+        args: "",
+    },
+    Command {
+        name: "favorites",
+        args: "",
+    },
+    Command {
+        name: "accessible",
+        args: "",
+    },
+    Command {
+        name: "si units",
+        args: "",
+    },
+    Command {
+        name: "quit",
+        args: "",
+    },
+];
+
+/// The shared commands and the ones of the TUI.
+fn all() -> impl Iterator<Item = &'static Command> + Clone {
+    commands::COMMANDS.iter().chain(COMMANDS.iter())
+}
+
+/// The command the input starts with, the longest name wins.
+pub fn find(input: &str) -> Option<&'static Command> {
+    commands::find(all(), input)
+}
+
+/// The commands whose names contain the letters of the input in order, best matches first.
+pub fn matches(input: &str) -> Vec<&'static Command> {
+    commands::matches(all(), input)
+}
+
+/// The messages of a command line, e.g. `decode brown black red gold`.
+pub fn parse(input: &str) -> Result<Vec<Msg>, String> {
+    let command = find(input).ok_or_else(|| format!("unknown command: {}", input.trim()))?;
+    if commands::COMMANDS.contains(command) {
+        return Ok(commands::parse(input, &TABS)?
+            .into_iter()
+            .map(Msg::from)
+            .collect());
+    }
+    // paths keep their case
+    let raw_args = input.trim()[command.name.len()..].trim();
+    let input = input.trim().to_lowercase();
+    let args = input[command.name.len()..]
+        .split_whitespace()
+        .collect::<Vec<_>>();
+    let msgs = match (command.name, args.as_slice()) {
+        ("measure", []) => vec![Msg::Shared {
+            msg: SharedMsg::ColorCodesMsg {
+                msg: ColorCodesMsg::SetMeasured { ohm: None },
+            },
+        }],
+        ("measure", [value]) => vec![
+            Msg::Shared {
+                msg: SharedMsg::ColorCodesMsg {
+                    msg: ColorCodesMsg::SetMeasured {
+                        ohm: Some(try_parse_resistance(value)?),
+                    },
+                },
+            },
+            Msg::Shared {
+                msg: SharedMsg::SelectTab {
+                    tab: SelectedTab::ColorCodesToSpecs,
+                },
+            },
+        ],
+        ("power", []) => vec![Msg::Shared {
+            msg: SharedMsg::ColorCodesMsg {
+                msg: ColorCodesMsg::SetPowerRating { watts: None },
+            },
+        }],
+        ("power", [watts]) => {
+            let watts = watts
+                .trim_end_matches('w')
+                .parse::<f64>()
+                .ok()
+                .filter(|w| *w > 0.0 && w.is_finite())
+                .ok_or_else(|| format!("invalid power rating: {}", watts))?;
+            vec![
+                Msg::Shared {
+                    msg: SharedMsg::ColorCodesMsg {
+                        msg: ColorCodesMsg::SetPowerRating { watts: Some(watts) },
+                    },
+                },
+                Msg::Shared {
+                    msg: SharedMsg::SelectTab {
+                        tab: SelectedTab::ColorCodesToSpecs,
+                    },
+                },
+            ]
+        }
+        ("theme", [name]) => vec![Msg::SetTheme {
+            theme: name
+                .parse::<Theme>()
+                .map_err(|_| format!("invalid theme: {}", name))?,
+        }],
+        ("import", [_, ..]) => vec![Msg::BomMsg {
+            msg: BomMsg::Import {
+                path: PathBuf::from(raw_args),
+            },
+        }],
+        ("export csv", []) => vec![Msg::ExportHistory {
+            path: Path::new(export::DEFAULT_FILE).to_path_buf(),
+        }],
+        ("export json", []) => vec![Msg::ExportHistory {
+            path: Path::new(export::DEFAULT_FILE).with_extension("json"),
+        }],
+        ("export svg", []) => vec![Msg::ExportChart {
+            path: PathBuf::from(export::CHART_FILE),
+        }],
+        ("export html", []) => vec![Msg::ExportChart {
+            path: Path::new(export::CHART_FILE).with_extension("html"),
+        }],
+        ("add to network", []) => vec![Msg::AddToNetwork],
+        ("copy", []) => vec![Msg::Copy],
+        ("share", []) => vec![Msg::Share],
+        ("favorites", []) => vec![Msg::FavoritesMsg {
+            msg: FavoritesMsg::Open,
+        }],
+        ("accessible", []) => vec![Msg::ToggleAccessible],
+        ("si units", []) => vec![Msg::ToggleSiUnits],
+        ("quit", []) => vec![Msg::Exit],
+        (name, _) => {
+            return Err(format!("usage: {} {}", name, command.args)
+                .trim()
+                .to_string());
+        }
+    };
+    Ok(msgs)
+}
+
+/// The messages of a script of `--script` with a command per line.
+pub fn parse_script(script: &str) -> Result<Vec<Msg>, String> {
+    commands::parse_script(script, parse)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{COMMANDS, matches, parse, parse_script};
+    use crate::update::{BomMsg, Msg};
+    use rusistor::{Color, Resistor};
+    use tusistor_core::{
+        commands,
+        model::SelectedTab,
+        update::{ColorCodesMsg, Msg as SharedMsg},
+    };
+
+    #[test]
+    fn test_matches() {
+        assert_eq!(matches("").len(), commands::COMMANDS.len() + COMMANDS.len());
+        let names = |input| matches(input).iter().map(|c| c.name).collect::<Vec<_>>();
+        assert_eq!(names("exj"), vec!["export json"]);
+        assert_eq!(names("th")[0], "theme");
+        assert_eq!(names("bands 5"), vec!["bands"]);
+        assert!(names("xyz").is_empty());
+    }
+
+    #[test]
+    fn test_parse() {
+        let resistor =
+            Resistor::try_create(vec![Color::Brown, Color::Black, Color::Red, Color::Gold])
+                .unwrap();
+        assert!(matches!(
+            parse("decode brown black red gold").as_deref(),
+            Ok([
+                Msg::Shared {
+                    msg: SharedMsg::ColorCodesMsg {
+                        msg: ColorCodesMsg::SetResistor { resistor: r }
+                    }
+                },
+                _
+            ]) if *r == resistor
+        ));
+        assert!(matches!(
+            parse(" Tab ohms-law").as_deref(),
+            Ok([Msg::Shared {
+                msg: SharedMsg::SelectTab {
+                    tab: SelectedTab::OhmsLaw
+                }
+            }])
+        ));
+        assert!(matches!(parse("bands 5").as_deref(), Ok([_, _])));
+        assert!(matches!(
+            parse("measure 4k68").as_deref(),
+            Ok([
+                Msg::Shared {
+                    msg: SharedMsg::ColorCodesMsg {
+                        msg: ColorCodesMsg::SetMeasured { ohm: Some(4680.0) }
+                    }
+                },
+                _
+            ])
+        ));
+        assert_eq!(
+            parse("bands 7").err(),
+            Some("invalid bands count: 7".to_string())
+        );
+        assert_eq!(
+            parse("theme").err(),
+            Some("usage: theme dark|light".to_string())
+        );
+        assert_eq!(parse("theme x").err(), Some("invalid theme: x".to_string()));
+        assert!(matches!(
+            parse("power 0.5W").as_deref(),
+            Ok([
+                Msg::Shared {
+                    msg: SharedMsg::ColorCodesMsg {
+                        msg: ColorCodesMsg::SetPowerRating { watts: Some(0.5) }
+                    }
+                },
+                _
+            ])
+        ));
+        assert!(parse("power -1").is_err());
+        assert!(matches!(
+            parse("import boms/My BOM.csv").as_deref(),
+            Ok([Msg::BomMsg { msg: BomMsg::Import { path } }]) if path.to_str() == Some("boms/My BOM.csv")
+        ));
+        assert!(parse("decode brown blak red").is_err());
+        assert!(parse("frobnicate").is_err());
+    }
+
+    #[test]
+    fn test_parse_script() {
+        assert!(matches!(
+            parse_script("# the light theme\ntheme light\n\nquit\n").as_deref(),
+            Ok([_, Msg::Exit])
+        ));
+        assert_eq!(
+            parse_script("bands 4\nexport pdf").err(),
+            Some("line 2: unknown command: export pdf".to_string())
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use tusistor_core::{config::Config, i18n::Language};
+
+use crate::keymap::Keymap;
+
+/// `$XDG_DATA_HOME/tusistor`, falling back to `~/.local/share/tusistor`.
+pub fn data_dir() -> Option<PathBuf> {
+    xdg_dir("XDG_DATA_HOME", ".local/share")
+}
+
+/// `$XDG_CONFIG_HOME/tusistor/config.toml`, falling back to `~/.config/tusistor/config.toml`.
+pub fn default_path() -> Option<PathBuf> {
+    Some(xdg_dir("XDG_CONFIG_HOME", ".config")?.join("config.toml"))
+}
+
+fn xdg_dir(var: &str, fallback: &str) -> Option<PathBuf> {
+    let home = std::env::var_os(var)
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(fallback)))?;
+    Some(home.join("tusistor"))
+}
+
+/// The language of the locale, the first set of `LC_ALL`, `LC_MESSAGES` and `LANG`.
+pub fn locale_language() -> Option<Language> {
+    let locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .into_iter()
+        .filter_map(std::env::var_os)
+        .find(|v| !v.is_empty())?;
+    Language::from_locale(&locale.to_string_lossy())
+}
+
+pub fn load(path: &Path) -> color_eyre::Result<Config> {
+    let content = fs::read_to_string(path)?;
+    let config = Config::from_toml(&content)
+        .and_then(|config| keymap(&config).map(|_| config))
+        .map_err(|e| color_eyre::eyre::eyre!("{} in {}", e, path.display()))?;
+    Ok(config)
+}
+
+/// Loads the default config file, a missing one is the default config.
+pub fn load_default() -> color_eyre::Result<Config> {
+    match default_path() {
+        Some(path) if path.exists() => load(&path),
+        _ => Ok(Config::default()),
+    }
+}
+
+pub fn keymap(config: &Config) -> Result<Keymap, String> {
+    Keymap::with_overrides(&config.keys)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::keymap;
+    use tusistor_core::config::Config;
+
+    #[test]
+    fn test_keymap() {
+        let config = Config::from_toml(
+            r#"
+            [keys]
+            next_tab = "ctrl+n"
+            prev_tab = ["ctrl+p", "f1"]
+            "#,
+        )
+        .unwrap();
+        assert!(keymap(&config).is_ok());
+
+        let config = Config::from_toml("keys = { reset = \"ctrl+\" }").unwrap();
+        assert!(keymap(&config).is_err());
+    }
+}
@@ -0,0 +1,323 @@
+use std::{fs, path::Path};
+
+use ratatui::{
+    buffer::{Buffer, Cell},
+    style::{Color as TermColor, Modifier},
+};
+use rusistor::Color;
+use serde::Serialize;
+use tusistor_core::{model::SpecsHistory, update::try_determine_resistor};
+
+/// The file the history is exported to from the UI.
+pub const DEFAULT_FILE: &str = "tusistor-history.csv";
+
+/// The file the chart of a resistor is exported to from the UI.
+pub const CHART_FILE: &str = "tusistor-chart.svg";
+
+/// The colors of cells without one.
+const DEFAULT_FG: &str = "#e5e5e5";
+const DEFAULT_BG: &str = "#000000";
+
+/// The size of a cell in the SVG.
+const CELL_WIDTH: usize = 9;
+const CELL_HEIGHT: usize = 18;
+
+#[derive(Debug, PartialEq, Serialize)]
+struct ExportEntry {
+    resistance: String,
+    tolerance: String,
+    tcr: String,
+    bands: Vec<Color>,
+    ohm: Option<f64>,
+    min_ohm: Option<f64>,
+    max_ohm: Option<f64>,
+    error: Option<String>,
+}
+
+fn entries(history: &SpecsHistory) -> Vec<ExportEntry> {
+    (0..history.len())
+        .filter_map(|idx| history.get(idx))
+        .map(|(resistance, tolerance, tcr)| {
+            let resistor = try_determine_resistor(resistance, tolerance, tcr, None);
+            let specs = resistor.as_ref().ok().map(|r| r.specs());
+            ExportEntry {
+                resistance: resistance.clone(),
+                tolerance: tolerance.clone(),
+                tcr: tcr.clone(),
+                bands: resistor
+                    .as_ref()
+                    .map(|r| r.bands().into_iter().cloned().collect())
+                    .unwrap_or_default(),
+                ohm: specs.as_ref().map(|s| s.ohm),
+                min_ohm: specs.as_ref().map(|s| s.min_ohm),
+                max_ohm: specs.as_ref().map(|s| s.max_ohm),
+                error: resistor.err(),
+            }
+        })
+        .collect()
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn to_csv(entries: &[ExportEntry]) -> String {
+    let number = |n: Option<f64>| n.map(|n| n.to_string()).unwrap_or_default();
+    let mut csv = String::from("resistance,tolerance,tcr,bands,ohm,min_ohm,max_ohm,error\n");
+    for e in entries {
+        let bands = e
+            .bands
+            .iter()
+            .map(|b| b.to_string())
+            .collect::<Vec<String>>()
+            .join(" ");
+        let fields = [
+            csv_field(&e.resistance),
+            csv_field(&e.tolerance),
+            csv_field(&e.tcr),
+            bands,
+            number(e.ohm),
+            number(e.min_ohm),
+            number(e.max_ohm),
+            csv_field(e.error.as_deref().unwrap_or_default()),
+        ];
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Writes the history with the decoded resistors as JSON if the path ends with `.json`, as CSV otherwise.
+pub fn export(path: &Path, history: &SpecsHistory) -> color_eyre::Result<usize> {
+    let entries = entries(history);
+    let content = match path.extension().and_then(|e| e.to_str()) {
+        Some("json") => serde_json::to_string_pretty(&entries)?,
+        _ => to_csv(&entries),
+    };
+    fs::write(path, content)?;
+    Ok(entries.len())
+}
+
+/// Writes the rendered chart as HTML if the path ends with `.html`, as SVG otherwise.
+pub fn export_chart(path: &Path, chart: &Buffer) -> color_eyre::Result<()> {
+    let content = match path.extension().and_then(|e| e.to_str()) {
+        Some("html" | "htm") => to_html(chart),
+        _ => to_svg(chart),
+    };
+    fs::write(path, content)?;
+    Ok(())
+}
+
+/// The css color of a terminal color, `None` for the default one.
+fn css_color(color: TermColor) -> Option<String> {
+    const ANSI: [&str; 16] = [
+        "#000000", "#cd0000", "#00cd00", "#cdcd00", "#0000ee", "#cd00cd", "#00cdcd", "#e5e5e5",
+        "#7f7f7f", "#ff0000", "#00ff00", "#ffff00", "#5c5cff", "#ff00ff", "#00ffff", "#ffffff",
+    ];
+    let (r, g, b) = match color {
+        TermColor::Reset => return None,
+        TermColor::Rgb(r, g, b) => (r, g, b),
+        TermColor::Indexed(idx) if idx >= 232 => {
+            let level = 8 + (idx - 232) * 10;
+            (level, level, level)
+        }
+        TermColor::Indexed(idx) if idx >= 16 => {
+            let level = |c: u8| if c == 0 { 0 } else { 55 + c * 40 };
+            let idx = idx - 16;
+            (level(idx / 36), level(idx / 6 % 6), level(idx % 6))
+        }
+        TermColor::Indexed(idx) => return Some(ANSI[idx as usize].to_string()),
+        named => {
+            let idx = [
+                TermColor::Black,
+                TermColor::Red,
+                TermColor::Green,
+                TermColor::Yellow,
+                TermColor::Blue,
+                TermColor::Magenta,
+                TermColor::Cyan,
+                TermColor::Gray,
+                TermColor::DarkGray,
+                TermColor::LightRed,
+                TermColor::LightGreen,
+                TermColor::LightYellow,
+                TermColor::LightBlue,
+                TermColor::LightMagenta,
+                TermColor::LightCyan,
+                TermColor::White,
+            ]
+            .iter()
+            .position(|c| *c == named)?;
+            return Some(ANSI[idx].to_string());
+        }
+    };
+    Some(format!("#{:02x}{:02x}{:02x}", r, g, b))
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// The text of a row in runs of the same style, with the column they start at.
+fn runs(chart: &Buffer, y: u16) -> Vec<(usize, String, &Cell)> {
+    let mut runs: Vec<(usize, String, &Cell)> = Vec::new();
+    for x in 0..chart.area.width {
+        let cell = &chart[(chart.area.x + x, chart.area.y + y)];
+        match runs.last_mut() {
+            Some((_, text, first))
+                if (first.fg, first.bg, first.modifier) == (cell.fg, cell.bg, cell.modifier) =>
+            {
+                text.push_str(cell.symbol())
+            }
+            _ => runs.push((x as usize, cell.symbol().to_string(), cell)),
+        }
+    }
+    runs
+}
+
+/// The colors of a cell, swapped if it's reversed.
+fn cell_colors(cell: &Cell) -> (String, String) {
+    let fg = css_color(cell.fg).unwrap_or(DEFAULT_FG.to_string());
+    let bg = css_color(cell.bg).unwrap_or(DEFAULT_BG.to_string());
+    if cell.modifier.contains(Modifier::REVERSED) {
+        (bg, fg)
+    } else {
+        (fg, bg)
+    }
+}
+
+fn to_html(chart: &Buffer) -> String {
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html>\n<head><meta charset=\"utf-8\"><title>tusistor</title></head>\n\
+         <body>\n<pre style=\"display: inline-block; color: {}; background: {}; \
+         font-family: monospace; line-height: 1.2\">\n",
+        DEFAULT_FG, DEFAULT_BG
+    );
+    for y in 0..chart.area.height {
+        for (_, text, cell) in runs(chart, y) {
+            let (fg, bg) = cell_colors(cell);
+            let weight = if cell.modifier.contains(Modifier::BOLD) {
+                "; font-weight: bold"
+            } else {
+                ""
+            };
+            html.push_str(&format!(
+                "<span style=\"color: {}; background: {}{}\">{}</span>",
+                fg,
+                bg,
+                weight,
+                escape_xml(&text)
+            ));
+        }
+        html.push('\n');
+    }
+    html.push_str("</pre>\n</body>\n</html>\n");
+    html
+}
+
+fn to_svg(chart: &Buffer) -> String {
+    let (width, height) = (
+        chart.area.width as usize * CELL_WIDTH,
+        chart.area.height as usize * CELL_HEIGHT,
+    );
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" \
+         viewBox=\"0 0 {w} {h}\" font-family=\"monospace\" font-size=\"15\">\n\
+         <rect width=\"100%\" height=\"100%\" fill=\"{bg}\"/>\n",
+        w = width,
+        h = height,
+        bg = DEFAULT_BG
+    );
+    for y in 0..chart.area.height {
+        let top = y as usize * CELL_HEIGHT;
+        for (x, text, cell) in runs(chart, y) {
+            let (fg, bg) = cell_colors(cell);
+            let left = x * CELL_WIDTH;
+            let cells = text.chars().count();
+            if bg != DEFAULT_BG {
+                svg.push_str(&format!(
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>\n",
+                    left,
+                    top,
+                    cells * CELL_WIDTH,
+                    CELL_HEIGHT,
+                    bg
+                ));
+            }
+            if !text.trim().is_empty() {
+                let weight = if cell.modifier.contains(Modifier::BOLD) {
+                    " font-weight=\"bold\""
+                } else {
+                    ""
+                };
+                svg.push_str(&format!(
+                    "<text x=\"{}\" y=\"{}\" fill=\"{}\"{} textLength=\"{}\" xml:space=\"preserve\">{}</text>\n",
+                    left,
+                    top + CELL_HEIGHT - 4,
+                    fg,
+                    weight,
+                    cells * CELL_WIDTH,
+                    escape_xml(&text)
+                ));
+            }
+        }
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{css_color, entries, to_csv, to_html, to_svg};
+    use ratatui::{
+        buffer::Buffer,
+        layout::Rect,
+        style::{Color, Style},
+    };
+    use tusistor_core::model::SpecsHistory;
+
+    #[test]
+    fn test_to_csv() {
+        let history = SpecsHistory::from_text("4k7\t5\t\n1,5\t\t\n");
+        assert_eq!(
+            to_csv(&entries(&history)),
+            "resistance,tolerance,tcr,bands,ohm,min_ohm,max_ohm,error\n\
+             4k7,5,,yellow violet red gold,4700,4465,4935,\n\
+             \"1,5\",,,,,,,invalid input for resistance: invalid float literal\n"
+        );
+    }
+
+    #[test]
+    fn test_css_color() {
+        assert_eq!(css_color(Color::Reset), None);
+        assert_eq!(css_color(Color::Red), Some("#cd0000".to_string()));
+        assert_eq!(
+            css_color(Color::Rgb(255, 165, 0)),
+            Some("#ffa500".to_string())
+        );
+        assert_eq!(css_color(Color::Indexed(196)), Some("#ff0000".to_string()));
+    }
+
+    #[test]
+    fn test_chart() {
+        let mut chart = Buffer::empty(Rect::new(0, 0, 4, 1));
+        chart.set_string(0, 0, "<a>", Style::default().bg(Color::Red));
+        assert!(to_html(&chart).contains(
+            "<span style=\"color: #e5e5e5; background: #cd0000\">&lt;a&gt;</span>\
+             <span style=\"color: #e5e5e5; background: #000000\"> </span>\n"
+        ));
+        let svg = to_svg(&chart);
+        assert!(
+            svg.contains("<rect x=\"0\" y=\"0\" width=\"27\" height=\"18\" fill=\"#cd0000\"/>")
+        );
+        assert!(svg.contains(">&lt;a&gt;</text>"));
+        assert_eq!(svg.matches("<text").count(), 1);
+    }
+}
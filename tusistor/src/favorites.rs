@@ -0,0 +1,72 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use rusistor::{Color, Resistor};
+use serde::{Deserialize, Serialize};
+
+use crate::config::data_dir;
+
+/// A named resistor that can be recalled into the color codes tab.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Favorite {
+    pub name: String,
+    pub bands: Vec<Color>,
+}
+
+impl Favorite {
+    pub fn new(name: &str, resistor: &Resistor) -> Favorite {
+        Favorite {
+            name: name.to_string(),
+            bands: resistor.bands().into_iter().cloned().collect(),
+        }
+    }
+
+    pub fn resistor(&self) -> Result<Resistor, String> {
+        Resistor::try_create(self.bands.clone())
+    }
+}
+
+/// `favorites.json` in the data directory.
+pub fn default_path() -> Option<PathBuf> {
+    Some(data_dir()?.join("favorites.json"))
+}
+
+/// Loads the favorites, a missing file is an empty list.
+pub fn load(path: &Path) -> color_eyre::Result<Vec<Favorite>> {
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(serde_json::from_str(&content)?),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+pub fn save(path: &Path, favorites: &[Favorite]) -> color_eyre::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, serde_json::to_string_pretty(favorites)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Favorite, load, save};
+    use rusistor::{Color, Resistor};
+
+    #[test]
+    fn test_save_and_load() {
+        let path = std::env::temp_dir()
+            .join(format!("tusistor-favorites-test-{}", std::process::id()))
+            .join("favorites.json");
+        assert_eq!(load(&path).unwrap(), Vec::new());
+        let resistor =
+            Resistor::try_create(vec![Color::Yellow, Color::Violet, Color::Red]).unwrap();
+        let favorites = vec![Favorite::new("pull-up", &resistor)];
+        save(&path, &favorites).unwrap();
+        assert_eq!(load(&path).unwrap(), favorites);
+        assert_eq!(favorites[0].resistor(), Ok(resistor));
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+}
@@ -0,0 +1,76 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use tusistor_core::model::{HistoryStore, SpecsHistory};
+
+use crate::config::data_dir;
+
+/// `history.txt` in the data directory.
+pub fn default_path() -> Option<PathBuf> {
+    Some(data_dir()?.join("history.txt"))
+}
+
+/// The seconds since the Unix epoch, the clock of the history entries.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+/// The specs history in a text file, one entry per line.
+#[derive(Debug)]
+pub struct HistoryFile {
+    path: PathBuf,
+}
+
+impl HistoryFile {
+    pub fn new(path: PathBuf) -> HistoryFile {
+        HistoryFile { path }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl HistoryStore for HistoryFile {
+    /// A missing file is an empty history.
+    fn load(&self) -> Result<SpecsHistory, String> {
+        match fs::read_to_string(&self.path) {
+            Ok(content) => Ok(SpecsHistory::from_text(&content)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(SpecsHistory::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn save(&self, history: &SpecsHistory) -> Result<(), String> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        }
+        fs::write(&self.path, history.to_text()).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HistoryFile;
+    use tusistor_core::model::{HistoryStore, SpecsHistory};
+
+    #[test]
+    fn test_history_file() {
+        let path = std::env::temp_dir().join(format!("tusistor-history-{}", std::process::id()));
+        let file = HistoryFile::new(path.join("history.txt"));
+        assert!(file.load().unwrap().is_empty());
+
+        let history = SpecsHistory::from_text("4k7\t5\t\t1760000000\n1k\t1\t\n");
+        file.save(&history).unwrap();
+        let loaded = file.load().unwrap();
+        assert_eq!(loaded.to_text(), history.to_text());
+        assert_eq!(loaded.time(0), Some(1760000000));
+        std::fs::remove_dir_all(path).unwrap();
+    }
+}
@@ -0,0 +1,49 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use rusistor::inventory::Inventory;
+
+use crate::config::data_dir;
+
+/// `inventory.json` in the data directory.
+pub fn default_path() -> Option<PathBuf> {
+    Some(data_dir()?.join("inventory.json"))
+}
+
+/// Loads the inventory, a missing file is an empty inventory.
+pub fn load(path: &Path) -> color_eyre::Result<Inventory> {
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(serde_json::from_str(&content)?),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Inventory::default()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+pub fn save(path: &Path, inventory: &Inventory) -> color_eyre::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, serde_json::to_string_pretty(inventory)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{load, save};
+    use rusistor::inventory::Inventory;
+
+    #[test]
+    fn test_save_and_load() {
+        let path = std::env::temp_dir()
+            .join(format!("tusistor-test-{}", std::process::id()))
+            .join("inventory.json");
+        assert_eq!(load(&path).unwrap(), Inventory::default());
+        let mut inventory = Inventory::default();
+        inventory.add(4700.0, 0.05, 3);
+        save(&path, &inventory).unwrap();
+        assert_eq!(load(&path).unwrap(), inventory);
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+}
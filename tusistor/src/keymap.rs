@@ -0,0 +1,300 @@
+use std::collections::BTreeMap;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+pub use tusistor_core::config::{Action, Keys};
+use tusistor_core::key::{self, KeyInput};
+
+/// A key with its modifiers, e.g. "shift+right" or "ctrl+n".
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KeyBinding {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    pub fn parse(input: &str) -> Result<KeyBinding, String> {
+        let mut modifiers = KeyModifiers::NONE;
+        let mut tokens = input.split('+').map(str::trim).peekable();
+        let mut key = "";
+        while let Some(token) = tokens.next() {
+            if tokens.peek().is_none() {
+                key = token;
+                break;
+            }
+            modifiers |= match token.to_lowercase().as_str() {
+                "shift" => KeyModifiers::SHIFT,
+                "ctrl" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                _ => return Err(format!("invalid modifier {} in {}", token, input)),
+            };
+        }
+        let code = match key.to_lowercase().as_str() {
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "enter" => KeyCode::Enter,
+            "esc" => KeyCode::Esc,
+            "tab" => KeyCode::Tab,
+            "backtab" => KeyCode::BackTab,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            "delete" => KeyCode::Delete,
+            "space" => KeyCode::Char(' '),
+            k if k.len() > 1 && k.starts_with('f') => k[1..]
+                .parse::<u8>()
+                .map(KeyCode::F)
+                .map_err(|_| format!("invalid key {}", input))?,
+            _ => {
+                let mut chars = key.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => KeyCode::Char(c),
+                    _ => return Err(format!("invalid key {}", input)),
+                }
+            }
+        };
+        Ok(KeyBinding { code, modifiers })
+    }
+
+    fn matches(&self, key: &KeyEvent) -> bool {
+        // the case of a char and BackTab already imply Shift, terminals differ in reporting it
+        let significant = |code: &KeyCode, modifiers: KeyModifiers| match code {
+            KeyCode::Char(_) | KeyCode::BackTab => modifiers - KeyModifiers::SHIFT,
+            _ => modifiers,
+        };
+        self.code == key.code
+            && significant(&self.code, self.modifiers) == significant(&key.code, key.modifiers)
+    }
+
+    fn code_label(&self) -> String {
+        match self.code {
+            KeyCode::Left => "←".to_string(),
+            KeyCode::Right => "→".to_string(),
+            KeyCode::Up => "↑".to_string(),
+            KeyCode::Down => "↓".to_string(),
+            KeyCode::Delete => "Del".to_string(),
+            KeyCode::PageUp => "PgUp".to_string(),
+            KeyCode::PageDown => "PgDn".to_string(),
+            KeyCode::Char(' ') => "Space".to_string(),
+            code => code.to_string(),
+        }
+    }
+
+    fn modifiers_label(&self) -> String {
+        [
+            (KeyModifiers::CONTROL, "Ctrl"),
+            (KeyModifiers::ALT, "Alt"),
+            (KeyModifiers::SHIFT, "Shift"),
+        ]
+        .iter()
+        .filter(|(m, _)| self.modifiers.contains(*m))
+        .map(|(_, name)| *name)
+        .collect::<Vec<&str>>()
+        .join("+")
+    }
+}
+
+/// The key of a crossterm key event.
+pub fn key_input(key: &KeyEvent) -> KeyInput {
+    let code = match key.code {
+        KeyCode::Char(c) => key::KeyCode::Char(c),
+        KeyCode::F(n) => key::KeyCode::F(n),
+        KeyCode::Backspace => key::KeyCode::Backspace,
+        KeyCode::Enter => key::KeyCode::Enter,
+        KeyCode::Left => key::KeyCode::Left,
+        KeyCode::Right => key::KeyCode::Right,
+        KeyCode::Up => key::KeyCode::Up,
+        KeyCode::Down => key::KeyCode::Down,
+        KeyCode::Tab => key::KeyCode::Tab,
+        KeyCode::BackTab => key::KeyCode::BackTab,
+        KeyCode::Delete => key::KeyCode::Delete,
+        KeyCode::Home => key::KeyCode::Home,
+        KeyCode::End => key::KeyCode::End,
+        KeyCode::PageUp => key::KeyCode::PageUp,
+        KeyCode::PageDown => key::KeyCode::PageDown,
+        KeyCode::Esc => key::KeyCode::Esc,
+        _ => key::KeyCode::Other,
+    };
+    KeyInput {
+        code,
+        ctrl: key.modifiers.contains(KeyModifiers::CONTROL),
+        alt: key.modifiers.contains(KeyModifiers::ALT),
+        shift: key.modifiers.contains(KeyModifiers::SHIFT),
+    }
+}
+
+/// The arrow key of a vim-style key, e.g. `Left` for `h`.
+pub fn vim_arrow(code: KeyCode) -> Option<KeyCode> {
+    match code {
+        KeyCode::Char('h') => Some(KeyCode::Left),
+        KeyCode::Char('j') => Some(KeyCode::Down),
+        KeyCode::Char('k') => Some(KeyCode::Up),
+        KeyCode::Char('l') => Some(KeyCode::Right),
+        _ => None,
+    }
+}
+
+/// The action of a vim-style key, `t` and `T` switch tabs after a `g`.
+/// These keys and the `g` itself take precedence over the type-ahead of the colors.
+pub fn vim_action(code: KeyCode, after_g: bool) -> Option<Action> {
+    match (after_g, code) {
+        (true, KeyCode::Char('t')) => Some(Action::NextTab),
+        (true, KeyCode::Char('T')) => Some(Action::PrevTab),
+        (false, KeyCode::Char('h')) => Some(Action::PrevBand),
+        (false, KeyCode::Char('j')) => Some(Action::NextColor),
+        (false, KeyCode::Char('k')) => Some(Action::PrevColor),
+        (false, KeyCode::Char('l')) => Some(Action::NextBand),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keymap {
+    bindings: BTreeMap<Action, Vec<KeyBinding>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let binding = |code, modifiers| vec![KeyBinding { code, modifiers }];
+        Keymap {
+            bindings: BTreeMap::from([
+                (Action::Exit, binding(KeyCode::Esc, KeyModifiers::NONE)),
+                (
+                    Action::NextTab,
+                    binding(KeyCode::Right, KeyModifiers::SHIFT),
+                ),
+                (Action::PrevTab, binding(KeyCode::Left, KeyModifiers::SHIFT)),
+                (Action::NextBand, binding(KeyCode::Tab, KeyModifiers::NONE)),
+                (
+                    Action::PrevBand,
+                    binding(KeyCode::BackTab, KeyModifiers::NONE),
+                ),
+                (
+                    Action::NextColor,
+                    binding(KeyCode::Down, KeyModifiers::NONE),
+                ),
+                (Action::PrevColor, binding(KeyCode::Up, KeyModifiers::NONE)),
+                (
+                    Action::Determine,
+                    binding(KeyCode::Enter, KeyModifiers::NONE),
+                ),
+                (
+                    Action::Reset,
+                    binding(KeyCode::Char('X'), KeyModifiers::NONE),
+                ),
+            ]),
+        }
+    }
+}
+
+impl Keymap {
+    /// The default keymap with the keys of the given actions replaced.
+    pub fn with_overrides(overrides: &BTreeMap<Action, Keys>) -> Result<Keymap, String> {
+        let mut keymap = Keymap::default();
+        for (action, keys) in overrides {
+            let keys = match keys {
+                Keys::One(key) => std::slice::from_ref(key),
+                Keys::Many(keys) => keys.as_slice(),
+            };
+            let bindings = keys
+                .iter()
+                .map(|k| KeyBinding::parse(k))
+                .collect::<Result<Vec<KeyBinding>, String>>()?;
+            keymap.bindings.insert(*action, bindings);
+        }
+        Ok(keymap)
+    }
+
+    pub fn action(&self, key: &KeyEvent) -> Option<Action> {
+        self.bindings
+            .iter()
+            .find(|(_, bindings)| bindings.iter().any(|b| b.matches(key)))
+            .map(|(action, _)| *action)
+    }
+
+    /// The label of the first key of an action for the help lines, e.g. "Ctrl+N".
+    pub fn label(&self, action: Action) -> String {
+        match self.bindings.get(&action).and_then(|b| b.first()) {
+            Some(b) if b.modifiers.is_empty() => b.code_label(),
+            Some(b) => format!("{}+{}", b.modifiers_label(), b.code_label()),
+            None => "-".to_string(),
+        }
+    }
+
+    /// The label of two related actions, e.g. "Shift ←/→".
+    pub fn pair_label(&self, first: Action, second: Action) -> String {
+        let binding = |action| self.bindings.get(&action).and_then(|b| b.first());
+        match (binding(first), binding(second)) {
+            (Some(a), Some(b)) if a.modifiers == b.modifiers && !a.modifiers.is_empty() => {
+                format!(
+                    "{} {}/{}",
+                    a.modifiers_label(),
+                    a.code_label(),
+                    b.code_label()
+                )
+            }
+            (Some(a), Some(b)) if a.modifiers == b.modifiers => {
+                format!("{}/{}", a.code_label(), b.code_label())
+            }
+            _ => format!("{}/{}", self.label(first), self.label(second)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Action, KeyBinding, Keymap, Keys, vim_action, vim_arrow};
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            KeyBinding::parse("ctrl+alt+n"),
+            Ok(KeyBinding {
+                code: KeyCode::Char('n'),
+                modifiers: KeyModifiers::CONTROL | KeyModifiers::ALT,
+            })
+        );
+        assert_eq!(KeyBinding::parse("F2").map(|b| b.code), Ok(KeyCode::F(2)));
+        assert!(KeyBinding::parse("hyper+x").is_err());
+        assert!(KeyBinding::parse("nope").is_err());
+    }
+
+    #[test]
+    fn test_vim_action() {
+        assert_eq!(
+            vim_action(KeyCode::Char('j'), false),
+            Some(Action::NextColor)
+        );
+        assert_eq!(vim_action(KeyCode::Char('T'), true), Some(Action::PrevTab));
+        assert_eq!(vim_action(KeyCode::Char('t'), false), None);
+        assert_eq!(vim_action(KeyCode::Char('j'), true), None);
+        assert_eq!(vim_arrow(KeyCode::Char('k')), Some(KeyCode::Up));
+        assert_eq!(vim_arrow(KeyCode::Char('g')), None);
+    }
+
+    #[test]
+    fn test_overrides() {
+        let overrides = BTreeMap::from([
+            (Action::NextTab, Keys::One("alt+l".to_string())),
+            (
+                Action::PrevTab,
+                Keys::Many(vec!["alt+h".to_string(), "f1".to_string()]),
+            ),
+        ]);
+        let keymap = Keymap::with_overrides(&overrides).unwrap();
+        let alt_l = KeyEvent::new(KeyCode::Char('l'), KeyModifiers::ALT);
+        let shift_right = KeyEvent::new(KeyCode::Right, KeyModifiers::SHIFT);
+        assert_eq!(keymap.action(&alt_l), Some(Action::NextTab));
+        assert_eq!(keymap.action(&shift_right), None);
+        assert_eq!(
+            keymap.pair_label(Action::PrevTab, Action::NextTab),
+            "Alt h/l"
+        );
+        assert_eq!(
+            Keymap::default().pair_label(Action::PrevTab, Action::NextTab),
+            "Shift ←/→"
+        );
+    }
+}
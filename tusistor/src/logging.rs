@@ -0,0 +1,28 @@
+use std::{
+    fs::{self, OpenOptions},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use tracing::Level;
+
+use crate::config::data_dir;
+
+/// `tusistor.log` in the data directory.
+pub fn default_path() -> Option<PathBuf> {
+    Some(data_dir()?.join("tusistor.log"))
+}
+
+/// Appends the events of the level and the more severe ones to the file.
+pub fn init(path: &Path, level: Level) -> color_eyre::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let file = OpenOptions::new().create(true).append(true).open(path)?;
+    tracing_subscriber::fmt()
+        .with_writer(Mutex::new(file))
+        .with_max_level(level)
+        .try_init()
+        .map_err(|e| color_eyre::eyre::eyre!(e))?;
+    Ok(())
+}
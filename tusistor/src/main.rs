@@ -1,24 +1,89 @@
+pub mod announce;
+pub mod bom;
+pub mod cli;
+pub mod clipboard;
+pub mod commands;
+pub mod config;
+pub mod export;
+pub mod favorites;
+pub mod history;
+pub mod inventory;
+pub mod keymap;
+pub mod logging;
 pub mod model;
+pub mod quiz_stats;
+pub mod session;
 pub mod update;
+pub mod value_search;
 pub mod view;
 
+use clap::Parser;
+use cli::Cli;
 use color_eyre::eyre::Ok;
+use crossterm::{
+    ExecutableCommand,
+    event::{DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture},
+};
 use model::Model;
-use update::{handle_event, update};
+use ratatui::DefaultTerminal;
+use update::{handle_event, update_traced};
 use view::view;
 
 fn main() -> color_eyre::Result<()> {
     color_eyre::install()?;
+    let cli = Cli::try_parse().unwrap_or_else(|e| {
+        let _ = e.print();
+        std::process::exit(if e.use_stderr() {
+            cli::exit_code::USAGE_ERROR
+        } else {
+            cli::exit_code::OK
+        })
+    });
+    let monochrome = cli.is_monochrome();
+    if let Some(command) = cli.command {
+        std::process::exit(cli::run(command, cli.output, monochrome));
+    }
+    if let Some(path) = &cli.export_history {
+        std::process::exit(cli::export_history(path));
+    }
+    if cli.is_pipe_mode() {
+        std::process::exit(cli::run_pipe(cli.output));
+    }
+
+    if let Some(level) = cli.log {
+        let path = logging::default_path()
+            .ok_or_else(|| color_eyre::eyre::eyre!("no data directory for the log file"))?;
+        logging::init(&path, level)?;
+    }
+    let mut model = cli.init_model()?;
+
     let mut terminal = ratatui::init();
-    let mut model = Model::default();
+    let result = run(&mut terminal, &mut model);
+    // restored on errors, too, the shell is unusable otherwise
+    let _ = std::io::stdout()
+        .execute(DisableBracketedPaste)
+        .and_then(|stdout| stdout.execute(DisableMouseCapture));
+    ratatui::restore();
+    result?;
+    for text in &model.shared {
+        println!("{}", text);
+    }
+    if let Some(path) = &model.session_path {
+        session::save(path, &model.session())?;
+    }
+    Ok(())
+}
 
+/// Runs the UI until it exits, the caller restores the terminal.
+fn run(terminal: &mut DefaultTerminal, model: &mut Model) -> color_eyre::Result<()> {
+    std::io::stdout()
+        .execute(EnableMouseCapture)?
+        .execute(EnableBracketedPaste)?;
     while model.running {
-        terminal.draw(|f| view(&mut model, f))?;
-        if let Some(msg) = handle_event(&mut model)? {
-            update(&mut model, msg)
+        terminal.draw(|f| view(model, f))?;
+        if let Some(msg) = handle_event(model)? {
+            update_traced(model, msg)
         }
     }
-
-    ratatui::restore();
     Ok(())
 }
@@ -1,20 +1,302 @@
-use tusistor_core::model::{ColorCodesToSpecsModel, SelectedTab, SpecsToColorModel};
+use std::{collections::VecDeque, path::PathBuf, time::Instant};
+
+use ratatui::layout::Rect;
+use ratatui_textarea::TextArea;
+use rusistor::Resistor;
+use tusistor_core::config::Config;
+use tusistor_core::i18n::Language;
+use tusistor_core::model::{
+    AppModel, CapacitorModel, ColorCodesToSpecsModel, CompareModel, DividerModel, ESeriesModel,
+    InductorModel, InventoryModel, LedModel, NetworkModel, OhmsLawModel, QuizModel, SelectedTab,
+    SpecsToColorModel,
+};
+use tusistor_core::session::Session;
+use tusistor_core::theme::{Palette, PaletteOverrides, Theme};
+use tusistor_core::update::update_on_colorcodemsg;
+
+use crate::{
+    bom::BomLine, clipboard::Clipboard, favorites::Favorite, history::HistoryFile, keymap::Keymap,
+    quiz_stats::QuizStats,
+};
+
+pub const TABS: [SelectedTab; 12] = [
+    SelectedTab::ColorCodesToSpecs,
+    SelectedTab::SpecsToColorCodes,
+    SelectedTab::ESeries,
+    SelectedTab::Network,
+    SelectedTab::OhmsLaw,
+    SelectedTab::Divider,
+    SelectedTab::Led,
+    SelectedTab::Quiz,
+    SelectedTab::Capacitor,
+    SelectedTab::Inductor,
+    SelectedTab::Compare,
+    SelectedTab::Inventory,
+];
+
+/// The popup to name a new favorite or to pick one to recall.
+#[derive(Debug)]
+pub enum FavoritesPopup<'a> {
+    Add {
+        resistor: Resistor,
+        name_textarea: Box<TextArea<'a>>,
+    },
+    List {
+        selected: usize,
+    },
+}
+
+/// The resistor lines of an imported BOM, to pick one to load into the specs tab.
+#[derive(Debug)]
+pub struct BomPopup {
+    pub path: PathBuf,
+    pub lines: Vec<BomLine>,
+    pub selected: usize,
+}
+
+/// The prompt that runs the commands of `commands::COMMANDS` by name.
+#[derive(Debug, Default)]
+pub struct CommandPalette<'a> {
+    pub textarea: TextArea<'a>,
+    /// The index of the selected command in the matches of the input.
+    pub selected: usize,
+    pub error: Option<String>,
+}
+
+/// The popup that finds a value of the E-series by its digits, see `value_search::matches`.
+#[derive(Debug, Default)]
+pub struct ValueSearch<'a> {
+    pub textarea: TextArea<'a>,
+    /// The index of the selected value in the matches of the input.
+    pub selected: usize,
+}
+
+/// The areas of the last drawn frame that react to mouse clicks.
+#[derive(Debug, Default)]
+pub struct HitAreas {
+    pub tabs: Vec<(Rect, SelectedTab)>,
+    /// The area and the scroll offset of each band list.
+    pub bands: Vec<(Rect, usize)>,
+}
 
 #[derive(Debug)]
 pub struct Model<'a> {
     pub running: bool,
     pub selected_tab: SelectedTab,
+    pub theme: Theme,
+    /// The colors of the config file that replace the ones of the theme.
+    pub palette_overrides: PaletteOverrides,
+    pub monochrome: bool,
+    /// Shows the codes and patterns of the bands next to their colors.
+    pub accessible: bool,
+    /// Shows resistances with SI prefixes, e.g. 22 kΩ instead of 22000.
+    pub si_units: bool,
+    /// Shows the hex values and the other names of the colors in the band lists.
+    pub color_details: bool,
+    /// Describes every change in plain text on the last row, for screen readers.
+    pub screen_reader: bool,
+    /// The description of the last change in the screen reader mode.
+    pub announcement: Option<String>,
+    pub language: Language,
+    pub keymap: Keymap,
+    pub vim_keys: bool,
+    /// The `g` of the vim keys `gt` and `gT` was pressed.
+    pub pending_g: bool,
     pub specs_to_color: SpecsToColorModel<'a>,
     pub color_codes_to_specs: ColorCodesToSpecsModel,
+    pub eseries: ESeriesModel<'a>,
+    pub network: NetworkModel<'a>,
+    pub ohms_law: OhmsLawModel<'a>,
+    pub divider: DividerModel<'a>,
+    pub led: LedModel<'a>,
+    pub quiz: QuizModel<'a>,
+    pub quiz_stats: QuizStats,
+    /// Where the quiz stats are stored, `None` keeps them in memory only.
+    pub quiz_stats_path: Option<PathBuf>,
+    /// When the current quiz question was asked, to time the answer.
+    pub quiz_asked_at: Instant,
+    /// Shows the quiz stats instead of the question.
+    pub show_quiz_stats: bool,
+    pub capacitor: CapacitorModel,
+    pub inductor: InductorModel,
+    pub compare: CompareModel,
+    pub inventory: InventoryModel<'a>,
+    /// Where the inventory is stored, `None` keeps it in memory only.
+    pub inventory_path: Option<PathBuf>,
+    /// Where the specs history is stored, `None` keeps it in memory only.
+    pub history_file: Option<HistoryFile>,
+    pub favorites: Vec<Favorite>,
+    /// Where the favorites are stored, `None` keeps them in memory only.
+    pub favorites_path: Option<PathBuf>,
+    pub favorites_popup: Option<FavoritesPopup<'a>>,
+    /// Where the session is stored on exit, `None` if it isn't restored.
+    pub session_path: Option<PathBuf>,
+    pub command_palette: Option<CommandPalette<'a>>,
+    pub value_search: Option<ValueSearch<'a>>,
+    pub bom_popup: Option<BomPopup>,
+    pub hit_areas: HitAreas,
+    /// Set by the view when the terminal is too short for the band lists, ←/→ change the colors then.
+    pub compact_bands: bool,
+    pub clipboard: Clipboard,
+    /// The shared summaries, printed after the UI is closed.
+    pub shared: Vec<String>,
+    /// A message about the outcome of the last action, e.g. a copy to the clipboard.
+    pub status: Option<String>,
+    /// The ticks since the status was set, it's dismissed after `STATUS_TICKS`.
+    pub status_ticks: u32,
+    /// The last keys, messages and state transitions, the oldest first.
+    pub debug_log: VecDeque<String>,
+    /// Shows the `debug_log` over the selected tab.
+    pub show_debug: bool,
 }
 
+/// The count of entries the debug pane keeps.
+pub const DEBUG_LOG_LEN: usize = 20;
+
 impl<'a> Default for Model<'a> {
     fn default() -> Model<'a> {
         Model {
             running: true,
             selected_tab: SelectedTab::default(),
+            theme: Theme::default(),
+            palette_overrides: PaletteOverrides::default(),
+            monochrome: false,
+            accessible: false,
+            si_units: true,
+            color_details: false,
+            screen_reader: false,
+            announcement: None,
+            language: Language::default(),
+            keymap: Keymap::default(),
+            vim_keys: false,
+            pending_g: false,
             specs_to_color: SpecsToColorModel::default(),
             color_codes_to_specs: ColorCodesToSpecsModel::default(),
+            eseries: ESeriesModel::default(),
+            network: NetworkModel::default(),
+            ohms_law: OhmsLawModel::default(),
+            divider: DividerModel::default(),
+            led: LedModel::default(),
+            quiz: QuizModel::new(seed()),
+            quiz_stats: QuizStats::default(),
+            quiz_stats_path: None,
+            quiz_asked_at: Instant::now(),
+            show_quiz_stats: false,
+            capacitor: CapacitorModel::default(),
+            inductor: InductorModel::default(),
+            compare: CompareModel::default(),
+            inventory: InventoryModel::default(),
+            inventory_path: None,
+            history_file: None,
+            favorites: Vec::new(),
+            favorites_path: None,
+            favorites_popup: None,
+            session_path: None,
+            command_palette: None,
+            value_search: None,
+            bom_popup: None,
+            hit_areas: HitAreas::default(),
+            compact_bands: false,
+            clipboard: Clipboard::default(),
+            shared: Vec::new(),
+            status: None,
+            status_ticks: 0,
+            debug_log: VecDeque::new(),
+            show_debug: false,
+        }
+    }
+}
+
+fn seed() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or_default()
+}
+
+impl<'a> Model<'a> {
+    pub fn palette(&self) -> Palette {
+        Palette {
+            monochrome: self.monochrome,
+            accessible: self.accessible,
+            color_details: self.color_details,
+            ..self.theme.palette().with_overrides(&self.palette_overrides)
+        }
+    }
+
+    /// Logs the entry and keeps it for the debug pane.
+    pub fn trace(&mut self, entry: String) {
+        tracing::debug!("{}", entry);
+        if self.debug_log.len() == DEBUG_LOG_LEN {
+            self.debug_log.pop_front();
         }
+        self.debug_log.push_back(entry);
+    }
+
+    pub fn session(&self) -> Session {
+        Session::new(
+            self.selected_tab,
+            &self.color_codes_to_specs,
+            &self.specs_to_color,
+        )
+    }
+
+    pub fn new(config: &Config) -> Model<'a> {
+        let mut model = Model::default();
+        if let Some(tab) = config.tab {
+            model.selected_tab = tab;
+        }
+        if let Some(theme) = config.theme {
+            model.theme = theme;
+        }
+        model.palette_overrides = config.palette_overrides().unwrap_or_default();
+        model.screen_reader = config.screen_reader.unwrap_or_default();
+        model.monochrome = config.no_color.unwrap_or_default() || model.screen_reader;
+        model.accessible = config.accessible.unwrap_or_default();
+        model.si_units = config.si_units.unwrap_or(true);
+        model.color_details = config.color_details.unwrap_or_default();
+        model.language = config.language.unwrap_or_default();
+        model.keymap = Keymap::with_overrides(&config.keys).unwrap_or_default();
+        model.vim_keys = config.vim_keys.unwrap_or_default();
+        if let Some(msg) = config.bands_msg() {
+            update_on_colorcodemsg(&mut model.color_codes_to_specs, msg);
+            // the configured bands count isn't an edit to undo
+            model.color_codes_to_specs.undo.clear();
+        }
+        model
+    }
+}
+
+impl<'a> AppModel<'a> for Model<'a> {
+    fn tabs(&self) -> &[SelectedTab] {
+        &TABS
+    }
+
+    fn selected_tab(&self) -> SelectedTab {
+        self.selected_tab
+    }
+
+    fn set_selected_tab(&mut self, tab: SelectedTab) {
+        self.selected_tab = tab;
+    }
+
+    fn color_codes_to_specs_mut(&mut self) -> &mut ColorCodesToSpecsModel {
+        &mut self.color_codes_to_specs
+    }
+
+    fn specs_to_color(&self) -> &SpecsToColorModel<'a> {
+        &self.specs_to_color
+    }
+
+    fn specs_to_color_mut(&mut self) -> &mut SpecsToColorModel<'a> {
+        &mut self.specs_to_color
+    }
+
+    fn quiz(&self) -> &QuizModel<'a> {
+        &self.quiz
+    }
+
+    fn quiz_mut(&mut self) -> &mut QuizModel<'a> {
+        &mut self.quiz
     }
 }
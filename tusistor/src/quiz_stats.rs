@@ -0,0 +1,173 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use rusistor::{Color, Resistor};
+use serde::{Deserialize, Serialize};
+
+use crate::config::data_dir;
+
+/// Longer answers were likely interrupted and count as this long.
+const MAX_ANSWER_TIME: Duration = Duration::from_secs(60);
+
+/// The answers to a kind of question and the time they took.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Tally {
+    pub asked: u32,
+    pub correct: u32,
+    pub millis: u64,
+}
+
+impl Tally {
+    fn record(&mut self, is_correct: bool, elapsed: Duration) {
+        self.asked += 1;
+        self.correct += is_correct as u32;
+        self.millis += elapsed.min(MAX_ANSWER_TIME).as_millis() as u64;
+    }
+
+    /// The share of correct answers in percent.
+    pub fn accuracy(&self) -> f64 {
+        if self.asked == 0 {
+            0.0
+        } else {
+            self.correct as f64 * 100.0 / self.asked as f64
+        }
+    }
+
+    /// The mean time of an answer in seconds.
+    pub fn mean_seconds(&self) -> f64 {
+        if self.asked == 0 {
+            0.0
+        } else {
+            self.millis as f64 / 1000.0 / self.asked as f64
+        }
+    }
+}
+
+/// The quiz answers of all runs by band color and bands count, and the days practiced in a row.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuizStats {
+    pub total: Tally,
+    pub colors: HashMap<Color, Tally>,
+    pub bands: BTreeMap<usize, Tally>,
+    /// The day of the last answer, counted from the unix epoch.
+    pub last_day: Option<u64>,
+    pub day_streak: u32,
+    pub best_day_streak: u32,
+}
+
+impl QuizStats {
+    /// Counts an answer to the question for each of its colors and its bands count.
+    pub fn record(&mut self, question: &Resistor, is_correct: bool, elapsed: Duration, day: u64) {
+        self.total.record(is_correct, elapsed);
+        let bands = question.bands();
+        self.bands
+            .entry(bands.len())
+            .or_default()
+            .record(is_correct, elapsed);
+        // a color of several bands counts once
+        for color in Color::ALL.iter().filter(|c| bands.contains(c)) {
+            self.colors
+                .entry(*color)
+                .or_default()
+                .record(is_correct, elapsed);
+        }
+
+        self.day_streak = match self.last_day {
+            Some(last) if last == day => self.day_streak,
+            Some(last) if last + 1 == day => self.day_streak + 1,
+            _ => 1,
+        };
+        self.best_day_streak = self.best_day_streak.max(self.day_streak);
+        self.last_day = Some(day);
+    }
+
+    /// The days in a row up to today, 0 if the last answer was before yesterday.
+    pub fn current_day_streak(&self, today: u64) -> u32 {
+        match self.last_day {
+            Some(last) if last + 1 >= today => self.day_streak,
+            _ => 0,
+        }
+    }
+}
+
+/// The current day counted from the unix epoch, in UTC.
+pub fn today() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() / 86400)
+        .unwrap_or_default()
+}
+
+/// `quiz-stats.json` in the data directory.
+pub fn default_path() -> Option<PathBuf> {
+    Some(data_dir()?.join("quiz-stats.json"))
+}
+
+/// Loads the stats, a missing file is no answers yet.
+pub fn load(path: &Path) -> color_eyre::Result<QuizStats> {
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(serde_json::from_str(&content)?),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(QuizStats::default()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+pub fn save(path: &Path, stats: &QuizStats) -> color_eyre::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, serde_json::to_string_pretty(stats)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{QuizStats, load, save};
+    use rusistor::{Color, Resistor};
+    use std::time::Duration;
+
+    #[test]
+    fn test_record() {
+        let mut stats = QuizStats::default();
+        let question =
+            Resistor::try_create(vec![Color::Brown, Color::Black, Color::Brown]).unwrap();
+        stats.record(&question, true, Duration::from_secs(4), 100);
+        stats.record(&question, false, Duration::from_secs(600), 101);
+        assert_eq!(stats.total.asked, 2);
+        assert_eq!(stats.total.accuracy(), 50.0);
+        assert_eq!(stats.total.mean_seconds(), 32.0);
+        assert_eq!(stats.colors[&Color::Brown].asked, 2);
+        assert_eq!(stats.colors[&Color::Black].correct, 1);
+        assert_eq!(stats.bands[&3].asked, 2);
+        assert_eq!(stats.day_streak, 2);
+
+        stats.record(&question, true, Duration::from_secs(1), 101);
+        assert_eq!(stats.day_streak, 2);
+        assert_eq!(stats.current_day_streak(102), 2);
+        assert_eq!(stats.current_day_streak(103), 0);
+        stats.record(&question, true, Duration::from_secs(1), 105);
+        assert_eq!(stats.day_streak, 1);
+        assert_eq!(stats.best_day_streak, 2);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let path = std::env::temp_dir()
+            .join(format!("tusistor-quiz-stats-test-{}", std::process::id()))
+            .join("quiz-stats.json");
+        assert_eq!(load(&path).unwrap(), QuizStats::default());
+        let mut stats = QuizStats::default();
+        let question =
+            Resistor::try_create(vec![Color::Yellow, Color::Violet, Color::Red, Color::Gold])
+                .unwrap();
+        stats.record(&question, true, Duration::from_millis(2500), 20000);
+        save(&path, &stats).unwrap();
+        assert_eq!(load(&path).unwrap(), stats);
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+}
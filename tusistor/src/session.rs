@@ -0,0 +1,71 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use tusistor_core::session::{Session, Versioned};
+
+use crate::config::data_dir;
+
+/// `session.json` in the data directory.
+pub fn default_path() -> Option<PathBuf> {
+    Some(data_dir()?.join("session.json"))
+}
+
+/// Loads the session of the last run, `None` if there is none.
+pub fn load(path: &Path) -> color_eyre::Result<Option<Session>> {
+    match fs::read_to_string(path) {
+        Ok(content) => {
+            let value = serde_json::from_str::<serde_json::Value>(&content)?;
+            // the sessions of older releases have no version
+            let session = if value.get("version").is_some() {
+                serde_json::from_value::<Versioned<Session>>(value)?
+                    .into_session()
+                    .map_err(|e| color_eyre::eyre::eyre!(e))?
+            } else {
+                serde_json::from_value(value)?
+            };
+            Ok(Some(session))
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+pub fn save(path: &Path, session: &Session) -> color_eyre::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, serde_json::to_string_pretty(&session.versioned())?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{load, save};
+    use tusistor_core::{model::SelectedTab, session::Session};
+
+    #[test]
+    fn test_save_and_load() {
+        let path = std::env::temp_dir()
+            .join(format!("tusistor-session-test-{}", std::process::id()))
+            .join("session.json");
+        assert_eq!(load(&path).unwrap(), None);
+        let session = Session {
+            tab: SelectedTab::Compare,
+            resistance: "4k7".to_string(),
+            ..Session::default()
+        };
+        save(&path, &session).unwrap();
+        assert_eq!(load(&path).unwrap(), Some(session));
+        std::fs::write(&path, r#"{"tab": "specs", "resistance": "1k"}"#).unwrap();
+        assert_eq!(load(&path).unwrap().unwrap().resistance, "1k");
+        std::fs::write(&path, r#"{"version": 2, "state": {}}"#).unwrap();
+        assert!(load(&path).is_err());
+        std::fs::write(&path, r#"{"version": 1, "state": {"tab": 5}}"#).unwrap();
+        assert!(load(&path).is_err());
+        std::fs::write(&path, r#"{"version": 1, "state": "#).unwrap();
+        assert!(load(&path).is_err());
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+}
@@ -1,100 +1,1498 @@
-use crate::model::Model;
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
-use tusistor_core::model::{InputFocus, SelectedTab};
-use tusistor_core::update::{ColorCodesMsg, SpecsMsg, update_on_colorcodemsg, update_on_specsmsg};
+use std::{
+    path::PathBuf,
+    time::{Duration, Instant},
+};
 
+use crate::{
+    announce::{Reading, announcement},
+    bom, commands, export,
+    favorites::{self, Favorite},
+    inventory,
+    keymap::{Action, key_input, vim_action, vim_arrow},
+    model::{BomPopup, CommandPalette, FavoritesPopup, Model, ValueSearch},
+    quiz_stats,
+    value_search::{self, StandardValue},
+    view::{chart_buffer, format_combined},
+};
+use crossterm::event::{
+    self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent,
+    MouseEventKind,
+};
+use ratatui::layout::Position;
+use ratatui_textarea::TextArea;
+use rusistor::{Resistor, eseries::ESeries, network::Combined};
+use tusistor_core::model::{
+    CompareSide, DividerFocus, ESERIES_DECADES, HistoryStore, InputFocus, NetworkFocus,
+    OhmsLawFocus, QuizMode, SelectedTab,
+};
+use tusistor_core::theme::Theme;
+use tusistor_core::update::{
+    CapacitorMsg, ColorCodesMsg, CompareMsg, DividerMsg, ESeriesMsg, InductorMsg, InventoryMsg,
+    LedMsg, Msg as SharedMsg, NetworkMsg, OhmsLawMsg, QuizMsg, SpecsMsg, handle_key,
+    try_parse_resistance, update_on_capacitormsg, update_on_colorcodemsg, update_on_comparemsg,
+    update_on_dividermsg, update_on_eseriesmsg, update_on_inductormsg, update_on_inventorymsg,
+    update_on_ledmsg, update_on_msg, update_on_networkmsg, update_on_ohmslawmsg,
+};
+use tusistor_core::view::{format_ohm, resistor_summary, share_text};
+
+#[derive(Debug)]
 pub enum Msg {
-    ToggleTab,
+    /// The messages the web version has, too.
+    Shared {
+        msg: SharedMsg,
+    },
     Exit,
-    SpecsMsg { msg: SpecsMsg },
-    ColorCodesMsg { msg: ColorCodesMsg },
+    ESeriesMsg {
+        msg: ESeriesMsg,
+    },
+    NetworkMsg {
+        msg: NetworkMsg,
+    },
+    OhmsLawMsg {
+        msg: OhmsLawMsg,
+    },
+    DividerMsg {
+        msg: DividerMsg,
+    },
+    LedMsg {
+        msg: LedMsg,
+    },
+    CapacitorMsg {
+        msg: CapacitorMsg,
+    },
+    InductorMsg {
+        msg: InductorMsg,
+    },
+    CompareMsg {
+        msg: CompareMsg,
+    },
+    InventoryMsg {
+        msg: InventoryMsg,
+    },
+    FavoritesMsg {
+        msg: FavoritesMsg,
+    },
+    CommandPaletteMsg {
+        msg: CommandPaletteMsg,
+    },
+    BomMsg {
+        msg: BomMsg,
+    },
+    ValueSearchMsg {
+        msg: ValueSearchMsg,
+    },
+    ShowColorCodes {
+        resistor: Resistor,
+    },
+    Copy,
+    /// Copies a one-line summary and keeps it to print on exit, for terminals without a clipboard.
+    Share,
+    NextTheme,
+    SetTheme {
+        theme: Theme,
+    },
+    ToggleAccessible,
+    ToggleSiUnits,
+    Paste {
+        text: String,
+    },
+    PasteClipboard,
+    ExportHistory {
+        path: PathBuf,
+    },
+    /// Writes the chart of the current resistor as SVG or HTML.
+    ExportChart {
+        path: PathBuf,
+    },
+    ToggleDebug,
+    ToggleQuizStats,
+    /// Adds the resistor of the tab to the selected group of the series/parallel tab.
+    AddToNetwork,
+    /// Sent every `TICK_RATE` without other events.
+    Tick,
+}
+
+impl From<SharedMsg> for Msg {
+    fn from(msg: SharedMsg) -> Msg {
+        Msg::Shared { msg }
+    }
+}
+
+/// How long to wait for an event before a `Msg::Tick`.
+pub const TICK_RATE: Duration = Duration::from_millis(250);
+
+/// The ticks a status is shown for.
+pub const STATUS_TICKS: u32 = 16;
+
+#[derive(Debug)]
+pub enum FavoritesMsg {
+    /// Asks for a name for the resistor of the selected tab.
+    Bookmark,
+    Save,
+    Open,
+    Close,
+    Next,
+    Prev,
+    /// Shows the selected favorite in the color codes tab.
+    Recall,
+    Remove,
+}
+
+#[derive(Debug)]
+pub enum CommandPaletteMsg {
+    Open,
+    Close,
+    Next,
+    Prev,
+    /// Replaces the input with the name of the selected command.
+    Complete,
+    /// Runs the input, or the selected command if the input isn't one.
+    Run,
 }
 
+#[derive(Debug)]
+pub enum BomMsg {
+    /// Reads the resistor lines of a BOM file and lists them.
+    Import {
+        path: PathBuf,
+    },
+    Close,
+    Next,
+    Prev,
+    NextPage,
+    PrevPage,
+    /// Determines the selected line in the specs tab.
+    Load,
+}
+
+#[derive(Debug)]
+pub enum ValueSearchMsg {
+    Open,
+    Close,
+    Next,
+    Prev,
+    /// Loads the selected value into the selected tab.
+    Load,
+}
+
+/// The lines `BomMsg::NextPage` and `BomMsg::PrevPage` move.
+const BOM_PAGE: usize = 10;
+
 pub fn handle_event(model: &mut Model) -> color_eyre::Result<Option<Msg>> {
+    if !event::poll(TICK_RATE)? {
+        return Ok(Some(Msg::Tick));
+    }
     match event::read()? {
         // it's important to check KeyEventKind::Press to avoid handling key release events
-        Event::Key(key) if key.kind == KeyEventKind::Press => Result::Ok(on_key_event(model, key)),
+        Event::Key(key) if key.kind == KeyEventKind::Press => {
+            if key.modifiers.is_empty() {
+                model.trace(format!("key {}", key.code));
+            } else {
+                model.trace(format!("key {}+{}", key.modifiers, key.code));
+            }
+            Result::Ok(on_key_event(model, key))
+        }
+        Event::Mouse(mouse) => Result::Ok(on_mouse_event(model, mouse)),
+        Event::Paste(text) => Result::Ok(Some(Msg::Paste { text })),
         _ => Result::Ok(None),
     }
 }
 
 fn on_key_event(model: &mut Model, key: KeyEvent) -> Option<Msg> {
+    if model.selected_tab == SelectedTab::SpecsToColorCodes && model.specs_to_color.search.is_some()
+    {
+        return on_search_key_event(key).map(|msg| Msg::Shared {
+            msg: SharedMsg::SpecsMsg { msg },
+        });
+    }
+    if model.favorites_popup.is_some() {
+        return on_favorites_key_event(model, key).map(|msg| Msg::FavoritesMsg { msg });
+    }
+    if model.command_palette.is_some() {
+        return on_command_palette_key_event(model, key).map(|msg| Msg::CommandPaletteMsg { msg });
+    }
+    if model.bom_popup.is_some() {
+        return on_bom_key_event(key).map(|msg| Msg::BomMsg { msg });
+    }
+    if model.value_search.is_some() {
+        return on_value_search_key_event(model, key).map(|msg| Msg::ValueSearchMsg { msg });
+    }
+    // like the palette, `/` is text in the inputs and `Ctrl+F` opens the search everywhere
+    if (key.code == KeyCode::Char('/') && focused_textarea(model).is_none())
+        || (key.code == KeyCode::Char('f') && key.modifiers == KeyModifiers::CONTROL)
+    {
+        return Some(Msg::ValueSearchMsg {
+            msg: ValueSearchMsg::Open,
+        });
+    }
+    // `:` is text in the inputs, `Ctrl+P` opens the palette everywhere
+    if (key.code == KeyCode::Char(':') && focused_textarea(model).is_none())
+        || (key.code == KeyCode::Char('p') && key.modifiers == KeyModifiers::CONTROL)
+    {
+        return Some(Msg::CommandPaletteMsg {
+            msg: CommandPaletteMsg::Open,
+        });
+    }
+    if model.selected_tab == SelectedTab::SpecsToColorCodes
+        && model.specs_to_color.error.is_some()
+        && matches!(key.code, KeyCode::Esc | KeyCode::Enter)
+    {
+        return Some(Msg::Shared {
+            msg: SharedMsg::SpecsMsg {
+                msg: SpecsMsg::DismissError,
+            },
+        });
+    }
+    // the letters are text in the inputs of the specs tab, so its vim keys need Alt
+    if model.vim_keys
+        && model.selected_tab == SelectedTab::SpecsToColorCodes
+        && key.modifiers == KeyModifiers::ALT
+        && let Some(code) = vim_arrow(key.code)
+    {
+        return on_key_event(model, KeyEvent::new(code, KeyModifiers::NONE));
+    }
+    // h/j/k/l and g aren't jumps to colors with the vim keys, black is still on `b`
+    if model.vim_keys
+        && is_band_tab(&model.selected_tab)
+        && (key.modifiers - KeyModifiers::SHIFT).is_empty()
+    {
+        let after_g = std::mem::take(&mut model.pending_g);
+        if !after_g && key.code == KeyCode::Char('g') {
+            model.pending_g = true;
+            return None;
+        }
+        if let Some(action) = vim_action(key.code, after_g) {
+            return on_action(model, action);
+        }
+    }
+    if let Some(msg) = model
+        .keymap
+        .action(&key)
+        .and_then(|action| on_action(model, action))
+    {
+        return Some(msg);
+    }
+    // the keys both frontends share, the ones of the terminal above take precedence
+    let shared = handle_key(&*model, &key_input(&key)).map(Msg::from);
     match (&model.selected_tab, key.code) {
-        (_, KeyCode::Esc) => Some(Msg::Exit),
-        (_, KeyCode::Left) | (_, KeyCode::Right) if key.modifiers == KeyModifiers::SHIFT => {
-            Some(Msg::ToggleTab)
+        (_, KeyCode::Char('c')) if key.modifiers == KeyModifiers::CONTROL => Some(Msg::Copy),
+        (_, KeyCode::F(2)) => Some(Msg::NextTheme),
+        (_, KeyCode::F(3)) => Some(Msg::ToggleAccessible),
+        (_, KeyCode::F(4)) => Some(Msg::ToggleSiUnits),
+        (_, KeyCode::F(5)) => Some(Msg::Share),
+        (_, KeyCode::F(12)) => Some(Msg::ToggleDebug),
+        (_, KeyCode::Char('v')) if key.modifiers == KeyModifiers::CONTROL => {
+            Some(Msg::PasteClipboard)
         }
-        (SelectedTab::ColorCodesToSpecs, KeyCode::Up) => Some(Msg::ColorCodesMsg {
-            msg: ColorCodesMsg::PrevColor,
+        (_, KeyCode::Char('o')) if key.modifiers == KeyModifiers::CONTROL => {
+            Some(Msg::FavoritesMsg {
+                msg: FavoritesMsg::Open,
+            })
+        }
+        (SelectedTab::ColorCodesToSpecs | SelectedTab::SpecsToColorCodes, KeyCode::Char('d'))
+            if key.modifiers == KeyModifiers::CONTROL =>
+        {
+            Some(Msg::FavoritesMsg {
+                msg: FavoritesMsg::Bookmark,
+            })
+        }
+        (SelectedTab::ColorCodesToSpecs | SelectedTab::SpecsToColorCodes, KeyCode::Char('w'))
+            if key.modifiers == KeyModifiers::CONTROL =>
+        {
+            Some(Msg::AddToNetwork)
+        }
+        (SelectedTab::ColorCodesToSpecs, KeyCode::Char('c')) => Some(Msg::Copy),
+        (SelectedTab::ColorCodesToSpecs, KeyCode::Char('e'))
+            if key.modifiers == KeyModifiers::CONTROL =>
+        {
+            Some(Msg::ExportChart {
+                path: PathBuf::from(export::CHART_FILE),
+            })
+        }
+        (SelectedTab::ColorCodesToSpecs, KeyCode::Char('a'))
+            if key.modifiers == KeyModifiers::CONTROL =>
+        {
+            let specs = model.color_codes_to_specs.resistor.specs();
+            Some(Msg::InventoryMsg {
+                msg: InventoryMsg::Add {
+                    ohm: specs.ohm,
+                    tolerance: specs.tolerance,
+                },
+            })
+        }
+        (SelectedTab::SpecsToColorCodes, KeyCode::BackTab) => Some(Msg::Shared {
+            msg: SharedMsg::SpecsMsg {
+                msg: SpecsMsg::PrevSpecInput,
+            },
         }),
-        (SelectedTab::ColorCodesToSpecs, KeyCode::Down) => Some(Msg::ColorCodesMsg {
-            msg: ColorCodesMsg::NextColor,
+        (SelectedTab::SpecsToColorCodes, KeyCode::Tab) => Some(Msg::Shared {
+            msg: SharedMsg::SpecsMsg {
+                msg: SpecsMsg::NextSpecInput,
+            },
         }),
-        (SelectedTab::ColorCodesToSpecs, KeyCode::BackTab) => Some(Msg::ColorCodesMsg {
-            msg: ColorCodesMsg::PrevBand,
+        (SelectedTab::SpecsToColorCodes, KeyCode::PageUp) => Some(Msg::Shared {
+            msg: SharedMsg::SpecsMsg {
+                msg: SpecsMsg::PrevChoice,
+            },
         }),
-        (SelectedTab::ColorCodesToSpecs, KeyCode::Tab) => Some(Msg::ColorCodesMsg {
-            msg: ColorCodesMsg::NextBand,
+        (SelectedTab::SpecsToColorCodes, KeyCode::PageDown) => Some(Msg::Shared {
+            msg: SharedMsg::SpecsMsg {
+                msg: SpecsMsg::NextChoice,
+            },
         }),
-        (SelectedTab::ColorCodesToSpecs, KeyCode::Char('3')) => Some(Msg::ColorCodesMsg {
-            msg: ColorCodesMsg::ThreeBands,
+        (SelectedTab::SpecsToColorCodes, KeyCode::Char('r'))
+            if key.modifiers == KeyModifiers::CONTROL =>
+        {
+            Some(Msg::Shared {
+                msg: SharedMsg::SpecsMsg {
+                    msg: SpecsMsg::SearchHistory,
+                },
+            })
+        }
+        (SelectedTab::SpecsToColorCodes, KeyCode::Char('e'))
+            if key.modifiers == KeyModifiers::CONTROL =>
+        {
+            Some(Msg::ExportHistory {
+                path: PathBuf::from(export::DEFAULT_FILE),
+            })
+        }
+        (SelectedTab::SpecsToColorCodes, KeyCode::Char('b'))
+            if key.modifiers == KeyModifiers::CONTROL =>
+        {
+            Some(Msg::Shared {
+                msg: SharedMsg::SpecsMsg {
+                    msg: SpecsMsg::NextBandsCount,
+                },
+            })
+        }
+        (SelectedTab::SpecsToColorCodes, KeyCode::Char('t'))
+            if key.modifiers == KeyModifiers::CONTROL =>
+        {
+            Some(Msg::Shared {
+                msg: SharedMsg::SpecsMsg {
+                    msg: SpecsMsg::ToggleShowAll,
+                },
+            })
+        }
+        (SelectedTab::SpecsToColorCodes, KeyCode::Char('a'))
+            if key.modifiers == KeyModifiers::CONTROL =>
+        {
+            model.specs_to_color.resistor.as_ref().map(|resistor| {
+                let specs = resistor.specs();
+                Msg::InventoryMsg {
+                    msg: InventoryMsg::Add {
+                        ohm: specs.ohm,
+                        tolerance: specs.tolerance,
+                    },
+                }
+            })
+        }
+        (SelectedTab::SpecsToColorCodes, _) if shared.is_some() => shared,
+        (SelectedTab::SpecsToColorCodes, _) => {
+            let target_textarea = match model.specs_to_color.focus {
+                InputFocus::Resistance => &mut model.specs_to_color.resistance_textarea,
+                InputFocus::Tolerance => &mut model.specs_to_color.tolerance_textarea,
+                InputFocus::Tcr => &mut model.specs_to_color.tcr_textarea,
+            };
+            target_textarea.input(key);
+            None
+        }
+        (SelectedTab::ESeries, KeyCode::Enter) => model
+            .eseries
+            .selected_resistor()
+            .map(|resistor| Msg::ShowColorCodes { resistor }),
+        (SelectedTab::ESeries, KeyCode::Up) => Some(Msg::ESeriesMsg {
+            msg: ESeriesMsg::PrevValue,
         }),
-        (SelectedTab::ColorCodesToSpecs, KeyCode::Char('4')) => Some(Msg::ColorCodesMsg {
-            msg: ColorCodesMsg::FourBands,
+        (SelectedTab::ESeries, KeyCode::Down) => Some(Msg::ESeriesMsg {
+            msg: ESeriesMsg::NextValue,
         }),
-        (SelectedTab::ColorCodesToSpecs, KeyCode::Char('5')) => Some(Msg::ColorCodesMsg {
-            msg: ColorCodesMsg::FiveBands,
+        (SelectedTab::ESeries, KeyCode::Left) => Some(Msg::ESeriesMsg {
+            msg: ESeriesMsg::PrevDecade,
         }),
-        (SelectedTab::ColorCodesToSpecs, KeyCode::Char('6')) => Some(Msg::ColorCodesMsg {
-            msg: ColorCodesMsg::SixBands,
+        (SelectedTab::ESeries, KeyCode::Right) => Some(Msg::ESeriesMsg {
+            msg: ESeriesMsg::NextDecade,
         }),
-        (SelectedTab::SpecsToColorCodes, KeyCode::Enter) => Some(Msg::SpecsMsg {
-            msg: SpecsMsg::Determine,
+        (SelectedTab::ESeries, KeyCode::BackTab) => Some(Msg::ESeriesMsg {
+            msg: ESeriesMsg::PrevSeries,
         }),
-        (SelectedTab::SpecsToColorCodes, KeyCode::BackTab) => Some(Msg::SpecsMsg {
-            msg: SpecsMsg::PrevSpecInput,
+        (SelectedTab::ESeries, KeyCode::Tab) => Some(Msg::ESeriesMsg {
+            msg: ESeriesMsg::NextSeries,
         }),
-        (SelectedTab::SpecsToColorCodes, KeyCode::Tab) => Some(Msg::SpecsMsg {
-            msg: SpecsMsg::NextSpecInput,
+        (SelectedTab::ESeries, _) => {
+            model.eseries.filter_textarea.input(key);
+            Some(Msg::ESeriesMsg {
+                msg: ESeriesMsg::FilterChanged,
+            })
+        }
+        (SelectedTab::Network, _) => {
+            on_network_key_event(model, key).map(|msg| Msg::NetworkMsg { msg })
+        }
+        (SelectedTab::OhmsLaw, KeyCode::Enter) => Some(Msg::OhmsLawMsg {
+            msg: OhmsLawMsg::Calculate,
         }),
-        (SelectedTab::SpecsToColorCodes, KeyCode::Up) => Some(Msg::SpecsMsg {
-            msg: SpecsMsg::PrevHistory,
+        (SelectedTab::OhmsLaw, KeyCode::BackTab) => Some(Msg::OhmsLawMsg {
+            msg: OhmsLawMsg::PrevInput,
         }),
-        (SelectedTab::SpecsToColorCodes, KeyCode::Down) => Some(Msg::SpecsMsg {
-            msg: SpecsMsg::NextHistory,
+        (SelectedTab::OhmsLaw, KeyCode::Tab) => Some(Msg::OhmsLawMsg {
+            msg: OhmsLawMsg::NextInput,
         }),
-        (SelectedTab::SpecsToColorCodes, KeyCode::Char('X')) => Some(Msg::SpecsMsg {
-            msg: SpecsMsg::Reset,
+        (SelectedTab::OhmsLaw, KeyCode::Char('l')) if key.modifiers == KeyModifiers::CONTROL => {
+            Some(Msg::OhmsLawMsg {
+                msg: OhmsLawMsg::ToggleLink {
+                    ohm: model.color_codes_to_specs.resistor.specs().ohm,
+                },
+            })
+        }
+        (SelectedTab::OhmsLaw, _) => {
+            let linked = model.ohms_law.linked && model.ohms_law.focus == OhmsLawFocus::Resistance;
+            if !linked {
+                model.ohms_law.focused_textarea().input(key);
+            }
+            None
+        }
+        (SelectedTab::Divider, _) => {
+            on_divider_key_event(model, key).map(|msg| Msg::DividerMsg { msg })
+        }
+        (SelectedTab::Led, KeyCode::Enter) => Some(Msg::LedMsg {
+            msg: LedMsg::Calculate,
         }),
-        (SelectedTab::SpecsToColorCodes, _) => {
-            let target_textarea = match model.specs_to_color.focus {
-                InputFocus::Resistance => &mut model.specs_to_color.resistance_textarea,
-                InputFocus::Tolerance => &mut model.specs_to_color.tolerance_textarea,
-                InputFocus::Tcr => &mut model.specs_to_color.tcr_textarea,
+        (SelectedTab::Led, KeyCode::BackTab) => Some(Msg::LedMsg {
+            msg: LedMsg::PrevInput,
+        }),
+        (SelectedTab::Led, KeyCode::Tab) => Some(Msg::LedMsg {
+            msg: LedMsg::NextInput,
+        }),
+        (SelectedTab::Led, KeyCode::PageUp) => Some(Msg::LedMsg {
+            msg: LedMsg::PrevSeries,
+        }),
+        (SelectedTab::Led, KeyCode::PageDown) => Some(Msg::LedMsg {
+            msg: LedMsg::NextSeries,
+        }),
+        (SelectedTab::Led, _) => {
+            model.led.focused_textarea().input(key);
+            None
+        }
+        (SelectedTab::Quiz, KeyCode::Char('s')) if key.modifiers == KeyModifiers::CONTROL => {
+            Some(Msg::ToggleQuizStats)
+        }
+        (SelectedTab::Quiz, _) if model.show_quiz_stats => None,
+        (SelectedTab::Quiz, _) if shared.is_some() => shared,
+        (SelectedTab::Quiz, _) => on_quiz_key_event(model, key).map(|msg| Msg::Shared {
+            msg: SharedMsg::QuizMsg { msg },
+        }),
+        (SelectedTab::Capacitor, KeyCode::Char('4')) => Some(Msg::CapacitorMsg {
+            msg: CapacitorMsg::FourBands,
+        }),
+        (SelectedTab::Capacitor, KeyCode::Char('5')) => Some(Msg::CapacitorMsg {
+            msg: CapacitorMsg::FiveBands,
+        }),
+        // the band lists only show the selected colors in short terminals
+        (
+            SelectedTab::ColorCodesToSpecs
+            | SelectedTab::Capacitor
+            | SelectedTab::Inductor
+            | SelectedTab::Compare,
+            KeyCode::Left,
+        ) if model.compact_bands => band_msg(model, ColorCodesMsg::PrevColor),
+        (
+            SelectedTab::ColorCodesToSpecs
+            | SelectedTab::Capacitor
+            | SelectedTab::Inductor
+            | SelectedTab::Compare,
+            KeyCode::Right,
+        ) if model.compact_bands => band_msg(model, ColorCodesMsg::NextColor),
+        (SelectedTab::ColorCodesToSpecs, _) => shared,
+        (SelectedTab::Compare, KeyCode::Char('a')) => Some(Msg::CompareMsg {
+            msg: CompareMsg::Focus {
+                side: CompareSide::A,
+            },
+        }),
+        (SelectedTab::Compare, KeyCode::Char('b')) => Some(Msg::CompareMsg {
+            msg: CompareMsg::Focus {
+                side: CompareSide::B,
+            },
+        }),
+        (SelectedTab::Compare, KeyCode::Char(c @ '3'..='6')) => {
+            let msg = match c {
+                '3' => ColorCodesMsg::ThreeBands,
+                '4' => ColorCodesMsg::FourBands,
+                '5' => ColorCodesMsg::FiveBands,
+                _ => ColorCodesMsg::SixBands,
             };
-            target_textarea.input(key);
+            Some(Msg::CompareMsg {
+                msg: CompareMsg::BandsMsg { msg },
+            })
+        }
+        (SelectedTab::Inventory, _) => {
+            on_inventory_key_event(model, key).map(|msg| Msg::InventoryMsg { msg })
+        }
+        _ => None,
+    }
+}
+
+fn on_search_key_event(key: KeyEvent) -> Option<SpecsMsg> {
+    match key.code {
+        KeyCode::Char('r') if key.modifiers == KeyModifiers::CONTROL => {
+            Some(SpecsMsg::SearchHistory)
+        }
+        KeyCode::Char('g') if key.modifiers == KeyModifiers::CONTROL => {
+            Some(SpecsMsg::CancelSearch)
+        }
+        KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+            Some(SpecsMsg::SearchInput { c })
+        }
+        KeyCode::Backspace => Some(SpecsMsg::SearchBackspace),
+        KeyCode::Enter => Some(SpecsMsg::AcceptSearch),
+        KeyCode::Esc => Some(SpecsMsg::CancelSearch),
+        _ => None,
+    }
+}
+
+fn on_favorites_key_event(model: &mut Model, key: KeyEvent) -> Option<FavoritesMsg> {
+    match (model.favorites_popup.as_mut()?, key.code) {
+        (_, KeyCode::Esc) => Some(FavoritesMsg::Close),
+        (FavoritesPopup::Add { .. }, KeyCode::Enter) => Some(FavoritesMsg::Save),
+        (FavoritesPopup::Add { name_textarea, .. }, _) => {
+            name_textarea.input(key);
+            None
+        }
+        (FavoritesPopup::List { .. }, KeyCode::Up) => Some(FavoritesMsg::Prev),
+        (FavoritesPopup::List { .. }, KeyCode::Down) => Some(FavoritesMsg::Next),
+        (FavoritesPopup::List { .. }, KeyCode::Enter) => Some(FavoritesMsg::Recall),
+        (FavoritesPopup::List { .. }, KeyCode::Delete) => Some(FavoritesMsg::Remove),
+        _ => None,
+    }
+}
+
+fn on_bom_key_event(key: KeyEvent) -> Option<BomMsg> {
+    match key.code {
+        KeyCode::Esc => Some(BomMsg::Close),
+        KeyCode::Enter => Some(BomMsg::Load),
+        KeyCode::Up => Some(BomMsg::Prev),
+        KeyCode::Down => Some(BomMsg::Next),
+        KeyCode::PageUp => Some(BomMsg::PrevPage),
+        KeyCode::PageDown => Some(BomMsg::NextPage),
+        _ => None,
+    }
+}
+
+fn on_command_palette_key_event(model: &mut Model, key: KeyEvent) -> Option<CommandPaletteMsg> {
+    let palette = model.command_palette.as_mut()?;
+    match key.code {
+        KeyCode::Esc => Some(CommandPaletteMsg::Close),
+        KeyCode::Enter => Some(CommandPaletteMsg::Run),
+        KeyCode::Tab => Some(CommandPaletteMsg::Complete),
+        KeyCode::Up => Some(CommandPaletteMsg::Prev),
+        KeyCode::Down => Some(CommandPaletteMsg::Next),
+        _ => {
+            if palette.textarea.input(key) {
+                palette.selected = 0;
+                palette.error = None;
+            }
+            None
+        }
+    }
+}
+
+fn on_value_search_key_event(model: &mut Model, key: KeyEvent) -> Option<ValueSearchMsg> {
+    let search = model.value_search.as_mut()?;
+    match key.code {
+        KeyCode::Esc => Some(ValueSearchMsg::Close),
+        KeyCode::Enter => Some(ValueSearchMsg::Load),
+        KeyCode::Up => Some(ValueSearchMsg::Prev),
+        KeyCode::Down => Some(ValueSearchMsg::Next),
+        _ => {
+            if search.textarea.input(key) {
+                search.selected = 0;
+            }
             None
         }
+    }
+}
+
+/// The message of a remappable action, `None` if it has no meaning in the selected tab.
+fn on_action(model: &Model, action: Action) -> Option<Msg> {
+    let band_tab = is_band_tab(&model.selected_tab);
+    match (action, &model.selected_tab) {
+        (Action::Exit, _) => Some(Msg::Exit),
+        (Action::NextTab, _) => Some(Msg::Shared {
+            msg: SharedMsg::NextTab,
+        }),
+        (Action::PrevTab, _) => Some(Msg::Shared {
+            msg: SharedMsg::PrevTab,
+        }),
+        (Action::NextBand, _) if band_tab => band_msg(model, ColorCodesMsg::NextBand),
+        (Action::PrevBand, _) if band_tab => band_msg(model, ColorCodesMsg::PrevBand),
+        (Action::NextColor, _) if band_tab => band_msg(model, ColorCodesMsg::NextColor),
+        (Action::PrevColor, _) if band_tab => band_msg(model, ColorCodesMsg::PrevColor),
+        (Action::Determine, SelectedTab::SpecsToColorCodes) => Some(Msg::Shared {
+            msg: SharedMsg::SpecsMsg {
+                msg: SpecsMsg::Determine,
+            },
+        }),
+        (Action::Reset, SelectedTab::SpecsToColorCodes) => Some(Msg::Shared {
+            msg: SharedMsg::SpecsMsg {
+                msg: SpecsMsg::Reset,
+            },
+        }),
+        (Action::Reset, SelectedTab::OhmsLaw) => Some(Msg::OhmsLawMsg {
+            msg: OhmsLawMsg::Reset,
+        }),
         _ => None,
     }
 }
 
+/// The tabs whose keys only select bands and colors.
+fn is_band_tab(tab: &SelectedTab) -> bool {
+    matches!(
+        tab,
+        SelectedTab::ColorCodesToSpecs
+            | SelectedTab::Capacitor
+            | SelectedTab::Inductor
+            | SelectedTab::Compare
+    )
+}
+
+fn on_mouse_event(model: &Model, mouse: MouseEvent) -> Option<Msg> {
+    let position = Position::new(mouse.column, mouse.row);
+    if let Some((_, tab)) = model
+        .hit_areas
+        .tabs
+        .iter()
+        .find(|(rect, _)| rect.contains(position))
+    {
+        return match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => Some(Msg::Shared {
+                msg: SharedMsg::SelectTab { tab: *tab },
+            }),
+            MouseEventKind::ScrollUp => Some(Msg::Shared {
+                msg: SharedMsg::PrevTab,
+            }),
+            MouseEventKind::ScrollDown => Some(Msg::Shared {
+                msg: SharedMsg::NextTab,
+            }),
+            _ => None,
+        };
+    }
+    let (band_idx, (rect, offset)) = model
+        .hit_areas
+        .bands
+        .iter()
+        .enumerate()
+        .find(|(_, (rect, _))| rect.contains(position))?;
+    let msg = match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) => {
+            // the first and last rows are the borders of the list
+            let row = (mouse.row - rect.y) as usize;
+            let color = (1..rect.height as usize - 1)
+                .contains(&row)
+                .then(|| rusistor::Color::ALL.get(row - 1 + offset).copied())
+                .flatten();
+            match color {
+                Some(color) => ColorCodesMsg::SelectColor { band_idx, color },
+                None => ColorCodesMsg::SelectBand { band_idx },
+            }
+        }
+        // scrolling over a band focuses it first
+        MouseEventKind::ScrollUp | MouseEventKind::ScrollDown
+            if selected_band(model) != Some(band_idx) =>
+        {
+            ColorCodesMsg::SelectBand { band_idx }
+        }
+        MouseEventKind::ScrollUp => ColorCodesMsg::PrevColor,
+        MouseEventKind::ScrollDown => ColorCodesMsg::NextColor,
+        _ => return None,
+    };
+    band_msg(model, msg)
+}
+
+/// The focused band of the selected tab.
+fn selected_band(model: &Model) -> Option<usize> {
+    match model.selected_tab {
+        SelectedTab::ColorCodesToSpecs => Some(model.color_codes_to_specs.selected_band),
+        SelectedTab::Network if model.network.focus == NetworkFocus::Bands => {
+            Some(model.network.bands.selected_band)
+        }
+        SelectedTab::Quiz => Some(model.quiz.answer_bands.selected_band),
+        SelectedTab::Capacitor => Some(model.capacitor.selected_band),
+        SelectedTab::Inductor => Some(model.inductor.selected_band),
+        SelectedTab::Compare => Some(model.compare.focused().selected_band),
+        _ => None,
+    }
+}
+
+/// Translates a band message to the message of the selected tab.
+fn band_msg(model: &Model, msg: ColorCodesMsg) -> Option<Msg> {
+    match model.selected_tab {
+        SelectedTab::ColorCodesToSpecs => Some(Msg::Shared {
+            msg: SharedMsg::ColorCodesMsg { msg },
+        }),
+        // a click on the bands focuses them first
+        SelectedTab::Network if model.network.focus != NetworkFocus::Bands => {
+            Some(Msg::NetworkMsg {
+                msg: NetworkMsg::ToggleFocus,
+            })
+        }
+        SelectedTab::Network => Some(Msg::NetworkMsg {
+            msg: NetworkMsg::BandsMsg { msg },
+        }),
+        SelectedTab::Quiz => Some(Msg::Shared {
+            msg: SharedMsg::QuizMsg {
+                msg: QuizMsg::BandsMsg { msg },
+            },
+        }),
+        SelectedTab::Compare => Some(Msg::CompareMsg {
+            msg: CompareMsg::BandsMsg { msg },
+        }),
+        SelectedTab::Capacitor => {
+            let msg = match msg {
+                ColorCodesMsg::SelectBand { band_idx } => CapacitorMsg::SelectBand { band_idx },
+                ColorCodesMsg::SelectColor { band_idx, color } => {
+                    CapacitorMsg::SelectColor { band_idx, color }
+                }
+                ColorCodesMsg::NextColor => CapacitorMsg::NextColor,
+                ColorCodesMsg::PrevColor => CapacitorMsg::PrevColor,
+                ColorCodesMsg::NextBand => CapacitorMsg::NextBand,
+                ColorCodesMsg::PrevBand => CapacitorMsg::PrevBand,
+                _ => return None,
+            };
+            Some(Msg::CapacitorMsg { msg })
+        }
+        SelectedTab::Inductor => {
+            let msg = match msg {
+                ColorCodesMsg::SelectBand { band_idx } => InductorMsg::SelectBand { band_idx },
+                ColorCodesMsg::SelectColor { band_idx, color } => {
+                    InductorMsg::SelectColor { band_idx, color }
+                }
+                ColorCodesMsg::NextColor => InductorMsg::NextColor,
+                ColorCodesMsg::PrevColor => InductorMsg::PrevColor,
+                ColorCodesMsg::NextBand => InductorMsg::NextBand,
+                ColorCodesMsg::PrevBand => InductorMsg::PrevBand,
+                _ => return None,
+            };
+            Some(Msg::InductorMsg { msg })
+        }
+        _ => None,
+    }
+}
+
+fn on_inventory_key_event(model: &mut Model, key: KeyEvent) -> Option<InventoryMsg> {
+    match key.code {
+        KeyCode::Enter => Some(InventoryMsg::Search),
+        KeyCode::Char('a') if key.modifiers == KeyModifiers::CONTROL => {
+            Some(InventoryMsg::AddInput)
+        }
+        KeyCode::Char('x') if key.modifiers == KeyModifiers::CONTROL => Some(InventoryMsg::ShowAll),
+        KeyCode::Up => Some(InventoryMsg::PrevPart),
+        KeyCode::Down => Some(InventoryMsg::NextPart),
+        KeyCode::Char('+') => Some(InventoryMsg::Increment),
+        KeyCode::Char('-') => Some(InventoryMsg::Decrement),
+        KeyCode::Delete => Some(InventoryMsg::Remove),
+        _ => {
+            model.inventory.input_textarea.input(key);
+            None
+        }
+    }
+}
+
+fn on_quiz_key_event(model: &mut Model, key: KeyEvent) -> Option<QuizMsg> {
+    match (&model.quiz.mode, key.code) {
+        (_, KeyCode::Char('n')) if key.modifiers == KeyModifiers::CONTROL => Some(QuizMsg::Skip),
+        (_, KeyCode::Char('t')) if key.modifiers == KeyModifiers::CONTROL => {
+            Some(QuizMsg::ToggleMode)
+        }
+        (QuizMode::ColorsToValue, _) => {
+            model.quiz.answer_textarea.input(key);
+            None
+        }
+        _ => None,
+    }
+}
+
+fn on_divider_key_event(model: &mut Model, key: KeyEvent) -> Option<DividerMsg> {
+    match key.code {
+        KeyCode::Enter => Some(DividerMsg::Calculate),
+        KeyCode::Tab | KeyCode::BackTab => Some(DividerMsg::ToggleInput),
+        KeyCode::PageUp => Some(DividerMsg::PrevSeries),
+        KeyCode::PageDown => Some(DividerMsg::NextSeries),
+        KeyCode::Up => Some(DividerMsg::PrevSuggestion),
+        KeyCode::Down => Some(DividerMsg::NextSuggestion),
+        _ => {
+            match model.divider.focus {
+                DividerFocus::Vin => model.divider.vin_textarea.input(key),
+                DividerFocus::Vout => model.divider.vout_textarea.input(key),
+            };
+            None
+        }
+    }
+}
+
+fn on_network_key_event(model: &mut Model, key: KeyEvent) -> Option<NetworkMsg> {
+    let bands_msg = |msg| Some(NetworkMsg::BandsMsg { msg });
+    match (&model.network.focus, key.code) {
+        (_, KeyCode::Tab) | (_, KeyCode::BackTab) => Some(NetworkMsg::ToggleFocus),
+        (_, KeyCode::Enter) => Some(NetworkMsg::AddPart),
+        (_, KeyCode::Delete) => Some(NetworkMsg::RemovePart),
+        (_, KeyCode::PageUp) => Some(NetworkMsg::PrevGroup),
+        (_, KeyCode::PageDown) => Some(NetworkMsg::NextGroup),
+        (_, KeyCode::Char('g')) if key.modifiers == KeyModifiers::CONTROL => {
+            Some(NetworkMsg::AddGroup)
+        }
+        (_, KeyCode::Char('t')) if key.modifiers == KeyModifiers::CONTROL => {
+            Some(NetworkMsg::ToggleConnection)
+        }
+        (_, KeyCode::Char('x')) if key.modifiers == KeyModifiers::CONTROL => {
+            Some(NetworkMsg::Clear)
+        }
+        (NetworkFocus::Bands, KeyCode::Up) => bands_msg(ColorCodesMsg::PrevColor),
+        (NetworkFocus::Bands, KeyCode::Down) => bands_msg(ColorCodesMsg::NextColor),
+        (NetworkFocus::Bands, KeyCode::Left) => bands_msg(ColorCodesMsg::PrevBand),
+        (NetworkFocus::Bands, KeyCode::Right) => bands_msg(ColorCodesMsg::NextBand),
+        (NetworkFocus::Bands, KeyCode::Char('3')) => bands_msg(ColorCodesMsg::ThreeBands),
+        (NetworkFocus::Bands, KeyCode::Char('4')) => bands_msg(ColorCodesMsg::FourBands),
+        (NetworkFocus::Bands, KeyCode::Char('5')) => bands_msg(ColorCodesMsg::FiveBands),
+        (NetworkFocus::Bands, KeyCode::Char('6')) => bands_msg(ColorCodesMsg::SixBands),
+        (NetworkFocus::Value, _) => {
+            model.network.value_textarea.input(key);
+            None
+        }
+        _ => None,
+    }
+}
+
+/// Updates the model like `update` and traces the message and the transitions it caused.
+pub fn update_traced(model: &mut Model, msg: Msg) {
+    if let Msg::Tick = msg {
+        return update(model, msg);
+    }
+    model.trace(format!("msg {:?}", msg));
+    let tab = model.selected_tab;
+    let reading = model.screen_reader.then(|| Reading::of(model));
+    update(model, msg);
+    if let Some(reading) = reading
+        && let Some(announcement) = announcement(&reading, model)
+    {
+        model.announcement = Some(announcement);
+    }
+    if model.selected_tab != tab {
+        model.trace(format!("tab {:?} -> {:?}", tab, model.selected_tab));
+    }
+    if let Some(status) = &model.status {
+        model.trace(format!("status {}", status));
+    }
+    if !model.running {
+        tracing::info!("exit");
+    }
+}
+
 pub fn update(model: &mut Model, msg: Msg) {
+    if let Msg::Tick = msg {
+        model.status_ticks += 1;
+        if model.status_ticks >= STATUS_TICKS {
+            model.status = None;
+        }
+        return;
+    }
+    model.status = None;
+    model.status_ticks = 0;
     match msg {
+        Msg::Tick => (),
         Msg::Exit => {
             model.running = false;
         }
-        Msg::ToggleTab => model.selected_tab = model.selected_tab.toggle(),
-        Msg::ColorCodesMsg { msg } => update_on_colorcodemsg(&mut model.color_codes_to_specs, msg),
-        Msg::SpecsMsg { msg } => update_on_specsmsg(&mut model.specs_to_color, msg),
+        Msg::Shared { msg } => update_on_shared(model, msg),
+        Msg::NextTheme => model.theme = model.theme.next(),
+        Msg::SetTheme { theme } => model.theme = theme,
+        Msg::ToggleAccessible => model.accessible = !model.accessible,
+        Msg::ToggleSiUnits => model.si_units = !model.si_units,
+        Msg::ToggleDebug => model.show_debug = !model.show_debug,
+        Msg::ToggleQuizStats => model.show_quiz_stats = !model.show_quiz_stats,
+        Msg::AddToNetwork => {
+            model.status = Some(match current_resistor(model) {
+                Some(resistor) => {
+                    let part = Combined::from(&resistor.specs());
+                    update_on_networkmsg(&mut model.network, NetworkMsg::Add { part });
+                    let total = model.network.total().map(|t| format_combined(&t));
+                    format!(
+                        "added {} to group {} of the series/parallel tab, total {}",
+                        resistor_summary(&resistor),
+                        model.network.selected_group + 1,
+                        total.unwrap_or_default()
+                    )
+                }
+                None => "no resistor to add".to_string(),
+            });
+        }
+        Msg::Copy => {
+            model.status = Some(match current_summary(model) {
+                Some(summary) => match model.clipboard.set_text(&summary) {
+                    Ok(()) => format!("copied: {}", summary),
+                    Err(e) => format!("could not copy: {}", e),
+                },
+                None => "nothing to copy".to_string(),
+            })
+        }
+        Msg::Share => {
+            model.status = Some(match current_share_text(model) {
+                Some(text) => {
+                    let status = match model.clipboard.set_text(&text) {
+                        Ok(()) => format!("copied: {}", text),
+                        Err(_) => format!("printed on exit: {}", text),
+                    };
+                    model.shared.push(text);
+                    status
+                }
+                None => "nothing to share".to_string(),
+            })
+        }
+        Msg::ESeriesMsg { msg } => update_on_eseriesmsg(&mut model.eseries, msg),
+        Msg::NetworkMsg { msg } => update_on_networkmsg(&mut model.network, msg),
+        Msg::OhmsLawMsg { msg } => update_on_ohmslawmsg(&mut model.ohms_law, msg),
+        Msg::DividerMsg { msg } => update_on_dividermsg(&mut model.divider, msg),
+        Msg::LedMsg { msg } => update_on_ledmsg(&mut model.led, msg),
+        Msg::CapacitorMsg { msg } => update_on_capacitormsg(&mut model.capacitor, msg),
+        Msg::InductorMsg { msg } => update_on_inductormsg(&mut model.inductor, msg),
+        Msg::CompareMsg { msg } => update_on_comparemsg(&mut model.compare, msg),
+        Msg::InventoryMsg { msg } => {
+            let changes_stock = msg.changes_stock();
+            update_on_inventorymsg(&mut model.inventory, msg);
+            if changes_stock {
+                save_inventory(model);
+            }
+        }
+        Msg::FavoritesMsg { msg } => update_on_favoritesmsg(model, msg),
+        Msg::CommandPaletteMsg { msg } => update_on_commandpalettemsg(model, msg),
+        Msg::ValueSearchMsg { msg } => update_on_valuesearchmsg(model, msg),
+        Msg::BomMsg { msg } => update_on_bommsg(model, msg),
+        Msg::ExportHistory { path } => {
+            model.status = Some(match export::export(&path, &model.specs_to_color.history) {
+                Ok(count) => format!("exported {} entries to {}", count, path.display()),
+                Err(e) => format!("could not export to {}: {}", path.display(), e),
+            });
+        }
+        Msg::ExportChart { path } => {
+            model.status = Some(match current_resistor(model) {
+                Some(resistor) => {
+                    let chart = chart_buffer(&resistor, &model.palette(), model.language);
+                    match export::export_chart(&path, &chart) {
+                        Ok(()) => format!("exported the chart to {}", path.display()),
+                        Err(e) => format!("could not export to {}: {}", path.display(), e),
+                    }
+                }
+                None => "no chart to export".to_string(),
+            });
+        }
+        Msg::Paste { text } => paste(model, &text),
+        Msg::PasteClipboard => match model.clipboard.get_text() {
+            Ok(text) => paste(model, &text),
+            Err(e) => model.status = Some(format!("could not paste: {}", e)),
+        },
+        Msg::ShowColorCodes { resistor } => {
+            update_on_colorcodemsg(
+                &mut model.color_codes_to_specs,
+                ColorCodesMsg::SetResistor { resistor },
+            );
+            model.selected_tab = SelectedTab::ColorCodesToSpecs;
+            update_linked_resistance(model);
+        }
+    }
+}
+
+fn update_on_favoritesmsg(model: &mut Model, msg: FavoritesMsg) {
+    let selected = match &mut model.favorites_popup {
+        Some(FavoritesPopup::List { selected }) => Some(selected),
+        _ => None,
+    };
+    match msg {
+        FavoritesMsg::Bookmark => {
+            let resistor = match model.selected_tab {
+                SelectedTab::SpecsToColorCodes => model.specs_to_color.resistor.clone(),
+                _ => Some(model.color_codes_to_specs.resistor.clone()),
+            };
+            match resistor {
+                Some(resistor) => {
+                    let specs = resistor.specs();
+                    let mut name_textarea = TextArea::default();
+                    name_textarea.insert_str(format!(
+                        "{} ±{}%",
+                        format_ohm(specs.ohm),
+                        specs.tolerance * 100.0
+                    ));
+                    model.favorites_popup = Some(FavoritesPopup::Add {
+                        resistor,
+                        name_textarea: Box::new(name_textarea),
+                    });
+                }
+                None => model.status = Some("no resistor to bookmark".to_string()),
+            }
+        }
+        FavoritesMsg::Save => {
+            if let Some(FavoritesPopup::Add {
+                resistor,
+                name_textarea,
+            }) = model.favorites_popup.take()
+            {
+                let name = name_textarea.lines()[0].trim();
+                model.favorites.push(Favorite::new(name, &resistor));
+                model.status = Some(format!("bookmarked {}", name));
+                save_favorites(model);
+            }
+        }
+        FavoritesMsg::Open => model.favorites_popup = Some(FavoritesPopup::List { selected: 0 }),
+        FavoritesMsg::Close => model.favorites_popup = None,
+        FavoritesMsg::Next | FavoritesMsg::Prev => {
+            let count = model.favorites.len();
+            if let Some(selected) = selected
+                && count > 0
+            {
+                *selected = if matches!(msg, FavoritesMsg::Next) {
+                    (*selected + 1) % count
+                } else {
+                    (*selected + count - 1) % count
+                };
+            }
+        }
+        FavoritesMsg::Recall => {
+            let Some(favorite) = selected.and_then(|idx| model.favorites.get(*idx)) else {
+                return;
+            };
+            match favorite.resistor() {
+                Ok(resistor) => {
+                    model.favorites_popup = None;
+                    update(model, Msg::ShowColorCodes { resistor });
+                }
+                Err(e) => model.status = Some(format!("invalid favorite: {}", e)),
+            }
+        }
+        FavoritesMsg::Remove => {
+            if let Some(selected) = selected
+                && *selected < model.favorites.len()
+            {
+                model.favorites.remove(*selected);
+                *selected = (*selected).min(model.favorites.len().saturating_sub(1));
+                save_favorites(model);
+            }
+        }
+    }
+}
+
+fn update_on_bommsg(model: &mut Model, msg: BomMsg) {
+    if let BomMsg::Import { path } = msg {
+        match bom::load(&path) {
+            Ok(lines) if lines.is_empty() => {
+                model.status = Some(format!("no resistors in {}", path.display()))
+            }
+            Ok(lines) => {
+                model.bom_popup = Some(BomPopup {
+                    path,
+                    lines,
+                    selected: 0,
+                })
+            }
+            Err(e) => model.status = Some(format!("could not import: {}", e)),
+        }
+        return;
+    }
+    let Some(popup) = &mut model.bom_popup else {
+        return;
+    };
+    let last = popup.lines.len().saturating_sub(1);
+    match msg {
+        BomMsg::Import { .. } => (),
+        BomMsg::Close => model.bom_popup = None,
+        BomMsg::Next => popup.selected = (popup.selected + 1).min(last),
+        BomMsg::Prev => popup.selected = popup.selected.saturating_sub(1),
+        BomMsg::NextPage => popup.selected = (popup.selected + BOM_PAGE).min(last),
+        BomMsg::PrevPage => popup.selected = popup.selected.saturating_sub(BOM_PAGE),
+        BomMsg::Load => {
+            let Some(line) = popup.lines.get(popup.selected) else {
+                return;
+            };
+            match &line.specs {
+                Ok((ohm, tolerance)) => {
+                    model.specs_to_color.set_specs((
+                        ohm.to_string(),
+                        tolerance.map(|t| t.to_string()).unwrap_or_default(),
+                        String::new(),
+                    ));
+                    model.bom_popup = None;
+                    update(
+                        model,
+                        Msg::Shared {
+                            msg: SharedMsg::SelectTab {
+                                tab: SelectedTab::SpecsToColorCodes,
+                            },
+                        },
+                    );
+                    update(
+                        model,
+                        Msg::Shared {
+                            msg: SharedMsg::SpecsMsg {
+                                msg: SpecsMsg::Determine,
+                            },
+                        },
+                    );
+                }
+                Err(e) => model.status = Some(format!("line {}: {}", line.line, e)),
+            }
+        }
     }
 }
 
+fn update_on_commandpalettemsg(model: &mut Model, msg: CommandPaletteMsg) {
+    if let CommandPaletteMsg::Open = msg {
+        model.command_palette = Some(CommandPalette::default());
+        return;
+    }
+    let Some(palette) = &mut model.command_palette else {
+        return;
+    };
+    let input = palette.textarea.lines()[0].clone();
+    let matches = commands::matches(&input);
+    let selected = matches.get(palette.selected);
+    match msg {
+        CommandPaletteMsg::Open => (),
+        CommandPaletteMsg::Close => model.command_palette = None,
+        CommandPaletteMsg::Next | CommandPaletteMsg::Prev if !matches.is_empty() => {
+            let count = matches.len();
+            palette.selected = if matches!(msg, CommandPaletteMsg::Next) {
+                (palette.selected + 1) % count
+            } else {
+                (palette.selected + count - 1) % count
+            };
+        }
+        CommandPaletteMsg::Next | CommandPaletteMsg::Prev => (),
+        CommandPaletteMsg::Complete => {
+            if let Some(command) = selected
+                && commands::find(&input).is_none()
+            {
+                palette.textarea = TextArea::from([format!("{} ", command.name)]);
+                palette
+                    .textarea
+                    .move_cursor(ratatui_textarea::CursorMove::End);
+                palette.selected = 0;
+            }
+        }
+        CommandPaletteMsg::Run => {
+            // a partly typed command is completed first if it takes arguments
+            let line = match (commands::find(&input), selected) {
+                (None, Some(command)) if !command.args.is_empty() => {
+                    return update_on_commandpalettemsg(model, CommandPaletteMsg::Complete);
+                }
+                (None, Some(command)) => command.name.to_string(),
+                _ => input,
+            };
+            match commands::parse(&line) {
+                Ok(msgs) => {
+                    model.command_palette = None;
+                    msgs.into_iter().for_each(|msg| update(model, msg));
+                }
+                Err(e) => palette.error = Some(e),
+            }
+        }
+    }
+}
+
+fn update_on_valuesearchmsg(model: &mut Model, msg: ValueSearchMsg) {
+    if let ValueSearchMsg::Open = msg {
+        model.value_search = Some(ValueSearch::default());
+        return;
+    }
+    let Some(search) = &mut model.value_search else {
+        return;
+    };
+    let mut matches = value_search::matches(&search.textarea.lines()[0]);
+    match msg {
+        ValueSearchMsg::Open => (),
+        ValueSearchMsg::Close => model.value_search = None,
+        ValueSearchMsg::Next | ValueSearchMsg::Prev if !matches.is_empty() => {
+            let count = matches.len();
+            search.selected = if matches!(msg, ValueSearchMsg::Next) {
+                (search.selected + 1) % count
+            } else {
+                (search.selected + count - 1) % count
+            };
+        }
+        ValueSearchMsg::Next | ValueSearchMsg::Prev => (),
+        ValueSearchMsg::Load if search.selected < matches.len() => {
+            let value = matches.swap_remove(search.selected);
+            model.value_search = None;
+            if let Err(e) = load_standard_value(model, value) {
+                model.status = Some(format!("could not load: {}", e));
+            }
+        }
+        ValueSearchMsg::Load => (),
+    }
+}
+
+/// Loads the value into the input or bands of the selected tab. The tabs without
+/// a resistance show it in the color codes tab.
+fn load_standard_value(model: &mut Model, value: StandardValue) -> Result<(), String> {
+    let resistor = value.resistor()?;
+    let series = value.series[0];
+    match model.selected_tab {
+        SelectedTab::SpecsToColorCodes => {
+            // E6 values have the implicit tolerance of 3 bands
+            let tolerance = match series {
+                ESeries::E6 => String::new(),
+                series => series.tolerance().to_string(),
+            };
+            model
+                .specs_to_color
+                .set_specs((value.ohm.to_string(), tolerance, String::new()));
+            update(
+                model,
+                Msg::Shared {
+                    msg: SharedMsg::SpecsMsg {
+                        msg: SpecsMsg::Determine,
+                    },
+                },
+            );
+        }
+        SelectedTab::ESeries => {
+            let eseries = &mut model.eseries;
+            eseries.series = series;
+            eseries.filter_textarea = TextArea::default();
+            if let Some(decade) = ESERIES_DECADES
+                .into_iter()
+                .find(|d| series.values_in_decade(*d).contains(&value.ohm))
+            {
+                eseries.decade = decade;
+            }
+            eseries.selected_value = eseries
+                .values()
+                .iter()
+                .position(|v| *v == value.ohm)
+                .unwrap_or_default();
+        }
+        SelectedTab::Network => {
+            let part = Combined::from(&resistor.specs());
+            update_on_networkmsg(&mut model.network, NetworkMsg::Add { part });
+        }
+        SelectedTab::OhmsLaw if !model.ohms_law.linked => {
+            model.ohms_law.resistance_textarea = single_line(value.ohm.to_string());
+            model.ohms_law.focus = OhmsLawFocus::Resistance;
+        }
+        // the linked resistance follows the color codes tab
+        SelectedTab::OhmsLaw => {
+            update_on_colorcodemsg(
+                &mut model.color_codes_to_specs,
+                ColorCodesMsg::SetResistor { resistor },
+            );
+            update_linked_resistance(model);
+        }
+        SelectedTab::Compare => update_on_comparemsg(
+            &mut model.compare,
+            CompareMsg::BandsMsg {
+                msg: ColorCodesMsg::SetResistor { resistor },
+            },
+        ),
+        SelectedTab::Inventory => {
+            let tolerance = resistor.specs().tolerance * 100.0;
+            model.inventory.input_textarea = single_line(format!("{} {}", value.ohm, tolerance));
+        }
+        _ => update(model, Msg::ShowColorCodes { resistor }),
+    }
+    Ok(())
+}
+
+/// An input with the text and the cursor at its end.
+fn single_line<'a>(text: String) -> TextArea<'a> {
+    let mut textarea = TextArea::from([text]);
+    textarea.move_cursor(ratatui_textarea::CursorMove::End);
+    textarea
+}
+
+/// Inserts the first line of the text into the focused input.
+fn paste(model: &mut Model, text: &str) {
+    let line = text.lines().next().unwrap_or_default().trim();
+    if model.bom_popup.is_some() {
+        return;
+    }
+    if let Some(FavoritesPopup::Add { name_textarea, .. }) = &mut model.favorites_popup {
+        name_textarea.insert_str(line);
+    } else if let Some(palette) = &mut model.command_palette {
+        palette.textarea.insert_str(line);
+        palette.selected = 0;
+    } else if let Some(search) = &mut model.value_search {
+        search.textarea.insert_str(line);
+        search.selected = 0;
+    } else if let Some(textarea) = focused_textarea(model) {
+        textarea.insert_str(line);
+        if model.selected_tab == SelectedTab::ESeries {
+            update_on_eseriesmsg(&mut model.eseries, ESeriesMsg::FilterChanged);
+        }
+    } else if model.selected_tab == SelectedTab::ColorCodesToSpecs {
+        // e.g. the reading of a multimeter
+        match try_parse_resistance(line) {
+            Ok(ohm) => update_on_colorcodemsg(
+                &mut model.color_codes_to_specs,
+                ColorCodesMsg::SetMeasured { ohm: Some(ohm) },
+            ),
+            Err(e) => model.status = Some(format!("could not paste: {}", e)),
+        }
+    }
+}
+
+fn focused_textarea<'m, 'a>(model: &'m mut Model<'a>) -> Option<&'m mut TextArea<'a>> {
+    match model.selected_tab {
+        SelectedTab::SpecsToColorCodes => Some(match model.specs_to_color.focus {
+            InputFocus::Resistance => &mut model.specs_to_color.resistance_textarea,
+            InputFocus::Tolerance => &mut model.specs_to_color.tolerance_textarea,
+            InputFocus::Tcr => &mut model.specs_to_color.tcr_textarea,
+        }),
+        SelectedTab::ESeries => Some(&mut model.eseries.filter_textarea),
+        SelectedTab::Network if model.network.focus == NetworkFocus::Value => {
+            Some(&mut model.network.value_textarea)
+        }
+        SelectedTab::OhmsLaw
+            if !(model.ohms_law.linked && model.ohms_law.focus == OhmsLawFocus::Resistance) =>
+        {
+            Some(model.ohms_law.focused_textarea())
+        }
+        SelectedTab::Divider => Some(match model.divider.focus {
+            DividerFocus::Vin => &mut model.divider.vin_textarea,
+            DividerFocus::Vout => &mut model.divider.vout_textarea,
+        }),
+        SelectedTab::Led => Some(model.led.focused_textarea()),
+        SelectedTab::Quiz if model.quiz.mode == QuizMode::ColorsToValue => {
+            Some(&mut model.quiz.answer_textarea)
+        }
+        SelectedTab::Inventory => Some(&mut model.inventory.input_textarea),
+        _ => None,
+    }
+}
+
+/// The summary of the resistor shown in the selected tab.
+fn current_summary(model: &Model) -> Option<String> {
+    match model.selected_tab {
+        SelectedTab::ColorCodesToSpecs => {
+            Some(resistor_summary(&model.color_codes_to_specs.resistor))
+        }
+        SelectedTab::SpecsToColorCodes => {
+            model.specs_to_color.resistor.as_ref().map(resistor_summary)
+        }
+        SelectedTab::ESeries => model
+            .eseries
+            .selected_resistor()
+            .as_ref()
+            .map(resistor_summary),
+        SelectedTab::Network => model.network.total().as_ref().map(format_combined),
+        SelectedTab::Divider => model
+            .divider
+            .selected_resistors()
+            .map(|(r1, r2)| format!("R1 {}; R2 {}", resistor_summary(&r1), resistor_summary(&r2))),
+        SelectedTab::Led => model.led.resistor().as_ref().map(resistor_summary),
+        _ => None,
+    }
+}
+
+/// The summary of the tab in one line, the resistors with their bands after a dash.
+fn current_share_text(model: &Model) -> Option<String> {
+    match current_resistor(model) {
+        Some(resistor) => Some(share_text(&resistor)),
+        None => current_summary(model),
+    }
+}
+
+/// The resistor whose chart the tab shows.
+pub fn current_resistor(model: &Model) -> Option<Resistor> {
+    match model.selected_tab {
+        SelectedTab::ColorCodesToSpecs => Some(model.color_codes_to_specs.resistor.clone()),
+        SelectedTab::SpecsToColorCodes => model.specs_to_color.resistor.clone(),
+        SelectedTab::ESeries => model.eseries.selected_resistor(),
+        SelectedTab::Led => model.led.resistor(),
+        _ => None,
+    }
+}
+
+fn save_inventory(model: &mut Model) {
+    if let Some(path) = &model.inventory_path
+        && let Err(e) = inventory::save(path, &model.inventory.inventory)
+    {
+        model.inventory.error = Some(format!("could not save {}: {}", path.display(), e));
+    }
+}
+
+fn save_quiz_stats(model: &mut Model) {
+    if let Some(path) = &model.quiz_stats_path
+        && let Err(e) = quiz_stats::save(path, &model.quiz_stats)
+    {
+        model.status = Some(format!("could not save {}: {}", path.display(), e));
+    }
+}
+
+/// Applies a message the web version has, too, with the follow-ups only the TUI has.
+fn update_on_shared(model: &mut Model, msg: SharedMsg) {
+    let changes_resistor = matches!(msg, SharedMsg::ColorCodesMsg { .. });
+    let changes_history = matches!(
+        msg,
+        SharedMsg::SpecsMsg {
+            msg: SpecsMsg::Determine | SpecsMsg::NextBandsCount | SpecsMsg::ToggleShowAll
+        }
+    );
+    let is_skip = matches!(
+        msg,
+        SharedMsg::QuizMsg {
+            msg: QuizMsg::Skip | QuizMsg::ToggleMode
+        }
+    );
+    let question = model.quiz.question.clone();
+    let asked = model.quiz.asked;
+    update_on_msg(model, msg);
+    if changes_resistor {
+        update_linked_resistance(model);
+    }
+    if changes_history {
+        save_history(model);
+    }
+    if model.quiz.asked > asked {
+        let is_correct = matches!(model.quiz.last_result, Some((true, _)));
+        let elapsed = model.quiz_asked_at.elapsed();
+        model
+            .quiz_stats
+            .record(&question, is_correct, elapsed, quiz_stats::today());
+        save_quiz_stats(model);
+    }
+    if model.quiz.asked > asked || is_skip {
+        model.quiz_asked_at = Instant::now();
+    }
+}
+
+fn save_favorites(model: &mut Model) {
+    if let Some(path) = &model.favorites_path
+        && let Err(e) = favorites::save(path, &model.favorites)
+    {
+        model.status = Some(format!("could not save {}: {}", path.display(), e));
+    }
+}
+
+fn save_history(model: &mut Model) {
+    if let Some(file) = &model.history_file
+        && let Err(e) = file.save(&model.specs_to_color.history)
+    {
+        model.status = Some(format!("could not save {}: {}", file.path().display(), e));
+    }
+}
+
+fn update_linked_resistance(model: &mut Model) {
+    let ohm = model.color_codes_to_specs.resistor.specs().ohm;
+    update_on_ohmslawmsg(
+        &mut model.ohms_law,
+        OhmsLawMsg::LinkedResistanceChanged { ohm },
+    );
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{Msg, update};
-    use crate::model::Model;
-    use tusistor_core::model::SelectedTab;
+    use super::{
+        Msg, STATUS_TICKS, SharedMsg, ValueSearchMsg, on_key_event, update, update_traced,
+    };
+    use crate::model::{DEBUG_LOG_LEN, Model, TABS};
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use rusistor::{Color, eseries::ESeries};
+    use tusistor_core::{model::SelectedTab, update::SpecsMsg};
 
     #[test]
     fn test_exit_msg() {
@@ -104,11 +1502,232 @@ mod tests {
     }
 
     #[test]
-    fn test_toggletab_msg() {
+    fn test_tab_msgs() {
         let mut model = Model::default();
-        update(&mut model, Msg::ToggleTab);
+        update(
+            &mut model,
+            Msg::Shared {
+                msg: SharedMsg::NextTab,
+            },
+        );
         assert_eq!(model.selected_tab, SelectedTab::SpecsToColorCodes);
-        update(&mut model, Msg::ToggleTab);
-        assert_eq!(model.selected_tab, SelectedTab::ColorCodesToSpecs)
+        for _ in 1..TABS.len() {
+            update(
+                &mut model,
+                Msg::Shared {
+                    msg: SharedMsg::NextTab,
+                },
+            );
+        }
+        assert_eq!(model.selected_tab, SelectedTab::ColorCodesToSpecs);
+        update(
+            &mut model,
+            Msg::Shared {
+                msg: SharedMsg::PrevTab,
+            },
+        );
+        assert_eq!(model.selected_tab, TABS[TABS.len() - 1])
+    }
+
+    #[test]
+    fn test_tick_msg() {
+        let mut model = Model::default();
+        update(&mut model, Msg::Copy);
+        assert!(model.status.is_some());
+        for _ in 1..STATUS_TICKS {
+            update(&mut model, Msg::Tick);
+        }
+        assert!(model.status.is_some());
+        update(&mut model, Msg::Tick);
+        assert!(model.status.is_none());
+    }
+
+    #[test]
+    fn test_update_traced() {
+        let mut model = Model::default();
+        update_traced(&mut model, Msg::Tick);
+        assert!(model.debug_log.is_empty());
+        update_traced(
+            &mut model,
+            Msg::Shared {
+                msg: SharedMsg::NextTab,
+            },
+        );
+        assert_eq!(model.debug_log[0], "msg Shared { msg: NextTab }");
+        assert_eq!(
+            model.debug_log[1],
+            "tab ColorCodesToSpecs -> SpecsToColorCodes"
+        );
+        for _ in 0..DEBUG_LOG_LEN {
+            update_traced(&mut model, Msg::ToggleDebug);
+        }
+        assert_eq!(model.debug_log.len(), DEBUG_LOG_LEN);
+        assert!(model.debug_log.iter().all(|e| e == "msg ToggleDebug"));
+    }
+
+    #[test]
+    fn test_paste_msg() {
+        let mut model = Model {
+            selected_tab: SelectedTab::SpecsToColorCodes,
+            ..Default::default()
+        };
+        update(
+            &mut model,
+            Msg::Paste {
+                text: " 4k7\nignored".to_string(),
+            },
+        );
+        assert_eq!(model.specs_to_color.resistance_textarea.lines()[0], "4k7");
+    }
+
+    #[test]
+    fn test_add_to_network_msg() {
+        let mut model = Model::default();
+        update(&mut model, Msg::AddToNetwork);
+        update(&mut model, Msg::AddToNetwork);
+        let total = model.network.total().unwrap();
+        assert_eq!(
+            total.ohm,
+            2.0 * model.color_codes_to_specs.resistor.specs().ohm
+        );
+        assert!(model.status.as_ref().unwrap().starts_with("added"));
+
+        model.selected_tab = SelectedTab::SpecsToColorCodes;
+        update(&mut model, Msg::AddToNetwork);
+        assert_eq!(model.network.groups[0].parts.len(), 2);
+        assert_eq!(model.status.as_deref(), Some("no resistor to add"));
+    }
+
+    #[test]
+    fn test_share_msg() {
+        let mut model = Model::default();
+        update(&mut model, Msg::Share);
+        let text = "100 Ω ±1% 250ppm/K — brown black black black brown black";
+        assert_eq!(model.shared, vec![text.to_string()]);
+        assert!(model.status.as_ref().unwrap().ends_with(text));
+
+        model.selected_tab = SelectedTab::SpecsToColorCodes;
+        update(&mut model, Msg::Share);
+        assert_eq!(model.shared.len(), 1);
+        assert_eq!(model.status.as_deref(), Some("nothing to share"));
+    }
+
+    #[test]
+    fn test_vim_keys() {
+        let press = |model: &mut Model, code, modifiers| {
+            if let Some(msg) = on_key_event(model, KeyEvent::new(code, modifiers)) {
+                update(model, msg);
+            }
+        };
+        let band = |model: &Model| *model.color_codes_to_specs.resistor.bands()[0];
+        let mut model = Model {
+            vim_keys: true,
+            ..Default::default()
+        };
+        // `j` is the next color, not a jump
+        press(&mut model, KeyCode::Char('j'), KeyModifiers::NONE);
+        assert_eq!(band(&model), Color::Red);
+        press(&mut model, KeyCode::Char('k'), KeyModifiers::NONE);
+        assert_eq!(band(&model), Color::Brown);
+        press(&mut model, KeyCode::Char('l'), KeyModifiers::NONE);
+        assert_eq!(model.color_codes_to_specs.selected_band, 1);
+        press(&mut model, KeyCode::Char('h'), KeyModifiers::NONE);
+        // a `g` without `t` or `T` is dropped, the next key jumps as usual
+        press(&mut model, KeyCode::Char('g'), KeyModifiers::NONE);
+        press(&mut model, KeyCode::Char('o'), KeyModifiers::NONE);
+        assert_eq!(band(&model), Color::Orange);
+        // black is still on `b`
+        press(&mut model, KeyCode::Char('l'), KeyModifiers::NONE);
+        press(&mut model, KeyCode::Char('j'), KeyModifiers::NONE);
+        press(&mut model, KeyCode::Char('b'), KeyModifiers::NONE);
+        press(&mut model, KeyCode::Char('b'), KeyModifiers::NONE);
+        assert_eq!(
+            model.color_codes_to_specs.resistor.bands()[1],
+            &Color::Black
+        );
+        press(&mut model, KeyCode::Char('g'), KeyModifiers::NONE);
+        press(&mut model, KeyCode::Char('t'), KeyModifiers::NONE);
+        assert_eq!(model.selected_tab, SelectedTab::SpecsToColorCodes);
+
+        // the specs tab takes them with Alt
+        update(
+            &mut model,
+            Msg::Paste {
+                text: "4k7".to_string(),
+            },
+        );
+        update(
+            &mut model,
+            Msg::Shared {
+                msg: SharedMsg::SpecsMsg {
+                    msg: SpecsMsg::Determine,
+                },
+            },
+        );
+        update(
+            &mut model,
+            Msg::Shared {
+                msg: SharedMsg::SpecsMsg {
+                    msg: SpecsMsg::Reset,
+                },
+            },
+        );
+        press(&mut model, KeyCode::Char('k'), KeyModifiers::ALT);
+        assert_eq!(model.specs_to_color.resistance_textarea.lines()[0], "4k7");
+        press(&mut model, KeyCode::Char('h'), KeyModifiers::ALT);
+        assert_eq!(model.specs_to_color.resistance_textarea.cursor(), (0, 2));
+        press(&mut model, KeyCode::Char('j'), KeyModifiers::NONE);
+        assert_eq!(model.specs_to_color.resistance_textarea.lines()[0], "4kj7");
+
+        let mut model = Model::default();
+        press(&mut model, KeyCode::Char('j'), KeyModifiers::NONE);
+        assert_eq!(band(&model), Color::Brown);
+        press(&mut model, KeyCode::Char('g'), KeyModifiers::NONE);
+        assert_eq!(band(&model), Color::Green);
+    }
+
+    #[test]
+    fn test_value_search_msg() {
+        let search = |model: &mut Model, input: &str| {
+            update(
+                model,
+                Msg::ValueSearchMsg {
+                    msg: ValueSearchMsg::Open,
+                },
+            );
+            update(
+                model,
+                Msg::Paste {
+                    text: input.to_string(),
+                },
+            );
+            update(
+                model,
+                Msg::ValueSearchMsg {
+                    msg: ValueSearchMsg::Load,
+                },
+            );
+        };
+        let mut model = Model::default();
+        search(&mut model, "4.7k");
+        assert!(model.value_search.is_none());
+        assert_eq!(model.color_codes_to_specs.resistor.specs().ohm, 4700.0);
+
+        model.selected_tab = SelectedTab::SpecsToColorCodes;
+        search(&mut model, "47");
+        assert_eq!(model.specs_to_color.resistance_textarea.lines()[0], "47");
+        assert_eq!(
+            model.specs_to_color.resistor.as_ref().unwrap().specs().ohm,
+            47.0
+        );
+
+        model.selected_tab = SelectedTab::ESeries;
+        search(&mut model, "1.05k");
+        assert_eq!(model.eseries.series, ESeries::E48);
+        assert_eq!(model.eseries.decade, 3);
+        assert_eq!(
+            model.eseries.selected_resistor().unwrap().specs().ohm,
+            1050.0
+        );
     }
 }
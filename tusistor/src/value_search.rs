@@ -0,0 +1,111 @@
+use rusistor::{Resistor, eseries::ESeries};
+use tusistor_core::{model::ESERIES_DECADES, update::try_parse_resistance};
+
+/// A value of the E-series with the series it's in, the coarsest first.
+#[derive(Debug, PartialEq)]
+pub struct StandardValue {
+    pub ohm: f64,
+    pub series: Vec<ESeries>,
+}
+
+impl StandardValue {
+    /// The resistor of the coarsest series of the value, with its tolerance.
+    pub fn resistor(&self) -> Result<Resistor, String> {
+        self.series[0].resistor(self.ohm)
+    }
+}
+
+/// The values of all series and decades in ohm, ascending and each once.
+pub fn standard_values() -> Vec<StandardValue> {
+    let mut values = ESeries::ALL
+        .iter()
+        .flat_map(|series| {
+            series
+                .values(ESERIES_DECADES)
+                .into_iter()
+                .map(move |ohm| (ohm, *series))
+        })
+        .collect::<Vec<_>>();
+    values.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+    let mut standard_values: Vec<StandardValue> = Vec::new();
+    for (ohm, series) in values {
+        match standard_values.last_mut() {
+            Some(last) if last.ohm == ohm => last.series.push(series),
+            _ => standard_values.push(StandardValue {
+                ohm,
+                series: vec![series],
+            }),
+        }
+    }
+    standard_values
+}
+
+/// The values whose significant digits contain the ones of the input, e.g. "47" or "4.7k".
+/// The values starting with them come first, the closest to the input's resistance first.
+pub fn matches(input: &str) -> Vec<StandardValue> {
+    let digits = significant_digits(input);
+    let target = try_parse_resistance(input.trim())
+        .ok()
+        .filter(|ohm| *ohm > 0.0);
+    let mut scored = standard_values()
+        .into_iter()
+        .filter_map(|value| {
+            let value_digits = significant_digits(&value.ohm.to_string());
+            if !value_digits.contains(&digits) {
+                return None;
+            }
+            let distance = target.map_or(0.0, |target| (value.ohm / target).log10().abs());
+            Some((!value_digits.starts_with(&digits), distance, value))
+        })
+        .collect::<Vec<_>>();
+    scored.sort_by(|(a_prefix, a_distance, _), (b_prefix, b_distance, _)| {
+        a_prefix
+            .cmp(b_prefix)
+            .then(a_distance.total_cmp(b_distance))
+    });
+    scored.into_iter().map(|(_, _, value)| value).collect()
+}
+
+/// The digits without the leading and trailing zeros, e.g. "47" of "0.47" and "4.7k".
+fn significant_digits(text: &str) -> String {
+    let digits = text
+        .chars()
+        .filter(|c| c.is_ascii_digit())
+        .collect::<String>();
+    digits.trim_matches('0').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{matches, significant_digits, standard_values};
+    use rusistor::eseries::ESeries;
+
+    #[test]
+    fn test_standard_values() {
+        let values = standard_values();
+        assert_eq!(values[0].ohm, 0.1);
+        assert_eq!(values[0].series, ESeries::ALL.to_vec());
+        assert!(values.windows(2).all(|w| w[0].ohm < w[1].ohm));
+        let value = values.iter().find(|v| v.ohm == 4700.0).unwrap();
+        assert_eq!(value.series, vec![ESeries::E6, ESeries::E12, ESeries::E24]);
+    }
+
+    #[test]
+    fn test_matches() {
+        let ohms = |input| matches(input).iter().map(|v| v.ohm).collect::<Vec<_>>();
+        assert_eq!(ohms("4.7k")[..2], [4700.0, 4750.0]);
+        assert_eq!(ohms("47")[..2], [47.0, 47.5]);
+        assert!(ohms("47").contains(&0.47));
+        // "147" contains "47", it only comes after the values starting with it
+        let values = ohms("47");
+        let idx_147 = values.iter().position(|v| *v == 147.0).unwrap();
+        assert!(
+            values[..idx_147]
+                .iter()
+                .all(|v| significant_digits(&v.to_string()).starts_with("47"))
+        );
+        assert!(ohms("123").is_empty());
+        assert_eq!(matches("").len(), standard_values().len());
+        assert_eq!(matches("4k7")[0].resistor().unwrap().specs().tolerance, 0.2);
+    }
+}
@@ -1,50 +1,200 @@
-use crate::model::Model;
+use std::{collections::VecDeque, ops::Range};
+
+use crate::bom::BomLine;
+use crate::commands;
+use crate::favorites::Favorite;
+use crate::keymap::Action;
+use crate::model::{BomPopup, CommandPalette, FavoritesPopup, HitAreas, Model, TABS, ValueSearch};
+use crate::quiz_stats::{self, QuizStats, Tally};
+use crate::value_search;
 use ratatui::{
-    Frame,
-    layout::{Constraint, Direction, Flex, Layout, Rect},
+    Frame, Terminal,
+    backend::TestBackend,
+    buffer::Buffer,
+    layout::{Constraint, Direction, Flex, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
     symbols,
-    text::{Line, Span, Text},
+    text::{Line, Span, Text as StyledText},
     widgets::{
-        Bar, BarChart, BarGroup, Block, Borders, List, ListDirection, ListItem, ListState, Padding,
-        Paragraph, Tabs,
+        Bar, BarChart, BarGroup, Block, BorderType, Borders, Cell, Clear, LineGauge, List,
+        ListDirection, ListItem, ListState, Padding, Paragraph, Row, Scrollbar,
+        ScrollbarOrientation, ScrollbarState, Table, TableState, Tabs, Wrap,
     },
 };
+use rusistor::{ColorCoded, ResistorSpecs, eseries::ESeries};
 use tusistor_core::{
-    model::{InputFocus, SelectedTab},
-    view::{band_numeric_info, band_semantic_info},
+    format::{NumberFormat, round_significant},
+    i18n::{Language, Text},
+    model::{
+        CompareSide, DividerFocus, HistorySearch, InputFocus, LedFocus, NetworkFocus, OhmsLawFocus,
+        QuizMode, SelectedTab, SpecsHistory, TEMPERATURES,
+    },
+    theme::Palette,
+    update::validate_input,
+    view::{
+        self, ChartBand, band_code, band_list_title, band_numeric_info, band_pattern,
+        band_semantic_info, capacitor_band_numeric_info, capacitor_band_semantic_info, chart_bands,
+        format_ohm, inductor_band_numeric_info, inductor_band_semantic_info, tolerance_gauge,
+    },
 };
 
 const BAR_WIDTH: u16 = 19;
 
-fn tabs<'a>(selected: &SelectedTab) -> Tabs<'a> {
-    Tabs::new(vec![" color codes to specs ", " specs to color codes "])
+/// The height of an exported chart.
+const CHART_HEIGHT: u16 = 16;
+
+const BAND_LISTS_HEIGHT: u16 = 15;
+
+/// Only the selected color of each band is shown when the terminal is too short for more colors.
+const COMPACT_BAND_LISTS_HEIGHT: u16 = 3;
+
+/// The height of the band lists in the rows the other rows of a tab leave, shorter ones scroll.
+fn band_lists_height(area: Rect, other_rows: u16) -> u16 {
+    area.height
+        .saturating_sub(other_rows)
+        .clamp(COMPACT_BAND_LISTS_HEIGHT, BAND_LISTS_HEIGHT)
+}
+
+/// The range of the tabs around the selected one that fit into the given width.
+fn visible_tabs(selected: &SelectedTab, width: u16, lang: Language) -> Range<usize> {
+    let idx = TABS.iter().position(|t| t == selected).unwrap_or(0);
+    let (mut start, mut end) = (idx, idx + 1);
+    loop {
+        if end < TABS.len() && tabs_width(&TABS[start..=end], lang) <= width {
+            end += 1;
+        } else if start > 0 && tabs_width(&TABS[start - 1..end], lang) <= width {
+            start -= 1;
+        } else {
+            break;
+        }
+    }
+    start..end
+}
+
+/// Renders the visible tabs and returns the area of each of them.
+fn render_tabs(
+    frame: &mut Frame,
+    selected: &SelectedTab,
+    area: Rect,
+    lang: Language,
+) -> Vec<(Rect, SelectedTab)> {
+    let range = visible_tabs(selected, area.width, lang);
+    let idx = TABS.iter().position(|t| t == selected).unwrap_or(0);
+    let tabs = Tabs::new(TABS[range.clone()].iter().map(|t| t.title(lang)))
         .padding(" ", " ")
         .divider(symbols::DOT)
-        .select(selected)
-}
-
-fn band_list<'a>(band_idx: usize, bands: usize, is_focused: bool) -> List<'a> {
-    let items = [
-        rusistor::Color::Black,
-        rusistor::Color::Brown,
-        rusistor::Color::Red,
-        rusistor::Color::Orange,
-        rusistor::Color::Yellow,
-        rusistor::Color::Green,
-        rusistor::Color::Blue,
-        rusistor::Color::Violet,
-        rusistor::Color::Grey,
-        rusistor::Color::White,
-        rusistor::Color::Gold,
-        rusistor::Color::Silver,
-        rusistor::Color::Pink,
-    ]
-    .iter()
-    .map(|color| {
-        let numeric_info = band_numeric_info(bands, band_idx, color);
-        let (color, name) = rusistor_color_to_ratatui_color(color);
-        let s = format!(" {numeric_info} {name}");
+        .select(idx - range.start);
+    frame.render_widget(tabs, area);
+
+    let mut x = area.x;
+    TABS[range]
+        .iter()
+        .map(|tab| {
+            let width = tabs_width(&[*tab], lang);
+            let rect = Rect::new(x, area.y, width, 1).intersection(area);
+            // the divider
+            x += width + 1;
+            (rect, *tab)
+        })
+        .collect()
+}
+
+fn tabs_width(tabs: &[SelectedTab], lang: Language) -> u16 {
+    let titles: usize = tabs.iter().map(|t| t.title(lang).chars().count() + 2).sum();
+    (titles + tabs.len() - 1) as u16
+}
+
+/// Renders a color list per band, labeled by the given semantic and numeric infos.
+/// Returns the area and the scroll offset of each list.
+#[allow(clippy::too_many_arguments)]
+fn render_band_lists(
+    frame: &mut Frame,
+    rects: &[Rect],
+    bands: &[&rusistor::Color],
+    selected_band: Option<usize>,
+    semantic_info: impl Fn(usize) -> String,
+    numeric_info: impl Fn(usize, &rusistor::Color) -> String,
+    palette: &Palette,
+    lang: Language,
+) -> Vec<(Rect, usize)> {
+    let mut areas = Vec::new();
+    for (i, (band, rect)) in bands.iter().zip(rects).enumerate() {
+        let mut state = ListState::default().with_selected(Some(**band as usize));
+        let is_focused = selected_band == Some(i);
+        let mut list = band_list(
+            i,
+            semantic_info(i),
+            |color| numeric_info(i, color),
+            is_focused,
+            palette,
+            lang,
+        );
+        if is_focused && rect.height == COMPACT_BAND_LISTS_HEIGHT {
+            list = list.block(
+                Block::bordered()
+                    .title(band_list_title(i, &semantic_info(i), true, lang))
+                    .title_bottom(Line::from(" ←/→ ").right_aligned())
+                    .style(Style::default().add_modifier(Modifier::BOLD)),
+            );
+        }
+        frame.render_stateful_widget(list, *rect, &mut state);
+        // the list scrolls to the selected color, the scrollbar shows where it is
+        let visible = rect.height.saturating_sub(2) as usize;
+        if rect.height > COMPACT_BAND_LISTS_HEIGHT && visible < rusistor::Color::ALL.len() {
+            let mut scrollbar_state =
+                ScrollbarState::new(rusistor::Color::ALL.len() - visible).position(state.offset());
+            frame.render_stateful_widget(
+                Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                    .begin_symbol(None)
+                    .end_symbol(None),
+                rect.inner(Margin {
+                    vertical: 1,
+                    horizontal: 0,
+                }),
+                &mut scrollbar_state,
+            );
+        }
+        areas.push((*rect, state.offset()));
+    }
+    areas
+}
+
+/// The hex value with the name and the other names, e.g. "#808080 grey/gray/slate".
+fn color_details(color: &rusistor::Color, lang: Language) -> String {
+    let (r, g, b) = color.as_rgb();
+    let names = std::iter::once(lang.color(color))
+        .chain(color.aliases().iter().copied())
+        .collect::<Vec<_>>()
+        .join("/");
+    format!("#{:02x}{:02x}{:02x} {}", r, g, b, names)
+}
+
+fn band_list<'a>(
+    band_idx: usize,
+    semantic_info: String,
+    numeric_info: impl Fn(&rusistor::Color) -> String,
+    is_focused: bool,
+    palette: &Palette,
+    lang: Language,
+) -> List<'a> {
+    let items = rusistor::Color::ALL.iter().map(|color| {
+        let numeric_info = numeric_info(color);
+        let pattern = band_pattern(color).to_string().repeat(2);
+        let code = band_code(color);
+        let name = if palette.color_details {
+            color_details(color, lang)
+        } else {
+            lang.color(color).to_string()
+        };
+        if palette.monochrome {
+            return ListItem::new(format!(" {numeric_info} {pattern} {code} {name}"));
+        }
+        let s = if palette.accessible {
+            format!(" {numeric_info} {pattern} {code} {name}")
+        } else {
+            format!(" {numeric_info} {name}")
+        };
+        let color = palette.band_color(color);
         let style = if color == Color::Black {
             Style::default().bg(color)
         } else {
@@ -59,374 +209,3118 @@ fn band_list<'a>(band_idx: usize, bands: usize, is_focused: bool) -> List<'a> {
         Style::default()
     };
 
-    let semantic_info = band_semantic_info(bands, band_idx);
+    let highlight_style = if palette.monochrome {
+        Style::default().add_modifier(Modifier::REVERSED)
+    } else {
+        Style::default()
+    };
 
     List::new(items)
         .block(
             Block::bordered()
-                .title(format!(
-                    " Band {}: {}{}",
-                    band_idx + 1,
-                    semantic_info,
-                    if is_focused { "* " } else { " " }
-                ))
+                .title(band_list_title(band_idx, &semantic_info, is_focused, lang))
                 .style(style),
         )
         .highlight_symbol(">> ")
+        .highlight_style(highlight_style)
         .repeat_highlight_symbol(true)
         .direction(ListDirection::TopToBottom)
 }
 
+fn center_horizontal(area: Rect, width: u16) -> Rect {
+    let [area] = Layout::horizontal([Constraint::Length(width)])
+        .flex(Flex::Center)
+        .areas(area);
+    area
+}
+
+/// The style of the specs panels and the status line.
+fn specs_style(model: &Model) -> Style {
+    if model.monochrome {
+        Style::default()
+    } else {
+        Style::default().fg(model.palette().accent)
+    }
+}
+
+/// The block with the title of an input, marked if the input is focused.
+fn apply_title<'a>(
+    block: Block<'a>,
+    current_focus: &InputFocus,
+    input: InputFocus,
+    title: &str,
+    title_style: Style,
+) -> Block<'a> {
+    if *current_focus == input {
+        block
+            .title(format!("{}* ", title))
+            .title_style(title_style.bold())
+    } else {
+        block.title(format!("{} ", title))
+    }
+}
+
 pub fn view(model: &mut Model, frame: &mut Frame) {
-    fn center_horizontal(area: Rect, width: u16) -> Rect {
-        let [area] = Layout::horizontal([Constraint::Length(width)])
-            .flex(Flex::Center)
-            .areas(area);
-        area
-    }
-
-    fn apply_title<'a>(
-        block: Block<'a>,
-        current_focus: &InputFocus,
-        input: InputFocus,
-        title: &str,
-        title_style: Style,
-    ) -> Block<'a> {
-        if *current_focus == input {
-            block
-                .title(format!("{}* ", title))
-                .title_style(title_style.bold())
-        } else {
-            block.title(format!("{} ", title))
+    model.hit_areas = HitAreas::default();
+    model.compact_bands = false;
+    let lang = model.language;
+    let palette = model.palette();
+    let specs_style = specs_style(model);
+
+    // the last row is below the margin of all tabs
+    if model.screen_reader {
+        // left aligned to start where screen readers start reading
+        if let Some(announcement) = &model.announcement {
+            let area = frame.area();
+            let announcement_rect =
+                Rect::new(area.x, area.bottom().saturating_sub(1), area.width, 1);
+            frame.render_widget(Paragraph::new(announcement.as_str()), announcement_rect);
         }
+    } else if let Some(status) = &model.status {
+        let area = frame.area();
+        let status_rect = Rect::new(area.x, area.bottom().saturating_sub(1), area.width, 1);
+        let status_rect = center_horizontal(status_rect, status.chars().count() as u16);
+        frame.render_widget(
+            Paragraph::new(status.as_str()).style(specs_style),
+            status_rect,
+        );
     }
 
-    let tabs_width = 49;
-    let specs_style = Style::default().fg(Color::Yellow);
-
     match model.selected_tab {
-        SelectedTab::ColorCodesToSpecs => {
-            let chunks = Layout::default()
-                .direction(Direction::Vertical)
-                .margin(2)
-                .constraints(
-                    [
-                        Constraint::Length(2),
-                        Constraint::Length(3),
-                        Constraint::Length(15),
-                        Constraint::Min(1),
-                    ]
-                    .as_ref(),
-                )
-                .split(frame.area());
-            let tabs_rect = center_horizontal(chunks[0], tabs_width);
-            let help_msg_rect = center_horizontal(chunks[3], 95);
-
-            let spec_chuncks = Layout::default()
-                .direction(Direction::Horizontal)
-                .constraints([
-                    Constraint::Ratio(1, 6),
-                    Constraint::Ratio(1, 6),
-                    Constraint::Ratio(1, 6),
-                    Constraint::Ratio(1, 6),
-                    Constraint::Ratio(1, 6),
-                    Constraint::Ratio(1, 6),
-                ])
-                .split(chunks[1]);
+        SelectedTab::ColorCodesToSpecs => render_color_codes_tab(frame, model),
+        SelectedTab::SpecsToColorCodes => render_specs_tab(frame, model),
+        SelectedTab::ESeries => render_e_series_tab(frame, model),
+        SelectedTab::Network => render_network_tab(frame, model),
+        SelectedTab::OhmsLaw => render_ohms_law_tab(frame, model),
+        SelectedTab::Divider => render_divider_tab(frame, model),
+        SelectedTab::Led => render_led_tab(frame, model),
+        SelectedTab::Quiz => render_quiz_tab(frame, model),
+        SelectedTab::Capacitor => render_capacitor_tab(frame, model),
+        SelectedTab::Inductor => render_inductor_tab(frame, model),
+        SelectedTab::Compare => render_compare_tab(frame, model),
+        SelectedTab::Inventory => render_inventory_tab(frame, model),
+    }
 
-            let bands_rect = Layout::default()
-                .direction(Direction::Horizontal)
-                .constraints([
-                    Constraint::Ratio(1, 6),
-                    Constraint::Ratio(1, 6),
-                    Constraint::Ratio(1, 6),
-                    Constraint::Ratio(1, 6),
-                    Constraint::Ratio(1, 6),
-                    Constraint::Ratio(1, 6),
-                ])
-                .split(chunks[2]);
-
-            let tabs = tabs(&model.selected_tab);
-            frame.render_widget(tabs, tabs_rect);
-
-            let specs = model.color_codes_to_specs.resistor.specs();
-
-            let resistance_paragraph = Paragraph::new(specs.ohm.to_string())
-                .style(specs_style)
-                .block(
-                    Block::default()
-                        .borders(Borders::ALL)
-                        .title(" Resistance (Ω) "),
-                );
-            frame.render_widget(resistance_paragraph, spec_chuncks[0]);
+    if let Some(popup) = &mut model.favorites_popup {
+        render_favorites_popup(frame, popup, &model.favorites, &palette, lang);
+    }
+    if let Some(popup) = &model.bom_popup {
+        render_bom_popup(frame, popup, &palette, lang);
+    }
+    if let Some(command_palette) = &mut model.command_palette {
+        render_command_palette(frame, command_palette, &palette, lang);
+    }
+    if let Some(search) = &mut model.value_search {
+        render_value_search(frame, search, &palette, lang);
+    }
+    if model.show_debug {
+        render_debug_pane(frame, &model.debug_log, lang);
+    }
+}
 
-            let tolerance_paragraph = Paragraph::new(format!("±{}", (specs.tolerance * 100.0)))
-                .style(specs_style)
-                .block(
-                    Block::default()
-                        .borders(Borders::ALL)
-                        .title(" Tolerance (%) "),
-                );
-            frame.render_widget(tolerance_paragraph, spec_chuncks[1]);
+/// Renders the tab of the color codes of a resistor to its specs.
+fn render_color_codes_tab(frame: &mut Frame, model: &mut Model) {
+    let lang = model.language;
+    let tabs_width = tabs_width(&TABS, lang);
+    let palette = model.palette();
+    let keymap = &model.keymap;
+    let tab_keys = keymap.pair_label(Action::PrevTab, Action::NextTab);
+    let exit_key = keymap.label(Action::Exit);
+    let band_key = keymap.label(Action::NextBand);
+    let color_keys = keymap.pair_label(Action::PrevColor, Action::NextColor);
+    let specs_style = specs_style(model);
 
-            let min_paragraph = Paragraph::new(specs.min_ohm.to_string())
-                .style(specs_style)
-                .block(
-                    Block::default()
-                        .borders(Borders::ALL)
-                        .title(" Minimum (Ω) "),
-                );
-            frame.render_widget(min_paragraph, spec_chuncks[2]);
+    let power_limits = model.color_codes_to_specs.power_limits();
+    // the power limits are a second row of specs
+    let specs_height = if power_limits.is_some() { 6 } else { 3 };
+    let lists_height = band_lists_height(frame.area(), 12 + specs_height);
+    model.compact_bands = lists_height == COMPACT_BAND_LISTS_HEIGHT;
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints(
+            [
+                Constraint::Length(2),
+                Constraint::Length(specs_height),
+                Constraint::Length(5),
+                Constraint::Length(lists_height),
+                Constraint::Min(1),
+            ]
+            .as_ref(),
+        )
+        .split(frame.area());
+    let tabs_rect = center_horizontal(chunks[0], tabs_width);
 
-            let max_paragraph = Paragraph::new(specs.max_ohm.to_string())
-                .style(specs_style)
-                .block(
-                    Block::default()
-                        .borders(Borders::ALL)
-                        .title(" Maximum (Ω) "),
-                );
-            frame.render_widget(max_paragraph, spec_chuncks[3]);
+    let spec_chuncks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Ratio(1, 6),
+            Constraint::Ratio(1, 6),
+            Constraint::Ratio(1, 6),
+            Constraint::Ratio(1, 6),
+            Constraint::Ratio(1, 6),
+            Constraint::Ratio(1, 6),
+        ])
+        .split(Rect {
+            height: 3,
+            ..chunks[1]
+        });
+
+    let bands_rect = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Ratio(1, 6),
+            Constraint::Ratio(1, 6),
+            Constraint::Ratio(1, 6),
+            Constraint::Ratio(1, 6),
+            Constraint::Ratio(1, 6),
+            Constraint::Ratio(1, 6),
+        ])
+        .split(chunks[3]);
+
+    model.hit_areas.tabs = render_tabs(frame, &model.selected_tab, tabs_rect, lang);
+
+    let art = resistor_art(&model.color_codes_to_specs.resistor.bands(), &palette);
+    let art_width = art.first().map(|l| l.width()).unwrap_or_default() as u16;
+    let art_rect = center_horizontal(chunks[2], art_width).inner(Margin::new(0, 1));
+    frame.render_widget(Paragraph::new(art), art_rect);
+    let direction_rect = Rect::new(chunks[2].x, art_rect.bottom(), chunks[2].width, 1);
+    let direction = match &model.color_codes_to_specs.error {
+        Some(e) if model.monochrome => {
+            Line::styled(e.as_str(), Style::default().add_modifier(Modifier::BOLD))
+        }
+        Some(e) => Line::styled(e.as_str(), Style::default().fg(Color::Red)),
+        None => Line::from(format!(
+            "{}, f: {}",
+            lang.text(Text::ReadLeftToRight),
+            lang.text(Text::Flip)
+        )),
+    };
+    frame.render_widget(Paragraph::new(direction).centered(), direction_rect);
+
+    let temperature = model.color_codes_to_specs.temperature;
+    let specs = model
+        .color_codes_to_specs
+        .resistor
+        .specs()
+        .at_temperature(temperature);
+    let drifted = specs.tcr.is_some() && temperature != ResistorSpecs::REFERENCE_CELSIUS;
+    let nf = NumberFormat::default();
+    let si_units = model.si_units;
+    let ohm_text = |ohm| {
+        if si_units {
+            nf.ohm(ohm)
+        } else {
+            nf.format(ohm)
+        }
+    };
+    let ohm_title = if si_units { "" } else { " (Ω)" };
+    let at_temperature = if drifted {
+        format!(" {} {} °C", lang.text(Text::At), temperature)
+    } else {
+        String::new()
+    };
+
+    let resistance_text = if drifted {
+        format!(
+            "{} ±{}",
+            ohm_text(specs.ohm),
+            ohm_text(specs.drift_ohm(temperature))
+        )
+    } else {
+        ohm_text(specs.ohm)
+    };
+    let resistance_paragraph = Paragraph::new(resistance_text).style(specs_style).block(
+        Block::default().borders(Borders::ALL).title(format!(
+            " {}{} ",
+            lang.text(Text::Resistance),
+            ohm_title
+        )),
+    );
+    frame.render_widget(resistance_paragraph, spec_chuncks[0]);
+
+    let tolerance_paragraph = Paragraph::new(format!("±{}", nf.percent(specs.tolerance)))
+        .style(specs_style)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" {} ", lang.text(Text::Tolerance))),
+        );
+    frame.render_widget(tolerance_paragraph, spec_chuncks[1]);
+
+    let range_rect = spec_chuncks[2].union(spec_chuncks[3]);
+    let measured = model.color_codes_to_specs.measured;
+    let measured_style = match measured {
+        _ if model.monochrome => Style::default().add_modifier(Modifier::BOLD),
+        Some(ohm) if specs.contains(ohm) => Style::default().fg(Color::Green),
+        _ => Style::default().fg(Color::Red),
+    };
+    let min_text = format!("{} ", ohm_text(specs.min_ohm));
+    let max_text = format!(" {}", ohm_text(specs.max_ohm));
+    let gauge_width = (range_rect.width.saturating_sub(2) as usize)
+        .saturating_sub(min_text.chars().count() + max_text.chars().count());
+    let mut range_spans = vec![Span::styled(min_text, specs_style)];
+    range_spans.extend(
+        tolerance_gauge(&specs, measured, gauge_width)
+            .chars()
+            .map(|c| match c {
+                '●' => Span::styled(c.to_string(), measured_style),
+                '─' => Span::styled(c.to_string(), Style::default().add_modifier(Modifier::DIM)),
+                _ => Span::styled(c.to_string(), specs_style),
+            }),
+    );
+    range_spans.push(Span::styled(max_text, specs_style));
+    let mut range_block = Block::bordered().title(format!(
+        " {}{}{} ",
+        lang.text(Text::Range),
+        at_temperature,
+        ohm_title
+    ));
+    if let Some(ohm) = measured {
+        let deviation = if specs.ohm > 0.0 {
+            format!(" {:+.2}%,", (ohm - specs.ohm) / specs.ohm * 100.0)
+        } else {
+            String::new()
+        };
+        let verdict = if specs.contains(ohm) {
+            lang.text(Text::InTolerance)
+        } else {
+            lang.text(Text::OutOfTolerance)
+        };
+        range_block = range_block.title_bottom(
+            Line::styled(
+                format!(" ● {}{} {} ", ohm_text(ohm), deviation, verdict),
+                measured_style,
+            )
+            .right_aligned(),
+        );
+    }
+    frame.render_widget(
+        Paragraph::new(Line::from(range_spans)).block(range_block),
+        range_rect,
+    );
+
+    let tcr_paragraph = Paragraph::new(specs.tcr.map(|t| nf.ppm(t)).unwrap_or_default())
+        .style(specs_style)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" {} ", lang.text(Text::Tcr))),
+        );
+    frame.render_widget(tcr_paragraph, spec_chuncks[4]);
 
-            let tcr_paragraph =
-                Paragraph::new(specs.tcr.map(|f| f.to_string()).unwrap_or_default())
+    if let Some(limits) = &power_limits {
+        let power_chunks = Layout::horizontal([Constraint::Ratio(1, 6); 6]).split(Rect {
+            y: chunks[1].y + 3,
+            height: 3,
+            ..chunks[1]
+        });
+        let rating = model.color_codes_to_specs.power_rating.unwrap_or_default();
+        let precise = nf.with_precision(3);
+        let (power_text, voltage_text, current_text) = match limits {
+            Ok(limits) => (
+                if limits.power < rating {
+                    format!("{} → {}", nf.format(rating), precise.format(limits.power))
+                } else {
+                    nf.format(rating)
+                },
+                precise.format(limits.voltage),
+                precise.format(limits.current * 1000.0),
+            ),
+            Err(e) => (nf.format(rating), e.clone(), String::new()),
+        };
+        for (text, title, unit, rect) in [
+            (power_text, Text::PowerRating, "W", power_chunks[0]),
+            (voltage_text, Text::MaxVoltage, "V", power_chunks[1]),
+            (current_text, Text::MaxCurrent, "mA", power_chunks[2]),
+        ] {
+            let title = format!(" {} ({}) ", lang.text(title), unit);
+            frame.render_widget(
+                Paragraph::new(text)
                     .style(specs_style)
-                    .block(
-                        Block::default()
-                            .borders(Borders::ALL)
-                            .title(" TCR (ppm/K) "),
-                    );
-            frame.render_widget(tcr_paragraph, spec_chuncks[4]);
-
-            let (msg, style) = (
-                vec![
-                    Span::styled("Tab", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(": next band, "),
-                    Span::styled("↑/↓", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(": prev/next color, "),
-                    Span::styled("3|4|5|6", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(": bands count, "),
-                    Span::styled("Shift ←/→", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(": prev/next tab, "),
-                    Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(": exit"),
-                ],
-                Style::default(),
+                    .block(Block::bordered().title(title)),
+                rect,
             );
-            let text = Text::from(Line::from(msg)).style(style);
-            let help_message = Paragraph::new(text);
-            frame.render_widget(help_message, help_msg_rect);
-
-            let bands = model.color_codes_to_specs.resistor.bands();
-            for i in 0..bands.len() {
-                let mut state = ListState::default().with_selected(Some(*bands[i] as usize));
-                let is_focused = model.color_codes_to_specs.selected_band == i;
-                let list = band_list(i, bands.len(), is_focused);
-                frame.render_stateful_widget(list, bands_rect[i], &mut state);
+        }
+    }
+
+    // the temperature drifts the resistance of 6-band resistors and derates the power
+    if specs.tcr.is_some() || power_limits.is_some() {
+        let ratio = (temperature - TEMPERATURES.start()) as f64
+            / (TEMPERATURES.end() - TEMPERATURES.start()) as f64;
+        let temperature_gauge = LineGauge::default()
+            .block(Block::bordered().title(format!(" {} (°C) ", lang.text(Text::Temperature))))
+            .filled_style(specs_style.add_modifier(Modifier::BOLD))
+            .unfilled_style(Style::default().add_modifier(Modifier::DIM))
+            .label(temperature.to_string())
+            .ratio(ratio);
+        frame.render_widget(temperature_gauge, spec_chuncks[5]);
+    }
+
+    let mut spans = vec![
+        Span::styled(
+            band_key.as_str(),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(format!(": {}, ", lang.text(Text::NextBand))),
+        Span::styled(
+            color_keys.as_str(),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(format!(": {}, ", lang.text(Text::PrevNextColor))),
+        Span::styled("3|4|5|6", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(format!(": {}, ", lang.text(Text::BandsCount))),
+    ];
+    spans.extend([
+        Span::styled("W", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(format!(": {}, ", lang.text(Text::PowerRatingKey))),
+    ]);
+    if specs.tcr.is_some() || power_limits.is_some() {
+        spans.extend([
+            Span::styled("+/-", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::TemperatureKey))),
+        ]);
+    }
+    spans.extend([
+        Span::styled("u/Ctrl+R", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(format!(": {}, ", lang.text(Text::UndoRedo))),
+        Span::styled(
+            tab_keys.as_str(),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(format!(": {}, ", lang.text(Text::PrevNextTab))),
+        Span::styled(
+            exit_key.as_str(),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(format!(": {}", lang.text(Text::Exit))),
+    ]);
+    let help_line = Line::from(spans);
+    let help_msg_rect = center_horizontal(chunks[4], help_line.width() as u16);
+    frame.render_widget(Paragraph::new(help_line), help_msg_rect);
+
+    let bands = model.color_codes_to_specs.resistor.bands();
+    model.hit_areas.bands = render_band_lists(
+        frame,
+        &bands_rect,
+        &bands,
+        Some(model.color_codes_to_specs.selected_band),
+        |i| band_semantic_info(bands.len(), i, lang),
+        |i, color| band_numeric_info(bands.len(), i, color),
+        &palette,
+        lang,
+    );
+}
+
+/// Renders the tab of the specs of a resistor to its color codes.
+fn render_specs_tab(frame: &mut Frame, model: &mut Model) {
+    let lang = model.language;
+    let tabs_width = tabs_width(&TABS, lang);
+    let palette = model.palette();
+    let keymap = &model.keymap;
+    let tab_keys = keymap.pair_label(Action::PrevTab, Action::NextTab);
+    let exit_key = keymap.label(Action::Exit);
+    let determine_key = keymap.label(Action::Determine);
+    let reset_key = keymap.label(Action::Reset);
+    let specs_style = specs_style(model);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints(
+            [
+                Constraint::Length(2),
+                Constraint::Length(3),
+                Constraint::Min(1),
+                Constraint::Length(1),
+            ]
+            .as_ref(),
+        )
+        .split(frame.area());
+    let input_rects = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+        ])
+        .split(chunks[1]);
+
+    let tabs_rect = center_horizontal(chunks[0], tabs_width);
+    let help_msg_rect = center_horizontal(chunks[3], 115);
+    let resistance_rect = input_rects[0];
+    let tolerance_rect = input_rects[1];
+    let tcr_rect = input_rects[2];
+    let main_rect = chunks[2];
+
+    model.hit_areas.tabs = render_tabs(frame, &model.selected_tab, tabs_rect, lang);
+
+    let (msg, style) = (
+        vec![
+            Span::styled("Tab", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::NextInput))),
+            Span::styled(
+                determine_key.as_str(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(": {}, ", lang.text(Text::CalculateColorCodes))),
+            Span::styled("↑/↓", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(" {}, ", lang.text(Text::PrevNextHistory))),
+            Span::styled(
+                reset_key.as_str(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(": {}, ", lang.text(Text::Reset))),
+            Span::styled(
+                tab_keys.as_str(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(": {}, ", lang.text(Text::PrevNextTab))),
+            Span::styled(
+                exit_key.as_str(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(": {}", lang.text(Text::Exit))),
+        ],
+        Style::default(),
+    );
+    let text = match &model.specs_to_color.search {
+        Some(search) => StyledText::from(history_search_line(
+            search,
+            &model.specs_to_color.history,
+            specs_style,
+            lang,
+        )),
+        None => StyledText::from(Line::from(msg)).style(style),
+    };
+    let help_message = Paragraph::new(text);
+    frame.render_widget(help_message, help_msg_rect);
+
+    // render resistance input
+    let error_style = if model.monochrome {
+        Style::default().add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::Red)
+    };
+    // the inputs are validated on every keystroke, `None` is an empty input
+    let [resistance_valid, tolerance_valid, tcr_valid] = [
+        InputFocus::Resistance,
+        InputFocus::Tolerance,
+        InputFocus::Tcr,
+    ]
+    .map(|input| {
+        let specs = &model.specs_to_color;
+        let invalid =
+            specs.error_input.as_ref() == Some(&input) || validate_input(specs, &input).is_some();
+        (invalid || !specs.input_value(&input).trim().is_empty()).then_some(!invalid)
+    });
+    let monochrome = model.monochrome;
+    let validated = |block: Block<'static>, valid: Option<bool>, hint: &str| match valid {
+        Some(false) => {
+            let block = block.title(Line::from(format!(" ✗ {} ", hint)).right_aligned());
+            if monochrome {
+                block.border_type(BorderType::Thick)
+            } else {
+                block.border_style(error_style)
             }
         }
-        SelectedTab::SpecsToColorCodes => {
-            let chunks = Layout::default()
-                .direction(Direction::Vertical)
-                .margin(2)
-                .constraints(
-                    [
-                        Constraint::Length(2),
-                        Constraint::Length(3),
-                        Constraint::Min(1),
-                        Constraint::Length(1),
-                    ]
-                    .as_ref(),
-                )
-                .split(frame.area());
-            let input_rects = Layout::default()
-                .direction(Direction::Horizontal)
-                .constraints([
-                    Constraint::Ratio(1, 3),
-                    Constraint::Ratio(1, 3),
-                    Constraint::Ratio(1, 3),
-                ])
-                .split(chunks[1]);
-
-            let tabs_rect = center_horizontal(chunks[0], tabs_width);
-            let help_msg_rect = center_horizontal(chunks[3], 115);
-            let resistance_rect = input_rects[0];
-            let tolerance_rect = input_rects[1];
-            let tcr_rect = input_rects[2];
-            let main_rect = chunks[2];
-
-            let tabs = tabs(&model.selected_tab);
-            frame.render_widget(tabs, tabs_rect);
-
-            let (msg, style) = (
-                vec![
-                    Span::styled("Tab", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(": next input, "),
-                    Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(": calculate color codes, "),
-                    Span::styled("↑/↓", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(" prev/next history, "),
-                    Span::styled("X", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(": reset, "),
-                    Span::styled("Shift ←/→", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(": prev/next tab, "),
-                    Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(": exit"),
-                ],
-                Style::default(),
-            );
-            let text = Text::from(Line::from(msg)).style(style);
-            let help_message = Paragraph::new(text);
-            frame.render_widget(help_message, help_msg_rect);
-
-            // render resistance input
-            let resistance_block = Block::default().borders(Borders::ALL).style(specs_style);
-            let resistance_block = apply_title(
-                resistance_block,
-                &model.specs_to_color.focus,
-                InputFocus::Resistance,
-                " Resistance (Ω)",
-                specs_style,
-            );
-            model
-                .specs_to_color
-                .resistance_textarea
-                .set_block(resistance_block);
-            model
-                .specs_to_color
-                .resistance_textarea
-                .set_cursor_line_style(specs_style);
-            frame.render_widget(&model.specs_to_color.resistance_textarea, resistance_rect);
-
-            // render tolerance input
-            let tolerance_block = Block::default().borders(Borders::ALL).style(specs_style);
-            let tolerance_block = apply_title(
-                tolerance_block,
-                &model.specs_to_color.focus,
-                InputFocus::Tolerance,
-                " Tolerance (%)",
-                specs_style,
-            );
-            model
-                .specs_to_color
-                .tolerance_textarea
-                .set_block(tolerance_block);
-            model
-                .specs_to_color
-                .tolerance_textarea
-                .set_cursor_line_style(specs_style);
-            frame.render_widget(&model.specs_to_color.tolerance_textarea, tolerance_rect);
-
-            // render TCR input
-            let tcr_block = Block::default().borders(Borders::ALL).style(specs_style);
-            let tcr_block = apply_title(
-                tcr_block,
-                &model.specs_to_color.focus,
-                InputFocus::Tcr,
-                " TCR (ppm/K)",
-                specs_style,
+        Some(true) if !monochrome => block
+            .title(Line::from(" ✓ ").right_aligned())
+            .border_style(Style::default().fg(Color::Green)),
+        Some(true) => block.title(Line::from(" ✓ ").right_aligned()),
+        None => block,
+    };
+    let resistance_block = Block::default().borders(Borders::ALL).style(specs_style);
+    let resistance_block = apply_title(
+        resistance_block,
+        &model.specs_to_color.focus,
+        InputFocus::Resistance,
+        &format!(" {} (Ω)", lang.text(Text::Resistance)),
+        specs_style,
+    );
+    let resistance_block = validated(
+        resistance_block,
+        resistance_valid,
+        &format!("{} 4k7", lang.text(Text::Eg)),
+    );
+    model
+        .specs_to_color
+        .resistance_textarea
+        .set_block(resistance_block);
+    model
+        .specs_to_color
+        .resistance_textarea
+        .set_cursor_line_style(specs_style);
+    frame.render_widget(&model.specs_to_color.resistance_textarea, resistance_rect);
+
+    // render tolerance input
+    let tolerance_block = Block::default().borders(Borders::ALL).style(specs_style);
+    let tolerance_block = apply_title(
+        tolerance_block,
+        &model.specs_to_color.focus,
+        InputFocus::Tolerance,
+        &format!(" {} (%)", lang.text(Text::Tolerance)),
+        specs_style,
+    );
+    let tolerance_block = validated(tolerance_block, tolerance_valid, "PgUp/PgDn");
+    model
+        .specs_to_color
+        .tolerance_textarea
+        .set_block(tolerance_block);
+    model
+        .specs_to_color
+        .tolerance_textarea
+        .set_cursor_line_style(specs_style);
+    frame.render_widget(&model.specs_to_color.tolerance_textarea, tolerance_rect);
+
+    // render TCR input
+    let tcr_block = Block::default().borders(Borders::ALL).style(specs_style);
+    let tcr_block = apply_title(
+        tcr_block,
+        &model.specs_to_color.focus,
+        InputFocus::Tcr,
+        &format!(" {} (ppm/K)", lang.text(Text::Tcr)),
+        specs_style,
+    );
+    let tcr_block = validated(tcr_block, tcr_valid, "PgUp/PgDn");
+    let bands = match model.specs_to_color.bands {
+        Some(bands) => lang.fill(Text::BandsOf, &[&bands]),
+        None => lang.text(Text::AutoBands).to_string(),
+    };
+    let tcr_block = tcr_block.title_bottom(
+        Line::from(vec![
+            Span::styled(" Ctrl+B", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", bands)),
+            Span::styled("Ctrl+T", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(
+                ": {} ",
+                if model.specs_to_color.show_all {
+                    lang.text(Text::AllCounts)
+                } else {
+                    lang.text(Text::OneCount)
+                }
+            )),
+        ])
+        .right_aligned(),
+    );
+    model.specs_to_color.tcr_textarea.set_block(tcr_block);
+    model
+        .specs_to_color
+        .tcr_textarea
+        .set_cursor_line_style(specs_style);
+    frame.render_widget(&model.specs_to_color.tcr_textarea, tcr_rect);
+
+    if let Some(resistor) = &model.specs_to_color.resistor {
+        let specs = resistor.specs();
+        let chart_length = |resistor: &rusistor::Resistor| -> u16 {
+            let bands_len: u16 = (resistor.bands().len() as u16).clamp(2, 6); // give title enough space
+            let bands_widths = bands_len * BAR_WIDTH;
+            let bands_gaps = bands_len - 1;
+            let border_plus_margin = 4;
+            bands_widths + bands_gaps + border_plus_margin
+        };
+        // a zero ohm resistor has no standard values
+        let standard_values = standard_values(specs.ohm, &palette, lang);
+        let standard_height = if standard_values.is_some() { 5 } else { 0 };
+        let [chart_rect, standard_rect] =
+            Layout::vertical([Constraint::Min(1), Constraint::Length(standard_height)])
+                .areas(main_rect);
+        let all_resistors = &model.specs_to_color.all_resistors;
+        let resistors = if model.specs_to_color.show_all && !all_resistors.is_empty() {
+            all_resistors.iter().collect::<Vec<_>>()
+        } else {
+            vec![resistor]
+        };
+        let chart_rects = Layout::vertical(vec![
+            Constraint::Ratio(1, resistors.len() as u32);
+            resistors.len()
+        ])
+        .split(chart_rect);
+        for (resistor, rect) in resistors.into_iter().zip(chart_rects.iter()) {
+            let specs = resistor.specs();
+            let title = chart_title(&specs, lang);
+            render_resistor_chart(
+                frame,
+                center_horizontal(*rect, chart_length(resistor)),
+                resistor,
+                title,
+                BAR_WIDTH,
+                &palette,
+                lang,
             );
-            model.specs_to_color.tcr_textarea.set_block(tcr_block);
-            model
-                .specs_to_color
-                .tcr_textarea
-                .set_cursor_line_style(specs_style);
-            frame.render_widget(&model.specs_to_color.tcr_textarea, tcr_rect);
-
-            if let Some(resistor) = &model.specs_to_color.resistor {
-                let bands = resistor.bands();
-                let band_infos = bands
-                    .iter()
-                    .enumerate()
-                    .map(|(idx, c)| {
-                        let sem_info = band_semantic_info(bands.len(), idx);
-                        let num_info = band_numeric_info(bands.len(), idx, c);
-                        let (color, name) = rusistor_color_to_ratatui_color(c);
-                        (sem_info, num_info, color, name)
-                    })
-                    .collect::<Vec<(String, String, Color, String)>>();
-                let specs = resistor.specs();
-                let chart = barchart(&band_infos, specs.ohm, specs.tolerance, specs.tcr);
-                let chart_length: u16 = {
-                    let bands_len: u16 = (bands.len() as u16).clamp(2, 6); // give title enough space
-                    let bands_widths = bands_len * BAR_WIDTH;
-                    let bands_gaps = bands_len - 1;
-                    let border_plus_margin = 4;
-                    bands_widths + bands_gaps + border_plus_margin
-                };
-                let centered_main_rect = center_horizontal(main_rect, chart_length);
-                frame.render_widget(chart, centered_main_rect);
-            }
-            if let Some(e) = &model.specs_to_color.error {
-                let text = Text::from(e.to_string());
-                let error_message = Paragraph::new(text).style(Style::default().fg(Color::Red));
-                let centered_main_rect = center_horizontal(main_rect, e.len() as u16);
-                frame.render_widget(error_message, centered_main_rect);
-            }
+        }
+        if let Some((standard_values, width)) = standard_values {
+            frame.render_widget(standard_values, center_horizontal(standard_rect, width));
         }
     }
+    if let Some(choices) = model.specs_to_color.choices() {
+        let input_rect = match model.specs_to_color.focus {
+            InputFocus::Tcr => tcr_rect,
+            _ => tolerance_rect,
+        };
+        let items = choices.iter().map(|c| {
+            if c.is_empty() {
+                ListItem::new(format!(" {}", lang.text(Text::None)))
+            } else {
+                ListItem::new(format!(" {}", c))
+            }
+        });
+        let list = List::new(items)
+            .block(Block::bordered().title(" PgUp/PgDn "))
+            .style(specs_style)
+            .highlight_style(palette.highlight)
+            .highlight_symbol(">>");
+        let popup_rect = Rect::new(
+            input_rect.x,
+            input_rect.bottom(),
+            16.min(input_rect.width),
+            (choices.len() as u16 + 2).min(main_rect.height),
+        );
+        let mut state = ListState::default().with_selected(model.specs_to_color.selected_choice());
+        frame.render_widget(Clear, popup_rect);
+        frame.render_stateful_widget(list, popup_rect, &mut state);
+    }
+    if let Some(e) = &model.specs_to_color.error {
+        // the last resistor stays visible around the popup
+        let width = (e.chars().count() as u16 + 4).max(26).min(main_rect.width);
+        let lines = (e.chars().count() as u16).div_ceil(width.saturating_sub(4).max(1));
+        let [popup_rect] = Layout::vertical([Constraint::Length(lines + 2)])
+            .flex(Flex::Center)
+            .areas(main_rect);
+        let popup_rect = center_horizontal(popup_rect, width);
+        let block = Block::bordered()
+            .title(format!(" {} ", lang.text(Text::Error)))
+            .title_bottom(
+                Line::from(format!(" Enter/Esc: {} ", lang.text(Text::Dismiss))).right_aligned(),
+            )
+            .padding(Padding::horizontal(1));
+        let error_message = Paragraph::new(e.to_string())
+            .style(error_style)
+            .wrap(Wrap { trim: true })
+            .block(block);
+        frame.render_widget(Clear, popup_rect);
+        frame.render_widget(error_message, popup_rect);
+    }
 }
 
-fn barchart(
-    band_infos: &[(String, String, Color, String)],
-    ohm: f64,
-    tolerance: f64,
-    tcr: Option<u32>,
-) -> BarChart<'_> {
-    let bars: Vec<Bar> = band_infos.iter().map(|i| bar(i)).collect();
-    let tcr = if let Some(tcr) = tcr {
-        format!(" - TCR: {}(ppm/K)", tcr)
+/// Renders the tab of the values of the E-series.
+fn render_e_series_tab(frame: &mut Frame, model: &mut Model) {
+    let lang = model.language;
+    let tabs_width = tabs_width(&TABS, lang);
+    let palette = model.palette();
+    let keymap = &model.keymap;
+    let tab_keys = keymap.pair_label(Action::PrevTab, Action::NextTab);
+    let exit_key = keymap.label(Action::Exit);
+    let specs_style = specs_style(model);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints(
+            [
+                Constraint::Length(2),
+                Constraint::Length(3),
+                Constraint::Min(1),
+                Constraint::Length(1),
+            ]
+            .as_ref(),
+        )
+        .split(frame.area());
+    let input_rects = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+        ])
+        .split(chunks[1]);
+    let main_rects = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(24), Constraint::Min(1)])
+        .split(chunks[2]);
+
+    let tabs_rect = center_horizontal(chunks[0], tabs_width);
+    let help_msg_rect = center_horizontal(chunks[3], 125);
+
+    model.hit_areas.tabs = render_tabs(frame, &model.selected_tab, tabs_rect, lang);
+
+    let (msg, style) = (
+        vec![
+            Span::styled("Tab", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::NextSeries))),
+            Span::styled("←/→", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::PrevNextDecade))),
+            Span::styled("↑/↓", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::PrevNextValue))),
+            Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::ShowColorCodes))),
+            Span::styled(
+                tab_keys.as_str(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(": {}, ", lang.text(Text::PrevNextTab))),
+            Span::styled(
+                exit_key.as_str(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(": {}", lang.text(Text::Exit))),
+        ],
+        Style::default(),
+    );
+    let text = StyledText::from(Line::from(msg)).style(style);
+    let help_message = Paragraph::new(text);
+    frame.render_widget(help_message, help_msg_rect);
+
+    let series = model.eseries.series;
+    let series_paragraph = Paragraph::new(format!("{} (±{}%)", series, series.tolerance()))
+        .style(specs_style)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" {} ", lang.text(Text::Series))),
+        );
+    frame.render_widget(series_paragraph, input_rects[0]);
+
+    let decade = 10f64.powi(model.eseries.decade);
+    let decade_paragraph = Paragraph::new(format!("{}Ω - {}Ω", decade, decade * 10.0))
+        .style(specs_style)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" {} ", lang.text(Text::Decade))),
+        );
+    frame.render_widget(decade_paragraph, input_rects[1]);
+
+    let filter_block = Block::default()
+        .borders(Borders::ALL)
+        .style(specs_style)
+        .title(format!(" {} ", lang.text(Text::Filter)));
+    model.eseries.filter_textarea.set_block(filter_block);
+    model
+        .eseries
+        .filter_textarea
+        .set_cursor_line_style(specs_style);
+    frame.render_widget(&model.eseries.filter_textarea, input_rects[2]);
+
+    let values = model
+        .eseries
+        .values()
+        .iter()
+        .map(|v| ListItem::new(format!("{v}Ω")))
+        .collect::<Vec<_>>();
+    let highlight_style = if model.monochrome {
+        Style::default().add_modifier(Modifier::REVERSED)
+    } else {
+        palette.highlight
+    };
+    let list = List::new(values)
+        .block(Block::bordered().title(format!(" {} ", lang.text(Text::Values))))
+        .highlight_symbol(">> ")
+        .highlight_style(highlight_style);
+    let mut state = ListState::default().with_selected(Some(model.eseries.selected_value));
+    frame.render_stateful_widget(list, main_rects[0], &mut state);
+
+    if let Some(resistor) = model.eseries.selected_resistor() {
+        let specs = resistor.specs();
+        let title = chart_title(&specs, lang);
+        let area = main_rects[1];
+        render_resistor_chart(frame, area, &resistor, title, BAR_WIDTH, &palette, lang);
+    }
+}
+
+/// Renders the tab of the resistor networks.
+fn render_network_tab(frame: &mut Frame, model: &mut Model) {
+    let lang = model.language;
+    let tabs_width = tabs_width(&TABS, lang);
+    let palette = model.palette();
+    let keymap = &model.keymap;
+    let tab_keys = keymap.pair_label(Action::PrevTab, Action::NextTab);
+    let exit_key = keymap.label(Action::Exit);
+    let specs_style = specs_style(model);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints(
+            [
+                Constraint::Length(2),
+                Constraint::Length(3),
+                // ↑/↓ select the colors here, the lists don't get compact
+                Constraint::Length(
+                    band_lists_height(frame.area(), 16).max(COMPACT_BAND_LISTS_HEIGHT + 1),
+                ),
+                Constraint::Min(3),
+                Constraint::Length(3),
+                Constraint::Length(1),
+            ]
+            .as_ref(),
+        )
+        .split(frame.area());
+    let input_rects = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Ratio(1, 3), Constraint::Ratio(2, 3)])
+        .split(chunks[1]);
+    let bands_rect = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Ratio(1, 6),
+            Constraint::Ratio(1, 6),
+            Constraint::Ratio(1, 6),
+            Constraint::Ratio(1, 6),
+            Constraint::Ratio(1, 6),
+            Constraint::Ratio(1, 6),
+        ])
+        .split(chunks[2]);
+
+    let tabs_rect = center_horizontal(chunks[0], tabs_width);
+    let help_msg_rect = center_horizontal(chunks[5], 134);
+
+    model.hit_areas.tabs = render_tabs(frame, &model.selected_tab, tabs_rect, lang);
+
+    let (msg, style) = (
+        vec![
+            Span::styled("Tab", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::Focus))),
+            Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::Add))),
+            Span::styled("Del", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::Remove))),
+            Span::styled("Ctrl+G", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::NewGroup))),
+            Span::styled("Ctrl+T", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::Connection))),
+            Span::styled("PgUp/PgDn", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::Group))),
+            Span::styled("Ctrl+X", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::Clear))),
+            Span::styled(
+                tab_keys.as_str(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(": {}, ", lang.text(Text::Tab))),
+            Span::styled(
+                exit_key.as_str(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(": {}", lang.text(Text::Exit))),
+        ],
+        Style::default(),
+    );
+    let text = StyledText::from(Line::from(msg)).style(style);
+    let help_message = Paragraph::new(text);
+    frame.render_widget(help_message, help_msg_rect);
+
+    // render value input
+    let value_block = Block::default().borders(Borders::ALL).style(specs_style);
+    let value_block = if model.network.focus == NetworkFocus::Value {
+        value_block
+            .title(format!(" {}* ", lang.text(Text::ValueAndTolerance)))
+            .title_style(specs_style.bold())
     } else {
-        String::from("")
+        value_block.title(format!(" {} ", lang.text(Text::ValueAndTolerance)))
     };
-    let title = format!(
-        " Resistance: {}Ω - Tolerance: ±{}%{} ",
-        ohm,
-        tolerance * 100.0,
-        tcr
+    model.network.value_textarea.set_block(value_block);
+    model
+        .network
+        .value_textarea
+        .set_cursor_line_style(specs_style);
+    frame.render_widget(&model.network.value_textarea, input_rects[0]);
+
+    if let Some(e) = &model.network.error {
+        let error_style = if model.monochrome {
+            Style::default().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Red)
+        };
+        let error_message = Paragraph::new(e.to_string())
+            .style(error_style)
+            .block(Block::default().padding(Padding::uniform(1)));
+        frame.render_widget(error_message, input_rects[1]);
+    }
+
+    let bands = model.network.bands.resistor.bands();
+    model.hit_areas.bands = render_band_lists(
+        frame,
+        &bands_rect,
+        &bands,
+        (model.network.focus == NetworkFocus::Bands).then_some(model.network.bands.selected_band),
+        |i| band_semantic_info(bands.len(), i, lang),
+        |i, color| band_numeric_info(bands.len(), i, color),
+        &palette,
+        lang,
     );
-    let title = Line::from(title).centered();
-    BarChart::default()
-        .data(BarGroup::default().bars(&bars))
-        .block(
-            Block::new()
-                .padding(Padding::new(1, 1, 1, 1))
-                .title(title)
-                .borders(Borders::all()),
+
+    let groups = model
+        .network
+        .groups
+        .iter()
+        .enumerate()
+        .map(|(idx, group)| {
+            let separator = match group.connection {
+                rusistor::network::Connection::Series => " + ",
+                rusistor::network::Connection::Parallel => " ∥ ",
+            };
+            let parts = group
+                .parts
+                .iter()
+                .map(format_combined)
+                .collect::<Vec<String>>()
+                .join(separator);
+            let combined = group
+                .combined()
+                .map(|c| format!(" = {}", format_combined(&c)))
+                .unwrap_or_default();
+            ListItem::new(format!(
+                " {} {}: {}{}",
+                idx + 1,
+                group.connection,
+                parts,
+                combined
+            ))
+        })
+        .collect::<Vec<ListItem>>();
+    let highlight_style = if model.monochrome {
+        Style::default().add_modifier(Modifier::REVERSED)
+    } else {
+        palette.highlight
+    };
+    let list = List::new(groups)
+        .block(Block::bordered().title(format!(" {} ", lang.text(Text::Groups))))
+        .highlight_symbol(">> ")
+        .highlight_style(highlight_style);
+    let mut state = ListState::default().with_selected(Some(model.network.selected_group));
+    frame.render_stateful_widget(list, chunks[3], &mut state);
+
+    let total = model
+        .network
+        .total()
+        .map(|total| {
+            format!(
+                "{} (min {}Ω, max {}Ω)",
+                format_combined(&total),
+                round_ohm(total.min_ohm),
+                round_ohm(total.max_ohm)
+            )
+        })
+        .unwrap_or_default();
+    let total_paragraph = Paragraph::new(total).style(specs_style).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {} ", lang.text(Text::Total))),
+    );
+    frame.render_widget(total_paragraph, chunks[4]);
+}
+
+/// Renders the tab of Ohm's law.
+fn render_ohms_law_tab(frame: &mut Frame, model: &mut Model) {
+    let lang = model.language;
+    let tabs_width = tabs_width(&TABS, lang);
+    let keymap = &model.keymap;
+    let tab_keys = keymap.pair_label(Action::PrevTab, Action::NextTab);
+    let exit_key = keymap.label(Action::Exit);
+    let reset_key = keymap.label(Action::Reset);
+    let specs_style = specs_style(model);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints(
+            [
+                Constraint::Length(2),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Min(1),
+                Constraint::Length(1),
+            ]
+            .as_ref(),
         )
-        .bar_width(BAR_WIDTH)
-        .bar_gap(1)
+        .split(frame.area());
+    let quarters = [
+        Constraint::Ratio(1, 4),
+        Constraint::Ratio(1, 4),
+        Constraint::Ratio(1, 4),
+        Constraint::Ratio(1, 4),
+    ];
+    let input_rects = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(quarters)
+        .split(chunks[1]);
+    let result_rects = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(quarters)
+        .split(chunks[2]);
+
+    let tabs_rect = center_horizontal(chunks[0], tabs_width);
+    let help_msg_rect = center_horizontal(chunks[4], 116);
+
+    model.hit_areas.tabs = render_tabs(frame, &model.selected_tab, tabs_rect, lang);
+
+    let (msg, style) = (
+        vec![
+            Span::styled("Tab", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::NextInput))),
+            Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::Calculate))),
+            Span::styled("Ctrl+L", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::LinkResistor))),
+            Span::styled(
+                reset_key.as_str(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(": {}, ", lang.text(Text::Reset))),
+            Span::styled(
+                tab_keys.as_str(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(": {}, ", lang.text(Text::PrevNextTab))),
+            Span::styled(
+                exit_key.as_str(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(": {}", lang.text(Text::Exit))),
+        ],
+        Style::default(),
+    );
+    let text = StyledText::from(Line::from(msg)).style(style);
+    let help_message = Paragraph::new(text);
+    frame.render_widget(help_message, help_msg_rect);
+
+    let ohms_law = &mut model.ohms_law;
+    let resistance_title = if ohms_law.linked {
+        format!(
+            " {} (Ω, {})",
+            lang.text(Text::Resistance),
+            lang.text(Text::Linked)
+        )
+    } else {
+        format!(" {} (Ω)", lang.text(Text::Resistance))
+    };
+    let inputs = [
+        (
+            &mut ohms_law.voltage_textarea,
+            OhmsLawFocus::Voltage,
+            format!(" {} (V)", lang.text(Text::Voltage)),
+        ),
+        (
+            &mut ohms_law.current_textarea,
+            OhmsLawFocus::Current,
+            format!(" {} (A)", lang.text(Text::Current)),
+        ),
+        (
+            &mut ohms_law.resistance_textarea,
+            OhmsLawFocus::Resistance,
+            resistance_title,
+        ),
+        (
+            &mut ohms_law.power_textarea,
+            OhmsLawFocus::Power,
+            format!(" {} (W)", lang.text(Text::Power)),
+        ),
+    ];
+    for ((textarea, focus, title), rect) in inputs.into_iter().zip(input_rects.iter()) {
+        let block = Block::default().borders(Borders::ALL).style(specs_style);
+        let block = if ohms_law.focus == focus {
+            block
+                .title(format!("{}* ", title))
+                .title_style(specs_style.bold())
+        } else {
+            block.title(format!("{} ", title))
+        };
+        textarea.set_block(block);
+        textarea.set_cursor_line_style(specs_style);
+        frame.render_widget(&*textarea, *rect);
+    }
+
+    if let Some(result) = &ohms_law.result {
+        let results = [
+            (result.voltage, " V "),
+            (result.current, " A "),
+            (result.resistance, " Ω "),
+            (result.power, " W "),
+        ];
+        for ((value, unit), rect) in results.into_iter().zip(result_rects.iter()) {
+            let paragraph = Paragraph::new(round_significant(value, 6).to_string())
+                .style(specs_style)
+                .block(Block::default().borders(Borders::ALL).title(unit));
+            frame.render_widget(paragraph, *rect);
+        }
+    }
+    if let Some(e) = &ohms_law.error {
+        let error_style = if model.monochrome {
+            Style::default().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Red)
+        };
+        let error_message = Paragraph::new(e.to_string()).style(error_style);
+        let centered_rect = center_horizontal(chunks[3], e.len() as u16);
+        frame.render_widget(error_message, centered_rect);
+    }
 }
 
-fn bar((sem_info, num_info, color, name): &(String, String, Color, String)) -> Bar<'_> {
-    Bar::default()
-        .value(100)
-        .text_value(format!(" {} ", name))
-        .value_style(Style::default().fg(Color::White).bg(Color::Black))
-        .label(Line::from(format!("{}: {}", sem_info, num_info.trim())))
-        .style(bar_style(color))
-}
-
-fn bar_style(color: &Color) -> Style {
-    Style::new().fg(*color)
-}
-
-fn rusistor_color_to_ratatui_color(color: &rusistor::Color) -> (Color, String) {
-    match color {
-        rusistor::Color::Black => (Color::Black, rusistor::Color::Black.to_string()),
-        rusistor::Color::Brown => (Color::Rgb(165, 42, 42), rusistor::Color::Brown.to_string()),
-        rusistor::Color::Red => (Color::Red, rusistor::Color::Red.to_string()),
-        rusistor::Color::Orange => (Color::Rgb(255, 165, 0), rusistor::Color::Orange.to_string()),
-        rusistor::Color::Yellow => (Color::Yellow, rusistor::Color::Yellow.to_string()),
-        rusistor::Color::Green => (Color::Green, rusistor::Color::Green.to_string()),
-        rusistor::Color::Blue => (Color::Blue, rusistor::Color::Blue.to_string()),
-        rusistor::Color::Violet => (Color::Rgb(148, 0, 211), rusistor::Color::Violet.to_string()),
-        rusistor::Color::Grey => (Color::Gray, rusistor::Color::Grey.to_string()),
-        rusistor::Color::White => (Color::White, rusistor::Color::White.to_string()),
-        rusistor::Color::Gold => (Color::Rgb(255, 215, 0), rusistor::Color::Gold.to_string()),
-        rusistor::Color::Silver => (
-            Color::Rgb(192, 192, 192),
-            rusistor::Color::Silver.to_string(),
+/// Renders the tab of the voltage dividers.
+fn render_divider_tab(frame: &mut Frame, model: &mut Model) {
+    let lang = model.language;
+    let tabs_width = tabs_width(&TABS, lang);
+    let palette = model.palette();
+    let keymap = &model.keymap;
+    let tab_keys = keymap.pair_label(Action::PrevTab, Action::NextTab);
+    let exit_key = keymap.label(Action::Exit);
+    let specs_style = specs_style(model);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints(
+            [
+                Constraint::Length(2),
+                Constraint::Length(3),
+                Constraint::Length(12),
+                Constraint::Min(1),
+                Constraint::Length(1),
+            ]
+            .as_ref(),
+        )
+        .split(frame.area());
+    let input_rects = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+        ])
+        .split(chunks[1]);
+    let chart_rects = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
+        .split(chunks[3]);
+
+    let tabs_rect = center_horizontal(chunks[0], tabs_width);
+    let help_msg_rect = center_horizontal(chunks[4], 115);
+
+    model.hit_areas.tabs = render_tabs(frame, &model.selected_tab, tabs_rect, lang);
+
+    let (msg, style) = (
+        vec![
+            Span::styled("Tab", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::NextInput))),
+            Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::Suggest))),
+            Span::styled("PgUp/PgDn", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::SeriesKey))),
+            Span::styled("↑/↓", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::PrevNextPair))),
+            Span::styled(
+                tab_keys.as_str(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(": {}, ", lang.text(Text::PrevNextTab))),
+            Span::styled(
+                exit_key.as_str(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(": {}", lang.text(Text::Exit))),
+        ],
+        Style::default(),
+    );
+    let text = StyledText::from(Line::from(msg)).style(style);
+    let help_message = Paragraph::new(text);
+    frame.render_widget(help_message, help_msg_rect);
+
+    let divider = &mut model.divider;
+    let inputs = [
+        (
+            &mut divider.vin_textarea,
+            DividerFocus::Vin,
+            String::from(" Vin (V)"),
+        ),
+        (
+            &mut divider.vout_textarea,
+            DividerFocus::Vout,
+            format!(" {} (V)", lang.text(Text::TargetVout)),
         ),
-        rusistor::Color::Pink => (Color::Rgb(255, 105, 180), rusistor::Color::Pink.to_string()),
+    ];
+    for ((textarea, focus, title), rect) in inputs.into_iter().zip(input_rects.iter()) {
+        let block = Block::default().borders(Borders::ALL).style(specs_style);
+        let block = if divider.focus == focus {
+            block
+                .title(format!("{}* ", title))
+                .title_style(specs_style.bold())
+        } else {
+            block.title(format!("{} ", title))
+        };
+        textarea.set_block(block);
+        textarea.set_cursor_line_style(specs_style);
+        frame.render_widget(&*textarea, *rect);
+    }
+
+    let series = divider.series;
+    let series_paragraph = Paragraph::new(format!("{} (±{}%)", series, series.tolerance()))
+        .style(specs_style)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" {} ", lang.text(Text::Series))),
+        );
+    frame.render_widget(series_paragraph, input_rects[2]);
+
+    if let Some(e) = &divider.error {
+        let error_style = if model.monochrome {
+            Style::default().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Red)
+        };
+        let error_message = Paragraph::new(e.to_string()).style(error_style);
+        let centered_rect = center_horizontal(chunks[2], e.len() as u16);
+        frame.render_widget(error_message, centered_rect);
+    }
+
+    if let Some((vin, vout)) = divider.voltages {
+        let suggestions = divider
+            .suggestions
+            .iter()
+            .map(|d| {
+                ListItem::new(format!(
+                    "R1 {:>7}Ω  R2 {:>7}Ω  Vout {:>8}V  {} {:>+7}%  {} {:>8}mA",
+                    d.r1,
+                    d.r2,
+                    round_significant(d.vout(vin), 4),
+                    lang.text(Text::ErrorLabel),
+                    round_significant(d.error(vin, vout) * 100.0, 3),
+                    lang.text(Text::CurrentLabel),
+                    round_significant(d.current(vin) * 1000.0, 3)
+                ))
+            })
+            .collect::<Vec<ListItem>>();
+        let highlight_style = if model.monochrome {
+            Style::default().add_modifier(Modifier::REVERSED)
+        } else {
+            palette.highlight
+        };
+        let list = List::new(suggestions)
+            .block(Block::bordered().title(format!(" {} ", lang.text(Text::Suggestions))))
+            .highlight_symbol(">> ")
+            .highlight_style(highlight_style);
+        let mut state = ListState::default().with_selected(Some(divider.selected_suggestion));
+        frame.render_stateful_widget(list, chunks[2], &mut state);
+    }
+
+    if let Some((r1, r2)) = divider.selected_resistors() {
+        for (name, resistor, rect) in [("R1", r1, chart_rects[0]), ("R2", r2, chart_rects[1])] {
+            let specs = resistor.specs();
+            let title = Line::from(format!(
+                " {}: {}Ω - {}: ±{}% ",
+                name,
+                specs.ohm,
+                lang.text(Text::Tolerance),
+                specs.tolerance * 100.0
+            ))
+            .centered();
+            let bands = resistor.bands().len() as u16;
+            let bar_width = (rect.width.saturating_sub(4 + bands - 1) / bands).min(BAR_WIDTH);
+            render_resistor_chart(frame, rect, &resistor, title, bar_width, &palette, lang);
+        }
+    }
+}
+
+/// Renders the tab of the series resistors of LEDs.
+fn render_led_tab(frame: &mut Frame, model: &mut Model) {
+    let lang = model.language;
+    let tabs_width = tabs_width(&TABS, lang);
+    let palette = model.palette();
+    let keymap = &model.keymap;
+    let tab_keys = keymap.pair_label(Action::PrevTab, Action::NextTab);
+    let exit_key = keymap.label(Action::Exit);
+    let specs_style = specs_style(model);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints(
+            [
+                Constraint::Length(2),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Min(1),
+                Constraint::Length(1),
+            ]
+            .as_ref(),
+        )
+        .split(frame.area());
+    let quarters = [
+        Constraint::Ratio(1, 4),
+        Constraint::Ratio(1, 4),
+        Constraint::Ratio(1, 4),
+        Constraint::Ratio(1, 4),
+    ];
+    let input_rects = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(quarters)
+        .split(chunks[1]);
+    let result_rects = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(quarters)
+        .split(chunks[2]);
+
+    let tabs_rect = center_horizontal(chunks[0], tabs_width);
+    let help_msg_rect = center_horizontal(chunks[4], 100);
+
+    model.hit_areas.tabs = render_tabs(frame, &model.selected_tab, tabs_rect, lang);
+
+    let (msg, style) = (
+        vec![
+            Span::styled("Tab", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::NextInput))),
+            Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::Calculate))),
+            Span::styled("PgUp/PgDn", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::SeriesKey))),
+            Span::styled(
+                tab_keys.as_str(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(": {}, ", lang.text(Text::PrevNextTab))),
+            Span::styled(
+                exit_key.as_str(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(": {}", lang.text(Text::Exit))),
+        ],
+        Style::default(),
+    );
+    let text = StyledText::from(Line::from(msg)).style(style);
+    let help_message = Paragraph::new(text);
+    frame.render_widget(help_message, help_msg_rect);
+
+    let led = &mut model.led;
+    let inputs = [
+        (
+            &mut led.supply_textarea,
+            LedFocus::Supply,
+            format!(" {} (V)", lang.text(Text::Supply)),
+        ),
+        (
+            &mut led.forward_voltage_textarea,
+            LedFocus::ForwardVoltage,
+            format!(" {} (V)", lang.text(Text::LedVoltage)),
+        ),
+        (
+            &mut led.current_textarea,
+            LedFocus::Current,
+            format!(" {} (mA)", lang.text(Text::Current)),
+        ),
+    ];
+    for ((textarea, focus, title), rect) in inputs.into_iter().zip(input_rects.iter()) {
+        let block = Block::default().borders(Borders::ALL).style(specs_style);
+        let block = if led.focus == focus {
+            block
+                .title(format!("{}* ", title))
+                .title_style(specs_style.bold())
+        } else {
+            block.title(format!("{} ", title))
+        };
+        textarea.set_block(block);
+        textarea.set_cursor_line_style(specs_style);
+        frame.render_widget(&*textarea, *rect);
+    }
+
+    let series = led.series;
+    let series_paragraph = Paragraph::new(format!("{} (±{}%)", series, series.tolerance()))
+        .style(specs_style)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" {} ", lang.text(Text::Series))),
+        );
+    frame.render_widget(series_paragraph, input_rects[3]);
+
+    if let Some(result) = &led.result {
+        let rating = result
+            .recommended_rating()
+            .map(|r| format!("{}W", r))
+            .unwrap_or(lang.fill(Text::MoreThan, &[&"5W"]));
+        let results = [
+            (
+                format!("{}Ω", round_significant(result.resistance, 6)),
+                format!(" {} ", lang.text(Text::ExactResistance)),
+            ),
+            (
+                format!(
+                    "{}Ω {} {}mA",
+                    result.standard,
+                    lang.text(Text::At),
+                    round_significant(result.current * 1000.0, 3)
+                ),
+                format!(" {} ", lang.text(Text::NearestStandardValue)),
+            ),
+            (
+                format!("{}W", round_significant(result.power, 3)),
+                format!(" {} ", lang.text(Text::PowerDissipation)),
+            ),
+            (rating, format!(" {} ", lang.text(Text::RecommendedRating))),
+        ];
+        for ((value, title), rect) in results.into_iter().zip(result_rects.iter()) {
+            let paragraph = Paragraph::new(value)
+                .style(specs_style)
+                .block(Block::default().borders(Borders::ALL).title(title));
+            frame.render_widget(paragraph, *rect);
+        }
+    }
+    if let Some(resistor) = led.resistor() {
+        let specs = resistor.specs();
+        let title = chart_title(&specs, lang);
+        let bands = resistor.bands().len() as u16;
+        let chart_length = bands * BAR_WIDTH + bands - 1 + 4;
+        let area = center_horizontal(chunks[3], chart_length);
+        render_resistor_chart(frame, area, &resistor, title, BAR_WIDTH, &palette, lang);
+    }
+    if let Some(e) = &led.error {
+        let error_style = if model.monochrome {
+            Style::default().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Red)
+        };
+        let error_message = Paragraph::new(e.to_string()).style(error_style);
+        let centered_rect = center_horizontal(chunks[3], e.len() as u16);
+        frame.render_widget(error_message, centered_rect);
+    }
+}
+
+/// Renders the tab of the color codes quiz.
+fn render_quiz_tab(frame: &mut Frame, model: &mut Model) {
+    let lang = model.language;
+    let tabs_width = tabs_width(&TABS, lang);
+    let palette = model.palette();
+    let keymap = &model.keymap;
+    let tab_keys = keymap.pair_label(Action::PrevTab, Action::NextTab);
+    let exit_key = keymap.label(Action::Exit);
+    let specs_style = specs_style(model);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints(
+            [
+                Constraint::Length(2),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Min(1),
+                Constraint::Length(1),
+            ]
+            .as_ref(),
+        )
+        .split(frame.area());
+    let status_rects = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Ratio(1, 3), Constraint::Ratio(2, 3)])
+        .split(chunks[1]);
+
+    let tabs_rect = center_horizontal(chunks[0], tabs_width);
+
+    model.hit_areas.tabs = render_tabs(frame, &model.selected_tab, tabs_rect, lang);
+
+    let quiz = &mut model.quiz;
+    let mut msg = if model.show_quiz_stats {
+        vec![
+            Span::styled("Ctrl+S", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::BackToQuiz))),
+        ]
+    } else {
+        vec![
+            Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::Check))),
+            Span::styled("Ctrl+N", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::Skip))),
+            Span::styled("Ctrl+T", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::Mode))),
+            Span::styled("Ctrl+S", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::Stats))),
+        ]
+    };
+    if quiz.mode == QuizMode::ValueToColors && !model.show_quiz_stats {
+        msg.extend([
+            Span::styled("←/→", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::Band))),
+            Span::styled("↑/↓", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::Color))),
+            Span::styled("3|4|5|6", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}, ", lang.text(Text::BandsCount))),
+        ]);
+    }
+    msg.extend([
+        Span::styled(
+            tab_keys.as_str(),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(format!(": {}, ", lang.text(Text::PrevNextTab))),
+        Span::styled(
+            exit_key.as_str(),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(format!(": {}", lang.text(Text::Exit))),
+    ]);
+    let help_line = Line::from(msg);
+    let help_msg_rect = center_horizontal(chunks[4], help_line.width() as u16);
+    frame.render_widget(Paragraph::new(help_line), help_msg_rect);
+
+    let score = lang.fill(
+        Text::QuizScore,
+        &[&quiz.correct, &quiz.asked, &quiz.streak, &quiz.best_streak],
+    );
+    let score_paragraph = Paragraph::new(score).style(specs_style).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {} ", lang.text(Text::Score))),
+    );
+    frame.render_widget(score_paragraph, status_rects[0]);
+
+    if let Some((is_correct, solution)) = &quiz.last_result {
+        let (verdict, style) = match (is_correct, model.monochrome) {
+            (true, false) => (Text::CorrectAnswer, Style::default().fg(Color::Green)),
+            (false, false) => (Text::WrongAnswer, Style::default().fg(Color::Red)),
+            (true, true) => (Text::CorrectAnswer, Style::default()),
+            (false, true) => (
+                Text::WrongAnswer,
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+        };
+        let result_paragraph = Paragraph::new(format!("{}: {}", lang.text(verdict), solution))
+            .style(style)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!(" {} ", lang.text(Text::LastAnswer))),
+            );
+        frame.render_widget(result_paragraph, status_rects[1]);
+    }
+
+    let question = quiz.question.specs();
+    match quiz.mode {
+        _ if model.show_quiz_stats => {
+            let area = chunks[2].union(chunks[3]);
+            render_quiz_stats(frame, area, &model.quiz_stats, &palette, lang);
+        }
+        QuizMode::ColorsToValue => {
+            let answer_block = Block::default()
+                .borders(Borders::ALL)
+                .style(specs_style)
+                .title(format!(" {}* ", lang.text(Text::ResistanceOfBands)))
+                .title_style(specs_style.bold());
+            quiz.answer_textarea.set_block(answer_block);
+            quiz.answer_textarea.set_cursor_line_style(specs_style);
+            frame.render_widget(&quiz.answer_textarea, chunks[2]);
+
+            let bands = quiz.question.bands().len() as u16;
+            let chart_length = bands * BAR_WIDTH + bands - 1 + 4;
+            let area = center_horizontal(chunks[3], chart_length);
+            let title = Line::from(format!(" {} ", lang.text(Text::Question))).centered();
+            render_resistor_chart(
+                frame,
+                area,
+                &quiz.question,
+                title,
+                BAR_WIDTH,
+                &palette,
+                lang,
+            );
+        }
+        QuizMode::ValueToColors => {
+            let tcr = question
+                .tcr
+                .map(|tcr| format!(", {} {}ppm/K", lang.text(Text::Tcr), tcr))
+                .unwrap_or_default();
+            let specs = format!("{}Ω ±{}%{}", question.ohm, question.tolerance * 100.0, tcr);
+            let text = lang.fill(Text::WhichBands, &[&specs]);
+            let question_paragraph = Paragraph::new(text).style(specs_style).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!(" {} ", lang.text(Text::Question))),
+            );
+            frame.render_widget(question_paragraph, chunks[2]);
+
+            let bands_rect = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Ratio(1, 6); 6])
+                .split(chunks[3]);
+            let bands = quiz.answer_bands.resistor.bands();
+            model.hit_areas.bands = render_band_lists(
+                frame,
+                &bands_rect,
+                &bands,
+                Some(quiz.answer_bands.selected_band),
+                |i| band_semantic_info(bands.len(), i, lang),
+                |i, color| band_numeric_info(bands.len(), i, color),
+                &palette,
+                lang,
+            );
+        }
+    }
+}
+
+/// Renders the tab of the color codes of capacitors.
+fn render_capacitor_tab(frame: &mut Frame, model: &mut Model) {
+    let lang = model.language;
+
+    let specs = model.capacitor.capacitor.specs();
+    let specs_rows = [
+        (
+            format_capacitance(specs.picofarad),
+            format!(" {} ", lang.text(Text::Capacitance)),
+        ),
+        (
+            format!("±{}", specs.tolerance * 100.0),
+            format!(" {} (%) ", lang.text(Text::Tolerance)),
+        ),
+        (
+            specs.voltage.map(|v| v.to_string()).unwrap_or_default(),
+            format!(" {} (V) ", lang.text(Text::Voltage)),
+        ),
+    ];
+    let bands = model.capacitor.capacitor.bands().into_iter().copied();
+    render_band_code_tab(
+        frame,
+        model,
+        &specs_rows,
+        &bands.collect::<Vec<_>>(),
+        model.capacitor.selected_band,
+        5,
+        Some("4|5"),
+        |i| capacitor_band_semantic_info(i, lang),
+        capacitor_band_numeric_info,
+    );
+}
+
+/// Renders the tab of the color codes of inductors.
+fn render_inductor_tab(frame: &mut Frame, model: &mut Model) {
+    let lang = model.language;
+
+    let specs = model.inductor.inductor.specs();
+    let specs_rows = [
+        (
+            round_significant(specs.microhenry, 3).to_string(),
+            format!(" {} (µH) ", lang.text(Text::Inductance)),
+        ),
+        (
+            format!("±{}", specs.tolerance * 100.0),
+            format!(" {} (%) ", lang.text(Text::Tolerance)),
+        ),
+    ];
+    let bands = model.inductor.inductor.bands().into_iter().copied();
+    render_band_code_tab(
+        frame,
+        model,
+        &specs_rows,
+        &bands.collect::<Vec<_>>(),
+        model.inductor.selected_band,
+        4,
+        None,
+        |i| inductor_band_semantic_info(i, lang),
+        inductor_band_numeric_info,
+    );
+}
+
+/// Renders the tab of the comparison of resistors.
+fn render_compare_tab(frame: &mut Frame, model: &mut Model) {
+    let lang = model.language;
+    let tabs_width = tabs_width(&TABS, lang);
+    let palette = model.palette();
+    let keymap = &model.keymap;
+    let tab_keys = keymap.pair_label(Action::PrevTab, Action::NextTab);
+    let exit_key = keymap.label(Action::Exit);
+    let band_key = keymap.label(Action::NextBand);
+    let color_keys = keymap.pair_label(Action::PrevColor, Action::NextColor);
+    let specs_style = specs_style(model);
+
+    let lists_height = band_lists_height(frame.area(), 19);
+    model.compact_bands = lists_height == COMPACT_BAND_LISTS_HEIGHT;
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints(
+            [
+                Constraint::Length(2),
+                Constraint::Length(10),
+                Constraint::Length(3),
+                Constraint::Length(lists_height),
+                Constraint::Min(1),
+            ]
+            .as_ref(),
+        )
+        .split(frame.area());
+    let tabs_rect = center_horizontal(chunks[0], tabs_width);
+    let panel_rects = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Ratio(1, 2); 2])
+        .split(chunks[1]);
+    let bands_rect = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Ratio(1, 6); 6])
+        .split(chunks[3]);
+
+    model.hit_areas.tabs = render_tabs(frame, &model.selected_tab, tabs_rect, lang);
+
+    let compare = &model.compare;
+    for ((side, bands), rect) in [(CompareSide::A, &compare.a), (CompareSide::B, &compare.b)]
+        .into_iter()
+        .zip(panel_rects.iter())
+    {
+        let title = format!(" {:?}", side);
+        let block = if compare.focus == side {
+            Block::bordered()
+                .title(format!("{}* ", title))
+                .title_style(Style::default().bold())
+        } else {
+            Block::bordered().title(format!("{} ", title))
+        };
+        let panel = Paragraph::new(compare_lines(&bands.resistor, &palette, lang))
+            .style(specs_style)
+            .block(block.padding(Padding::horizontal(1)));
+        frame.render_widget(panel, *rect);
+    }
+
+    let (a, b) = (compare.a.resistor.specs(), compare.b.resistor.specs());
+    let diff = b.ohm - a.ohm;
+    let sign = if diff < 0.0 { "-" } else { "+" };
+    let percent = if a.ohm == 0.0 {
+        String::new()
+    } else {
+        format!(
+            " ({}{}%)",
+            sign,
+            round_significant(diff.abs() / a.ohm * 100.0, 3)
+        )
+    };
+    let (overlap, overlap_style) = match (a.overlaps(&b), model.monochrome) {
+        (true, true) => (Text::RangesOverlap, Style::default()),
+        (false, true) => (
+            Text::RangesDontOverlap,
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        (true, false) => (Text::RangesOverlap, Style::default().fg(Color::Green)),
+        (false, false) => (Text::RangesDontOverlap, Style::default().fg(Color::Red)),
+    };
+    let diff_line = Line::from(vec![
+        Span::styled(
+            format!("B - A: {}{}{}, ", sign, format_ohm(diff.abs()), percent),
+            specs_style,
+        ),
+        Span::styled(lang.text(overlap), overlap_style),
+    ]);
+    let diff_paragraph = Paragraph::new(diff_line)
+        .centered()
+        .block(Block::bordered().title(format!(" {} ", lang.text(Text::Difference))));
+    frame.render_widget(diff_paragraph, chunks[2]);
+
+    let help_line = Line::from(vec![
+        Span::styled("a|b", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(format!(": {}, ", lang.text(Text::ResistorKey))),
+        Span::styled(
+            band_key.as_str(),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(format!(": {}, ", lang.text(Text::NextBand))),
+        Span::styled(
+            color_keys.as_str(),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(format!(": {}, ", lang.text(Text::PrevNextColor))),
+        Span::styled("3|4|5|6", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(format!(": {}, ", lang.text(Text::BandsCount))),
+        Span::styled(
+            tab_keys.as_str(),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(format!(": {}, ", lang.text(Text::PrevNextTab))),
+        Span::styled(
+            exit_key.as_str(),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(format!(": {}", lang.text(Text::Exit))),
+    ]);
+    let help_msg_rect = center_horizontal(chunks[4], help_line.width() as u16);
+    frame.render_widget(Paragraph::new(help_line), help_msg_rect);
+
+    let focused = model.compare.focused();
+    let bands = focused.resistor.bands();
+    model.hit_areas.bands = render_band_lists(
+        frame,
+        &bands_rect,
+        &bands,
+        Some(focused.selected_band),
+        |i| band_semantic_info(bands.len(), i, lang),
+        |i, color| band_numeric_info(bands.len(), i, color),
+        &palette,
+        lang,
+    );
+}
+
+/// Renders the tab of the inventory of resistors.
+fn render_inventory_tab(frame: &mut Frame, model: &mut Model) {
+    let lang = model.language;
+    let tabs_width = tabs_width(&TABS, lang);
+    let palette = model.palette();
+    let keymap = &model.keymap;
+    let tab_keys = keymap.pair_label(Action::PrevTab, Action::NextTab);
+    let exit_key = keymap.label(Action::Exit);
+    let specs_style = specs_style(model);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints(
+            [
+                Constraint::Length(2),
+                Constraint::Length(3),
+                Constraint::Length(1),
+                Constraint::Min(1),
+                Constraint::Length(1),
+            ]
+            .as_ref(),
+        )
+        .split(frame.area());
+    let input_rects = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
+        .split(chunks[1]);
+
+    let tabs_rect = center_horizontal(chunks[0], tabs_width);
+    model.hit_areas.tabs = render_tabs(frame, &model.selected_tab, tabs_rect, lang);
+
+    let help_line = Line::from(vec![
+        Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(format!(": {}, ", lang.text(Text::Search))),
+        Span::styled("Ctrl+A", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(format!(": {}, ", lang.text(Text::Add))),
+        Span::styled("Ctrl+X", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(format!(": {}, ", lang.text(Text::ShowAll))),
+        Span::styled("↑/↓", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(format!(": {}, ", lang.text(Text::Select))),
+        Span::styled("+/-", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(format!(": {}, ", lang.text(Text::Quantity))),
+        Span::styled("Del", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(format!(": {}, ", lang.text(Text::Remove))),
+        Span::styled(
+            tab_keys.as_str(),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(format!(": {}, ", lang.text(Text::PrevNextTab))),
+        Span::styled(
+            exit_key.as_str(),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(format!(": {}", lang.text(Text::Exit))),
+    ]);
+    let help_msg_rect = center_horizontal(chunks[4], help_line.width() as u16);
+    frame.render_widget(Paragraph::new(help_line), help_msg_rect);
+
+    let inventory = &mut model.inventory;
+    let input_block = Block::default()
+        .borders(Borders::ALL)
+        .style(specs_style)
+        .title(format!(
+            " {} [{} (%)] ",
+            lang.text(Text::Resistance),
+            lang.text(Text::Tolerance)
+        ));
+    inventory.input_textarea.set_block(input_block);
+    inventory.input_textarea.set_cursor_line_style(specs_style);
+    frame.render_widget(&inventory.input_textarea, input_rects[0]);
+
+    let parts = &inventory.inventory.parts;
+    let pieces: u32 = parts.iter().map(|p| p.quantity).sum();
+    let stock_paragraph = Paragraph::new(lang.fill(Text::StockSummary, &[&parts.len(), &pieces]))
+        .style(specs_style)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" {} ", lang.text(Text::Stock))),
+        );
+    frame.render_widget(stock_paragraph, input_rects[1]);
+
+    if let Some(e) = &inventory.error {
+        let error_style = if model.monochrome {
+            Style::default().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Red)
+        };
+        let error_message = Paragraph::new(e.to_string()).style(error_style);
+        let centered_rect = center_horizontal(chunks[2], e.chars().count() as u16);
+        frame.render_widget(error_message, centered_rect);
+    }
+
+    let title = match &inventory.search {
+        Some(c) => format!(
+            " {} {}Ω - {}Ω ",
+            lang.text(Text::Within),
+            round_ohm(c.min_ohm),
+            round_ohm(c.max_ohm)
+        ),
+        None => format!(" {} ", lang.text(Text::Inventory)),
+    };
+    let items = inventory
+        .listed()
+        .into_iter()
+        .map(|idx| {
+            let part = &parts[idx];
+            ListItem::new(format!(
+                "{:>12}Ω {:<7} {:>6}x",
+                round_ohm(part.ohm),
+                format!("±{}%", round_significant(part.tolerance * 100.0, 3)),
+                part.quantity
+            ))
+        })
+        .collect::<Vec<_>>();
+    let highlight_style = if model.monochrome {
+        Style::default().add_modifier(Modifier::REVERSED)
+    } else {
+        palette.highlight
+    };
+    let list = List::new(items)
+        .block(Block::bordered().title(title))
+        .highlight_symbol(">> ")
+        .highlight_style(highlight_style);
+    let mut state = ListState::default().with_selected(Some(inventory.selected_part));
+    frame.render_stateful_widget(list, chunks[3], &mut state);
+}
+
+/// Renders a tab of the band code of a component, its specs above the lists of its bands,
+/// e.g. of capacitors. `bands_counts` are the keys of the bands counts in the help line.
+#[allow(clippy::too_many_arguments)]
+fn render_band_code_tab(
+    frame: &mut Frame,
+    model: &mut Model,
+    specs_rows: &[(String, String)],
+    bands: &[rusistor::Color],
+    selected_band: usize,
+    columns: u32,
+    bands_counts: Option<&str>,
+    semantic_info: impl Fn(usize) -> String,
+    numeric_info: impl Fn(usize, &rusistor::Color) -> String,
+) {
+    let lang = model.language;
+    let lists_height = band_lists_height(frame.area(), 10);
+    model.compact_bands = lists_height == COMPACT_BAND_LISTS_HEIGHT;
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints(
+            [
+                Constraint::Length(2),
+                Constraint::Length(3),
+                Constraint::Length(lists_height),
+                Constraint::Min(1),
+            ]
+            .as_ref(),
+        )
+        .split(frame.area());
+    let tabs_rect = center_horizontal(chunks[0], tabs_width(&TABS, lang));
+
+    let spec_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![Constraint::Ratio(1, columns); columns as usize])
+        .split(chunks[1]);
+    let bands_rect = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![Constraint::Ratio(1, columns); columns as usize])
+        .split(chunks[2]);
+
+    model.hit_areas.tabs = render_tabs(frame, &model.selected_tab, tabs_rect, lang);
+
+    let specs_style = specs_style(model);
+    for ((value, title), rect) in specs_rows.iter().zip(spec_chunks.iter()) {
+        let paragraph = Paragraph::new(value.as_str())
+            .style(specs_style)
+            .block(Block::default().borders(Borders::ALL).title(title.as_str()));
+        frame.render_widget(paragraph, *rect);
+    }
+
+    let keymap = &model.keymap;
+    let mut keys = vec![
+        (keymap.label(Action::NextBand), Text::NextBand),
+        (
+            keymap.pair_label(Action::PrevColor, Action::NextColor),
+            Text::PrevNextColor,
+        ),
+    ];
+    keys.extend(bands_counts.map(|counts| (counts.to_string(), Text::BandsCount)));
+    keys.push((
+        keymap.pair_label(Action::PrevTab, Action::NextTab),
+        Text::PrevNextTab,
+    ));
+    keys.push((keymap.label(Action::Exit), Text::Exit));
+    let help_spans = keys.into_iter().enumerate().flat_map(|(i, (key, text))| {
+        let separator = if i == 0 { "" } else { ", " };
+        [
+            Span::raw(separator),
+            Span::styled(key, Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}", lang.text(text))),
+        ]
+    });
+    let help_line = Line::from(help_spans.collect::<Vec<_>>());
+    let help_msg_rect = center_horizontal(chunks[3], help_line.width() as u16);
+    frame.render_widget(Paragraph::new(help_line), help_msg_rect);
+
+    let bands = bands.iter().collect::<Vec<_>>();
+    model.hit_areas.bands = render_band_lists(
+        frame,
+        &bands_rect,
+        &bands,
+        Some(selected_band),
+        semantic_info,
+        numeric_info,
+        &model.palette(),
+        lang,
+    );
+}
+
+/// The resistor lines of an imported BOM with their bands, over the selected tab.
+fn render_bom_popup(frame: &mut Frame, popup: &BomPopup, palette: &Palette, lang: Language) {
+    let area = frame.area().inner(Margin::new(2, 2));
+    let rect = Rect {
+        height: area.height.min(popup.lines.len() as u16 + 3),
+        ..area
+    };
+    let column_width = |width: fn(&BomLine) -> usize, title: &str| {
+        popup
+            .lines
+            .iter()
+            .map(width)
+            .chain([title.chars().count()])
+            .max()
+            .unwrap_or_default()
+            .min(24) as u16
+    };
+    let widths = [
+        Constraint::Length(column_width(
+            |l| l.line.to_string().len(),
+            lang.text(Text::Line),
+        )),
+        Constraint::Length(column_width(
+            |l| l.references.chars().count(),
+            lang.text(Text::References),
+        )),
+        Constraint::Length(column_width(
+            |l| l.value.chars().count(),
+            lang.text(Text::Value),
+        )),
+        Constraint::Length(3),
+        Constraint::Min(10),
+    ];
+    let error_style = if palette.monochrome {
+        Style::default().add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::Red)
+    };
+    let rows = popup.lines.iter().map(|line| {
+        let bands = match line.resistor() {
+            Ok(resistor) => Line::from(
+                resistor
+                    .bands()
+                    .into_iter()
+                    .flat_map(|color| [band_span(color, palette, lang), Span::raw(" ")])
+                    .collect::<Vec<_>>(),
+            ),
+            Err(e) => Line::styled(e, error_style),
+        };
+        Row::new([
+            Cell::from(line.line.to_string()),
+            Cell::from(line.references.as_str()),
+            Cell::from(line.value.as_str()),
+            Cell::from(line.quantity.map(|q| q.to_string()).unwrap_or_default()),
+            Cell::from(bands),
+        ])
+    });
+    let highlight_style = if palette.monochrome {
+        Style::default().add_modifier(Modifier::REVERSED)
+    } else {
+        palette.highlight
+    };
+    let name = popup
+        .path
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default();
+    let table = Table::new(rows, widths)
+        .header(
+            Row::new([
+                lang.text(Text::Line),
+                lang.text(Text::References),
+                lang.text(Text::Value),
+                lang.text(Text::Qty),
+                lang.text(Text::Bands),
+            ])
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+        )
+        .block(
+            Block::bordered().title(format!(" {} ", name)).title_bottom(
+                Line::from(format!(
+                    " Enter: {}, Esc: {} ",
+                    lang.text(Text::LoadIntoSpecs),
+                    lang.text(Text::Close)
+                ))
+                .right_aligned(),
+            ),
+        )
+        .row_highlight_style(highlight_style);
+    let mut state = TableState::default().with_selected(Some(popup.selected));
+    frame.render_widget(Clear, rect);
+    frame.render_stateful_widget(table, rect, &mut state);
+}
+
+/// The answers of all quiz runs by color and bands count, and the days practiced in a row.
+fn render_quiz_stats(
+    frame: &mut Frame,
+    area: Rect,
+    stats: &QuizStats,
+    palette: &Palette,
+    lang: Language,
+) {
+    let rects = Layout::vertical([Constraint::Length(3), Constraint::Min(1)]).split(area);
+    let table_rects = Layout::horizontal([Constraint::Ratio(1, 2); 2])
+        .spacing(1)
+        .split(rects[1]);
+    let summary = lang.fill(
+        Text::QuizStats,
+        &[
+            &stats.total.asked,
+            &format!("{:.0}", stats.total.accuracy()),
+            &format!("{:.1}", stats.total.mean_seconds()),
+            &stats.current_day_streak(quiz_stats::today()),
+            &stats.best_day_streak,
+        ],
+    );
+    frame.render_widget(
+        Paragraph::new(summary)
+            .block(Block::bordered().title(format!(" {} ", lang.text(Text::AllRuns)))),
+        rects[0],
+    );
+
+    let tally_cells = |tally: &Tally| {
+        [
+            Cell::from(tally.asked.to_string()),
+            Cell::from(format!("{:.0}%", tally.accuracy())),
+            Cell::from(format!("{:.1} s", tally.mean_seconds())),
+        ]
+    };
+    let widths = [
+        Constraint::Length(14),
+        Constraint::Length(7),
+        Constraint::Length(8),
+        Constraint::Length(8),
+    ];
+    let header = |first| {
+        Row::new([
+            first,
+            lang.text(Text::Answers),
+            lang.text(Text::CorrectTitle),
+            lang.text(Text::Time),
+        ])
+        .style(Style::default().add_modifier(Modifier::BOLD))
+    };
+    let color_rows = rusistor::Color::ALL.iter().filter_map(|color| {
+        let tally = stats.colors.get(color)?;
+        let mut cells = vec![Cell::from(band_span(color, palette, lang))];
+        cells.extend(tally_cells(tally));
+        Some(Row::new(cells))
+    });
+    frame.render_widget(
+        Table::new(color_rows, widths)
+            .header(header(lang.text(Text::ColorTitle)))
+            .block(Block::bordered().title(format!(" {} ", lang.text(Text::ByColor)))),
+        table_rects[0],
+    );
+    let bands_rows = stats.bands.iter().map(|(bands, tally)| {
+        let mut cells = vec![Cell::from(lang.fill(Text::BandsOf, &[bands]))];
+        cells.extend(tally_cells(tally));
+        Row::new(cells)
+    });
+    frame.render_widget(
+        Table::new(bands_rows, widths)
+            .header(header(lang.text(Text::Bands)))
+            .block(Block::bordered().title(format!(" {} ", lang.text(Text::ByBandsCount)))),
+        table_rects[1],
+    );
+}
+
+/// The last keys, messages and state transitions at the right, the newest at the bottom.
+fn render_debug_pane(frame: &mut Frame, debug_log: &VecDeque<String>, lang: Language) {
+    let area = frame.area();
+    let width = 50.min(area.width);
+    let height = (debug_log.len() as u16 + 2).min(area.height);
+    let rect = Rect::new(area.right() - width, area.y, width, height);
+    let skip = debug_log
+        .len()
+        .saturating_sub(height.saturating_sub(2) as usize);
+    let lines = debug_log
+        .iter()
+        .skip(skip)
+        .map(|entry| Line::from(entry.as_str()))
+        .collect::<Vec<_>>();
+    frame.render_widget(Clear, rect);
+    frame.render_widget(
+        Paragraph::new(lines).block(Block::bordered().title(format!(
+            " {}, F12: {} ",
+            lang.text(Text::Debug),
+            lang.text(Text::Hide)
+        ))),
+        rect,
+    );
+}
+
+/// The input of the command palette with the matching commands below it, below the tabs.
+fn render_command_palette(
+    frame: &mut Frame,
+    command_palette: &mut CommandPalette,
+    palette: &Palette,
+    lang: Language,
+) {
+    let area = frame.area();
+    let width = 60.min(area.width);
+    let x = area.x + (area.width - width) / 2;
+    let input_rect = Rect::new(x, area.y + 3, width, 3).intersection(area);
+    let bottom_title = match &command_palette.error {
+        Some(e) => Line::styled(
+            format!(" {} ", e),
+            if palette.monochrome {
+                Style::default().add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Red)
+            },
+        ),
+        None => Line::from(format!(
+            " Tab: {}, Enter: {}, Esc: {} ",
+            lang.text(Text::Complete),
+            lang.text(Text::Run),
+            lang.text(Text::Close)
+        )),
+    };
+    command_palette.textarea.set_block(
+        Block::bordered()
+            .title(format!(" {} ", lang.text(Text::Command)))
+            .title_bottom(bottom_title.right_aligned()),
+    );
+    command_palette
+        .textarea
+        .set_cursor_line_style(Style::default());
+    frame.render_widget(Clear, input_rect);
+    frame.render_widget(&command_palette.textarea, input_rect);
+
+    let matches = commands::matches(&command_palette.textarea.lines()[0]);
+    if matches.is_empty() {
+        return;
+    }
+    let items = matches.iter().map(|command| {
+        ListItem::new(Line::from(vec![
+            Span::raw(command.name),
+            Span::raw(" "),
+            Span::styled(command.args, Style::default().add_modifier(Modifier::DIM)),
+        ]))
+    });
+    let list_rect =
+        Rect::new(x, input_rect.bottom(), width, matches.len() as u16 + 2).intersection(area);
+    let highlight_style = if palette.monochrome {
+        Style::default().add_modifier(Modifier::REVERSED)
+    } else {
+        palette.highlight
+    };
+    let list = List::new(items)
+        .block(Block::bordered().title(" ↑/↓ "))
+        .highlight_symbol(">> ")
+        .highlight_style(highlight_style);
+    let mut state = ListState::default().with_selected(Some(command_palette.selected));
+    frame.render_widget(Clear, list_rect);
+    frame.render_stateful_widget(list, list_rect, &mut state);
+}
+
+/// The input of the value search with the matching values, their series and bands below it.
+fn render_value_search(
+    frame: &mut Frame,
+    search: &mut ValueSearch,
+    palette: &Palette,
+    lang: Language,
+) {
+    let area = frame.area();
+    let width = 76.min(area.width);
+    let x = area.x + (area.width - width) / 2;
+    let input_rect = Rect::new(x, area.y + 3, width, 3).intersection(area);
+    search.textarea.set_block(
+        Block::bordered()
+            .title(format!(" {} ", lang.text(Text::StandardValues)))
+            .title_bottom(
+                Line::from(format!(
+                    " Enter: {}, Esc: {} ",
+                    lang.text(Text::Load),
+                    lang.text(Text::Close)
+                ))
+                .right_aligned(),
+            ),
+    );
+    search.textarea.set_cursor_line_style(Style::default());
+    frame.render_widget(Clear, input_rect);
+    frame.render_widget(&search.textarea, input_rect);
+
+    let matches = value_search::matches(&search.textarea.lines()[0]);
+    if matches.is_empty() {
+        return;
+    }
+    let list_rect = Rect::new(
+        x,
+        input_rect.bottom(),
+        width,
+        area.bottom().saturating_sub(input_rect.bottom()),
+    )
+    .intersection(area);
+    // only the visible values are drawn, there are more than a thousand
+    let rows = (list_rect.height.saturating_sub(2) as usize).min(matches.len());
+    let skip = search.selected.saturating_sub(rows.saturating_sub(1));
+    let visible = matches
+        .iter()
+        .skip(skip)
+        .take(rows)
+        .map(|value| {
+            let series = value.series.iter().map(|s| s.to_string());
+            (value, series.collect::<Vec<_>>().join(" "))
+        })
+        .collect::<Vec<_>>();
+    let series_width = visible
+        .iter()
+        .map(|(_, series)| series.len())
+        .max()
+        .unwrap_or_default();
+    let items = visible.into_iter().map(|(value, series)| {
+        let mut spans = vec![Span::raw(format!(
+            "{:>9}  {:<width$} ",
+            format_ohm(value.ohm),
+            series,
+            width = series_width
+        ))];
+        if let Ok(resistor) = value.resistor() {
+            for color in resistor.bands() {
+                spans.push(Span::raw(" "));
+                spans.push(band_span(color, palette, lang));
+            }
+        }
+        ListItem::new(Line::from(spans))
+    });
+    let list_rect = Rect {
+        height: rows as u16 + 2,
+        ..list_rect
+    };
+    let highlight_style = if palette.monochrome {
+        Style::default().add_modifier(Modifier::REVERSED)
+    } else {
+        palette.highlight
+    };
+    let list = List::new(items)
+        .block(Block::bordered().title(format!(" ↑/↓ {}/{} ", search.selected + 1, matches.len())))
+        .highlight_symbol(">> ")
+        .highlight_style(highlight_style);
+    let mut state = ListState::default().with_selected(Some(search.selected - skip));
+    frame.render_widget(Clear, list_rect);
+    frame.render_stateful_widget(list, list_rect, &mut state);
+}
+
+/// The input for the name of a new favorite or the list of favorites, over the selected tab.
+fn render_favorites_popup(
+    frame: &mut Frame,
+    popup: &mut FavoritesPopup,
+    favorites: &[Favorite],
+    palette: &Palette,
+    lang: Language,
+) {
+    let centered = |width: u16, height: u16| {
+        let [rect] = Layout::vertical([Constraint::Length(height)])
+            .flex(Flex::Center)
+            .areas(frame.area());
+        let [rect] = Layout::horizontal([Constraint::Length(width)])
+            .flex(Flex::Center)
+            .areas(rect);
+        rect
+    };
+    match popup {
+        FavoritesPopup::Add { name_textarea, .. } => {
+            let rect = centered(50, 3);
+            name_textarea.set_block(
+                Block::bordered()
+                    .title(format!(" {} ", lang.text(Text::FavoriteName)))
+                    .title_bottom(
+                        Line::from(format!(
+                            " Enter: {}, Esc: {} ",
+                            lang.text(Text::Save),
+                            lang.text(Text::Cancel)
+                        ))
+                        .right_aligned(),
+                    ),
+            );
+            name_textarea.set_cursor_line_style(Style::default());
+            frame.render_widget(Clear, rect);
+            frame.render_widget(&**name_textarea, rect);
+        }
+        FavoritesPopup::List { selected } => {
+            let name_width = favorites
+                .iter()
+                .map(|f| f.name.chars().count())
+                .max()
+                .unwrap_or_default();
+            let items = favorites
+                .iter()
+                .map(|favorite| {
+                    let mut spans = vec![Span::raw(format!(
+                        "{:<width$} ",
+                        favorite.name,
+                        width = name_width
+                    ))];
+                    for color in &favorite.bands {
+                        spans.push(Span::raw(" "));
+                        spans.push(band_span(color, palette, lang));
+                    }
+                    ListItem::new(Line::from(spans))
+                })
+                .collect::<Vec<ListItem>>();
+            let width = items.iter().map(|i| i.width()).max().unwrap_or_default() as u16 + 5;
+            let height = items.len().max(1) as u16 + 2;
+            let rect = centered(width.max(46), height);
+            let highlight_style = if palette.monochrome {
+                Style::default().add_modifier(Modifier::REVERSED)
+            } else {
+                palette.highlight
+            };
+            let block = Block::bordered()
+                .title(format!(" {} ", lang.text(Text::Favorites)))
+                .title_bottom(
+                    Line::from(format!(
+                        " Enter: {}, Del: {}, Esc: {} ",
+                        lang.text(Text::Recall),
+                        lang.text(Text::Remove),
+                        lang.text(Text::Close)
+                    ))
+                    .right_aligned(),
+                );
+            let list = if items.is_empty() {
+                List::new([ListItem::new(format!(" {}", lang.text(Text::BookmarkHint)))])
+            } else {
+                List::new(items)
+            };
+            let mut state =
+                ListState::default().with_selected((!favorites.is_empty()).then_some(*selected));
+            frame.render_widget(Clear, rect);
+            frame.render_stateful_widget(
+                list.block(block)
+                    .highlight_symbol(">> ")
+                    .highlight_style(highlight_style),
+                rect,
+                &mut state,
+            );
+        }
+    }
+}
+
+fn chart_title<'a>(specs: &ResistorSpecs, lang: Language) -> Line<'a> {
+    Line::from(view::chart_title(specs, lang, NumberFormat::default())).centered()
+}
+
+/// Rounds to milliohms to hide the float noise of parallel combinations.
+fn round_ohm(ohm: f64) -> f64 {
+    (ohm * 1000.0).round() / 1000.0
+}
+
+fn format_capacitance(picofarad: f64) -> String {
+    if picofarad >= 1_000_000.0 {
+        format!("{}µF", round_significant(picofarad / 1_000_000.0, 6))
+    } else if picofarad >= 1_000.0 {
+        format!("{}nF", round_significant(picofarad / 1_000.0, 6))
+    } else {
+        format!("{}pF", picofarad)
+    }
+}
+
+pub(crate) fn format_combined(combined: &rusistor::network::Combined) -> String {
+    let tolerance = (combined.tolerance() * 10000.0).round() / 100.0;
+    format!("{}Ω ±{}%", round_ohm(combined.ohm), tolerance)
+}
+
+fn render_resistor_chart(
+    frame: &mut Frame,
+    area: Rect,
+    resistor: &rusistor::Resistor,
+    title: Line,
+    bar_width: u16,
+    palette: &Palette,
+    lang: Language,
+) {
+    let bands = resistor.bands();
+    if palette.monochrome || palette.accessible {
+        render_pattern_chart(frame, area, &bands, title, bar_width, palette, lang);
+    } else {
+        let chart_bands = chart_bands(&bands, lang);
+        frame.render_widget(barchart(&chart_bands, title, bar_width, palette), area);
+    }
+}
+
+/// The chart of a resistor rendered off screen, for exporting it.
+pub fn chart_buffer(resistor: &rusistor::Resistor, palette: &Palette, lang: Language) -> Buffer {
+    let bands = resistor.bands().len() as u16;
+    let width = bands * BAR_WIDTH + bands - 1 + 4;
+    let Ok(mut terminal) = Terminal::new(TestBackend::new(width, CHART_HEIGHT));
+    let specs = resistor.specs();
+    let _ = terminal.draw(|frame| {
+        let title = chart_title(&specs, lang);
+        render_resistor_chart(
+            frame,
+            frame.area(),
+            resistor,
+            title,
+            BAR_WIDTH,
+            palette,
+            lang,
+        );
+    });
+    terminal.backend().buffer().clone()
+}
+
+/// A resistor body with its bands as stripes, the tolerance band set apart.
+fn resistor_art(bands: &[&rusistor::Color], palette: &Palette) -> Vec<Line<'static>> {
+    let tolerance_idx = match bands.len() {
+        4 | 5 => Some(bands.len() - 1),
+        6 => Some(4),
+        _ => None,
+    };
+    let body_style = if palette.monochrome {
+        Style::default().add_modifier(Modifier::REVERSED)
+    } else {
+        Style::default().bg(Color::Rgb(0xd2, 0xb4, 0x8c))
+    };
+    let stripe = |color: &rusistor::Color| {
+        let pattern = band_pattern(color).to_string().repeat(2);
+        if palette.monochrome {
+            Span::raw(pattern)
+        } else if palette.accessible {
+            Span::styled(pattern, body_style.fg(palette.band_color(color)))
+        } else {
+            Span::styled("  ", Style::default().bg(palette.band_color(color)))
+        }
+    };
+    (0..3)
+        .map(|row| {
+            let lead = if row == 1 {
+                "━━━━━━"
+            } else {
+                "      "
+            };
+            let mut spans = vec![Span::raw(lead), Span::styled("  ", body_style)];
+            for (idx, color) in bands.iter().enumerate() {
+                let gap = if Some(idx) == tolerance_idx {
+                    "    "
+                } else {
+                    " "
+                };
+                if idx > 0 {
+                    spans.push(Span::styled(gap, body_style));
+                }
+                spans.push(stripe(color));
+            }
+            spans.push(Span::styled("  ", body_style));
+            spans.push(Span::raw(lead));
+            Line::from(spans)
+        })
+        .collect()
+}
+
+/// The bands and specs of a resistor of the compare tab.
+fn compare_lines(
+    resistor: &rusistor::Resistor,
+    palette: &Palette,
+    lang: Language,
+) -> Vec<Line<'static>> {
+    let specs = resistor.specs();
+    let mut lines = resistor_art(&resistor.bands(), palette);
+    lines.push(Line::default());
+    lines.push(Line::from(format!(
+        "{:<12}{}",
+        lang.text(Text::Resistance),
+        format_ohm(specs.ohm)
+    )));
+    lines.push(Line::from(format!(
+        "{:<12}±{}%",
+        lang.text(Text::Tolerance),
+        specs.tolerance * 100.0
+    )));
+    lines.push(Line::from(format!(
+        "{:<12}{} - {}",
+        lang.text(Text::Range),
+        format_ohm(specs.min_ohm),
+        format_ohm(specs.max_ohm)
+    )));
+    lines.push(Line::from(format!(
+        "{:<12}{}",
+        lang.text(Text::Tcr),
+        specs
+            .tcr
+            .map(|tcr| format!("{} ppm/K", tcr))
+            .unwrap_or_else(|| "-".to_string())
+    )));
+    lines
+}
+
+/// The prompt of the history search with the found inputs, like the reverse-i-search of shells.
+fn history_search_line<'a>(
+    search: &HistorySearch,
+    history: &SpecsHistory,
+    style: Style,
+    lang: Language,
+) -> Line<'a> {
+    let failed = !search.query.is_empty() && search.found.is_none();
+    let found = search
+        .found
+        .and_then(|idx| history.get(idx))
+        .map(|(resistance, tolerance, tcr)| {
+            [resistance, tolerance, tcr]
+                .iter()
+                .filter(|input| !input.is_empty())
+                .map(|input| input.as_str())
+                .collect::<Vec<&str>>()
+                .join(" ")
+        })
+        .unwrap_or_default();
+    Line::from(vec![
+        Span::raw(format!(
+            "({}reverse-i-search)`{}': ",
+            if failed { "failed " } else { "" },
+            search.query
+        )),
+        Span::styled(found, style),
+        Span::raw("   "),
+        Span::styled("Ctrl+R", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(format!(": {}, ", lang.text(Text::Older))),
+        Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(format!(": {}, ", lang.text(Text::Accept))),
+        Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(format!(": {}", lang.text(Text::Cancel))),
+    ])
+}
+
+/// The nearest E12, E24 and E96 values with their bands and the width they need,
+/// `None` for a value without standard values, e.g. 0 Ω.
+fn standard_values(
+    ohm: f64,
+    palette: &Palette,
+    lang: Language,
+) -> Option<(Paragraph<'static>, u16)> {
+    let lines = [ESeries::E12, ESeries::E24, ESeries::E96]
+        .iter()
+        .map(|series| {
+            let value = series.nearest(ohm)?;
+            let mut spans = vec![Span::raw(format!(
+                "{:<4}{:>9} ±{:<3} ",
+                series.to_string(),
+                format_ohm(value),
+                format!("{}%", series.tolerance())
+            ))];
+            if let Ok(resistor) = series.resistor(value) {
+                for color in resistor.bands() {
+                    spans.push(Span::raw(" "));
+                    spans.push(band_span(color, palette, lang));
+                }
+            }
+            Ok(Line::from(spans))
+        })
+        .collect::<Result<Vec<Line>, String>>()
+        .ok()?;
+    let width = lines.iter().map(|l| l.width()).max().unwrap_or_default() as u16 + 4;
+    let paragraph = Paragraph::new(lines).block(
+        Block::bordered()
+            .title(format!(" {} ", lang.text(Text::NearestStandardValues)))
+            .padding(Padding::horizontal(1)),
+    );
+    Some((paragraph, width))
+}
+
+/// The name of a band color, on its color unless in monochrome mode.
+fn band_span(color: &rusistor::Color, palette: &Palette, lang: Language) -> Span<'static> {
+    let name = lang.color(color);
+    if palette.monochrome {
+        return Span::raw(format!("{} {}", band_code(color), name));
+    }
+    let text = if palette.accessible {
+        format!(" {} {} ", band_code(color), name)
+    } else {
+        format!(" {} ", name)
+    };
+    let bg = palette.band_color(color);
+    let fg = if bg == Color::Black {
+        Color::White
+    } else {
+        Color::Black
+    };
+    Span::styled(text, Style::default().bg(bg).fg(fg))
+}
+
+fn chart_block(title: Line<'_>) -> Block<'_> {
+    Block::new()
+        .padding(Padding::new(1, 1, 1, 1))
+        .title(title)
+        .borders(Borders::all())
+}
+
+fn barchart<'a>(
+    chart_bands: &[ChartBand],
+    title: Line<'a>,
+    bar_width: u16,
+    palette: &Palette,
+) -> BarChart<'a> {
+    let bars: Vec<Bar> = chart_bands
+        .iter()
+        .map(|band| bar(band, palette.band_color(&band.color)))
+        .collect();
+    BarChart::default()
+        .data(BarGroup::default().bars(&bars))
+        .block(chart_block(title))
+        .bar_width(bar_width)
+        .bar_gap(1)
+}
+
+/// Counterpart of the bar chart, the bands are filled with their patterns.
+fn render_pattern_chart(
+    frame: &mut Frame,
+    area: Rect,
+    bands: &[&rusistor::Color],
+    title: Line,
+    bar_width: u16,
+    palette: &Palette,
+    lang: Language,
+) {
+    let block = chart_block(title);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let columns = Layout::horizontal(vec![Constraint::Length(bar_width); bands.len()])
+        .spacing(1)
+        .split(inner);
+    let fill_height = inner.height.saturating_sub(2) as usize;
+    for (band, rect) in chart_bands(bands, lang).iter().zip(columns.iter()) {
+        let color = &band.color;
+        let fill = band_pattern(color).to_string().repeat(bar_width as usize);
+        let (fill_style, name_style) = if palette.monochrome {
+            (Style::default(), Style::default())
+        } else {
+            (
+                Style::default().fg(palette.band_color(color)),
+                Style::default().add_modifier(Modifier::BOLD),
+            )
+        };
+        let mut lines = vec![Line::styled(fill, fill_style); fill_height];
+        lines.push(
+            Line::styled(
+                format!("{} {}", band_code(color), band.name.to_uppercase()),
+                name_style,
+            )
+            .centered(),
+        );
+        lines.push(Line::from(band.label()).centered());
+        frame.render_widget(Paragraph::new(lines), *rect);
+    }
+}
+
+fn bar<'a>(band: &ChartBand, color: Color) -> Bar<'a> {
+    Bar::default()
+        .value(100)
+        .text_value(format!(" {} ", band.name))
+        .value_style(Style::default().fg(Color::White).bg(Color::Black))
+        .label(Line::from(band.label()))
+        .style(Style::new().fg(color))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::view;
+    use crate::{
+        bom,
+        model::{BomPopup, Model},
+        update::{Msg, ValueSearchMsg, update},
+    };
+    use ratatui::{Terminal, backend::TestBackend};
+    use rusistor::{Color, Resistor};
+    use std::{path::PathBuf, time::Duration};
+    use tusistor_core::{
+        i18n::Language,
+        model::SelectedTab,
+        update::{ColorCodesMsg, Msg as SharedMsg, SpecsMsg},
+    };
+
+    const SIZES: [(u16, u16); 3] = [(80, 24), (120, 40), (60, 20)];
+
+    fn render(model: &mut Model, width: u16, height: u16) -> TestBackend {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal.draw(|frame| view(model, frame)).unwrap();
+        terminal.backend().clone()
+    }
+
+    #[test]
+    fn test_color_codes_view() {
+        for msg in [
+            ColorCodesMsg::ThreeBands,
+            ColorCodesMsg::FourBands,
+            ColorCodesMsg::FiveBands,
+            ColorCodesMsg::SixBands,
+        ] {
+            let mut model = Model::default();
+            let name = format!("color_codes_{:?}", msg);
+            update(
+                &mut model,
+                Msg::Shared {
+                    msg: SharedMsg::ColorCodesMsg { msg },
+                },
+            );
+            for (width, height) in SIZES {
+                insta::assert_snapshot!(
+                    format!("{}_{}x{}", name, width, height),
+                    render(&mut model, width, height)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_scrolled_band_lists_view() {
+        let mut model = Model::default();
+        update(
+            &mut model,
+            Msg::Shared {
+                msg: SharedMsg::ColorCodesMsg {
+                    msg: ColorCodesMsg::SelectColor {
+                        band_idx: 0,
+                        color: Color::White,
+                    },
+                },
+            },
+        );
+        insta::assert_snapshot!(render(&mut model, 80, 24));
+    }
+
+    #[test]
+    fn test_color_details_view() {
+        let mut model = Model {
+            color_details: true,
+            ..Model::default()
+        };
+        update(
+            &mut model,
+            Msg::Shared {
+                msg: SharedMsg::ColorCodesMsg {
+                    msg: ColorCodesMsg::ThreeBands,
+                },
+            },
+        );
+        insta::assert_snapshot!(render(&mut model, 140, 40));
+    }
+
+    #[test]
+    fn test_temperature_view() {
+        let mut model = Model::default();
+        for _ in 0..12 {
+            update(
+                &mut model,
+                Msg::Shared {
+                    msg: SharedMsg::ColorCodesMsg {
+                        msg: ColorCodesMsg::Warmer,
+                    },
+                },
+            );
+        }
+        insta::assert_snapshot!(render(&mut model, 140, 40));
+    }
+
+    #[test]
+    fn test_measured_view() {
+        let mut model = Model::default();
+        update(
+            &mut model,
+            Msg::Paste {
+                text: "100.5".to_string(),
+            },
+        );
+        insta::assert_snapshot!(render(&mut model, 120, 40));
+    }
+
+    #[test]
+    fn test_power_view() {
+        let mut model = Model::default();
+        update(
+            &mut model,
+            Msg::Shared {
+                msg: SharedMsg::ColorCodesMsg {
+                    msg: ColorCodesMsg::SetPowerRating { watts: Some(1.0) },
+                },
+            },
+        );
+        for (width, height) in SIZES {
+            insta::assert_snapshot!(
+                format!("power_{}x{}", width, height),
+                render(&mut model, width, height)
+            );
+        }
+    }
+
+    #[test]
+    fn test_german_view() {
+        let mut model = Model {
+            language: Language::De,
+            ..Default::default()
+        };
+        update(
+            &mut model,
+            Msg::Shared {
+                msg: SharedMsg::ColorCodesMsg {
+                    msg: ColorCodesMsg::SetPowerRating { watts: Some(1.0) },
+                },
+            },
+        );
+        insta::assert_snapshot!(render(&mut model, 120, 40));
+    }
+
+    #[test]
+    fn test_value_search_view() {
+        let mut model = Model::default();
+        update(
+            &mut model,
+            Msg::ValueSearchMsg {
+                msg: ValueSearchMsg::Open,
+            },
+        );
+        update(
+            &mut model,
+            Msg::Paste {
+                text: "4.7k".to_string(),
+            },
+        );
+        insta::assert_snapshot!(render(&mut model, 80, 24));
+    }
+
+    #[test]
+    fn test_bom_view() {
+        let mut model = Model {
+            bom_popup: Some(BomPopup {
+                path: PathBuf::from("bom.csv"),
+                lines: bom::parse("Refs;Value;Qty\nR1,R4;4k7;2\nR2;10R 1%;1\nR5;DNP;1\n").unwrap(),
+                selected: 1,
+            }),
+            ..Default::default()
+        };
+        insta::assert_snapshot!(render(&mut model, 80, 24));
+    }
+
+    #[test]
+    fn test_quiz_stats_view() {
+        let mut model = Model {
+            selected_tab: SelectedTab::Quiz,
+            show_quiz_stats: true,
+            ..Default::default()
+        };
+        let question = Resistor::try_create(vec![Color::Red, Color::Red, Color::Brown]).unwrap();
+        let elapsed = Duration::from_secs(3);
+        model.quiz_stats.record(&question, true, elapsed, 0);
+        model.quiz_stats.record(&question, false, elapsed, 1);
+        insta::assert_snapshot!(render(&mut model, 80, 24));
+    }
+
+    #[test]
+    fn test_specs_view() {
+        let mut model = Model {
+            selected_tab: SelectedTab::SpecsToColorCodes,
+            ..Default::default()
+        };
+        for (width, height) in SIZES {
+            insta::assert_snapshot!(
+                format!("specs_empty_{}x{}", width, height),
+                render(&mut model, width, height)
+            );
+        }
+
+        update(
+            &mut model,
+            Msg::Paste {
+                text: "4k7".to_string(),
+            },
+        );
+        update(
+            &mut model,
+            Msg::Shared {
+                msg: SharedMsg::SpecsMsg {
+                    msg: SpecsMsg::Determine,
+                },
+            },
+        );
+        for bands in ["fewest", "4", "5", "6"] {
+            for (width, height) in SIZES {
+                insta::assert_snapshot!(
+                    format!("specs_4k7_{}_{}x{}", bands, width, height),
+                    render(&mut model, width, height)
+                );
+            }
+            update(
+                &mut model,
+                Msg::Shared {
+                    msg: SharedMsg::SpecsMsg {
+                        msg: SpecsMsg::NextBandsCount,
+                    },
+                },
+            );
+        }
+    }
+
+    #[test]
+    fn test_specs_error_view() {
+        let mut model = Model {
+            selected_tab: SelectedTab::SpecsToColorCodes,
+            ..Default::default()
+        };
+        update(
+            &mut model,
+            Msg::Paste {
+                text: "4x7".to_string(),
+            },
+        );
+        update(
+            &mut model,
+            Msg::Shared {
+                msg: SharedMsg::SpecsMsg {
+                    msg: SpecsMsg::Determine,
+                },
+            },
+        );
+        insta::assert_snapshot!(render(&mut model, 80, 24));
+    }
+
+    #[test]
+    fn test_zero_ohm_specs_view() {
+        let mut model = Model {
+            selected_tab: SelectedTab::SpecsToColorCodes,
+            ..Default::default()
+        };
+        update(
+            &mut model,
+            Msg::Paste {
+                text: "0".to_string(),
+            },
+        );
+        update(
+            &mut model,
+            Msg::Shared {
+                msg: SharedMsg::SpecsMsg {
+                    msg: SpecsMsg::Determine,
+                },
+            },
+        );
+        insta::assert_snapshot!(render(&mut model, 80, 24));
+    }
+
+    #[test]
+    fn test_band_code_tabs_view() {
+        for tab in [SelectedTab::Capacitor, SelectedTab::Inductor] {
+            let mut model = Model {
+                selected_tab: tab,
+                ..Model::default()
+            };
+            insta::assert_snapshot!(format!("{}_80x24", tab.name()), render(&mut model, 80, 24));
+        }
     }
 }